@@ -0,0 +1,698 @@
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Joshua Booth <joshua.n.booth@gmail.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! This Rust module contains a cache in OUT_DIR of the files generated by the build script,
+//! so that the work for inputs that are unchanged since the previous run can be skipped.
+//!
+//! Entries are keyed by a SHA-256 digest of the contents of their inputs rather than
+//! modification times, as the generated headers are rewritten on every run.
+
+use std::{
+    cell::RefCell,
+    collections::{HashMap, HashSet},
+    env,
+    ffi::OsString,
+    hash::{Hash, Hasher},
+    io::Write,
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex, OnceLock,
+    },
+    time::SystemTime,
+};
+
+use qt_build_utils::{Initializer, MocArguments, MocProducts, QtBuild};
+use sha2::{Digest, Sha256};
+
+use crate::diagnostics::Diagnostic;
+
+/// Feeds the values hashed into a [CacheKey] into a SHA-256 digest
+#[derive(Clone, Default)]
+struct DigestHasher(Sha256);
+
+impl Hasher for DigestHasher {
+    fn write(&mut self, bytes: &[u8]) {
+        self.0.update(bytes);
+    }
+
+    fn finish(&self) -> u64 {
+        let digest = self.0.clone().finalize();
+        u64::from_le_bytes(digest[..8].try_into().unwrap())
+    }
+}
+
+/// A digest of the inputs of a cache entry
+#[derive(Clone)]
+pub(crate) struct CacheKey(DigestHasher);
+
+impl CacheKey {
+    /// Create a key for the given kind of entry
+    pub fn new(kind: &str) -> Self {
+        let mut hasher = DigestHasher::default();
+        // Generated code may differ between versions of cxx-qt-build
+        env!("CARGO_PKG_VERSION").hash(&mut hasher);
+        kind.hash(&mut hasher);
+        Self(hasher)
+    }
+
+    /// Add a value to the key
+    pub fn value(mut self, value: impl Hash) -> Self {
+        value.hash(&mut self.0);
+        self
+    }
+
+    /// Add the path and contents of a file to the key
+    pub fn file(mut self, path: &Path) -> Self {
+        path.hash(&mut self.0);
+        std::fs::read(path).ok().hash(&mut self.0);
+        self
+    }
+
+    /// Add the code generators compiled into the build script to the key
+    ///
+    /// The exact versions of cxx-qt-gen and cxx-gen are not known at compile time and may change
+    /// without cxx-qt-build changing, so the executable of the build script they are linked into
+    /// is hashed instead. If it cannot be read, the entry is never reused.
+    pub fn generators(self) -> Self {
+        static GENERATORS: OnceLock<Vec<u8>> = OnceLock::new();
+        let digest = GENERATORS.get_or_init(|| match env::current_exe().and_then(std::fs::read) {
+            Ok(executable) => Sha256::digest(executable).to_vec(),
+            Err(_) => format!("{:?}", SystemTime::now()).into_bytes(),
+        });
+        self.value(digest)
+    }
+
+    /// Add the `cfg` and feature values of the crate to the key, as these change the generated code
+    pub fn cfgs(self) -> Self {
+        let mut cfgs = env::vars()
+            .filter(|(key, _)| key.starts_with("CARGO_CFG_") || key.starts_with("CARGO_FEATURE_"))
+            .collect::<Vec<_>>();
+        cfgs.sort();
+        self.value(cfgs)
+    }
+
+    fn name(&self) -> String {
+        self.0
+             .0
+            .clone()
+            .finalize()
+            .iter()
+            .map(|byte| format!("{byte:02x}"))
+            .collect()
+    }
+}
+
+/// The files and values stored for a [CacheKey]
+#[derive(Default)]
+pub(crate) struct CacheEntry {
+    /// The generated files, which are restored to their paths when the entry is found
+    pub files: Vec<PathBuf>,
+    /// Any other results, such as the names claimed by a bridge
+    pub values: Vec<(String, String)>,
+}
+
+impl CacheEntry {
+    /// The values with the given name
+    pub fn values<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a str> + 'a {
+        self.values
+            .iter()
+            .filter(move |(value_name, _)| value_name == name)
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+const ENTRY_FILE_NAME: &str = "entry.txt";
+
+/// A content-hash based cache of generated files in OUT_DIR
+pub(crate) struct BuildCache {
+    dir: PathBuf,
+    // Entries which were used in this run, any others are removed by BuildCache::prune
+    used: RefCell<HashSet<PathBuf>>,
+}
+
+impl BuildCache {
+    pub fn new() -> Self {
+        Self::in_dir(crate::dir::out().join("cxx-qt-cache"))
+    }
+
+    fn in_dir(dir: PathBuf) -> Self {
+        Self {
+            dir,
+            used: RefCell::default(),
+        }
+    }
+
+    fn entry_dir(&self, kind: &str, key: &CacheKey) -> PathBuf {
+        let entry_dir = self.dir.join(kind).join(key.name());
+        self.used.borrow_mut().insert(entry_dir.clone());
+        entry_dir
+    }
+
+    /// Find the entry for a key, restoring its files if they are missing or differ
+    pub fn get(&self, kind: &str, key: &CacheKey) -> Option<CacheEntry> {
+        let entry_dir = self.entry_dir(kind, key);
+        let contents = std::fs::read_to_string(entry_dir.join(ENTRY_FILE_NAME)).ok()?;
+
+        let mut entry = CacheEntry::default();
+        for line in contents.lines() {
+            match line.split_once('\t')? {
+                ("file", path) => entry.files.push(PathBuf::from(path)),
+                ("value", value) => {
+                    let (name, value) = value.split_once('\t')?;
+                    entry.values.push((name.to_owned(), value.to_owned()));
+                }
+                _ => return None,
+            }
+        }
+
+        for (index, path) in entry.files.iter().enumerate() {
+            let cached = std::fs::read(entry_dir.join(index.to_string())).ok()?;
+            if std::fs::read(path).is_ok_and(|existing| existing == cached) {
+                continue;
+            }
+            if let Some(parent) = path.parent() {
+                std::fs::create_dir_all(parent).ok()?;
+            }
+            std::fs::write(path, cached).ok()?;
+        }
+        Some(entry)
+    }
+
+    /// Store the entry for a key, copying its files into the cache
+    pub fn insert(&self, kind: &str, key: &CacheKey, entry: &CacheEntry) {
+        let entry_dir = self.entry_dir(kind, key);
+        std::fs::create_dir_all(&entry_dir).expect("Could not create cache directory");
+
+        let mut contents = String::new();
+        for (index, path) in entry.files.iter().enumerate() {
+            std::fs::copy(path, entry_dir.join(index.to_string()))
+                .unwrap_or_else(|_| panic!("Could not cache {}", path.display()));
+            contents.push_str(&format!("file\t{}\n", path.display()));
+        }
+        for (name, value) in &entry.values {
+            contents.push_str(&format!("value\t{name}\t{value}\n"));
+        }
+        // The entry file is written last, so that an interrupted build does not leave behind
+        // an entry with missing files
+        std::fs::write(entry_dir.join(ENTRY_FILE_NAME), contents)
+            .expect("Could not write cache entry");
+    }
+
+    /// Run moc on a header, unless its output for the same contents and arguments is cached
+    pub fn moc(
+        &self,
+        qtbuild: &mut QtBuild,
+        header: &Path,
+        arguments: MocArguments,
+    ) -> MocProducts {
+        let key = CacheKey::new("moc")
+            .value(qtbuild.version().to_string())
+            .value(&arguments)
+            .file(header);
+        if let Some(CacheEntry { files, .. }) = self.get("moc", &key) {
+            if let [cpp, metatypes_json] = &files[..] {
+                return MocProducts {
+                    cpp: cpp.clone(),
+                    metatypes_json: metatypes_json.clone(),
+                };
+            }
+        }
+
+        let moc_products = match qtbuild.moc().try_compile(header, arguments) {
+            Ok(moc_products) => moc_products,
+            Err(err) => {
+                Diagnostic::from(err).report();
+                std::process::exit(1);
+            }
+        };
+        self.insert(
+            "moc",
+            &key,
+            &CacheEntry {
+                files: vec![
+                    moc_products.cpp.clone(),
+                    moc_products.metatypes_json.clone(),
+                ],
+                ..Default::default()
+            },
+        );
+        moc_products
+    }
+
+    /// Run rcc on a .qrc file, unless its output for the same contents of the .qrc file and the
+    /// files listed in it is cached. The listed files are returned along with the initializer.
+    pub fn rcc(&self, qtbuild: &QtBuild, qrc_file: &Path) -> (Initializer, Vec<PathBuf>) {
+        let rcc = qtbuild.rcc();
+        let inputs = rcc.list(qrc_file);
+        let key = inputs.iter().fold(
+            CacheKey::new("rcc")
+                .value(qtbuild.version().to_string())
+                .file(qrc_file),
+            |key, input| key.file(input),
+        );
+
+        if let Some(entry) = self.get("rcc", &key) {
+            let initializer = Initializer {
+                file: entry.files.first().cloned(),
+                init_call: entry.values("init_call").next().map(str::to_owned),
+                init_declaration: entry.values("init_declaration").next().map(str::to_owned),
+            };
+            return (initializer, inputs);
+        }
+
+        let initializer = match rcc.try_compile(qrc_file) {
+            Ok(initializer) => initializer,
+            Err(err) => {
+                Diagnostic::from(err).report();
+                std::process::exit(1);
+            }
+        };
+        let values = [
+            ("init_call", &initializer.init_call),
+            ("init_declaration", &initializer.init_declaration),
+        ]
+        .into_iter()
+        .filter_map(|(name, value)| Some((name.to_owned(), value.clone()?)))
+        .collect();
+        self.insert(
+            "rcc",
+            &key,
+            &CacheEntry {
+                files: initializer.file.iter().cloned().collect(),
+                values,
+            },
+        );
+        (initializer, inputs)
+    }
+
+    /// Compile the builder into a static library, where the given generated sources are compiled
+    /// separately into cached object files.
+    ///
+    /// The object file of a source is reused if the source, the contents of the headers it
+    /// includes from the include paths of the builder, the compiler flags and the Qt version are
+    /// unchanged. Headers within the `external_include_paths`, such as those of Qt, are not
+    /// scanned, as they are covered by the Qt version.
+    ///
+    /// Any other files of the builder, such as those added by [crate::CxxQtBuilder::cc_builder],
+    /// are compiled as usual.
+    pub fn compile(
+        &self,
+        qtbuild: &QtBuild,
+        builder: &cc::Build,
+        sources: &[PathBuf],
+        external_include_paths: &[PathBuf],
+        lib_name: &str,
+    ) {
+        if sources.is_empty() {
+            // Only compile if we have added files to the builder
+            // otherwise we end up with no static library but ask cargo to link to it which causes an error
+            if builder.get_files().count() > 0 {
+                builder.compile(lib_name);
+            }
+            return;
+        }
+
+        let compiler = builder.get_compiler();
+        let object_dir = self.dir.join("objects").join(lib_name);
+        std::fs::create_dir_all(&object_dir).expect("Could not create cache directory");
+        let object_extension = if compiler.is_like_msvc() { "obj" } else { "o" };
+
+        let mut scanner = IncludeScanner::new(compiler.args(), external_include_paths);
+        let compiler_key = CacheKey::new("object")
+            .value(qtbuild.version().to_string())
+            .value(compiler.path())
+            .value(compiler.args())
+            .value(compiler.env())
+            .value(&scanner.include_paths)
+            .value(external_include_paths);
+
+        let mut objects = Vec::with_capacity(sources.len());
+        let mut misses = vec![];
+        for source in sources {
+            let key = scanner.object_key(&compiler_key, source);
+            let object = object_dir.join(format!("{}.{object_extension}", key.name()));
+            self.used.borrow_mut().insert(object.clone());
+            if !object.exists() {
+                misses.push((source.as_path(), object.clone()));
+            }
+            objects.push(object);
+        }
+
+        compile_objects(&compiler, &misses);
+
+        let mut builder = builder.clone();
+        builder.objects(objects).compile(lib_name);
+    }
+
+    /// Remove the entries and object files next to the ones used in this run, which are stale
+    pub fn prune(&self) {
+        let used = self.used.borrow();
+        let parents = used
+            .iter()
+            .filter_map(|path| path.parent())
+            .collect::<HashSet<_>>();
+        for parent in parents {
+            let Ok(read_dir) = std::fs::read_dir(parent) else {
+                continue;
+            };
+            for path in read_dir.filter_map(Result::ok).map(|entry| entry.path()) {
+                if used.contains(&path) {
+                    continue;
+                }
+                // This is best effort, a stale entry is only wasted space
+                if path.is_dir() {
+                    let _ = std::fs::remove_dir_all(&path);
+                } else {
+                    let _ = std::fs::remove_file(&path);
+                }
+            }
+        }
+    }
+}
+
+/// Compile each source into its object file, running as many compilers in parallel as cargo's
+/// jobserver allows
+fn compile_objects(compiler: &cc::Tool, objects: &[(&Path, PathBuf)]) {
+    let jobs = env::var("NUM_JOBS")
+        .ok()
+        .and_then(|jobs| jobs.parse::<usize>().ok())
+        .or_else(|| std::thread::available_parallelism().ok().map(usize::from))
+        .unwrap_or(1)
+        .clamp(1, objects.len().max(1));
+    // SAFETY: the file descriptors of the jobserver are inherited from cargo and are not closed
+    // by the build script. If cargo does not provide a jobserver, NUM_JOBS is used instead.
+    let jobserver = unsafe { jobserver::Client::from_env() }
+        .map_or_else(|| jobserver::Client::new(jobs - 1), Ok)
+        .expect("Could not create jobserver");
+
+    let next = AtomicUsize::new(0);
+    let errors = Mutex::new(vec![]);
+    std::thread::scope(|scope| {
+        for job in 0..jobs {
+            let jobserver = &jobserver;
+            let next = &next;
+            let errors = &errors;
+            scope.spawn(move || {
+                while next.load(Ordering::SeqCst) < objects.len() {
+                    // The build script itself holds a token, which is used by the first job
+                    let _token = if job > 0 {
+                        match jobserver.acquire() {
+                            Ok(token) => Some(token),
+                            Err(_) => return,
+                        }
+                    } else {
+                        None
+                    };
+                    let Some((source, object)) = objects.get(next.fetch_add(1, Ordering::SeqCst))
+                    else {
+                        return;
+                    };
+                    if let Err(error) = compile_object(compiler, source, object) {
+                        errors.lock().unwrap().push(error);
+                    }
+                }
+            });
+        }
+    });
+
+    let errors = errors.into_inner().unwrap();
+    if !errors.is_empty() {
+        panic!("Failed to compile C++ sources:\n{}", errors.join("\n"));
+    }
+}
+
+fn compile_object(compiler: &cc::Tool, source: &Path, object: &Path) -> Result<(), String> {
+    // Compile into a temporary file, so that a failed or interrupted compile is not cached
+    let mut partial = object.as_os_str().to_owned();
+    partial.push(".partial");
+    let partial = PathBuf::from(partial);
+
+    let mut cmd = compiler.to_command();
+    if compiler.is_like_msvc() {
+        let mut output = OsString::from("-Fo");
+        output.push(&partial);
+        cmd.arg(output);
+    } else {
+        cmd.arg("-o").arg(&partial);
+    }
+    cmd.arg("-c");
+    if compiler.is_like_clang_cl() {
+        cmd.arg("--");
+    }
+    cmd.arg(source);
+
+    let output = cmd
+        .output()
+        .map_err(|error| format!("{}: {error}", source.display()))?;
+    if !output.status.success() {
+        let _ = std::fs::remove_file(&partial);
+        return Err(format!(
+            "{}:\n{}{}",
+            source.display(),
+            String::from_utf8_lossy(&output.stdout),
+            String::from_utf8_lossy(&output.stderr)
+        ));
+    }
+    // Warnings are kept in the output of the build script, rather than repeating every line
+    // of them as a cargo warning
+    std::io::stderr().write_all(&output.stderr).ok();
+    std::fs::rename(&partial, object).map_err(|error| format!("{}: {error}", object.display()))
+}
+
+/// Finds the headers included by a source within the include paths of a compiler
+struct IncludeScanner {
+    include_paths: Vec<PathBuf>,
+    // The resolved includes of each file which was scanned
+    includes: HashMap<PathBuf, Vec<PathBuf>>,
+}
+
+impl IncludeScanner {
+    fn new(args: &[OsString], external_include_paths: &[PathBuf]) -> Self {
+        let mut include_paths = vec![];
+        let mut args = args.iter().map(|arg| arg.to_string_lossy());
+        while let Some(arg) = args.next() {
+            let path = match ["-I", "/I", "-isystem"]
+                .iter()
+                .find_map(|flag| arg.strip_prefix(flag))
+            {
+                Some("") => args.next().map(|path| path.into_owned()),
+                Some(path) => Some(path.to_owned()),
+                None => None,
+            };
+            if let Some(path) = path.map(PathBuf::from) {
+                if !external_include_paths
+                    .iter()
+                    .any(|external| path.starts_with(external))
+                {
+                    include_paths.push(path);
+                }
+            }
+        }
+
+        Self {
+            include_paths,
+            includes: HashMap::new(),
+        }
+    }
+
+    /// The key of the object file of a source, including the contents of its headers
+    fn object_key(&mut self, compiler_key: &CacheKey, source: &Path) -> CacheKey {
+        self.includes(source)
+            .iter()
+            .fold(compiler_key.clone().file(source), |key, include| {
+                key.file(include)
+            })
+    }
+
+    /// All headers included by the source, directly or indirectly, in a stable order
+    fn includes(&mut self, source: &Path) -> Vec<PathBuf> {
+        let mut found = vec![];
+        let mut visited = HashSet::from([source.to_owned()]);
+        let mut queue = vec![source.to_owned()];
+        while let Some(file) = queue.pop() {
+            for include in self.direct_includes(&file) {
+                if visited.insert(include.clone()) {
+                    found.push(include.clone());
+                    queue.push(include);
+                }
+            }
+        }
+        found
+    }
+
+    fn direct_includes(&mut self, file: &Path) -> Vec<PathBuf> {
+        if let Some(includes) = self.includes.get(file) {
+            return includes.clone();
+        }
+
+        let contents = std::fs::read(file).unwrap_or_default();
+        let includes = String::from_utf8_lossy(&contents)
+            .lines()
+            .filter_map(|line| {
+                let directive = line.trim_start().strip_prefix('#')?.trim_start();
+                let target = directive.strip_prefix("include")?.trim();
+                let (quoted, name) = if let Some(name) = target.strip_prefix('"') {
+                    (true, name.split_once('"')?.0)
+                } else {
+                    (false, target.strip_prefix('<')?.split_once('>')?.0)
+                };
+                self.resolve(file, name, quoted)
+            })
+            .collect::<Vec<_>>();
+        self.includes.insert(file.to_owned(), includes.clone());
+        includes
+    }
+
+    fn resolve(&self, file: &Path, name: &str, quoted: bool) -> Option<PathBuf> {
+        let name = Path::new(name);
+        if name.is_absolute() {
+            return name.is_file().then(|| name.to_owned());
+        }
+        quoted
+            .then(|| file.parent().map(|parent| parent.join(name)))
+            .flatten()
+            .into_iter()
+            .chain(self.include_paths.iter().map(|path| path.join(name)))
+            .find(|path| path.is_file())
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    /// A temporary directory for a test, which is removed when dropped
+    pub(crate) struct TestDir(pub(crate) PathBuf);
+
+    impl TestDir {
+        pub(crate) fn new(name: &str) -> Self {
+            let dir = env::temp_dir().join(format!("cxx-qt-build-{name}-{}", std::process::id()));
+            let _ = std::fs::remove_dir_all(&dir);
+            std::fs::create_dir_all(&dir).unwrap();
+            Self(dir)
+        }
+
+        pub(crate) fn write(&self, name: &str, contents: &str) -> PathBuf {
+            let path = self.0.join(name);
+            if let Some(parent) = path.parent() {
+                std::fs::create_dir_all(parent).unwrap();
+            }
+            std::fs::write(&path, contents).unwrap();
+            path
+        }
+    }
+
+    impl Drop for TestDir {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_dir_all(&self.0);
+        }
+    }
+
+    #[test]
+    fn cache_hit_and_miss() {
+        let dir = TestDir::new("cache-hit");
+        let input = dir.write("input.h", "class A;");
+        let output = dir.write("output.cpp", "generated");
+        let cache = BuildCache::in_dir(dir.0.join("cache"));
+
+        let key = CacheKey::new("test").value("argument").file(&input);
+        assert!(cache.get("test", &key).is_none());
+
+        cache.insert(
+            "test",
+            &key,
+            &CacheEntry {
+                files: vec![output.clone()],
+                values: vec![("name".to_owned(), "value".to_owned())],
+            },
+        );
+        // The cached file is restored when it was changed or removed
+        std::fs::remove_file(&output).unwrap();
+        let entry = cache.get("test", &key).unwrap();
+        assert_eq!(entry.files, vec![output.clone()]);
+        assert_eq!(entry.values("name").collect::<Vec<_>>(), vec!["value"]);
+        assert_eq!(std::fs::read_to_string(&output).unwrap(), "generated");
+
+        // A different kind of entry or value is a miss
+        assert!(cache.get("other", &key).is_none());
+        let other_key = CacheKey::new("test").value("other").file(&input);
+        assert!(cache.get("test", &other_key).is_none());
+    }
+
+    #[test]
+    fn cache_invalidation() {
+        let dir = TestDir::new("cache-invalidation");
+        let input = dir.write("input.h", "class A;");
+        let cache = BuildCache::in_dir(dir.0.join("cache"));
+
+        let key = CacheKey::new("test").file(&input);
+        assert_eq!(key.name(), CacheKey::new("test").file(&input).name());
+        // The code generators are part of the key, but do not change within a run
+        let generators_key = CacheKey::new("test").generators().file(&input);
+        assert_ne!(key.name(), generators_key.name());
+        assert_eq!(
+            generators_key.name(),
+            CacheKey::new("test").generators().file(&input).name()
+        );
+        cache.insert("test", &key, &CacheEntry::default());
+        assert!(cache.get("test", &key).is_some());
+
+        dir.write("input.h", "class B;");
+        let changed_key = CacheKey::new("test").file(&input);
+        assert_ne!(key.name(), changed_key.name());
+        assert!(cache.get("test", &changed_key).is_none());
+
+        // The entry of the previous contents is stale once it is not used in a run
+        let cache = BuildCache::in_dir(dir.0.join("cache"));
+        cache.insert("test", &changed_key, &CacheEntry::default());
+        cache.prune();
+        assert!(cache.get("test", &key).is_none());
+        assert!(cache.get("test", &changed_key).is_some());
+    }
+
+    #[test]
+    fn object_key_includes_headers() {
+        let dir = TestDir::new("cache-object");
+        let include_dir = dir.0.join("include");
+        let external_dir = dir.0.join("external");
+        std::fs::create_dir_all(include_dir.join("nested")).unwrap();
+        std::fs::create_dir_all(&external_dir).unwrap();
+        dir.write("include/nested/b.h", "#pragma once\nint b();");
+        dir.write("include/a.h", "#pragma once\n#include \"nested/b.h\"");
+        dir.write("external/qt.h", "#pragma once");
+        let source = dir.write(
+            "source.cpp",
+            "#include <a.h>\n  #  include \"missing.h\"\n#include <qt.h>\n",
+        );
+
+        let args = [
+            OsString::from("-I"),
+            include_dir.clone().into_os_string(),
+            OsString::from(format!("-I{}", external_dir.display())),
+        ];
+        let mut scanner = IncludeScanner::new(&args, &[external_dir]);
+        assert_eq!(
+            scanner.includes(&source),
+            vec![include_dir.join("a.h"), include_dir.join("nested/b.h")]
+        );
+
+        let compiler_key = CacheKey::new("object").value("-O2");
+        let key = scanner.object_key(&compiler_key, &source).name();
+        assert_eq!(key, scanner.object_key(&compiler_key, &source).name());
+        // Headers outside of the external include paths are part of the key
+        dir.write("include/nested/b.h", "#pragma once\nint b(int);");
+        let mut scanner = IncludeScanner::new(&args, &[dir.0.join("external")]);
+        let changed_key = scanner.object_key(&compiler_key, &source).name();
+        assert_ne!(key, changed_key);
+        // As are the compiler flags
+        assert_ne!(
+            changed_key,
+            scanner
+                .object_key(&CacheKey::new("object").value("-O0"), &source)
+                .name()
+        );
+    }
+}
@@ -4,11 +4,22 @@
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
 //! This module takes care of displaying errors emitted by CXX-Qt as nicely-printed diagnostics
-//! using codespan-reporting.
+//! using codespan-reporting, or as JSON diagnostics in the format of rustc.
 
-use std::{fmt::Display, ops::Range, path::PathBuf};
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    io::Write,
+    ops::Range,
+    path::PathBuf,
+    sync::atomic::{AtomicBool, Ordering},
+};
 
+use codespan_reporting::diagnostic::Severity;
 use proc_macro2::{LineColumn, Span};
+use qt_build_utils::QtBuildError;
+
+use crate::ui::UiFormError;
 
 /// We need to wrap the CXX and CXX-Qt errors in a single error type so that they
 /// can be returned from the generation phases. This then allows for us to not unwrap
@@ -83,86 +94,470 @@ impl Iterator for IntoIter {
     }
 }
 
-pub(crate) struct Diagnostic {
-    file_path: PathBuf,
-    errors: Vec<GeneratedError>,
+/// The format used to report diagnostics, selected with the `CXX_QT_DIAGNOSTICS` environment
+/// variable
+enum DiagnosticFormat {
+    /// Diagnostics rendered with codespan-reporting, which is the default
+    Human,
+    /// Diagnostics in the JSON format of rustc, one per line, selected with `CXX_QT_DIAGNOSTICS=json`
+    ///
+    /// Cargo only forwards the output of build scripts as plain text, even with
+    /// `--message-format=json`, so the JSON diagnostics are written to [json_diagnostics_path] in
+    /// addition to the rendered diagnostics on stderr. Tools read them from there after running
+    /// cargo, such as a `rust-analyzer.check.overrideCommand` which prints the file after
+    /// `cargo check --message-format=json`, as described in the book.
+    Json,
 }
 
-impl Diagnostic {
-    pub(crate) fn new(file_path: PathBuf, error: GeneratedError) -> Self {
-        Self {
-            file_path,
-            errors: error.into_iter().collect(),
+impl DiagnosticFormat {
+    fn from_env() -> Self {
+        println!("cargo::rerun-if-env-changed=CXX_QT_DIAGNOSTICS");
+        match std::env::var("CXX_QT_DIAGNOSTICS").as_deref() {
+            Ok("json") => Self::Json,
+            _ => Self::Human,
         }
     }
+}
+
+/// The file within OUT_DIR which JSON diagnostics are written to
+fn json_diagnostics_path() -> PathBuf {
+    crate::dir::out().join("cxx-qt-diagnostics.json")
+}
 
-    fn byte_span_in(error: &GeneratedError, source: &str) -> Option<Range<usize>> {
-        error.span().and_then(|span| {
-            let start_offset = line_column_to_byte_in(span.start(), source)?;
-            let end_offset = line_column_to_byte_in(span.end(), source)?;
+/// Remove the JSON diagnostics of a previous run, so that they are not shown once fixed
+pub(crate) fn remove_json_diagnostics() {
+    std::fs::remove_file(json_diagnostics_path()).ok();
+}
 
-            Some(start_offset..end_offset)
-        })
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Level {
+    Error,
+    Warning,
+    Note,
+}
+
+impl Level {
+    fn as_str(self) -> &'static str {
+        match self {
+            Level::Error => "error",
+            Level::Warning => "warning",
+            Level::Note => "note",
+        }
+    }
+
+    fn severity(self) -> Severity {
+        match self {
+            Level::Error => Severity::Error,
+            Level::Warning => Severity::Warning,
+            Level::Note => Severity::Note,
+        }
+    }
+}
+
+/// Where a message points to in its file
+enum Location {
+    /// The span of a CXX or CXX-Qt error
+    Span(Span),
+    /// A one-based line and column reported by a Qt tool, which points to the rest of the line
+    Line { line: usize, column: usize },
+}
+
+impl Location {
+    fn byte_span_in(&self, source: &str) -> Option<Range<usize>> {
+        match self {
+            Location::Span(span) => {
+                let start_offset = line_column_to_byte_in(span.start(), source)?;
+                let end_offset = line_column_to_byte_in(span.end(), source)?;
+
+                Some(start_offset..end_offset)
+            }
+            Location::Line { line, column } => {
+                let start_offset = line_column_to_byte_in(
+                    LineColumn {
+                        line: *line,
+                        column: column.saturating_sub(1),
+                    },
+                    source,
+                )?;
+                let end_offset = source[start_offset..]
+                    .find(['\r', '\n'])
+                    .map_or(source.len(), |len| start_offset + len);
+
+                Some(start_offset..end_offset)
+            }
+        }
     }
+}
 
+/// A single message of a [Diagnostic]
+struct Message {
+    level: Level,
+    code: String,
+    message: String,
+    file_path: Option<PathBuf>,
+    location: Option<Location>,
+}
+
+impl Message {
     fn create_codespan_diagnostic(
-        source: &str,
-        error: &GeneratedError,
+        &self,
+        byte_span: Option<Range<usize>>,
     ) -> codespan_reporting::diagnostic::Diagnostic<()> {
         use codespan_reporting::diagnostic::Label;
 
-        let mut diagnostic = codespan_reporting::diagnostic::Diagnostic::error()
-            .with_message(format!("{error}"))
-            .with_code(error.context());
+        let mut diagnostic = codespan_reporting::diagnostic::Diagnostic::new(self.level.severity())
+            .with_message(&self.message)
+            .with_code(&self.code);
 
-        if let Some(span) = Self::byte_span_in(error, source) {
+        if let Some(span) = byte_span {
             diagnostic = diagnostic.with_labels(vec![Label::primary((), span)]);
         }
 
         diagnostic
     }
 
-    fn create_codespan_diagnostics(
+    /// Create a diagnostic in the JSON format of rustc, so that tools which understand
+    /// `cargo build --message-format=json` can show the message at its location
+    fn create_json_diagnostic(
         &self,
-        source: &str,
-    ) -> Vec<codespan_reporting::diagnostic::Diagnostic<()>> {
-        self.errors
+        source: Option<&str>,
+        byte_span: Option<Range<usize>>,
+        rendered: String,
+    ) -> serde_json::Value {
+        let spans = match (&self.file_path, source, byte_span) {
+            (Some(file_path), Some(source), Some(byte_span)) => {
+                let (line_start, column_start) = line_column_of_byte_in(byte_span.start, source);
+                let (line_end, column_end) = line_column_of_byte_in(byte_span.end, source);
+                // The lines of the span along with the highlighted columns
+                let text = source
+                    .lines()
+                    .enumerate()
+                    .skip(line_start - 1)
+                    .take(line_end - line_start + 1)
+                    .map(|(index, text)| {
+                        let line = index + 1;
+                        serde_json::json!({
+                            "text": text,
+                            "highlight_start": if line == line_start { column_start } else { 1 },
+                            "highlight_end": if line == line_end {
+                                column_end
+                            } else {
+                                text.chars().count() + 1
+                            },
+                        })
+                    })
+                    .collect::<Vec<_>>();
+                vec![serde_json::json!({
+                    "file_name": file_path.display().to_string(),
+                    "byte_start": byte_span.start,
+                    "byte_end": byte_span.end,
+                    "line_start": line_start,
+                    "line_end": line_end,
+                    "column_start": column_start,
+                    "column_end": column_end,
+                    "is_primary": true,
+                    "text": text,
+                    "label": self.message,
+                    "suggested_replacement": null,
+                    "suggestion_applicability": null,
+                    "expansion": null,
+                })]
+            }
+            _ => vec![],
+        };
+
+        serde_json::json!({
+            "$message_type": "diagnostic",
+            "message": self.message,
+            "code": {
+                "code": self.code,
+                "explanation": null,
+            },
+            "level": self.level.as_str(),
+            "spans": spans,
+            "children": [],
+            "rendered": rendered,
+        })
+    }
+}
+
+/// A diagnostic which is reported when generating or building a crate fails, such as the errors
+/// of CXX and CXX-Qt in a bridge or the output of a failed Qt tool
+pub(crate) struct Diagnostic {
+    messages: Vec<Message>,
+}
+
+impl Diagnostic {
+    pub(crate) fn new(file_path: PathBuf, error: GeneratedError) -> Self {
+        Self {
+            messages: error
+                .into_iter()
+                .map(|error| Message {
+                    level: Level::Error,
+                    code: error.context().to_owned(),
+                    message: format!("{error}"),
+                    file_path: Some(file_path.clone()),
+                    location: error.span().map(Location::Span),
+                })
+                .collect(),
+        }
+    }
+
+    /// The text of each message, for tests of code which reports diagnostics
+    #[cfg(test)]
+    pub(crate) fn message_texts(&self) -> Vec<&str> {
+        self.messages
             .iter()
-            .map(|error| Self::create_codespan_diagnostic(source, error))
+            .map(|message| message.message.as_str())
             .collect()
     }
 
-    fn try_report(&self) -> Result<(), ()> {
+    fn try_report(&self, format: &DiagnosticFormat) -> Result<(), ()> {
         use codespan_reporting::{
             files::SimpleFile,
             term::{
                 self,
-                termcolor::{ColorChoice, StandardStream},
+                termcolor::{ColorChoice, NoColor, StandardStream},
                 Config,
             },
         };
-        let source_string = std::fs::read_to_string(&self.file_path).map_err(|_| ())?;
-
-        let diagnostics = self.create_codespan_diagnostics(source_string.as_ref());
-
-        let file_path = self.file_path.display();
-        let file = SimpleFile::new(format!("{file_path}"), source_string);
 
         let stderr = StandardStream::stderr(ColorChoice::Auto);
         let mut writer = stderr.lock();
+        let mut json_lines = String::new();
 
-        diagnostics.into_iter().try_for_each(|diagnostic| {
-            term::emit(&mut writer, &Config::default(), &file, &diagnostic).map_err(|_| ())
-        })
+        let mut sources = HashMap::new();
+        self.messages.iter().try_for_each(|message| {
+            let source = message.file_path.as_ref().and_then(|file_path| {
+                sources
+                    .entry(file_path.clone())
+                    .or_insert_with(|| std::fs::read_to_string(file_path).ok())
+                    .clone()
+            });
+            let byte_span = source.as_deref().and_then(|source| {
+                message
+                    .location
+                    .as_ref()
+                    .and_then(|location| location.byte_span_in(source))
+            });
+
+            let file_name = message
+                .file_path
+                .as_ref()
+                .map(|file_path| file_path.display().to_string())
+                .unwrap_or_default();
+            let file = SimpleFile::new(file_name, source.clone().unwrap_or_default());
+            let diagnostic = message.create_codespan_diagnostic(byte_span.clone());
+
+            term::emit(&mut writer, &Config::default(), &file, &diagnostic).map_err(|_| ())?;
+            if let DiagnosticFormat::Json = format {
+                let mut rendered = NoColor::new(vec![]);
+                term::emit(&mut rendered, &Config::default(), &file, &diagnostic)
+                    .map_err(|_| ())?;
+                let json = message.create_json_diagnostic(
+                    source.as_deref(),
+                    byte_span,
+                    String::from_utf8_lossy(&rendered.into_inner()).into_owned(),
+                );
+                json_lines.push_str(&format!("{json}\n"));
+            }
+            Ok(())
+        })?;
+
+        if let DiagnosticFormat::Json = format {
+            // The file is replaced by the first diagnostics of a run, so that it does not contain
+            // the diagnostics of previous runs
+            static WRITTEN: AtomicBool = AtomicBool::new(false);
+            let append = WRITTEN.swap(true, Ordering::SeqCst);
+            let path = json_diagnostics_path();
+            std::fs::OpenOptions::new()
+                .create(true)
+                .write(true)
+                .append(append)
+                .truncate(!append)
+                .open(&path)
+                .and_then(|mut file| file.write_all(json_lines.as_bytes()))
+                .map_err(|_| ())?;
+            println!(
+                "cargo::warning=CXX-Qt diagnostics were written to {}",
+                path.display()
+            );
+        }
+        Ok(())
     }
 
     pub(crate) fn report(self) {
-        // If loading the source file fails, or printing to stderr isn't
-        // possible, we try panicing as a last resort.
-        self.try_report().unwrap_or_else(|_| {
-            panic!("{}", self.errors.first().unwrap());
+        // If printing to stderr isn't possible, we try panicing as a last resort.
+        self.try_report(&DiagnosticFormat::from_env())
+            .unwrap_or_else(|_| {
+                panic!("{}", self.messages.first().unwrap().message);
+            })
+    }
+}
+
+impl From<UiFormError> for Diagnostic {
+    fn from(error: UiFormError) -> Self {
+        Self {
+            messages: vec![Message {
+                level: Level::Error,
+                code: "ui".to_owned(),
+                message: error.message,
+                file_path: Some(error.path),
+                location: None,
+            }],
+        }
+    }
+}
+
+impl From<QtBuildError> for Diagnostic {
+    /// Parse the output of a failed Qt tool into messages pointing to their files, falling back
+    /// to a single message with the whole output if it is not understood
+    fn from(error: QtBuildError) -> Self {
+        let QtBuildError::ToolFailed {
+            tool,
+            input,
+            output,
+        } = &error
+        else {
+            return Self {
+                messages: vec![Message {
+                    level: Level::Error,
+                    code: "qt".to_owned(),
+                    message: error.to_string(),
+                    file_path: None,
+                    location: None,
+                }],
+            };
+        };
+
+        let mut messages = Vec::<Message>::new();
+        for line in output.lines().filter(|line| !line.trim().is_empty()) {
+            match parse_tool_message(line) {
+                Some((file_path, location, level, message)) => messages.push(Message {
+                    level,
+                    code: tool.clone(),
+                    message,
+                    file_path: Some(file_path),
+                    location,
+                }),
+                // Lines which do not start with a location continue the previous message
+                None => match messages.last_mut() {
+                    Some(previous) => {
+                        previous.message.push('\n');
+                        previous.message.push_str(line);
+                    }
+                    None => break,
+                },
+            }
+        }
+
+        if !messages
+            .iter()
+            .any(|message| matches!(message.level, Level::Error))
+        {
+            let input_path = PathBuf::from(input);
+            messages.insert(
+                0,
+                Message {
+                    level: Level::Error,
+                    code: tool.clone(),
+                    message: format!("{tool} failed for {input}:\n{output}"),
+                    file_path: input_path.is_file().then_some(input_path),
+                    location: None,
+                },
+            );
+        }
+
+        Self { messages }
+    }
+}
+
+/// Parse a line of the output of a Qt tool which points to a file, such as
+/// `file.h:12:1: error: ...` from moc or `RCC: Error in 'file.qrc': ...` from rcc
+fn parse_tool_message(line: &str) -> Option<(PathBuf, Option<Location>, Level, String)> {
+    let parse_level = |message: &str| {
+        [
+            ("error: ", Level::Error),
+            ("warning: ", Level::Warning),
+            ("note: ", Level::Note),
+        ]
+        .into_iter()
+        .find_map(|(prefix, level)| {
+            message
+                .get(..prefix.len())
+                .filter(|start| start.eq_ignore_ascii_case(prefix))
+                .map(|_| (level, message[prefix.len()..].to_owned()))
         })
+        // Lines without a level, such as the notes of moc, are not errors by themselves,
+        // a failed tool without any error is reported with its whole output instead
+        .unwrap_or((Level::Warning, message.to_owned()))
+    };
+
+    // qmlcachegen and qmllint put the level before the location, such as
+    // `Error: main.qml:3:5: ...`
+    let (line, prefix_level) = [
+        ("Error: ", Level::Error),
+        ("Critical: ", Level::Error),
+        ("Warning: ", Level::Warning),
+        ("Info: ", Level::Note),
+    ]
+    .into_iter()
+    .find_map(|(prefix, level)| Some((line.strip_prefix(prefix)?, Some(level))))
+    .unwrap_or((line, None));
+
+    if let Some(rest) = line.strip_prefix("RCC: Error in '") {
+        let (file_path, message) = rest.split_once("': ")?;
+        return Some((
+            PathBuf::from(file_path),
+            None,
+            Level::Error,
+            message.to_owned(),
+        ));
     }
+
+    // Find the first `:<line>:` or `:<line>:<column>:` after the path, skipping the first
+    // character so that a Windows drive letter is not mistaken for the separator
+    line.char_indices()
+        .skip(1)
+        .filter(|(_, char)| *char == ':')
+        .find_map(|(index, _)| {
+            let mut parts = line[index + 1..].splitn(3, ':');
+            let line_number = parts.next()?.parse::<usize>().ok()?;
+            let (column, message) = match (parts.next()?, parts.next()) {
+                (column, Some(message)) if column.parse::<usize>().is_ok() => {
+                    (column.parse::<usize>().ok()?, message)
+                }
+                (message, None) => (1, message),
+                (message, Some(rest)) => {
+                    return Some((line_number, 1, format!("{message}:{rest}")));
+                }
+            };
+            Some((line_number, column, message.to_owned()))
+        })
+        .and_then(|(line_number, column, message)| {
+            let file_path = PathBuf::from(line.split_once(&format!(":{line_number}:"))?.0);
+            let (level, message) = match prefix_level {
+                Some(level) => (level, message.trim_start().to_owned()),
+                None => parse_level(message.trim_start()),
+            };
+            Some((
+                file_path,
+                Some(Location::Line {
+                    line: line_number,
+                    column,
+                }),
+                level,
+                message,
+            ))
+        })
+}
+
+/// The one-based line and column in characters of a byte offset, as used by rustc
+fn line_column_of_byte_in(byte: usize, source: &str) -> (usize, usize) {
+    let before = &source[..byte];
+    let line = before.matches('\n').count() + 1;
+    let line_start = before.rfind('\n').map_or(0, |index| index + 1);
+    (line, before[line_start..].chars().count() + 1)
 }
 
 fn line_column_to_byte_in(line_column: LineColumn, source: &str) -> Option<usize> {
@@ -187,3 +582,209 @@ fn line_column_to_byte_in(line_column: LineColumn, source: &str) -> Option<usize
         .map(|(byte, _char)| byte)
         .next()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type ParsedMessage = (PathBuf, Option<(usize, usize)>, Level, String);
+
+    fn parse(line: &str) -> Option<ParsedMessage> {
+        parse_tool_message(line).map(|(file_path, location, level, message)| {
+            let location = location.map(|location| match location {
+                Location::Line { line, column } => (line, column),
+                Location::Span(_) => unreachable!(),
+            });
+            (file_path, location, level, message)
+        })
+    }
+
+    fn json_message(level: Level, file_path: Option<&str>) -> Message {
+        Message {
+            level,
+            code: "cxxqt".to_owned(),
+            message: "Invalid type".to_owned(),
+            file_path: file_path.map(PathBuf::from),
+            location: None,
+        }
+    }
+
+    #[test]
+    fn create_json_diagnostic() {
+        let source = "mod ffi {\n    struct Ä;\n}\n";
+        let message = json_message(Level::Error, Some("src/lib.rs"));
+
+        // Columns are counted in characters rather than bytes
+        let json = message.create_json_diagnostic(Some(source), Some(21..24), "rendered".into());
+        assert_eq!(json["$message_type"], "diagnostic");
+        assert_eq!(json["message"], "Invalid type");
+        assert_eq!(json["code"]["code"], "cxxqt");
+        assert_eq!(json["level"], "error");
+        assert_eq!(json["rendered"], "rendered");
+        assert_eq!(
+            json["spans"],
+            serde_json::json!([{
+                "file_name": "src/lib.rs",
+                "byte_start": 21,
+                "byte_end": 24,
+                "line_start": 2,
+                "line_end": 2,
+                "column_start": 12,
+                "column_end": 14,
+                "is_primary": true,
+                "text": [{ "text": "    struct Ä;", "highlight_start": 12, "highlight_end": 14 }],
+                "label": "Invalid type",
+                "suggested_replacement": null,
+                "suggestion_applicability": null,
+                "expansion": null,
+            }])
+        );
+
+        // Each line of a span spanning multiple lines is highlighted up to the end of the span
+        let json = message.create_json_diagnostic(Some(source), Some(14..26), String::new());
+        let span = &json["spans"][0];
+        assert_eq!(
+            (&span["line_start"], &span["column_start"]),
+            (&2.into(), &5.into())
+        );
+        assert_eq!(
+            (&span["line_end"], &span["column_end"]),
+            (&3.into(), &2.into())
+        );
+        assert_eq!(
+            span["text"],
+            serde_json::json!([
+                { "text": "    struct Ä;", "highlight_start": 5, "highlight_end": 14 },
+                { "text": "}", "highlight_start": 1, "highlight_end": 2 },
+            ])
+        );
+
+        // Messages without a location have no spans
+        let json = json_message(Level::Warning, Some("src/lib.rs")).create_json_diagnostic(
+            Some(source),
+            None,
+            String::new(),
+        );
+        assert_eq!(json["level"], "warning");
+        assert_eq!(json["spans"], serde_json::json!([]));
+        let json =
+            json_message(Level::Note, None).create_json_diagnostic(None, None, String::new());
+        assert_eq!(json["level"], "note");
+        assert_eq!(json["spans"], serde_json::json!([]));
+    }
+
+    #[test]
+    fn parse_moc_messages() {
+        assert_eq!(
+            parse("/src/object.h:12:3: error: Class declaration lacks Q_OBJECT macro."),
+            Some((
+                PathBuf::from("/src/object.h"),
+                Some((12, 3)),
+                Level::Error,
+                "Class declaration lacks Q_OBJECT macro.".to_owned()
+            ))
+        );
+        assert_eq!(
+            parse("/src/object.h:0: Note: No relevant classes found. No output generated."),
+            Some((
+                PathBuf::from("/src/object.h"),
+                Some((0, 1)),
+                Level::Note,
+                "No relevant classes found. No output generated.".to_owned()
+            ))
+        );
+        assert_eq!(
+            parse(r"C:\src\object.h:7: Warning: Property declaration has no READ accessor"),
+            Some((
+                PathBuf::from(r"C:\src\object.h"),
+                Some((7, 1)),
+                Level::Warning,
+                "Property declaration has no READ accessor".to_owned()
+            ))
+        );
+    }
+
+    #[test]
+    fn parse_unrecognised_level() {
+        // Messages without a known level do not fail the build by themselves
+        assert_eq!(
+            parse("/src/object.h:4:1: something: happened"),
+            Some((
+                PathBuf::from("/src/object.h"),
+                Some((4, 1)),
+                Level::Warning,
+                "something: happened".to_owned()
+            ))
+        );
+        assert_eq!(parse("Parse error at \"IDENTIFIER\""), None);
+        assert_eq!(parse(""), None);
+    }
+
+    #[test]
+    fn parse_prefixed_messages() {
+        assert_eq!(
+            parse("Error: /qml/main.qml:3:5: Cannot assign to non-existent property \"foo\""),
+            Some((
+                PathBuf::from("/qml/main.qml"),
+                Some((3, 5)),
+                Level::Error,
+                "Cannot assign to non-existent property \"foo\"".to_owned()
+            ))
+        );
+        assert_eq!(
+            parse("Warning: /qml/main.qml:8:9: Unqualified access [unqualified]"),
+            Some((
+                PathBuf::from("/qml/main.qml"),
+                Some((8, 9)),
+                Level::Warning,
+                "Unqualified access [unqualified]".to_owned()
+            ))
+        );
+        assert_eq!(
+            parse("RCC: Error in 'resources.qrc': Cannot find file 'missing.png'"),
+            Some((
+                PathBuf::from("resources.qrc"),
+                None,
+                Level::Error,
+                "Cannot find file 'missing.png'".to_owned()
+            ))
+        );
+    }
+
+    #[test]
+    fn diagnostic_from_tool_failure() {
+        let diagnostic = Diagnostic::from(QtBuildError::ToolFailed {
+            tool: "moc".to_owned(),
+            input: "/src/object.h".to_owned(),
+            output: "/src/object.h:0: Note: No relevant classes found.\n\
+                /src/object.h:5:1: error: Parse error\n\
+                continued on the next line\n"
+                .to_owned(),
+        });
+        let messages = diagnostic
+            .messages
+            .iter()
+            .map(|message| (message.level, message.message.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            messages,
+            vec![
+                (Level::Note, "No relevant classes found."),
+                (Level::Error, "Parse error\ncontinued on the next line"),
+            ]
+        );
+
+        // Without any error, the whole output is reported as an error so that the build fails
+        let diagnostic = Diagnostic::from(QtBuildError::ToolFailed {
+            tool: "moc".to_owned(),
+            input: "/src/object.h".to_owned(),
+            output: "/src/object.h:0: Note: No relevant classes found.".to_owned(),
+        });
+        assert_eq!(diagnostic.messages.len(), 2);
+        assert_eq!(diagnostic.messages[0].level, Level::Error);
+        assert_eq!(
+            diagnostic.messages[0].message,
+            "moc failed for /src/object.h:\n/src/object.h:0: Note: No relevant classes found."
+        );
+    }
+}
@@ -85,6 +85,18 @@ pub(crate) fn module_export(module_uri: &str) -> Option<PathBuf> {
         })
 }
 
+/// The directory of the QML module with the given URI within the `qml` import path next to the
+/// libraries built by Cargo, used for QML modules with a dynamic plugin.
+///
+/// The OUT_DIR is `<target>/<profile>/build/<crate>-<hash>/out`, so the libraries are three
+/// directories up.
+pub(crate) fn qml_plugin(module_uri: &str) -> Option<PathBuf> {
+    out()
+        .ancestors()
+        .nth(3)
+        .map(|profile| profile.join("qml").join(module_uri.replace('.', "/")))
+}
+
 /// The target directory or another directory where we can write files that will be shared
 /// between crates.
 pub(crate) fn target() -> PathBuf {
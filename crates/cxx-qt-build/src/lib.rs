@@ -10,6 +10,16 @@
 //! This crate provides a builder which parses given Rust source code to search
 //! for CXX-Qt or CXX macros and generate any resulting C++ code. It also builds
 //! the C++ code into a binary with any cxx-qt-lib code and Qt linked.
+//!
+//! Errors in bridges and failures of Qt tools such as moc, rcc and qmltyperegistrar are reported
+//! as diagnostics pointing to the offending file and line. Set the `CXX_QT_DIAGNOSTICS`
+//! environment variable to `json` to also write them as JSON messages in the format of rustc,
+//! one per line, to `cxx-qt-diagnostics.json` in `OUT_DIR`. Cargo does not forward these, so
+//! tools such as rust-analyzer need to read the file after running cargo, see the
+//! [book](https://kdab.github.io/cxx-qt/book/concepts/build_systems.html#diagnostics-in-editors).
+
+mod cache;
+use cache::{BuildCache, CacheEntry, CacheKey};
 
 mod cfg_evaluator;
 mod utils;
@@ -29,16 +39,20 @@ pub use interface::Interface;
 mod opts;
 pub use opts::CxxQtBuildersOpts;
 pub use opts::QObjectHeaderOpts;
+pub use opts::UiFileOpts;
 
 mod qml_modules;
 use qml_modules::OwningQmlModule;
 pub use qml_modules::QmlModule;
 
-pub use qt_build_utils::MocArguments;
+mod ui;
+
+use proc_macro2::Span;
+pub use qt_build_utils::{MocArguments, QmlFile};
 use quote::ToTokens;
 use semver::Version;
 use std::{
-    collections::HashSet,
+    collections::{HashMap, HashSet},
     env,
     fs::File,
     io::Write,
@@ -50,20 +64,159 @@ use cxx_qt_gen::{
     CxxQtItem, GeneratedCppBlocks, GeneratedOpt, GeneratedRustBlocks, Parser,
 };
 
-// TODO: we need to eventually support having multiple modules defined in a single file. This
-// is currently an issue because we are using the Rust file name to derive the cpp file name
-// and are blindly re-writing files.
-//
-// As we use struct names for the QObject files, we should actually be able to support multiple
-// QObject macros and at most one "raw CXX" macro per file already. For now this remains a TODO
-// as to keep things simpler. We also want to able to warn users about duplicate names eventually.
-
 struct GeneratedCppFilePaths {
     plain_cpp: PathBuf,
+    plain_header: PathBuf,
     qobject: Option<PathBuf>,
     qobject_header: Option<PathBuf>,
 }
 
+impl GeneratedCppFilePaths {
+    /// Record the paths in a cache entry, so that they can be restored with [Self::from_cache_entry]
+    fn add_to_cache_entry(&self, file_ident: &str, entry: &mut CacheEntry) {
+        let paths = [
+            ("plain_cpp", Some(&self.plain_cpp)),
+            ("plain_header", Some(&self.plain_header)),
+            ("qobject", self.qobject.as_ref()),
+            ("qobject_header", self.qobject_header.as_ref()),
+        ];
+        entry
+            .values
+            .push(("file_ident".to_owned(), file_ident.to_owned()));
+        for (name, path) in paths {
+            if let Some(path) = path {
+                entry.files.push(path.clone());
+                entry
+                    .values
+                    .push((name.to_owned(), path.to_string_lossy().into_owned()));
+            }
+        }
+    }
+
+    fn from_cache_entry(entry: &CacheEntry) -> Vec<Self> {
+        let mut generated = Vec::<Self>::new();
+        for (name, value) in &entry.values {
+            let path = PathBuf::from(value);
+            match (name.as_str(), generated.last_mut()) {
+                ("file_ident", _) => generated.push(Self {
+                    plain_cpp: PathBuf::new(),
+                    plain_header: PathBuf::new(),
+                    qobject: None,
+                    qobject_header: None,
+                }),
+                ("plain_cpp", Some(paths)) => paths.plain_cpp = path,
+                ("plain_header", Some(paths)) => paths.plain_header = path,
+                ("qobject", Some(paths)) => paths.qobject = Some(path),
+                ("qobject_header", Some(paths)) => paths.qobject_header = Some(path),
+                _ => {}
+            }
+        }
+        generated
+    }
+}
+
+/// The library of a QML module, which is compiled after the library of the crate
+struct QmlModuleLibrary {
+    lib_name: String,
+    cc_builder: cc::Build,
+    /// The Qt include paths of the Qt modules of the crate and the QML module
+    qt_include_paths: Vec<PathBuf>,
+    /// The generated C++ files, which are compiled with the build cache
+    generated_sources: Vec<PathBuf>,
+}
+
+/// The import path of a QML module, which is the directory containing the directories of the
+/// module URI, given the path of the qmldir of the module
+fn qml_import_path<'a>(qmldir: &'a Path, uri: &str) -> Option<&'a Path> {
+    qmldir.ancestors().nth(uri.split('.').count() + 1)
+}
+
+/// The names claimed by the generated C++ so far, used to report duplicates
+#[derive(Default)]
+struct GeneratedNames {
+    /// Generated file identities and the Rust file which generated them
+    file_idents: HashMap<String, PathBuf>,
+    /// Fully qualified C++ QObject names and the Rust file which declared them
+    qobjects: HashMap<String, PathBuf>,
+}
+
+impl GeneratedNames {
+    fn claim(
+        names: &mut HashMap<String, PathBuf>,
+        name: String,
+        rust_file_path: &Path,
+        span: Span,
+        kind: &str,
+    ) -> Result<(), GeneratedError> {
+        if let Some(existing) = names.get(&name) {
+            return Err(GeneratedError::from(cxx_qt_gen::Error::new(
+                span,
+                format!(
+                    "The {kind} `{name}` is already generated from {}",
+                    existing.display()
+                ),
+            )));
+        }
+        names.insert(name, rust_file_path.to_owned());
+        Ok(())
+    }
+
+    fn claim_file_ident(
+        &mut self,
+        file_ident: &str,
+        rust_file_path: &Path,
+        span: Span,
+    ) -> Result<(), GeneratedError> {
+        Self::claim(
+            &mut self.file_idents,
+            file_ident.to_owned(),
+            rust_file_path,
+            span,
+            "C++ file",
+        )
+    }
+
+    fn claim_qobject(
+        &mut self,
+        qobject: String,
+        rust_file_path: &Path,
+        span: Span,
+    ) -> Result<(), GeneratedError> {
+        Self::claim(&mut self.qobjects, qobject, rust_file_path, span, "QObject")
+    }
+
+    /// The QObject names claimed by a Rust file, in a stable order
+    fn qobjects_of(&self, rust_file_path: &Path) -> Vec<String> {
+        let mut qobjects = self
+            .qobjects
+            .iter()
+            .filter(|(_, path)| *path == rust_file_path)
+            .map(|(qobject, _)| qobject.clone())
+            .collect::<Vec<_>>();
+        qobjects.sort();
+        qobjects
+    }
+
+    /// Claim the names of a Rust file whose generated files were found in the cache, so that
+    /// duplicates are still reported when another file is changed
+    fn claim_cached(
+        &mut self,
+        entry: &CacheEntry,
+        rust_file_path: &Path,
+    ) -> Result<(), Diagnostic> {
+        let to_diagnostic = |err| Diagnostic::new(rust_file_path.to_owned(), err);
+        for file_ident in entry.values("file_ident") {
+            self.claim_file_ident(file_ident, rust_file_path, Span::call_site())
+                .map_err(to_diagnostic)?;
+        }
+        for qobject in entry.values("qobject_name") {
+            self.claim_qobject(qobject.to_owned(), rust_file_path, Span::call_site())
+                .map_err(to_diagnostic)?;
+        }
+        Ok(())
+    }
+}
+
 struct GeneratedCpp {
     cxx_qt: Option<CppFragment>,
     cxx: cxx_gen::GeneratedCode,
@@ -71,12 +224,16 @@ struct GeneratedCpp {
 }
 
 impl GeneratedCpp {
-    /// Generate QObject and cxx header/source C++ file contents
+    /// Generate QObject and cxx header/source C++ file contents for each bridge in a Rust file
+    ///
+    /// A file containing a single bridge generates files named after the Rust file, when a file
+    /// contains multiple bridges the name of each bridge module is appended to the file name.
     pub fn new(
         rust_file_path: impl AsRef<Path>,
         relative_path: impl AsRef<Path>,
         include_prefix: &str,
-    ) -> Result<Self, Diagnostic> {
+        names: &mut GeneratedNames,
+    ) -> Result<Vec<Self>, Diagnostic> {
         let to_diagnostic = |err| Diagnostic::new(rust_file_path.as_ref().to_owned(), err);
 
         let rust_file_path = rust_file_path.as_ref();
@@ -85,56 +242,59 @@ impl GeneratedCpp {
             .map_err(GeneratedError::from)
             .map_err(to_diagnostic)?;
 
-        let mut cxx_qt = None;
-        let mut tokens = proc_macro2::TokenStream::new();
-
-        // Add any attributes in the file into the tokenstream
+        // Any attributes in the file are added to the tokenstream of each bridge
+        let mut file_attrs = proc_macro2::TokenStream::new();
         for attr in &file.attrs {
-            tokens.extend(attr.into_token_stream());
+            file_attrs.extend(attr.into_token_stream());
         }
 
         // Match upstream where they use the file name and folders as the ident
         //
         // We need the relative path here as we want the folders
-        let file_ident = relative_path
+        let file_stem = relative_path
             .as_ref()
             // Remove the .rs extension
             .with_extension("")
             .to_string_lossy()
             .into_owned();
 
-        // The include path we inject needs any prefix (eg the crate name) too
-        let include_ident = format!("{include_prefix}/{file_ident}");
-
         let mut cxx_qt_opt = GeneratedOpt::default();
         cxx_qt_opt.cfg_evaluator = Box::new(cfg_evaluator::CargoEnvCfgEvaluator);
 
+        let bridge_count = file
+            .items
+            .iter()
+            .filter(|item| matches!(item, CxxQtItem::Cxx(_) | CxxQtItem::CxxQt(_)))
+            .count();
+
         // Loop through the items looking for any CXX or CXX-Qt blocks
-        let mut found_bridge = false;
+        let mut generated = Vec::with_capacity(bridge_count);
         for item in &file.items {
+            let module_ident = match item {
+                CxxQtItem::Cxx(m) => &m.ident,
+                CxxQtItem::CxxQt(m) => &m.ident,
+                CxxQtItem::Item(_) => continue,
+            };
+
+            let file_ident = if bridge_count > 1 {
+                format!("{file_stem}_{module_ident}")
+            } else {
+                file_stem.clone()
+            };
+            names
+                .claim_file_ident(&file_ident, rust_file_path, module_ident.span())
+                .map_err(to_diagnostic)?;
+
+            // The include path we inject needs any prefix (eg the crate name) too
+            let include_ident = format!("{include_prefix}/{file_ident}");
+
+            let mut cxx_qt = None;
+            let mut tokens = file_attrs.clone();
             match item {
                 CxxQtItem::Cxx(m) => {
-                    // TODO: later we will allow for multiple CXX or CXX-Qt blocks in one file
-                    if found_bridge {
-                        panic!(
-                            "Unfortunately only files with either a single cxx or a single cxx_qt module are currently supported.
-                            The file {} has more than one of these.",
-                            rust_file_path.display());
-                    }
-                    found_bridge = true;
-
                     tokens.extend(m.into_token_stream());
                 }
                 CxxQtItem::CxxQt(m) => {
-                    // TODO: later we will allow for multiple CXX or CXX-Qt blocks in one file
-                    if found_bridge {
-                        panic!(
-                            "Unfortunately only files with either a single cxx or a single cxx_qt module are currently supported.
-                            The file {} has more than one of these.",
-                            rust_file_path.display());
-                    }
-                    found_bridge = true;
-
                     let mut parser = Parser::from(*m.clone())
                         .map_err(GeneratedError::from)
                         .map_err(to_diagnostic)?;
@@ -148,8 +308,16 @@ impl GeneratedCpp {
                         .map_err(GeneratedError::from)
                         .map_err(to_diagnostic)?;
 
-                    // TODO: we'll have to extend the C++ data here rather than overwriting
-                    // assuming we share the same file
+                    for qobject in &generated_cpp.qobjects {
+                        names
+                            .claim_qobject(
+                                qobject.name.cxx_qualified(),
+                                rust_file_path,
+                                qobject.name.rust_unqualified().span(),
+                            )
+                            .map_err(to_diagnostic)?;
+                    }
+
                     cxx_qt = Some(write_cpp(&generated_cpp, &include_ident));
                     let rust_tokens = write_rust(&generated_rust, Some(&include_ident));
 
@@ -157,23 +325,23 @@ impl GeneratedCpp {
                     // CXX bridge Rust code that is then fed into the cxx_gen generation.
                     tokens.extend(rust_tokens);
                 }
-                CxxQtItem::Item(item) => {
-                    tokens.extend(item.into_token_stream());
-                }
+                CxxQtItem::Item(_) => unreachable!(),
             }
-        }
 
-        let mut opt = cxx_gen::Opt::default();
-        opt.cfg_evaluator = Box::new(cfg_evaluator::CargoEnvCfgEvaluator);
-        let cxx = cxx_gen::generate_header_and_cc(tokens, &opt)
-            .map_err(GeneratedError::from)
-            .map_err(to_diagnostic)?;
+            let mut opt = cxx_gen::Opt::default();
+            opt.cfg_evaluator = Box::new(cfg_evaluator::CargoEnvCfgEvaluator);
+            let cxx = cxx_gen::generate_header_and_cc(tokens, &opt)
+                .map_err(GeneratedError::from)
+                .map_err(to_diagnostic)?;
+
+            generated.push(GeneratedCpp {
+                cxx_qt,
+                cxx,
+                file_ident,
+            });
+        }
 
-        Ok(GeneratedCpp {
-            cxx_qt,
-            cxx,
-            file_ident,
-        })
+        Ok(generated)
     }
 
     /// Write generated .cpp and .h files to specified directories. Returns the paths of all files written.
@@ -187,6 +355,7 @@ impl GeneratedCpp {
 
         let mut cpp_file_paths = GeneratedCppFilePaths {
             plain_cpp: PathBuf::new(),
+            plain_header: PathBuf::new(),
             qobject: None,
             qobject_header: None,
         };
@@ -241,10 +410,11 @@ impl GeneratedCpp {
             std::fs::create_dir_all(directory)
                 .expect("Could not create directory to write cxx-qt generated header files");
         }
-        let mut header = File::create(header_path).expect("Could not create cxx header file");
+        let mut header = File::create(&header_path).expect("Could not create cxx header file");
         header
             .write_all(&self.cxx.header)
             .expect("Could not write cxx header file");
+        cpp_file_paths.plain_header = header_path;
 
         let cpp_path = PathBuf::from(format!(
             "{}/{}.cxx.cpp",
@@ -269,6 +439,8 @@ fn generate_cxxqt_cpp_files(
     rs_source: &[impl AsRef<Path>],
     header_dir: impl AsRef<Path>,
     include_prefix: &str,
+    names: &mut GeneratedNames,
+    cache: &BuildCache,
 ) -> Vec<GeneratedCppFilePaths> {
     let cxx_qt_dir = dir::gen();
     std::fs::create_dir_all(&cxx_qt_dir).expect("Failed to create cxx-qt-gen directory!");
@@ -278,18 +450,54 @@ fn generate_cxxqt_cpp_files(
     let manifest_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
 
     let mut generated_file_paths: Vec<GeneratedCppFilePaths> = Vec::with_capacity(rs_source.len());
+    let out_dir = dir::out();
     for rs_path in rs_source {
         let path = manifest_dir.join(rs_path);
-        println!("cargo::rerun-if-changed={}", path.display());
+        // Bridges generated into OUT_DIR, such as for .ui files, are named relative to it and
+        // are not tracked as they are regenerated by the build script itself
+        let relative_path = if let Ok(relative_path) = path.strip_prefix(&out_dir) {
+            relative_path
+        } else {
+            println!("cargo::rerun-if-changed={}", path.display());
+            rs_path.as_ref()
+        };
 
-        let generated_code = match GeneratedCpp::new(&path, rs_path, include_prefix) {
+        // Skip cxx-qt-gen if the files generated for the same contents are cached
+        let key = CacheKey::new("cxx-qt-gen")
+            .generators()
+            .value(include_prefix)
+            .value(relative_path)
+            .value(&cxx_qt_dir)
+            .value(&header_dir)
+            .cfgs()
+            .file(&path);
+        if let Some(entry) = cache.get("cxx-qt-gen", &key) {
+            if let Err(diagnostic) = names.claim_cached(&entry, &path) {
+                diagnostic.report();
+                std::process::exit(1);
+            }
+            generated_file_paths.extend(GeneratedCppFilePaths::from_cache_entry(&entry));
+            continue;
+        }
+
+        let generated_code = match GeneratedCpp::new(&path, relative_path, include_prefix, names) {
             Ok(v) => v,
             Err(diagnostic) => {
                 diagnostic.report();
                 std::process::exit(1);
             }
         };
-        generated_file_paths.push(generated_code.write_to_directories(&cxx_qt_dir, &header_dir));
+        let mut entry = CacheEntry::default();
+        for generated in generated_code {
+            let file_ident = generated.file_ident.clone();
+            let paths = generated.write_to_directories(&cxx_qt_dir, &header_dir);
+            paths.add_to_cache_entry(&file_ident, &mut entry);
+            generated_file_paths.push(paths);
+        }
+        for qobject in names.qobjects_of(&path) {
+            entry.values.push(("qobject_name".to_owned(), qobject));
+        }
+        cache.insert("cxx-qt-gen", &key, &entry);
     }
 
     generated_file_paths
@@ -320,6 +528,14 @@ fn crate_init_key() -> String {
     format!("crate_{}", crate_name().replace('-', "_"))
 }
 
+fn qml_module_lib_name(module_uri: &str) -> String {
+    format!(
+        "{}-cxxqt-{}",
+        crate_name(),
+        module_name_from_uri(module_uri)
+    )
+}
+
 fn qml_module_init_key(module_uri: &str) -> String {
     format!("qml_module_{}", module_name_from_uri(module_uri))
 }
@@ -369,10 +585,17 @@ fn panic_duplicate_file_and_qml_module(
 pub struct CxxQtBuilder {
     rust_sources: Vec<PathBuf>,
     qobject_headers: Vec<QObjectHeaderOpts>,
+    // The C++ files generated while building, which are compiled with the build cache
+    generated_sources: Vec<PathBuf>,
+    ui_files: Vec<UiFileOpts>,
     qrc_files: Vec<PathBuf>,
+    translation_files: Vec<PathBuf>,
     init_files: Vec<qt_build_utils::Initializer>,
     qt_modules: HashSet<String>,
     qml_modules: Vec<OwningQmlModule>,
+    // Whether qmllint failures fail the build, or None if qmllint is not run
+    qmllint_fail_on_error: Option<bool>,
+    qmlformat_check: bool,
     cc_builder: cc::Build,
     include_prefix: String,
     crate_include_root: Option<String>,
@@ -410,10 +633,15 @@ impl CxxQtBuilder {
         Self {
             rust_sources: vec![],
             qobject_headers: vec![],
+            generated_sources: vec![],
+            ui_files: vec![],
             qrc_files: vec![],
+            translation_files: vec![],
             init_files: vec![],
             qt_modules,
             qml_modules: vec![],
+            qmllint_fail_on_error: None,
+            qmlformat_check: false,
             cc_builder: cc::Build::new(),
             include_prefix: crate_name(),
             crate_include_root: Some(String::new()),
@@ -511,6 +739,31 @@ impl CxxQtBuilder {
         self
     }
 
+    /// Compile [Qt Linguist](https://doc.qt.io/qt-6/qtlinguist-index.html) .ts files with
+    /// [lrelease](https://doc.qt.io/qt-6/linguist-lrelease.html) and include the resulting .qm
+    /// files into the binary with [Qt's resource system](https://doc.qt.io/qt-6/resources.html).
+    ///
+    /// The .qm files are available under the `:/i18n/` prefix with the name of the .ts file,
+    /// so that `translations/myapp_fr.ts` can be loaded by a `QTranslator` from `:/i18n/myapp_fr.qm`.
+    /// ```no_run
+    /// # use cxx_qt_build::CxxQtBuilder;
+    /// CxxQtBuilder::new()
+    ///     .file("src/cxxqt_module.rs")
+    ///     .translations(&["translations/myapp_de.ts", "translations/myapp_fr.ts"])
+    ///     .build();
+    /// ```
+    ///
+    /// The .ts files can be created and updated from the `tr!` invocations of the Rust sources
+    /// with [qt_build_utils::QtToolLupdate].
+    pub fn translations(mut self, ts_files: &[impl AsRef<Path>]) -> Self {
+        for ts_file in ts_files {
+            let ts_file = ts_file.as_ref();
+            self.translation_files.push(ts_file.to_path_buf());
+            println!("cargo::rerun-if-changed={}", ts_file.display());
+        }
+        self
+    }
+
     /// Link additional [Qt modules](https://doc.qt.io/qt-6/qtmodules.html).
     /// Specify their names without the `Qt` prefix, for example `"Widgets"`.
     /// The `Core` module and any modules from dependencies are linked automatically; there is no need to specify them.
@@ -520,15 +773,18 @@ impl CxxQtBuilder {
     /// It is therefore best practice to specify features on your crate that allow downstream users
     /// to disable any qt modules that are optional.
     pub fn qt_module(mut self, module: &str) -> Self {
-        // Ensure that CMake and Cargo build.rs are not out of sync
+        self.check_qt_module_imported(module);
+        self.qt_modules.insert(module.to_owned());
+        self
+    }
+
+    /// Ensure that CMake and Cargo build.rs are not out of sync
+    fn check_qt_module_imported(&self, module: &str) {
         if qt_modules_import().is_some() && !self.qt_modules.contains(module) {
             panic!("Qt module mismatch between cxx-qt-build and CMake!\n\
                     Qt module '{module}' was not specified in CMake!\n\
                     When building with CMake, all Qt modules must be specified with the QT_MODULES argument in cxx_qt_import_crate");
         }
-
-        self.qt_modules.insert(module.to_owned());
-        self
     }
 
     /// Instead of generating files under the crate name, generate files under the given prefix.
@@ -548,6 +804,12 @@ impl CxxQtBuilder {
     /// When using Qt 6, this will [run qmlcachegen](https://doc.qt.io/qt-6/qtqml-qtquick-compiler-tech.html)
     /// to compile the specified `.qml` files ahead-of-time.
     ///
+    /// Each QML module is compiled into its own static library with its own initializer. The
+    /// `include_dirs`, `defines` and `cc_builder` of the [QmlModule] only apply to that library,
+    /// rather than to the builder configured with [CxxQtBuilder::cc_builder]. The include paths of
+    /// the `qt_modules` of the [QmlModule] also only apply to that library, while the Qt modules
+    /// are linked once for the whole crate.
+    ///
     /// ```no_run
     /// use cxx_qt_build::{CxxQtBuilder, QmlModule};
     ///
@@ -560,7 +822,7 @@ impl CxxQtBuilder {
     ///     })
     ///     .build();
     /// ```
-    pub fn qml_module<A: AsRef<Path>, B: AsRef<Path>>(
+    pub fn qml_module<A: AsRef<Path>, B: Clone + Into<QmlFile>>(
         mut self,
         qml_module: QmlModule<A, B>,
     ) -> CxxQtBuilder {
@@ -575,10 +837,50 @@ impl CxxQtBuilder {
                 );
             }
         }
+        for module in &qml_module.qt_modules {
+            self.check_qt_module_imported(module);
+        }
         self.qml_modules.push(qml_module);
         self
     }
 
+    /// Run [qmllint](https://doc.qt.io/qt-6/qtqml-tooling-qmllint.html) on the `qml_files` of
+    /// each [QmlModule] when building.
+    ///
+    /// The types of the QML module are checked using its generated qmldir and qmltypes files,
+    /// so that for example a typo in a property of a QObject defined in Rust is found.
+    /// The warnings of qmllint are printed as cargo warnings with their file and line,
+    /// and if `fail_on_error` is set the build fails with a diagnostic for each warning when
+    /// qmllint reports any.
+    ///
+    /// ```no_run
+    /// use cxx_qt_build::{CxxQtBuilder, QmlModule};
+    ///
+    /// CxxQtBuilder::new()
+    ///     .qml_module(QmlModule {
+    ///         uri: "com.kdab.cxx_qt.demo",
+    ///         rust_files: &["src/cxxqt_object.rs"],
+    ///         qml_files: &["qml/main.qml"],
+    ///         ..Default::default()
+    ///     })
+    ///     .qmllint(true)
+    ///     .build();
+    /// ```
+    ///
+    /// Note that this requires Qt 6, with Qt 5 a warning is printed instead.
+    pub fn qmllint(mut self, fail_on_error: bool) -> Self {
+        self.qmllint_fail_on_error = Some(fail_on_error);
+        self
+    }
+
+    /// Check that the `qml_files` of each [QmlModule] are formatted with
+    /// [qmlformat](https://doc.qt.io/qt-6/qtqml-tooling-qmlformat.html) when building,
+    /// printing a cargo warning for each file which is not.
+    pub fn qmlformat_check(mut self) -> Self {
+        self.qmlformat_check = true;
+        self
+    }
+
     /// Specify a C++ header containing a Q_OBJECT macro to run [moc](https://doc.qt.io/qt-6/moc.html) on.
     /// This allows building QObject C++ subclasses besides the ones autogenerated by cxx-qt.
     pub fn qobject_header(mut self, opts: impl Into<QObjectHeaderOpts>) -> Self {
@@ -588,6 +890,37 @@ impl CxxQtBuilder {
         self
     }
 
+    /// Specify a [Qt Designer](https://doc.qt.io/qt-6/qtdesigner-manual.html) `.ui` file to run
+    /// [uic](https://doc.qt.io/qt-6/uic.html) on, which also links the Qt Widgets module.
+    /// The generated header can then be included by C++ code built with [CxxQtBuilder::cc_builder]
+    /// as `#include "ui_<name>.h"`, where `<name>` is the file name of the `.ui` file.
+    ///
+    /// If [UiFileOpts::bridge] is set, a `#[cxx_qt::bridge]` named `ui_<name>` is generated which
+    /// exposes the form and its widgets, layouts and actions as `extern "C++Qt"` types.
+    /// Include it into your crate from [cargo's OUT_DIR](https://doc.rust-lang.org/cargo/reference/environment-variables.html):
+    ///
+    /// ```no_run
+    /// use cxx_qt_build::{CxxQtBuilder, UiFileOpts};
+    ///
+    /// CxxQtBuilder::new()
+    ///     .ui_file(UiFileOpts::from("ui/mainwindow.ui").bridge(true))
+    ///     .build();
+    /// ```
+    ///
+    /// ```ignore
+    /// include!(concat!(env!("OUT_DIR"), "/ui/ui_mainwindow.rs"));
+    ///
+    /// let mut form = ui_mainwindow::form_new();
+    /// unsafe { form.pin_mut().setup_ui(window) };
+    /// let button = ui_mainwindow::push_button(&form);
+    /// ```
+    pub fn ui_file(mut self, opts: impl Into<UiFileOpts>) -> Self {
+        let opts = opts.into();
+        println!("cargo::rerun-if-changed={}", opts.path.display());
+        self.ui_files.push(opts);
+        self.qt_module("Widgets")
+    }
+
     /// Use a closure to run additional customization on [CxxQtBuilder]'s internal [cc::Build]
     /// before calling [CxxQtBuilder::build]. This allows to add extra include paths, compiler flags,
     /// or anything else available via [cc::Build]'s API. For example, to add an include path for
@@ -728,18 +1061,46 @@ impl CxxQtBuilder {
         }
     }
 
-    fn moc_qobject_headers(&mut self, qtbuild: &mut qt_build_utils::QtBuild) {
+    fn moc_qobject_headers(&mut self, qtbuild: &mut qt_build_utils::QtBuild, cache: &BuildCache) {
         for QObjectHeaderOpts {
             path,
             moc_arguments,
         } in &self.qobject_headers
         {
-            let moc_products = qtbuild.moc().compile(path, moc_arguments.clone());
+            let moc_products = cache.moc(qtbuild, path, moc_arguments.clone());
             // Include the moc folder
             if let Some(dir) = moc_products.cpp.parent() {
                 self.cc_builder.include(dir);
             }
-            self.cc_builder.file(moc_products.cpp);
+            self.generated_sources.push(moc_products.cpp);
+        }
+    }
+
+    fn compile_ui_files(&mut self, qtbuild: &qt_build_utils::QtBuild) {
+        if self.ui_files.is_empty() {
+            return;
+        }
+
+        let uic = qtbuild.uic();
+        self.cc_builder.include(uic.include_path());
+        for UiFileOpts { path, bridge } in &self.ui_files {
+            let header = match uic.try_compile(path) {
+                Ok(header) => header,
+                Err(err) => {
+                    Diagnostic::from(err).report();
+                    std::process::exit(1);
+                }
+            };
+            if *bridge {
+                let header_dir = header.parent().unwrap();
+                match ui::generate_bridge(path, header_dir) {
+                    Ok(bridge) => self.rust_sources.push(bridge),
+                    Err(err) => {
+                        Diagnostic::from(err).report();
+                        std::process::exit(1);
+                    }
+                }
+            }
         }
     }
 
@@ -747,11 +1108,19 @@ impl CxxQtBuilder {
         &mut self,
         header_dir: impl AsRef<Path>,
         include_prefix: &str,
+        names: &mut GeneratedNames,
+        cache: &BuildCache,
     ) {
-        for files in generate_cxxqt_cpp_files(&self.rust_sources, &header_dir, include_prefix) {
-            self.cc_builder.file(files.plain_cpp);
+        for files in generate_cxxqt_cpp_files(
+            &self.rust_sources,
+            &header_dir,
+            include_prefix,
+            names,
+            cache,
+        ) {
+            self.generated_sources.push(files.plain_cpp);
             if let (Some(qobject), Some(qobject_header)) = (files.qobject, files.qobject_header) {
-                self.cc_builder.file(&qobject);
+                self.generated_sources.push(qobject);
                 self.qobject_headers.push(qobject_header.into());
             }
         }
@@ -788,65 +1157,120 @@ impl CxxQtBuilder {
         }
     }
 
+    /// Generate the C++ code of each QML module, returning the initializers of the modules along
+    /// with the builder and library name of each module.
+    ///
+    /// The builders must be compiled after the main library, which calls the initializers.
+    /// The arguments to link the additional Qt modules of the QML modules are merged into
+    /// `link_args`, so that each library is only linked once.
+    #[allow(clippy::too_many_arguments)]
     fn build_qml_modules(
         &mut self,
         qtbuild: &mut qt_build_utils::QtBuild,
+        qt_modules: &HashSet<String>,
+        link_args: &mut qt_build_utils::LinkArgs,
+        include_paths: &[impl AsRef<Path>],
         generated_header_dir: impl AsRef<Path>,
         header_prefix: &str,
-    ) -> Vec<qt_build_utils::Initializer> {
+        names: &mut GeneratedNames,
+        cache: &BuildCache,
+    ) -> (Vec<qt_build_utils::Initializer>, Vec<QmlModuleLibrary>) {
         let mut initializer_functions = Vec::new();
+        let mut module_builders = Vec::new();
         // Extract qml_modules out of self so we don't have to hold onto `self` for the duration of
         // the loop.
         let qml_modules: Vec<_> = self.qml_modules.drain(..).collect();
-        for qml_module in qml_modules {
+        for mut qml_module in qml_modules {
             dir::clean(dir::module_target(&qml_module.uri))
                 .expect("Failed to clean qml module export directory!");
 
             let mut qml_metatypes_json = Vec::new();
 
-            // Check that all rust files are within the same directory
+            // Check that the rust files in different directories have unique file names
             //
             // Note we need to do this as moc generates an inputFile which only
             // includes the file name, qmltyperegistrar then uses this for the
-            // include path (and doesn't consider any prefix).
+            // include path (and doesn't consider any prefix). The directory of
+            // each generated header is added to the include path instead.
             //
             // This can also be observed when using qt_add_qml_module, if a class
             // has a QML_ELEMENT the file must be in the same directory as the
             // CMakeLists and cannot be a relative path to a sub directory.
-            let dirs = qml_module
-                .rust_files
+            // See Qt bug https://bugreports.qt.io/browse/QTBUG-93443
+            let mut file_names = HashMap::new();
+            for file in &qml_module.rust_files {
+                let Some(file_name) = file.file_name() else {
+                    continue;
+                };
+                if let Some(existing) = file_names.insert(file_name, file) {
+                    let error = cxx_qt_gen::Error::new(
+                        Span::call_site(),
+                        format!(
+                            "Rust files in multiple directories of QmlModule {} must have unique file names, but {} has the same file name as {}. \
+                            This is due to Qt bug https://bugreports.qt.io/browse/QTBUG-93443",
+                            qml_module.uri,
+                            file.display(),
+                            existing.display()
+                        ),
+                    );
+                    Diagnostic::new(file.clone(), GeneratedError::from(error)).report();
+                    std::process::exit(1);
+                }
+            }
+
+            // A QML module with additional Qt modules has its own QtBuild, so that the include
+            // paths of these Qt modules are only used for this module
+            let mut module_qtbuild = None;
+            if !qml_module
+                .qt_modules
                 .iter()
-                .map(|file| {
-                    if let Some(parent) = file.parent() {
-                        parent.to_string_lossy().into_owned()
-                    } else {
-                        // Fallback to an empty string if there is no parent path
-                        String::new()
-                    }
-                })
-                .collect::<HashSet<String>>();
-            if dirs.len() > 1 {
-                panic!(
-                    "Only one directory is supported per QmlModule for rust_files.\n\
-                    This is due to Qt bug https://bugreports.qt.io/browse/QTBUG-93443\n\
-                    Found directories: {dirs:?}"
+                .all(|module| qt_modules.contains(module))
+            {
+                let module_qt_modules = qt_modules
+                    .iter()
+                    .chain(&qml_module.qt_modules)
+                    .cloned()
+                    .collect::<HashSet<_>>();
+                module_qtbuild = Some(
+                    qt_build_utils::QtBuild::new(module_qt_modules.into_iter().collect())
+                        .expect("Could not find Qt installation"),
                 );
             }
-
-            // TODO: for now we use the global CxxQtBuilder cc_builder
-            // this means that any includes/files etc on these are in this builder
-            // but we cannot have separate builds until we can configure includes,
-            // qt modules, files, cc_builder options etc in the QmlModule itself
-            let cc_builder = &mut self.cc_builder;
-            qtbuild.cargo_link_libraries(cc_builder);
+            let qtbuild = match module_qtbuild.as_mut() {
+                Some(module_qtbuild) => module_qtbuild,
+                None => &mut *qtbuild,
+            };
+            let qt_include_paths = qtbuild.include_paths();
+
+            // Each QML module has its own builder, so that the includes, defines and flags of
+            // one module do not affect the others
+            let cc_builder = &mut qml_module.cc_builder;
+            Self::setup_cc_builder(cc_builder, include_paths);
+            for include_path in qt_include_paths.iter().filter(|path| {
+                !include_paths
+                    .iter()
+                    .any(|include_path| include_path.as_ref() == path.as_path())
+            }) {
+                cc_builder.include(include_path);
+            }
+            // The Qt modules are linked once for the whole crate, so only the compiler flags are
+            // needed for the builder of this module
+            let module_link_args = qtbuild.link_args();
+            for flag in module_link_args.builder_flags() {
+                cc_builder.flag_if_supported(flag);
+            }
+            link_args.merge(module_link_args);
+            let mut generated_sources = vec![];
 
             let mut moc_include_paths = HashSet::new();
             for files in generate_cxxqt_cpp_files(
                 &qml_module.rust_files,
                 &generated_header_dir,
                 header_prefix,
+                names,
+                cache,
             ) {
-                cc_builder.file(files.plain_cpp);
+                generated_sources.push(files.plain_cpp);
                 if let (Some(qobject), Some(qobject_header)) = (files.qobject, files.qobject_header)
                 {
                     // Ensure that the generated QObject header is in the include path
@@ -855,42 +1279,96 @@ impl CxxQtBuilder {
                         moc_include_paths.insert(dir.to_path_buf());
                     }
 
-                    cc_builder.file(&qobject);
-                    let moc_products = qtbuild.moc().compile(
-                        qobject_header,
+                    generated_sources.push(qobject);
+                    let moc_products = cache.moc(
+                        qtbuild,
+                        &qobject_header,
                         MocArguments::default().uri(qml_module.uri.clone()),
                     );
                     // Include the moc folder
                     if let Some(dir) = moc_products.cpp.parent() {
                         moc_include_paths.insert(dir.to_path_buf());
                     }
-                    cc_builder.file(moc_products.cpp);
+                    generated_sources.push(moc_products.cpp);
                     qml_metatypes_json.push(moc_products.metatypes_json);
                 }
             }
 
-            let qml_module_registration_files = qtbuild.register_qml_module(
+            // A dynamic plugin is the shared library of this crate, which Cargo places in the
+            // profile directory, so point the qmldir in the qml import path there
+            let qml_plugin_dir = qml_module
+                .dynamic_plugin
+                .then(|| dir::qml_plugin(&qml_module.uri))
+                .flatten();
+            let (plugin_name, plugin_type) = if qml_module.dynamic_plugin {
+                let path = qml_plugin_dir.as_ref().map(|_| {
+                    let depth = qml_module.uri.split('.').count() + 1;
+                    vec![".."; depth].join("/")
+                });
+                (
+                    crate_name().replace('-', "_"),
+                    qt_build_utils::QmlPluginType::Dynamic { path },
+                )
+            } else {
+                // This will be passed to the `optional plugin ...` part of the qmldir
+                // A static plugin is never loaded from a shared library, so the name shouldn't
+                // matter
+                (
+                    module_name_from_uri(&qml_module.uri),
+                    qt_build_utils::QmlPluginType::Static,
+                )
+            };
+
+            let qml_module_registration_files = match qtbuild.try_register_qml_module(
                 &qml_metatypes_json,
                 &qml_module.uri,
                 qml_module.version_major,
                 qml_module.version_minor,
-                // TODO: This will be passed to the `optional plugin ...` part of the qmldir
-                // We don't load any shared libraries, so the name shouldn't matter
-                // But make sure it still works
-                &module_name_from_uri(&qml_module.uri),
+                &plugin_name,
                 &qml_module.qml_files,
                 &qml_module.qrc_files,
+                &qml_module.qmldir_entries,
+                &plugin_type,
+            ) {
+                Ok(files) => files,
+                Err(err) => {
+                    Diagnostic::from(err).report();
+                    std::process::exit(1);
+                }
+            };
+
+            // Place the qmldir and qmltypes of a dynamic plugin in the qml import path
+            if let Some(qml_plugin_dir) = qml_plugin_dir {
+                std::fs::create_dir_all(&qml_plugin_dir)
+                    .expect("Could not create QML plugin directory");
+                std::fs::copy(
+                    &qml_module_registration_files.qmldir,
+                    qml_plugin_dir.join("qmldir"),
+                )
+                .expect("Could not copy qmldir to QML plugin directory");
+                if qml_module_registration_files.qmltypes.exists() {
+                    std::fs::copy(
+                        &qml_module_registration_files.qmltypes,
+                        qml_plugin_dir.join("plugin.qmltypes"),
+                    )
+                    .expect("Could not copy qmltypes to QML plugin directory");
+                }
+            }
+            self.check_qml_files(
+                qtbuild,
+                &qml_module.uri,
+                &qml_module.qml_files,
+                &qml_module_registration_files.qmldir,
             );
             if let Some(qmltyperegistrar) = qml_module_registration_files.qmltyperegistrar {
-                cc_builder.file(qmltyperegistrar);
+                generated_sources.push(qmltyperegistrar);
             }
-            cc_builder
-                .file(qml_module_registration_files.plugin)
-                // In comparison to the other RCC files, we don't need to link this with whole-archive or
-                // anything like that.
-                // The plugin_init file already takes care of loading the resources associated with this
-                // RCC file.
-                .file(qml_module_registration_files.rcc);
+            generated_sources.push(qml_module_registration_files.plugin);
+            // In comparison to the other RCC files, we don't need to link this with whole-archive or
+            // anything like that.
+            // The plugin_init file already takes care of loading the resources associated with this
+            // RCC file.
+            generated_sources.push(qml_module_registration_files.rcc);
 
             // Add any include paths the qml module registration needs
             // this is most likely the moc folder for the plugin
@@ -903,27 +1381,37 @@ impl CxxQtBuilder {
                 cc_builder.include(include_path);
             }
 
-            for qmlcachegen_file in qml_module_registration_files.qmlcachegen {
-                cc_builder.file(qmlcachegen_file);
+            generated_sources.extend(qml_module_registration_files.qmlcachegen);
+            // This is required for a static plugin, as described here: plugin_builder
+            //
+            // A dynamic plugin is compiled without it, so that moc generates the plugin metadata
+            // which the QML engine reads from the shared library
+            if !qml_module.dynamic_plugin {
+                cc_builder.define("QT_STATICPLUGIN", None);
             }
-            // This is required, as described here: plugin_builder
-            cc_builder.define("QT_STATICPLUGIN", None);
 
             // If any of the files inside the qml module change, then trigger a rerun
-            for path in qml_module.qml_files.iter().chain(
+            for path in qml_module.qml_files.iter().map(QmlFile::path).chain(
                 qml_module
                     .rust_files
                     .iter()
-                    .chain(qml_module.qrc_files.iter()),
+                    .chain(qml_module.qrc_files.iter())
+                    .map(PathBuf::as_path),
             ) {
                 println!("cargo::rerun-if-changed={}", path.display());
             }
+            // Along with the files listed in the qrc files
+            let rcc = qtbuild.rcc();
+            for qrc_inner_file in qml_module.qrc_files.iter().flat_map(|qrc| rcc.list(qrc)) {
+                println!("cargo::rerun-if-changed={}", qrc_inner_file.display());
+            }
 
             let module_init_key = qml_module_init_key(&qml_module.uri);
             let private_initializers = [qml_module_registration_files.plugin_init];
             let public_initializer =
                 Self::generate_public_initializer(&private_initializers, &module_init_key);
-            self.build_initializers(
+            Self::build_initializers(
+                &mut generated_sources,
                 &private_initializers,
                 &public_initializer,
                 dir::module_export(&qml_module.uri).map(|dir| dir.join("plugin_init.o")),
@@ -931,8 +1419,93 @@ impl CxxQtBuilder {
             );
 
             initializer_functions.push(public_initializer.strip_file());
+            module_builders.push(QmlModuleLibrary {
+                lib_name: qml_module_lib_name(&qml_module.uri),
+                cc_builder: qml_module.cc_builder,
+                qt_include_paths,
+                generated_sources,
+            });
+        }
+        (initializer_functions, module_builders)
+    }
+
+    /// Run qmllint and qmlformat on the QML files of a QML module, if enabled
+    fn check_qml_files(
+        &self,
+        qtbuild: &qt_build_utils::QtBuild,
+        uri: &str,
+        qml_files: &[QmlFile],
+        qmldir: &Path,
+    ) {
+        let qml_files = qml_files
+            .iter()
+            .map(QmlFile::path)
+            .filter(|path| path.extension().is_some_and(|extension| extension == "qml"))
+            .collect::<Vec<_>>();
+        if qml_files.is_empty() {
+            return;
+        }
+
+        if let Some(fail_on_error) = self.qmllint_fail_on_error {
+            if qtbuild.version().major < 6 {
+                println!("cargo::warning=qmllint requires Qt 6, skipping QML module {uri}");
+            } else {
+                let import_path =
+                    qml_import_path(qmldir, uri).expect("Could not find import path of QML module");
+                let output = match qtbuild
+                    .try_qmllint()
+                    .and_then(|qmllint| qmllint.try_lint(&qml_files, &[import_path]))
+                {
+                    Ok(output) => output,
+                    Err(err) => {
+                        Diagnostic::from(err).report();
+                        std::process::exit(1);
+                    }
+                };
+                if fail_on_error && !output.success {
+                    Diagnostic::from(qt_build_utils::QtBuildError::ToolFailed {
+                        tool: "qmllint".to_owned(),
+                        input: format!("QML module {uri}"),
+                        output: output.messages.join("\n"),
+                    })
+                    .report();
+                    std::process::exit(1);
+                }
+                for message in &output.messages {
+                    println!("cargo::warning=qmllint: {message}");
+                }
+            }
+        }
+
+        if self.qmlformat_check {
+            let qmlformat = match qtbuild.try_qmlformat() {
+                Ok(qmlformat) => qmlformat,
+                Err(err) => {
+                    Diagnostic::from(err).report();
+                    std::process::exit(1);
+                }
+            };
+            for qml_file in qml_files {
+                // The check is only advisory, so a file which qmlformat cannot format, for
+                // example due to a syntax error, is reported as a warning as well
+                match qmlformat.try_is_formatted(qml_file) {
+                    Ok(true) => {}
+                    Ok(false) => println!(
+                        "cargo::warning=qmlformat: {} is not formatted",
+                        qml_file.display()
+                    ),
+                    Err(err) => {
+                        println!(
+                            "cargo::warning=qmlformat could not check {}:",
+                            qml_file.display()
+                        );
+                        for line in err.to_string().lines() {
+                            println!("cargo::warning={line}");
+                        }
+                    }
+                }
+            }
         }
-        initializer_functions
     }
 
     /// Generate the public initializer.
@@ -992,25 +1565,24 @@ extern "C" bool {init_fun}() {{
     }
 
     fn build_initializers<'a>(
-        &mut self,
+        sources: &mut Vec<PathBuf>,
         private_initializers: impl IntoIterator<Item = &'a qt_build_utils::Initializer>,
         public_initializer: &qt_build_utils::Initializer,
         export_path: Option<PathBuf>,
         key: &str,
     ) {
-        // Build the initializers themselves into the main library.
-        self.cc_builder
-            .file(
-                public_initializer
-                    .file
-                    .as_ref()
-                    .expect("Public initializer must have a file!"),
-            )
-            .files(
-                private_initializers
-                    .into_iter()
-                    .filter_map(|initializer| initializer.file.as_ref()),
-            );
+        // Build the initializers themselves into the given library.
+        sources.push(
+            public_initializer
+                .file
+                .clone()
+                .expect("Public initializer must have a file!"),
+        );
+        sources.extend(
+            private_initializers
+                .into_iter()
+                .filter_map(|initializer| initializer.file.clone()),
+        );
 
         // Build the initializer call into a separate library to be linked with whole-archive.
         // We can just use a plain cc::Build for this, as this doesn't use any non-standard
@@ -1056,21 +1628,77 @@ extern "C" bool {init_fun}() {{
     fn generate_cpp_from_qrc_files(
         &mut self,
         qtbuild: &mut qt_build_utils::QtBuild,
+        cache: &BuildCache,
     ) -> Vec<qt_build_utils::Initializer> {
         self.qrc_files
             .iter()
             .map(|qrc_file| {
+                // We need to link this using an object file or +whole-achive, the static initializer of
+                // the qrc file isn't lost.
+                let (initializer, qrc_inner_files) = cache.rcc(qtbuild, qrc_file);
                 // Also ensure that each of the files in the qrc can cause a change
-                for qrc_inner_file in qtbuild.rcc().list(qrc_file) {
+                for qrc_inner_file in qrc_inner_files {
                     println!("cargo::rerun-if-changed={}", qrc_inner_file.display());
                 }
-                // We need to link this using an object file or +whole-achive, the static initializer of
-                // the qrc file isn't lost.
-                qtbuild.rcc().compile(qrc_file)
+                initializer
             })
             .collect()
     }
 
+    fn generate_cpp_from_translations(
+        &self,
+        qtbuild: &mut qt_build_utils::QtBuild,
+        cache: &BuildCache,
+    ) -> Option<qt_build_utils::Initializer> {
+        if self.translation_files.is_empty() {
+            return None;
+        }
+
+        let lrelease = qtbuild.lrelease();
+        let qm_files_qrc = self
+            .translation_files
+            .iter()
+            .map(|ts_file| {
+                let qm_file = match lrelease.try_compile(ts_file) {
+                    Ok(qm_file) => qm_file,
+                    Err(err) => {
+                        Diagnostic::from(err).report();
+                        std::process::exit(1);
+                    }
+                };
+                format!(
+                    "    <file alias=\"{}\">{}</file>",
+                    qm_file.file_name().unwrap().to_string_lossy(),
+                    qm_file.display()
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        // The name of the qrc file is used for the resource initializer, so it needs to be
+        // unique across crates
+        let qrc_path = dir::out().join(format!(
+            "{}_translations.qrc",
+            crate_name().replace('-', "_")
+        ));
+        std::fs::write(
+            &qrc_path,
+            format!(
+                r#"<RCC>
+<qresource prefix="/i18n">
+{qm_files_qrc}
+</qresource>
+</RCC>
+"#
+            ),
+        )
+        .expect("Could not write translations qrc file");
+
+        // The .qm files are regenerated on every run, so they are not tracked with rerun-if-changed
+        let (initializer, _) = cache.rcc(qtbuild, &qrc_path);
+        Some(initializer)
+    }
+
     fn qt_modules(&self, dependencies: &[Dependency]) -> HashSet<String> {
         let mut qt_modules = self.qt_modules.clone();
         for dependency in dependencies {
@@ -1081,8 +1709,14 @@ extern "C" bool {init_fun}() {{
 
     /// Generate and compile cxx-qt C++ code, as well as compile any additional files from
     /// [CxxQtBuilder::qobject_header] and [CxxQtBuilder::cc_builder].
+    ///
+    /// The generated C++ code, moc and rcc output and object files are cached in
+    /// [cargo's OUT_DIR](https://doc.rust-lang.org/cargo/reference/environment-variables.html)
+    /// by the hash of their inputs, so that only the bridges which changed are regenerated and
+    /// recompiled when the build script is rerun.
     pub fn build(mut self) -> Interface {
         dir::clean(dir::crate_target()).expect("Failed to clean crate export directory!");
+        diagnostics::remove_json_diagnostics();
 
         // We will do these two steps first, as setting up the dependencies can modify flags we
         // need further down the line
@@ -1102,11 +1736,12 @@ extern "C" bool {init_fun}() {{
 
         let mut qtbuild = qt_build_utils::QtBuild::new(qt_modules.iter().cloned().collect())
             .expect("Could not find Qt installation");
-        qtbuild.cargo_link_libraries(&mut self.cc_builder);
+        let mut link_args = qtbuild.link_args();
         Self::define_qt_version_cfg_variables(qtbuild.version());
 
         // Ensure that Qt modules and apple framework are linked and searched correctly
-        let mut include_paths = qtbuild.include_paths();
+        let qt_include_paths = qtbuild.include_paths();
+        let mut include_paths = qt_include_paths.clone();
         include_paths.push(header_root.clone());
         // TODO: Some of the code generated by qmltyperegistrar doesn't add the include_prefix to
         // the #include directives.
@@ -1146,22 +1781,46 @@ extern "C" bool {init_fun}() {{
 
         Self::setup_cc_builder(&mut self.cc_builder, &include_paths);
 
-        // Generate files
-        self.generate_cpp_files_from_cxxqt_bridges(&header_root, &self.include_prefix.clone());
+        // Run uic first, as the bridges generated for .ui files are built with the other bridges
+        self.compile_ui_files(&qtbuild);
+
+        // Generate files, tracking generated names across all bridges so duplicates are reported
+        // and skipping the work for inputs which are unchanged since the previous build
+        let cache = BuildCache::new();
+        let mut generated_names = GeneratedNames::default();
+        self.generate_cpp_files_from_cxxqt_bridges(
+            &header_root,
+            &self.include_prefix.clone(),
+            &mut generated_names,
+            &cache,
+        );
 
-        self.moc_qobject_headers(&mut qtbuild);
+        self.moc_qobject_headers(&mut qtbuild, &cache);
 
         // Bridges for QML modules are handled separately because
         // the metatypes_json generated by moc needs to be passed to qmltyperegistrar
-        let module_initializers =
-            self.build_qml_modules(&mut qtbuild, &header_root, &self.include_prefix.clone());
+        let (module_initializers, module_builders) = self.build_qml_modules(
+            &mut qtbuild,
+            &qt_modules,
+            &mut link_args,
+            &include_paths,
+            &header_root,
+            &self.include_prefix.clone(),
+            &mut generated_names,
+            &cache,
+        );
+
+        // Link the Qt modules of the crate and its QML modules
+        link_args.apply(&mut self.cc_builder);
 
-        let qrc_files = self.generate_cpp_from_qrc_files(&mut qtbuild);
+        let qrc_files = self.generate_cpp_from_qrc_files(&mut qtbuild, &cache);
+        let translations = self.generate_cpp_from_translations(&mut qtbuild, &cache);
 
         let dependency_initializers = dependencies::initializers(&dependencies);
         let private_initializers = dependency_initializers
             .into_iter()
             .chain(qrc_files)
+            .chain(translations)
             .chain(module_initializers)
             .chain(self.init_files.iter().cloned())
             .collect::<Vec<_>>();
@@ -1173,18 +1832,34 @@ extern "C" bool {init_fun}() {{
         } else {
             None
         };
-        self.build_initializers(
+        Self::build_initializers(
+            &mut self.generated_sources,
             &private_initializers,
             &public_initializer,
             export_path,
             &crate_init_key(),
         );
 
-        // Only compile if we have added files to the builder
-        // otherwise we end up with no static library but ask cargo to link to it which causes an error
-        if self.cc_builder.get_files().count() > 0 {
-            self.cc_builder.compile(&static_lib_name());
+        cache.compile(
+            &qtbuild,
+            &self.cc_builder,
+            &self.generated_sources,
+            &qt_include_paths,
+            &static_lib_name(),
+        );
+
+        // Each QML module is compiled into its own library, after the main library as the
+        // initializer of the crate calls the initializers of the modules
+        for module in module_builders {
+            cache.compile(
+                &qtbuild,
+                &module.cc_builder,
+                &module.generated_sources,
+                &module.qt_include_paths,
+                &module.lib_name,
+            );
         }
+        cache.prune();
 
         Interface {
             manifest: Manifest {
@@ -1199,3 +1874,127 @@ extern "C" bool {init_fun}() {{
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cache::tests::TestDir;
+
+    const ONE_BRIDGE: &str = r#"
+        #[cxx::bridge]
+        mod ffi {
+            extern "Rust" {
+                fn value() -> i32;
+            }
+        }
+    "#;
+
+    const TWO_BRIDGES: &str = r#"
+        #[cxx::bridge]
+        mod first {
+            extern "Rust" {
+                fn first() -> i32;
+            }
+        }
+
+        #[cxx::bridge]
+        mod second {
+            extern "Rust" {
+                fn second() -> i32;
+            }
+        }
+    "#;
+
+    fn file_idents(generated: &[GeneratedCpp]) -> Vec<&str> {
+        generated
+            .iter()
+            .map(|generated| generated.file_ident.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn qml_import_path_of_dotted_uri() {
+        let qmldir = Path::new("/out/qml_modules/com/kdab/cxx_qt/demo/qmldir");
+        assert_eq!(
+            qml_import_path(qmldir, "com.kdab.cxx_qt.demo"),
+            Some(Path::new("/out/qml_modules"))
+        );
+        assert_eq!(
+            qml_import_path(Path::new("/out/qml_modules/demo/qmldir"), "demo"),
+            Some(Path::new("/out/qml_modules"))
+        );
+    }
+
+    #[test]
+    fn generated_names_one_bridge() {
+        let dir = TestDir::new("names-one");
+        let path = dir.write("src/lib.rs", ONE_BRIDGE);
+        let mut names = GeneratedNames::default();
+        let generated =
+            GeneratedCpp::new(&path, "src/lib.rs", "crate", &mut names).unwrap_or_else(|_| {
+                panic!("Expected {} to generate C++", path.display());
+            });
+        assert_eq!(file_idents(&generated), vec!["src/lib"]);
+        assert_eq!(names.file_idents.get("src/lib"), Some(&path));
+    }
+
+    #[test]
+    fn generated_names_two_bridges() {
+        let dir = TestDir::new("names-two");
+        let path = dir.write("src/lib.rs", TWO_BRIDGES);
+        let mut names = GeneratedNames::default();
+        let generated =
+            GeneratedCpp::new(&path, "src/lib.rs", "crate", &mut names).unwrap_or_else(|_| {
+                panic!("Expected {} to generate C++", path.display());
+            });
+        assert_eq!(
+            file_idents(&generated),
+            vec!["src/lib_first", "src/lib_second"]
+        );
+        assert!(!names.file_idents.contains_key("src/lib"));
+    }
+
+    #[test]
+    fn generated_names_same_stem() {
+        let dir = TestDir::new("names-stem");
+        let first = dir.write("first/src/bridge.rs", ONE_BRIDGE);
+        let second = dir.write("second/src/bridge.rs", ONE_BRIDGE);
+        let mut names = GeneratedNames::default();
+        assert!(GeneratedCpp::new(&first, "src/bridge.rs", "crate", &mut names).is_ok());
+
+        let Err(diagnostic) = GeneratedCpp::new(&second, "src/bridge.rs", "crate", &mut names)
+        else {
+            panic!("Expected a diagnostic for the duplicate file name");
+        };
+        assert_eq!(
+            diagnostic.message_texts(),
+            vec![format!(
+                "The C++ file `src/bridge` is already generated from {}",
+                first.display()
+            )]
+        );
+    }
+
+    #[test]
+    fn generated_names_claim_cached() {
+        let dir = TestDir::new("names-cached");
+        let cached = dir.write("cached/src/lib.rs", ONE_BRIDGE);
+        let changed = dir.write("changed/src/lib.rs", ONE_BRIDGE);
+        let entry = CacheEntry {
+            files: vec![],
+            values: vec![
+                ("file_ident".to_owned(), "src/lib".to_owned()),
+                ("qobject_name".to_owned(), "ffi::MyObject".to_owned()),
+            ],
+        };
+
+        let mut names = GeneratedNames::default();
+        assert!(names.claim_cached(&entry, &cached).is_ok());
+        assert_eq!(names.qobjects_of(&cached), vec!["ffi::MyObject"]);
+
+        // A changed file is still checked against the names of the cached file
+        assert!(GeneratedCpp::new(&changed, "src/lib.rs", "crate", &mut names).is_err());
+        // As is another cached file
+        assert!(names.claim_cached(&entry, &changed).is_err());
+    }
+}
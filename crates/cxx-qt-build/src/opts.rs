@@ -93,3 +93,37 @@ impl QObjectHeaderOpts {
         }
     }
 }
+
+/// Options for ui_file
+///
+/// UiFileOpts can be created using the `From<impl AsRef<Path>>` trait.
+/// ```
+/// # use cxx_qt_build::UiFileOpts;
+/// UiFileOpts::from("path/to/mainwindow.ui")
+///     .bridge(true);
+/// ```
+pub struct UiFileOpts {
+    pub(crate) path: PathBuf,
+    pub(crate) bridge: bool,
+}
+
+impl<T> From<T> for UiFileOpts
+where
+    T: AsRef<Path>,
+{
+    fn from(path: T) -> Self {
+        Self {
+            path: path.as_ref().to_owned(),
+            bridge: false,
+        }
+    }
+}
+
+impl UiFileOpts {
+    /// Whether to generate a `#[cxx_qt::bridge]` exposing the widgets of the form to Rust
+    ///
+    /// By default this is `false`
+    pub fn bridge(self, bridge: bool) -> Self {
+        Self { bridge, ..self }
+    }
+}
@@ -5,14 +5,34 @@
 
 //! This Rust module contains structs for registering QML modules.
 
+use qt_build_utils::{QmlDirEntries, QmlFile};
 use std::path::{Path, PathBuf};
 
 /// Metadata for registering a QML module with [crate::CxxQtBuilder::qml_module]
+///
+/// ```no_run
+/// use cxx_qt_build::{CxxQtBuilder, QmlFile, QmlModule};
+///
+/// CxxQtBuilder::new()
+///     .qml_module(QmlModule {
+///         uri: "com.kdab.cxx_qt.demo",
+///         rust_files: &["src/objects.rs", "src/models/list_model.rs"],
+///         qml_files: &[
+///             QmlFile::from("qml/main.qml"),
+///             QmlFile::from("qml/style/Theme.qml")
+///                 .alias("Theme.qml")
+///                 .singleton(true),
+///         ],
+///         depends: &["QtQuick"],
+///         ..Default::default()
+///     })
+///     .build();
+/// ```
 pub struct QmlModule<'a, A, B>
 where
     A: AsRef<Path>,
-    // Use a separate generic to allow using different types that impl AsRef<Path>
-    B: AsRef<Path>,
+    // Use a separate generic to allow using different types that impl Into<QmlFile>
+    B: Clone + Into<QmlFile>,
 {
     /// The URI of the QML module
     pub uri: &'a str,
@@ -21,8 +41,12 @@ where
     /// The minor version of the QML module
     pub version_minor: usize,
     /// The `.rs` files containing a `#[cxx_qt::bridge]` module with at least one QObject type annotated with `#[qml_element]`
+    ///
+    /// The files may be in multiple directories, as long as their file names are unique.
     pub rust_files: &'a [A],
     /// `.qml` files included in the module
+    ///
+    /// Use [QmlFile] to give a file an alias or declare it as a singleton or internal type.
     pub qml_files: &'a [B],
     /// Other QRC resources (such as images) included in the module
     //
@@ -33,12 +57,38 @@ where
     // and an empty slice is likely desired in most cases; most users probably don't
     // care about this field.
     pub qrc_files: &'a [A],
+    /// Other QML modules which the QML engine needs to load before this module, for example
+    /// `"QtQuick"` or `"QtQuick 2.15"`
+    pub depends: &'a [&'a str],
+    /// Other QML modules which are imported along with this module, for example `"QtQuick auto"`
+    pub imports: &'a [&'a str],
+    /// Other QML modules which tooling may import along with this module
+    pub optional_imports: &'a [&'a str],
+    /// Whether the module is supported by Qt Design Studio
+    pub designer_supported: bool,
+    /// Additional [Qt modules](https://doc.qt.io/qt-6/qtmodules.html) required by this module.
+    /// Specify their names without the `Qt` prefix, for example `"Quick"`.
+    pub qt_modules: &'a [&'a str],
+    /// Additional include directories for the C++ code of this module
+    pub include_dirs: &'a [A],
+    /// Additional preprocessor definitions for the C++ code of this module
+    pub defines: &'a [(&'a str, Option<&'a str>)],
+    /// A closure to run additional customization on the [cc::Build] of this module,
+    /// in the same way as [crate::CxxQtBuilder::cc_builder]
+    pub cc_builder: Option<&'a dyn Fn(&mut cc::Build)>,
+    /// Build the plugin of this module to be loaded from a shared library, instead of linking it
+    /// statically into the application
+    ///
+    /// The crate needs to have the `cdylib` crate type and export the plugin with
+    /// `cxx_qt::export_qml_plugin!`. The qmldir of the module is written to `qml/<uri dirs>` in
+    /// the Cargo target directory of the build profile, which can then be used as an import path.
+    pub dynamic_plugin: bool,
 }
 
 impl<A, B> Default for QmlModule<'_, A, B>
 where
     A: AsRef<Path>,
-    B: AsRef<Path>,
+    B: Clone + Into<QmlFile>,
 {
     fn default() -> Self {
         QmlModule {
@@ -48,6 +98,15 @@ where
             rust_files: &[],
             qml_files: &[],
             qrc_files: &[],
+            depends: &[],
+            imports: &[],
+            optional_imports: &[],
+            designer_supported: false,
+            qt_modules: &[],
+            include_dirs: &[],
+            defines: &[],
+            cc_builder: None,
+            dynamic_plugin: false,
         }
     }
 }
@@ -60,23 +119,53 @@ pub(crate) struct OwningQmlModule {
     pub version_major: usize,
     pub version_minor: usize,
     pub rust_files: Vec<PathBuf>,
-    pub qml_files: Vec<PathBuf>,
+    pub qml_files: Vec<QmlFile>,
     pub qrc_files: Vec<PathBuf>,
+    pub qmldir_entries: QmlDirEntries,
+    pub qt_modules: Vec<String>,
+    /// The builder for this module, with any include directories, defines and customizations
+    /// applied
+    pub cc_builder: cc::Build,
+    pub dynamic_plugin: bool,
 }
 
 fn collect_pathbuf_vec(asref: &[impl AsRef<Path>]) -> Vec<PathBuf> {
     asref.iter().map(|p| p.as_ref().to_path_buf()).collect()
 }
 
-impl<A: AsRef<Path>, B: AsRef<Path>> From<QmlModule<'_, A, B>> for OwningQmlModule {
+fn collect_string_vec(strs: &[&str]) -> Vec<String> {
+    strs.iter().map(|s| (*s).to_owned()).collect()
+}
+
+impl<A: AsRef<Path>, B: Clone + Into<QmlFile>> From<QmlModule<'_, A, B>> for OwningQmlModule {
     fn from(other: QmlModule<'_, A, B>) -> Self {
+        let mut cc_builder = cc::Build::new();
+        for include_dir in other.include_dirs {
+            cc_builder.include(include_dir);
+        }
+        for (name, value) in other.defines {
+            cc_builder.define(name, *value);
+        }
+        if let Some(callback) = other.cc_builder {
+            callback(&mut cc_builder);
+        }
+
         OwningQmlModule {
             uri: other.uri.to_owned(),
             version_major: other.version_major,
             version_minor: other.version_minor,
             rust_files: collect_pathbuf_vec(other.rust_files),
-            qml_files: collect_pathbuf_vec(other.qml_files),
+            qml_files: other.qml_files.iter().cloned().map(Into::into).collect(),
             qrc_files: collect_pathbuf_vec(other.qrc_files),
+            qmldir_entries: QmlDirEntries {
+                depends: collect_string_vec(other.depends),
+                imports: collect_string_vec(other.imports),
+                optional_imports: collect_string_vec(other.optional_imports),
+                designer_supported: other.designer_supported,
+            },
+            qt_modules: collect_string_vec(other.qt_modules),
+            cc_builder,
+            dynamic_plugin: other.dynamic_plugin,
         }
     }
 }
@@ -0,0 +1,515 @@
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Joshua Booth <joshua.n.booth@gmail.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! This Rust module contains the generation of bridges for Qt Designer `.ui` files.
+
+use std::{
+    collections::BTreeSet,
+    path::{Path, PathBuf},
+};
+
+/// A member of the class generated by uic, such as a widget, layout or action
+struct UiMember {
+    class: String,
+    name: String,
+}
+
+/// An error in a `.ui` file which prevents generating its bridge
+#[derive(Debug)]
+pub(crate) struct UiFormError {
+    pub(crate) path: PathBuf,
+    pub(crate) message: String,
+}
+
+impl UiFormError {
+    fn new(path: &Path, message: impl Into<String>) -> Self {
+        Self {
+            path: path.to_owned(),
+            message: message.into(),
+        }
+    }
+}
+
+/// The parts of a `.ui` file which are needed to generate a bridge
+struct UiForm {
+    /// The name of the class generated by uic, within the `Ui` namespace
+    class: String,
+    /// The class of the top level widget, which is passed to `setupUi`
+    widget_class: String,
+    members: Vec<UiMember>,
+}
+
+impl UiForm {
+    fn parse(path: &Path, contents: &str) -> Result<Self, UiFormError> {
+        let mut class = None;
+        let mut widget_class = None;
+        let mut members = vec![];
+
+        let mut rest = contents;
+        while let Some(start) = rest.find('<') {
+            rest = &rest[start + 1..];
+            // Skip comments, processing instructions and closing tags
+            if rest.starts_with("!--") {
+                rest = rest.find("-->").map_or("", |end| &rest[end + 3..]);
+                continue;
+            }
+            let Some(end) = rest.find('>') else {
+                break;
+            };
+            let tag = &rest[..end];
+            rest = &rest[end + 1..];
+            if tag.starts_with(['/', '?', '!']) {
+                continue;
+            }
+
+            let tag = tag.trim_end_matches('/');
+            let (tag_name, attributes) = tag.split_once(char::is_whitespace).unwrap_or((tag, ""));
+            let attribute = |key: &str| {
+                let pattern = format!("{key}=\"");
+                // Ensure that for example name does not match within objectname
+                let start = attributes
+                    .match_indices(&pattern)
+                    .find(|(index, _)| {
+                        *index == 0 || attributes[..*index].ends_with(char::is_whitespace)
+                    })?
+                    .0
+                    + pattern.len();
+                let len = attributes[start..].find('"')?;
+                Some(decode_entities(&attributes[start..start + len]))
+            };
+
+            let default_class = match tag_name {
+                "class" if class.is_none() => {
+                    let end = rest.find('<').unwrap_or(rest.len());
+                    class = Some(decode_entities(rest[..end].trim()));
+                    continue;
+                }
+                // Spacers are QSpacerItems rather than QObjects, so they cannot be exposed
+                // as a qobject type in the bridge
+                "spacer" => continue,
+                "widget" | "layout" => None,
+                "action" => Some("QAction"),
+                "actiongroup" => Some("QActionGroup"),
+                "buttongroup" => Some("QButtonGroup"),
+                _ => continue,
+            };
+            let Some(member_class) = attribute("class").or(default_class.map(str::to_owned)) else {
+                continue;
+            };
+            // uic replaces the Line pseudo widget of Qt Designer with a QFrame
+            let member_class = if member_class == "Line" {
+                "QFrame".to_owned()
+            } else {
+                member_class
+            };
+
+            // The top level widget is not a member, it is passed to setupUi instead
+            if widget_class.is_none() {
+                if tag_name != "widget" {
+                    return Err(UiFormError::new(
+                        path,
+                        format!("Expected a top level widget, found <{tag_name}>"),
+                    ));
+                }
+                widget_class = Some(member_class);
+                continue;
+            }
+
+            if let Some(name) = attribute("name") {
+                members.push(UiMember {
+                    class: member_class,
+                    name,
+                });
+            }
+        }
+
+        Ok(Self {
+            class: class
+                .ok_or_else(|| UiFormError::new(path, "Could not find the <class> of the form"))?,
+            widget_class: widget_class.ok_or_else(|| {
+                UiFormError::new(path, "Could not find the top level <widget> of the form")
+            })?,
+            members,
+        })
+    }
+}
+
+/// Decode the predefined and numeric character references of XML text
+fn decode_entities(text: &str) -> String {
+    let mut decoded = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('&') {
+        decoded.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let reference = rest.find(';').and_then(|end| {
+            let c = match &rest[1..end] {
+                "amp" => Some('&'),
+                "lt" => Some('<'),
+                "gt" => Some('>'),
+                "quot" => Some('"'),
+                "apos" => Some('\''),
+                entity => entity
+                    .strip_prefix("#x")
+                    .map(|hex| u32::from_str_radix(hex, 16))
+                    .or_else(|| entity.strip_prefix('#').map(str::parse))
+                    .and_then(Result::ok)
+                    .and_then(char::from_u32),
+            }?;
+            Some((c, end))
+        });
+        if let Some((c, end)) = reference {
+            decoded.push(c);
+            rest = &rest[end + 1..];
+        } else {
+            // Keep an invalid reference as it is
+            decoded.push('&');
+            rest = &rest[1..];
+        }
+    }
+    decoded.push_str(rest);
+    decoded
+}
+
+/// The directory within OUT_DIR containing the generated bridges of `.ui` files
+pub(crate) fn bridge_dir() -> PathBuf {
+    crate::dir::out().join("ui")
+}
+
+/// Generate a `#[cxx_qt::bridge]` exposing the members of the class generated by uic for a `.ui`
+/// file, along with a C++ header containing the accessors used by the bridge.
+/// The path of the bridge is returned, or an error if the `.ui` file cannot be read or understood.
+///
+/// The header is written next to the `ui_<name>.h` header generated by uic in `header_dir`.
+pub(crate) fn generate_bridge(ui_path: &Path, header_dir: &Path) -> Result<PathBuf, UiFormError> {
+    let contents = std::fs::read_to_string(ui_path)
+        .map_err(|err| UiFormError::new(ui_path, format!("Could not read the form: {err}")))?;
+    let form = UiForm::parse(ui_path, &contents)?;
+    let file_name = ui_path.file_name().unwrap().to_string_lossy();
+    let stem = ui_path.file_stem().unwrap().to_string_lossy();
+    let module_name = format!(
+        "ui_{}",
+        stem.to_lowercase()
+            .replace(|c: char| !c.is_ascii_alphanumeric(), "_")
+    );
+
+    let header = header_dir.join(format!("{module_name}_cxxqt.h"));
+    write_if_changed(
+        &header,
+        &generate_header(&form, &file_name, &format!("ui_{stem}.h"), &module_name),
+    );
+
+    let bridge_dir = bridge_dir();
+    std::fs::create_dir_all(&bridge_dir).expect("Could not create ui bridge dir");
+    let bridge = bridge_dir.join(format!("{module_name}.rs"));
+    write_if_changed(&bridge, &generate_rust(&form, &file_name, &module_name));
+
+    Ok(bridge)
+}
+
+fn generate_header(form: &UiForm, file_name: &str, uic_header: &str, module_name: &str) -> String {
+    let UiForm { class, members, .. } = form;
+    let accessors = members
+        .iter()
+        .map(
+            |UiMember {
+                 class: member_class,
+                 name,
+             }| {
+                let member_class = member_class.trim_start_matches("::");
+                format!(
+                    r#"
+inline ::{member_class}*
+{name}(const ::Ui::{class}& form)
+{{
+  return form.{name};
+}}
+"#
+                )
+            },
+        )
+        .collect::<String>();
+
+    format!(
+        r#"// Generated by cxx-qt-build from {file_name}
+#pragma once
+
+#include <memory>
+
+#include "{uic_header}"
+
+namespace rust::cxxqtui::{module_name} {{
+inline ::std::unique_ptr<::Ui::{class}>
+formNew()
+{{
+  return ::std::make_unique<::Ui::{class}>();
+}}
+{accessors}
+}} // namespace rust::cxxqtui::{module_name}
+"#
+    )
+}
+
+fn generate_rust(form: &UiForm, file_name: &str, module_name: &str) -> String {
+    let UiForm {
+        class,
+        widget_class,
+        members,
+    } = form;
+    let header_name = format!("{module_name}_cxxqt.h");
+
+    let classes = std::iter::once(widget_class)
+        .chain(members.iter().map(|member| &member.class))
+        .collect::<BTreeSet<_>>();
+    let qobjects = classes
+        .iter()
+        .map(|qobject| {
+            let (namespace, name) = rust_type(qobject);
+            format!(
+                "        #[qobject]\n{namespace}        type {name};\n",
+                namespace = namespace_attribute(namespace, "        ")
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    let (_, widget) = rust_type(widget_class);
+
+    let accessors = members
+        .iter()
+        .map(
+            |UiMember {
+                 class: member_class,
+                 name,
+             }| {
+                let (_, member_type) = rust_type(member_class);
+                format!(
+                    r#"
+        /// The `{name}` member of the form, which is only valid after [{class}::setup_ui]
+        #[namespace = "rust::cxxqtui::{module_name}"]
+        #[cxx_name = "{name}"]
+        fn {rust_name}(form: &{class}) -> *mut {member_type};
+"#,
+                    rust_name = rust_name(name)
+                )
+            },
+        )
+        .collect::<String>();
+
+    format!(
+        r#"// Generated by cxx-qt-build from {file_name}
+#[cxx_qt::bridge]
+pub mod {module_name} {{
+    unsafe extern "C++Qt" {{
+        include!("{header_name}");
+
+{qobjects}    }}
+
+    unsafe extern "C++" {{
+        include!("{header_name}");
+
+        /// The class generated by uic, which creates the members of the form with [{class}::setup_ui]
+        #[namespace = "Ui"]
+        type {class};
+
+        /// Create the members of the form as children of the given widget
+        ///
+        /// # Safety
+        ///
+        /// The widget must be a valid pointer.
+        #[cxx_name = "setupUi"]
+        unsafe fn setup_ui(self: Pin<&mut {class}>, widget: *mut {widget});
+
+        /// Update the translatable strings of the form, for example after installing a translator
+        ///
+        /// # Safety
+        ///
+        /// The widget must be a valid pointer, which was passed to [{class}::setup_ui].
+        #[cxx_name = "retranslateUi"]
+        unsafe fn retranslate_ui(self: Pin<&mut {class}>, widget: *mut {widget});
+
+        /// Create a new form, without any members until [{class}::setup_ui] is called
+        #[namespace = "rust::cxxqtui::{module_name}"]
+        #[cxx_name = "formNew"]
+        fn form_new() -> UniquePtr<{class}>;
+{accessors}    }}
+
+    impl UniquePtr<{class}> {{}}
+}}
+"#
+    )
+}
+
+/// Split a C++ class name into its namespace and name
+fn rust_type(class: &str) -> (Option<&str>, &str) {
+    match class.rsplit_once("::") {
+        Some((namespace, name)) => (Some(namespace.trim_start_matches("::")), name),
+        None => (None, class),
+    }
+}
+
+fn namespace_attribute(namespace: Option<&str>, indent: &str) -> String {
+    namespace
+        .map(|namespace| format!("{indent}#[namespace = \"{namespace}\"]\n"))
+        .unwrap_or_default()
+}
+
+/// Convert the camelCase object name of a member into a snake_case Rust identifier
+fn rust_name(name: &str) -> String {
+    const KEYWORDS: &[&str] = &[
+        "abstract", "as", "async", "await", "become", "box", "break", "const", "continue", "crate",
+        "do", "dyn", "else", "enum", "extern", "false", "final", "fn", "for", "gen", "if", "impl",
+        "in", "let", "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub",
+        "ref", "return", "self", "static", "struct", "super", "trait", "true", "try", "type",
+        "typeof", "unsafe", "unsized", "use", "virtual", "where", "while", "yield",
+    ];
+
+    let chars = name.chars().collect::<Vec<_>>();
+    let mut rust_name = String::with_capacity(name.len() + 4);
+    for (i, c) in chars.iter().enumerate() {
+        if c.is_uppercase() && i > 0 {
+            let prev = chars[i - 1];
+            let next_is_lower = chars.get(i + 1).is_some_and(|next| next.is_lowercase());
+            if prev.is_lowercase()
+                || prev.is_ascii_digit()
+                || (prev.is_uppercase() && next_is_lower)
+            {
+                rust_name.push('_');
+            }
+        }
+        rust_name.extend(c.to_lowercase());
+    }
+
+    if KEYWORDS.contains(&rust_name.as_str()) {
+        rust_name.push('_');
+    }
+    rust_name
+}
+
+/// Only write the file if its contents changed, so that the modification time is kept and
+/// the generated code is not rebuilt unnecessarily
+fn write_if_changed(path: &Path, contents: &str) {
+    if std::fs::read_to_string(path).is_ok_and(|existing| existing == contents) {
+        return;
+    }
+    std::fs::write(path, contents).unwrap_or_else(|_| panic!("Could not write {}", path.display()));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn members(form: &UiForm) -> Vec<(&str, &str)> {
+        form.members
+            .iter()
+            .map(|member| (member.class.as_str(), member.name.as_str()))
+            .collect()
+    }
+
+    #[test]
+    fn parse_form() {
+        let form = UiForm::parse(
+            Path::new("mainwindow.ui"),
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+<ui version="4.0">
+ <class>MainWindow</class>
+ <widget class="QMainWindow" name="MainWindow">
+  <!-- <widget class="QLabel" name="commentedOut"/> -->
+  <widget class="QWidget" name="centralWidget">
+   <layout class="QVBoxLayout" name="verticalLayout">
+    <item>
+     <widget class="QLabel" name="label">
+      <property name="text">
+       <string>Tom &amp; Jerry &lt;3</string>
+      </property>
+     </widget>
+    </item>
+    <item>
+     <spacer name="verticalSpacer">
+      <property name="orientation">
+       <enum>Qt::Vertical</enum>
+      </property>
+     </spacer>
+    </item>
+    <item>
+     <widget class="Line" name="line"/>
+    </item>
+    <item>
+     <widget class="my::Custom&#x3C;int&#62;" objectname="ignored" name="custom&apos;s"/>
+    </item>
+   </layout>
+  </widget>
+  <action name="actionQuit"/>
+  <actiongroup name="actionGroup"/>
+ </widget>
+ <buttongroups>
+  <buttongroup name="buttonGroup"/>
+ </buttongroups>
+</ui>
+"#,
+        )
+        .unwrap();
+        assert_eq!(form.class, "MainWindow");
+        assert_eq!(form.widget_class, "QMainWindow");
+        assert_eq!(
+            members(&form),
+            vec![
+                ("QWidget", "centralWidget"),
+                ("QVBoxLayout", "verticalLayout"),
+                ("QLabel", "label"),
+                ("QFrame", "line"),
+                ("my::Custom<int>", "custom's"),
+                ("QAction", "actionQuit"),
+                ("QActionGroup", "actionGroup"),
+                ("QButtonGroup", "buttonGroup"),
+            ]
+        );
+    }
+
+    fn parse_error(contents: &str) -> String {
+        let Err(error) = UiForm::parse(Path::new("invalid.ui"), contents) else {
+            panic!("Expected an error for {contents}");
+        };
+        assert_eq!(error.path, Path::new("invalid.ui"));
+        error.message
+    }
+
+    #[test]
+    fn parse_invalid_forms() {
+        assert_eq!(
+            parse_error(r#"<ui version="4.0"><widget class="QWidget" name="Form"/></ui>"#),
+            "Could not find the <class> of the form"
+        );
+        assert_eq!(
+            parse_error(r#"<ui version="4.0"><class>Form</class></ui>"#),
+            "Could not find the top level <widget> of the form"
+        );
+        assert_eq!(
+            parse_error(r#"<ui version="4.0"><class>Form</class><action name="actionQuit"/></ui>"#),
+            "Expected a top level widget, found <action>"
+        );
+    }
+
+    #[test]
+    fn decode_xml_entities() {
+        assert_eq!(
+            decode_entities("&lt;a &amp; b&gt; &quot;c&quot; &#65;&#x42; & &unknown; &"),
+            "<a & b> \"c\" AB & &unknown; &"
+        );
+    }
+
+    #[test]
+    fn rust_names() {
+        assert_eq!(rust_name("label"), "label");
+        assert_eq!(rust_name("pushButton"), "push_button");
+        assert_eq!(rust_name("lineEdit2"), "line_edit2");
+        assert_eq!(rust_name("label2Text"), "label2_text");
+        assert_eq!(rust_name("openURLButton"), "open_url_button");
+        assert_eq!(rust_name("actionHTTP"), "action_http");
+        assert_eq!(rust_name("XMLView"), "xml_view");
+        assert_eq!(rust_name("horizontal_layout"), "horizontal_layout");
+        assert_eq!(rust_name("type"), "type_");
+        assert_eq!(rust_name("Self"), "self_");
+        assert_eq!(rust_name("move"), "move_");
+    }
+}
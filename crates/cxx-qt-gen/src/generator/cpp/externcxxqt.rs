@@ -4,11 +4,16 @@
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
 use crate::{
-    generator::{cpp::signal::generate_cpp_signal, GeneratedOpt},
+    generator::{
+        cpp::{qobject::generate_qml_specifiers, signal::generate_cpp_signal},
+        GeneratedOpt,
+    },
     naming::TypeNames,
-    parser::externcxxqt::ParsedExternCxxQt,
+    parser::{externcxxqt::ParsedExternCxxQt, externqobject::ParsedExternQObject},
+    writer::cpp::namespaced,
     CppFragment,
 };
+use indoc::formatdoc;
 use std::collections::BTreeSet;
 use syn::Result;
 
@@ -62,6 +67,13 @@ pub fn generate(
                 "QObject".to_owned()
             };
             generated.base_classes.push(base_class);
+
+            if let Some(foreign) = generate_qml_foreign(qobject, type_names)? {
+                generated
+                    .includes
+                    .insert("#include <QtQml/QQmlEngine>".to_owned());
+                generated.fragments.push(foreign);
+            }
         }
         out.push(generated);
     }
@@ -69,8 +81,40 @@ pub fn generate(
     Ok(out)
 }
 
+/// Generate a Q_GADGET which registers an existing QObject in QML with QML_FOREIGN
+fn generate_qml_foreign(
+    qobject: &ParsedExternQObject,
+    type_names: &TypeNames,
+) -> Result<Option<CppFragment>> {
+    let Some(qml_metadata) = &qobject.qml_metadata else {
+        return Ok(None);
+    };
+
+    let ident = qobject.name.cxx_unqualified();
+    let qml_specifiers = generate_qml_specifiers(qml_metadata, type_names)?
+        .into_iter()
+        .map(|specifier| format!("  {specifier}"))
+        .collect::<Vec<_>>()
+        .join("\n");
+    let class_definition = formatdoc! {r#"
+        struct {ident}QmlForeign
+        {{
+          Q_GADGET
+          QML_FOREIGN({qualified})
+        {qml_specifiers}
+        }};"#,
+        qualified = qobject.name.cxx_qualified(),
+    };
+
+    Ok(Some(CppFragment::Header(namespaced(
+        qobject.name.namespace().unwrap_or_default(),
+        &class_definition,
+    ))))
+}
+
 #[cfg(test)]
 mod tests {
+    use indoc::indoc;
     use quote::format_ident;
     use syn::parse_quote;
 
@@ -128,4 +172,52 @@ mod tests {
         let generated = generate(&blocks, &type_names, &GeneratedOpt::default()).unwrap();
         assert_eq!(generated.len(), 3);
     }
+
+    #[test]
+    fn test_generate_cpp_extern_qt_foreign() {
+        let blocks = vec![ParsedExternCxxQt::parse(
+            parse_quote! {
+                extern "C++Qt" {
+                    #[namespace = "mynamespace"]
+                    #[qobject]
+                    #[qml_foreign]
+                    #[qml_named_element = "Timer"]
+                    #[qml_extended = TimerExtension]
+                    #[qml_added_in = "1.2"]
+                    type QTimer;
+                }
+            },
+            &format_ident!("qobject"),
+            None,
+        )
+        .unwrap()];
+        let mut type_names = TypeNames::default();
+        type_names.mock_insert("QTimer", None, None, Some("mynamespace"));
+        type_names.mock_insert("TimerExtension", None, None, Some("extension"));
+
+        let generated = generate(&blocks, &type_names, &GeneratedOpt::default()).unwrap();
+        assert_eq!(generated.len(), 2);
+        assert!(generated[1]
+            .includes
+            .contains("#include <QtQml/QQmlEngine>"));
+        assert_eq!(
+            generated[1].fragments,
+            vec![CppFragment::Header(
+                indoc! {r#"
+                namespace mynamespace {
+                struct QTimerQmlForeign
+                {
+                  Q_GADGET
+                  QML_FOREIGN(mynamespace::QTimer)
+                  Q_CLASSINFO("QML.Element", "Timer")
+                  QML_EXTENDED(extension::TimerExtension)
+                  Q_CLASSINFO("QML.AddedInVersion", "258")
+                  Q_CLASSINFO("QML.AddedInMinorVersion", "2")
+                };
+                } // namespace mynamespace
+                "#}
+                .to_owned()
+            )]
+        );
+    }
 }
@@ -0,0 +1,163 @@
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Joshua Booth <joshua.n.booth@gmail.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::{
+    generator::{
+        cpp::{fragment::CppFragment, qobject::GeneratedCppQObjectBlocks},
+        naming::{property::QmlListPropertyNames, qobject::QObjectNames},
+    },
+    naming::TypeNames,
+    parser::listproperty::ParsedQmlListProperty,
+};
+use indoc::formatdoc;
+use syn::Result;
+
+pub fn generate_cpp_list_properties(
+    properties: &[ParsedQmlListProperty],
+    qobject_idents: &QObjectNames,
+    type_names: &TypeNames,
+) -> Result<GeneratedCppQObjectBlocks> {
+    let mut generated = GeneratedCppQObjectBlocks::default();
+    if properties.is_empty() {
+        return Ok(generated);
+    }
+
+    generated
+        .includes
+        .insert("#include <QtQml/QQmlListProperty>".to_owned());
+
+    let qobject_ident = qobject_idents.name.cxx_unqualified();
+    for property in properties {
+        let idents = QmlListPropertyNames::from(property);
+        let element = type_names.cxx_qualified(&property.ty)?;
+        let list = format!("QQmlListProperty<{element}>");
+        let name = idents.name.cxx_unqualified();
+        let getter = idents.getter.cxx_unqualified();
+        let append = idents.append.cxx_unqualified();
+        let count = idents.count.cxx_unqualified();
+        let at = idents.at.cxx_unqualified();
+        let clear = idents.clear.cxx_unqualified();
+        let replace = idents.replace.cxx_unqualified();
+        let remove_last = idents.remove_last.cxx_unqualified();
+
+        generated
+            .metaobjects
+            .push(format!("Q_PROPERTY({list} {name} READ {getter} CONSTANT)"));
+
+        // The callbacks are captureless lambdas, so that they can access the private
+        // methods implemented in Rust.
+        //
+        // The index type of QQmlListProperty is int in Qt 5 and qsizetype in Qt 6.
+        generated.methods.push(CppFragment::Pair {
+            header: format!("{list} {getter}();"),
+            source: formatdoc! {r#"
+                {list}
+                {qobject_ident}::{getter}()
+                {{
+                  using List = {list};
+                  using Index = decltype(::std::declval<List::CountFunction>()(nullptr));
+                  return List(
+                    this,
+                    nullptr,
+                    [](List* list, {element}* item) {{
+                      static_cast<{qobject_ident}*>(list->object)->{append}(item);
+                    }},
+                    [](List* list) {{
+                      return static_cast<Index>(
+                        static_cast<{qobject_ident}*>(list->object)->{count}());
+                    }},
+                    [](List* list, Index index) {{
+                      return static_cast<{qobject_ident}*>(list->object)->{at}(index);
+                    }},
+                    [](List* list) {{
+                      static_cast<{qobject_ident}*>(list->object)->{clear}();
+                    }},
+                    [](List* list, Index index, {element}* item) {{
+                      static_cast<{qobject_ident}*>(list->object)->{replace}(index, item);
+                    }},
+                    [](List* list) {{
+                      static_cast<{qobject_ident}*>(list->object)->{remove_last}();
+                    }});
+                }}
+                "#},
+        });
+
+        generated.private_methods.extend([
+            CppFragment::Header(format!("void {append}({element}* item) noexcept;")),
+            CppFragment::Header(format!("::rust::isize {count}() const noexcept;")),
+            CppFragment::Header(format!(
+                "{element}* {at}(::rust::isize index) const noexcept;"
+            )),
+            CppFragment::Header(format!("void {clear}() noexcept;")),
+            CppFragment::Header(format!(
+                "void {replace}(::rust::isize index, {element}* item) noexcept;"
+            )),
+            CppFragment::Header(format!("void {remove_last}() noexcept;")),
+        ]);
+    }
+
+    Ok(generated)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::generator::cpp::property::tests::require_pair;
+    use crate::parser::{qobject::ParsedQObject, CaseConversion};
+    use pretty_assertions::assert_str_eq;
+    use syn::{parse_quote, ItemStruct};
+
+    #[test]
+    fn test_generate_cpp_list_properties() {
+        let input: ItemStruct = parse_quote! {
+            #[qml_list_property(Child, children)]
+            struct MyStruct;
+        };
+        let properties =
+            vec![ParsedQmlListProperty::parse(&input.attrs[0], CaseConversion::none()).unwrap()];
+
+        let mut type_names = TypeNames::mock();
+        type_names.mock_insert("Child", None, None, Some("my_namespace"));
+        let qobject_idents =
+            QObjectNames::from_qobject(&ParsedQObject::mock(), &type_names).unwrap();
+
+        let generated =
+            generate_cpp_list_properties(&properties, &qobject_idents, &type_names).unwrap();
+
+        assert!(generated
+            .includes
+            .contains("#include <QtQml/QQmlListProperty>"));
+        assert_eq!(
+            generated.metaobjects,
+            vec!["Q_PROPERTY(QQmlListProperty<my_namespace::Child> children READ getChildren CONSTANT)"]
+        );
+
+        assert_eq!(generated.methods.len(), 1);
+        let (header, source) = require_pair(&generated.methods[0]).unwrap();
+        assert_str_eq!(
+            header,
+            "QQmlListProperty<my_namespace::Child> getChildren();"
+        );
+        assert!(source.contains("MyObject::getChildren()"));
+        assert!(
+            source.contains("static_cast<MyObject*>(list->object)->childrenReplace(index, item);")
+        );
+
+        assert_eq!(generated.private_methods.len(), 6);
+        assert_eq!(
+            generated.private_methods[0],
+            CppFragment::Header(
+                "void childrenAppend(my_namespace::Child* item) noexcept;".to_owned()
+            )
+        );
+        assert_eq!(
+            generated.private_methods[2],
+            CppFragment::Header(
+                "my_namespace::Child* childrenAt(::rust::isize index) const noexcept;".to_owned()
+            )
+        );
+    }
+}
@@ -8,9 +8,11 @@ pub mod cxxqttype;
 pub mod externcxxqt;
 pub mod fragment;
 pub mod inherit;
+pub mod listproperty;
 pub mod method;
 pub mod property;
 pub mod qenum;
+pub mod qgadget;
 pub mod qnamespace;
 pub mod qobject;
 pub mod signal;
@@ -28,6 +30,7 @@ use crate::{
     parser::Parser,
 };
 use externcxxqt::GeneratedCppExternCxxQtBlocks;
+use qgadget::GeneratedCppQGadget;
 use qobject::GeneratedCppQObject;
 use syn::{FnArg, ForeignItemFn, Pat, PatIdent, PatType, Result};
 
@@ -39,6 +42,8 @@ pub struct GeneratedCppBlocks {
     pub includes: BTreeSet<String>,
     /// Generated QObjects
     pub qobjects: Vec<GeneratedCppQObject>,
+    /// Generated Q_GADGETs
+    pub qgadgets: Vec<GeneratedCppQGadget>,
     /// Generated extern C++Qt blocks
     pub extern_cxx_qt: Vec<GeneratedCppExternCxxQtBlocks>,
 }
@@ -82,6 +87,21 @@ impl GeneratedCppBlocks {
                     }
                 })
                 .collect::<Result<Vec<GeneratedCppQObject>>>()?,
+            qgadgets: structures
+                .qgadgets
+                .iter()
+                .filter_map(|qgadget| {
+                    // Skip if the cfg attributes are not resolved to true
+                    match try_eval_attributes(opt.cfg_evaluator.as_ref(), &qgadget.declaration.cfgs)
+                    {
+                        Ok(true) => {
+                            Some(GeneratedCppQGadget::from(qgadget, &parser.type_names, opt))
+                        }
+                        Ok(false) => None,
+                        Err(err) => Some(Err(err)),
+                    }
+                })
+                .collect::<Result<Vec<GeneratedCppQGadget>>>()?,
             extern_cxx_qt: externcxxqt::generate(
                 &parser.cxx_qt_data.extern_cxxqt_blocks,
                 &parser.type_names,
@@ -61,6 +61,32 @@ pub fn generate_cpp_properties(
     Ok(generated)
 }
 
+pub fn generate_cpp_gadget_properties(
+    properties: &Vec<ParsedQProperty>,
+    type_names: &TypeNames,
+) -> Result<GeneratedCppQObjectBlocks> {
+    let mut generated = GeneratedCppQObjectBlocks::default();
+
+    for property in properties {
+        let idents = QPropertyNames::from_gadget_property(property);
+        let cxx_ty = syn_type_to_cpp_type(&property.ty, type_names)?;
+
+        generated
+            .metaobjects
+            .push(meta::generate(&idents, &property.flags, &cxx_ty));
+
+        if let Some(getter) = getter::generate(&idents, &cxx_ty) {
+            generated.methods.push(getter);
+        }
+
+        if let Some(setter) = setter::generate_gadget(&idents, &cxx_ty) {
+            generated.methods.push(setter)
+        }
+    }
+
+    Ok(generated)
+}
+
 #[cfg(test)]
 pub mod tests {
     use super::*;
@@ -19,3 +19,15 @@ pub fn generate(idents: &QPropertyNames, cxx_ty: &str) -> Option<CppFragment> {
         None
     }
 }
+
+/// Generate the setter for a Q_GADGET property, which cannot be a slot
+pub fn generate_gadget(idents: &QPropertyNames, cxx_ty: &str) -> Option<CppFragment> {
+    if let Some(NameState::Auto(setter)) = &idents.setter {
+        Some(CppFragment::Header(format!(
+            "void {ident_setter}({cxx_ty} value) noexcept;",
+            ident_setter = setter.cxx_unqualified(),
+        )))
+    } else {
+        None
+    }
+}
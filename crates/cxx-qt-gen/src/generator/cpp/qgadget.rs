@@ -0,0 +1,261 @@
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Joshua Booth <joshua.n.booth@gmail.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::{
+    generator::{
+        cpp::{
+            fragment::CppFragment, method::generate_cpp_methods,
+            property::generate_cpp_gadget_properties, qobject::GeneratedCppQObjectBlocks,
+        },
+        naming::namespace::NamespaceName,
+        structuring::StructuredQGadget,
+    },
+    naming::{Name, TypeNames},
+    writer::cpp::namespaced,
+    GeneratedOpt,
+};
+use indoc::formatdoc;
+use syn::Result;
+
+pub struct GeneratedCppQGadget {
+    /// Name of the Q_GADGET, with associated namespace, cxx_name, etc.
+    pub name: Name,
+    /// Name of the Rust struct that this Q_GADGET owns
+    pub rust_struct: Name,
+    /// Ident of the namespace for CXX-Qt internals of the Q_GADGET
+    pub namespace_internals: String,
+    /// The blocks of the Q_GADGET
+    pub blocks: GeneratedCppQObjectBlocks,
+}
+
+impl GeneratedCppQGadget {
+    pub fn from(
+        structured_qgadget: &StructuredQGadget,
+        type_names: &TypeNames,
+        opt: &GeneratedOpt,
+    ) -> Result<GeneratedCppQGadget> {
+        let qgadget = structured_qgadget.declaration;
+        let namespace_idents = NamespaceName::from(qgadget);
+        let mut generated = GeneratedCppQGadget {
+            name: qgadget.name.clone(),
+            rust_struct: type_names.lookup(&qgadget.rust_type)?.clone(),
+            namespace_internals: namespace_idents.internal,
+            blocks: GeneratedCppQObjectBlocks::default(),
+        };
+
+        generated
+            .blocks
+            .includes
+            .insert("#include <QtCore/QMetaType>".to_owned());
+        generated
+            .blocks
+            .includes
+            .insert("#include <QtCore/QObject>".to_owned());
+
+        if let Some(name) = &qgadget.qml_value_type {
+            // As with QML_ELEMENT on QObjects, we use what QML_VALUE_TYPE expands to,
+            // so that moc includes the info in metatypes.json that qmltyperegistrar needs.
+            generated
+                .blocks
+                .metaobjects
+                .push(format!("Q_CLASSINFO(\"QML.Element\", \"{name}\")"));
+        }
+
+        if qgadget.qml_sequential_container {
+            generated
+                .blocks
+                .includes
+                .insert("#include <QtCore/QList>".to_owned());
+            generated
+                .blocks
+                .includes
+                .insert("#include <QtQml/QQmlEngine>".to_owned());
+            generated
+                .blocks
+                .post_fragments
+                .push(generate_sequential_container(&generated.name));
+        }
+
+        generated.blocks.append(&mut generate_special_members(
+            &generated.name,
+            &generated.rust_struct,
+            &generated.namespace_internals,
+        ));
+        generated.blocks.append(&mut generate_cpp_gadget_properties(
+            &qgadget.properties,
+            type_names,
+        )?);
+        generated.blocks.append(&mut generate_cpp_methods(
+            &structured_qgadget.methods,
+            type_names,
+            opt,
+        )?);
+
+        Ok(generated)
+    }
+}
+
+/// Generate a foreign type which registers a QList of the Q_GADGET as a QML sequence type
+fn generate_sequential_container(name: &Name) -> CppFragment {
+    let ident = name.cxx_unqualified();
+    let qualified = name.cxx_qualified();
+    CppFragment::Header(namespaced(
+        name.namespace().unwrap_or_default(),
+        &formatdoc! {r#"
+            struct {ident}QmlSequence
+            {{
+              Q_GADGET
+              QML_FOREIGN(QList<{qualified}>)
+              QML_ANONYMOUS
+              QML_SEQUENTIAL_CONTAINER({qualified})
+            }};"#},
+    ))
+}
+
+/// Generate the constructors, destructor and copy assignment of the Q_GADGET
+///
+/// These own a copy of the Rust struct through the createRs and cloneRs functions.
+/// No move operations are declared, so a moved-from Q_GADGET is never left without a Rust struct.
+fn generate_special_members(
+    name: &Name,
+    rust_struct: &Name,
+    namespace_internals: &str,
+) -> GeneratedCppQObjectBlocks {
+    let ident = name.cxx_unqualified();
+    let rust_struct = rust_struct.cxx_qualified();
+
+    GeneratedCppQObjectBlocks {
+        methods: vec![
+            CppFragment::Pair {
+                header: format!("{ident}();"),
+                source: formatdoc! {r#"
+                    {ident}::{ident}()
+                      : m_rustObj(::{namespace_internals}::createRs())
+                    {{
+                    }}
+                    "#},
+            },
+            CppFragment::Pair {
+                header: format!("{ident}({ident} const& other);"),
+                source: formatdoc! {r#"
+                    {ident}::{ident}({ident} const& other)
+                      : m_rustObj(::{namespace_internals}::cloneRs(*other.m_rustObj))
+                    {{
+                    }}
+                    "#},
+            },
+            CppFragment::Pair {
+                header: format!("~{ident}();"),
+                source: format!("{ident}::~{ident}() = default;\n"),
+            },
+            CppFragment::Pair {
+                header: format!("{ident}& operator=({ident} const& other);"),
+                source: formatdoc! {r#"
+                    {ident}&
+                    {ident}::operator=({ident} const& other)
+                    {{
+                      if (this != &other) {{
+                        m_rustObj = ::{namespace_internals}::cloneRs(*other.m_rustObj);
+                      }}
+                      return *this;
+                    }}
+                    "#},
+            },
+        ],
+        private_methods: vec![CppFragment::Header(format!(
+            "::rust::Box<{rust_struct}> m_rustObj;"
+        ))],
+        ..Default::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::generator::structuring::Structures;
+    use crate::parser::Parser;
+    use indoc::indoc;
+    use pretty_assertions::assert_str_eq;
+    use syn::{parse_quote, ItemMod};
+
+    #[test]
+    fn test_generated_cpp_qgadget() {
+        let module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge(namespace = "cxx_qt")]
+            mod ffi {
+                extern "RustQt" {
+                    #[qgadget]
+                    #[qml_value_type]
+                    #[qml_sequential_container]
+                    #[qproperty(f64, x)]
+                    #[qproperty(f64, y, READ, CONSTANT)]
+                    type Point = super::PointRust;
+
+                    #[qinvokable]
+                    fn length(self: &Point) -> f64;
+                }
+            }
+        };
+        let parser = Parser::from(module).unwrap();
+        let structures = Structures::new(&parser.cxx_qt_data).unwrap();
+
+        let cpp = GeneratedCppQGadget::from(
+            structures.qgadgets.first().unwrap(),
+            &parser.type_names,
+            &GeneratedOpt::default(),
+        )
+        .unwrap();
+        assert_eq!(cpp.namespace_internals, "cxx_qt::cxx_qt_Point");
+
+        assert_eq!(cpp.blocks.metaobjects.len(), 3);
+        assert_str_eq!(
+            cpp.blocks.metaobjects[0],
+            "Q_CLASSINFO(\"QML.Element\", \"point\")"
+        );
+        assert_str_eq!(
+            cpp.blocks.metaobjects[1],
+            "Q_PROPERTY(double x READ getX WRITE setX)"
+        );
+        assert_str_eq!(
+            cpp.blocks.metaobjects[2],
+            "Q_PROPERTY(double y READ getY CONSTANT)"
+        );
+
+        // Special members, three property methods and the invokable
+        assert_eq!(cpp.blocks.methods.len(), 8);
+        assert_eq!(
+            cpp.blocks.methods[5],
+            CppFragment::Header("void setX(double value) noexcept;".to_owned())
+        );
+        assert_eq!(
+            cpp.blocks.methods[7],
+            CppFragment::Header("Q_INVOKABLE double length() const noexcept;".to_owned())
+        );
+        assert_eq!(
+            cpp.blocks.private_methods,
+            vec![CppFragment::Header(
+                "::rust::Box<PointRust> m_rustObj;".to_owned()
+            )]
+        );
+        assert_eq!(
+            cpp.blocks.post_fragments,
+            vec![CppFragment::Header(
+                indoc! {r#"
+                namespace cxx_qt {
+                struct PointQmlSequence
+                {
+                  Q_GADGET
+                  QML_FOREIGN(QList<cxx_qt::Point>)
+                  QML_ANONYMOUS
+                  QML_SEQUENTIAL_CONTAINER(cxx_qt::Point)
+                };
+                } // namespace cxx_qt
+                "#}
+                .to_owned()
+            )]
+        );
+    }
+}
@@ -6,7 +6,8 @@
 use crate::{
     generator::{
         cpp::{
-            constructor, cxxqttype, fragment::CppFragment, inherit, method::generate_cpp_methods,
+            constructor, cxxqttype, fragment::CppFragment, inherit,
+            listproperty::generate_cpp_list_properties, method::generate_cpp_methods,
             property::generate_cpp_properties, qenum, signal::generate_cpp_signals, threading,
         },
         naming::{namespace::NamespaceName, qobject::QObjectNames},
@@ -15,7 +16,11 @@ use crate::{
     naming::Name,
     GeneratedOpt,
 };
-use crate::{naming::TypeNames, parser::qobject::ParsedQObject};
+use crate::{
+    naming::TypeNames,
+    parser::qobject::{QClassInfo, QmlElementMetadata},
+};
+use indoc::formatdoc;
 use std::collections::BTreeSet;
 use syn::Result;
 
@@ -59,31 +64,76 @@ impl GeneratedCppQObjectBlocks {
         self.methods.append(&mut other.methods);
         self.private_methods.append(&mut other.private_methods);
     }
+}
 
-    pub fn from(qobject: &ParsedQObject) -> GeneratedCppQObjectBlocks {
-        let mut qml_specifiers = Vec::new();
-        if let Some(qml_metadata) = &qobject.qml_metadata {
-            // Somehow moc doesn't include the info in metatypes.json that qmltyperegistrar needs
-            // when using the QML_ELEMENT/QML_NAMED_ELEMENT macros, but moc works when using what
-            // those macros expand to.
-            qml_specifiers.push(format!(
-                "Q_CLASSINFO(\"QML.Element\", \"{}\")",
-                qml_metadata.name
-            ));
-
-            if qml_metadata.uncreatable {
-                qml_specifiers.push("Q_CLASSINFO(\"QML.Creatable\", \"false\")".to_owned());
-            }
+/// Generate the Qt Meta Object items which register a type as a QML element
+///
+/// Note that attached properties are generated separately, as they need a factory method on the QObject.
+pub fn generate_qml_specifiers(
+    qml_metadata: &QmlElementMetadata,
+    type_names: &TypeNames,
+) -> Result<Vec<String>> {
+    // Somehow moc doesn't include the info in metatypes.json that qmltyperegistrar needs
+    // when using the QML_ELEMENT/QML_NAMED_ELEMENT macros, but moc works when using what
+    // those macros expand to.
+    let mut qml_specifiers = vec![format!(
+        "Q_CLASSINFO(\"QML.Element\", \"{}\")",
+        qml_metadata.name
+    )];
+
+    if qml_metadata.uncreatable {
+        qml_specifiers.push("Q_CLASSINFO(\"QML.Creatable\", \"false\")".to_owned());
+    }
 
-            if qml_metadata.singleton {
-                qml_specifiers.push("QML_SINGLETON".to_owned());
-            }
-        }
-        GeneratedCppQObjectBlocks {
-            metaobjects: qml_specifiers,
-            ..Default::default()
+    if qml_metadata.singleton {
+        qml_specifiers.push("QML_SINGLETON".to_owned());
+    }
+
+    if let Some(extended) = &qml_metadata.extended {
+        qml_specifiers.push(format!(
+            "QML_EXTENDED({})",
+            type_names.lookup(extended)?.cxx_qualified()
+        ));
+    }
+
+    // QML_ADDED_IN_VERSION and QML_REMOVED_IN_VERSION do not exist in Qt 5, so generate the
+    // class infos which they expand to in Qt 6 instead. Qt 5 only reads the minor version
+    // from QML.AddedInMinorVersion and ignores the other entries.
+    if let Some(added_in) = qml_metadata.added_in {
+        qml_specifiers.push(format!(
+            "Q_CLASSINFO(\"QML.AddedInVersion\", \"{}\")",
+            added_in.encoded()
+        ));
+        qml_specifiers.push(format!(
+            "Q_CLASSINFO(\"QML.AddedInMinorVersion\", \"{}\")",
+            added_in.minor
+        ));
+    }
+
+    if let Some(removed_in) = qml_metadata.removed_in {
+        qml_specifiers.push(format!(
+            "Q_CLASSINFO(\"QML.RemovedInVersion\", \"{}\")",
+            removed_in.encoded()
+        ));
+    }
+
+    Ok(qml_specifiers)
+}
+
+/// Escape a string so that it can be used as the contents of a C++ string literal
+fn escape_string_literal(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c => escaped.push(c),
         }
     }
+    escaped
 }
 
 pub struct GeneratedCppQObject {
@@ -114,7 +164,7 @@ impl GeneratedCppQObject {
             name: qobject.name.clone(),
             rust_struct: type_names.lookup(&qobject.rust_type)?.clone(),
             namespace_internals: namespace_idents.internal,
-            blocks: GeneratedCppQObjectBlocks::from(qobject),
+            blocks: GeneratedCppQObjectBlocks::default(),
             has_qobject_macro: qobject.has_qobject_macro,
         };
 
@@ -130,6 +180,28 @@ impl GeneratedCppQObject {
 
         generated.blocks.base_classes.push(base_class.clone());
 
+        if let Some(qml_metadata) = &qobject.qml_metadata {
+            generated.blocks.metaobjects = generate_qml_specifiers(qml_metadata, type_names)?;
+
+            if let Some(attached) = &qml_metadata.attached {
+                generated.blocks.append(&mut generate_qml_attached(
+                    &generated.name,
+                    type_names.lookup(attached)?,
+                ));
+            }
+        }
+
+        generated
+            .blocks
+            .metaobjects
+            .extend(qobject.class_infos.iter().map(|QClassInfo { key, value }| {
+                format!(
+                    "Q_CLASSINFO(\"{key}\", \"{value}\")",
+                    key = escape_string_literal(key),
+                    value = escape_string_literal(value)
+                )
+            }));
+
         // Add the CxxQtType rust and rust_mut methods
         generated
             .blocks
@@ -143,6 +215,11 @@ impl GeneratedCppQObject {
             structured_qobject,
             opt,
         )?);
+        generated.blocks.append(&mut generate_cpp_list_properties(
+            &qobject.list_properties,
+            &qobject_idents,
+            type_names,
+        )?);
         generated.blocks.append(&mut generate_cpp_methods(
             &structured_qobject.methods,
             type_names,
@@ -196,11 +273,37 @@ impl GeneratedCppQObject {
     }
 }
 
+/// Generate the QML_ATTACHED specifier and the static factory which QML uses to create the attached object
+///
+/// The attached object is constructed with the attachee, which calls the Rust constructor of the
+/// attached type that takes a `*mut QObject`. Structuring checks that it exists for QObjects of the bridge.
+fn generate_qml_attached(name: &Name, attached: &Name) -> GeneratedCppQObjectBlocks {
+    let ident = name.cxx_unqualified();
+    let attached = attached.cxx_qualified();
+
+    GeneratedCppQObjectBlocks {
+        metaobjects: vec![format!("QML_ATTACHED({attached})")],
+        methods: vec![CppFragment::Pair {
+            header: format!("static {attached}* qmlAttachedProperties(QObject* object);"),
+            source: formatdoc! {r#"
+                {attached}*
+                {ident}::qmlAttachedProperties(QObject* object)
+                {{
+                  return new {attached}(object);
+                }}
+                "#},
+        }],
+        ..Default::default()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::generator::mock_qml_singleton;
     use crate::{generator::structuring::Structures, parser::Parser};
+    use indoc::indoc;
+    use pretty_assertions::assert_str_eq;
     use quote::format_ident;
     use syn::{parse_quote, ItemMod};
 
@@ -362,4 +465,137 @@ mod tests {
             "Q_CLASSINFO(\"QML.Creatable\", \"false\")"
         );
     }
+
+    #[test]
+    fn test_generated_cpp_qobject_attached() {
+        let module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge(namespace = "cxx_qt")]
+            mod ffi {
+                extern "RustQt" {
+                    #[qobject]
+                    #[qml_element]
+                    #[qml_attached = MyObjectAttached]
+                    type MyObject = super::MyObjectRust;
+
+                    #[qobject]
+                    type MyObjectAttached = super::MyObjectAttachedRust;
+                }
+
+                impl cxx_qt::Constructor<(*mut QObject,)> for MyObjectAttached {}
+            }
+        };
+        let parser = Parser::from(module).unwrap();
+        let structures = Structures::new(&parser.cxx_qt_data).unwrap();
+
+        let cpp = GeneratedCppQObject::from(
+            structures.qobjects.first().unwrap(),
+            &parser.type_names,
+            &GeneratedOpt::default(),
+        )
+        .unwrap();
+        assert_eq!(cpp.blocks.metaobjects.len(), 2);
+        assert_eq!(
+            cpp.blocks.metaobjects[0],
+            "Q_CLASSINFO(\"QML.Element\", \"MyObject\")"
+        );
+        assert_eq!(
+            cpp.blocks.metaobjects[1],
+            "QML_ATTACHED(cxx_qt::MyObjectAttached)"
+        );
+
+        let CppFragment::Pair { header, source } = &cpp.blocks.methods[0] else {
+            panic!("Expected pair")
+        };
+        assert_str_eq!(
+            header,
+            "static cxx_qt::MyObjectAttached* qmlAttachedProperties(QObject* object);"
+        );
+        assert_str_eq!(
+            source,
+            indoc! {r#"
+            cxx_qt::MyObjectAttached*
+            MyObject::qmlAttachedProperties(QObject* object)
+            {
+              return new cxx_qt::MyObjectAttached(object);
+            }
+            "#}
+        );
+    }
+
+    #[test]
+    fn test_generated_cpp_qobject_extended() {
+        let module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge(namespace = "cxx_qt")]
+            mod ffi {
+                extern "RustQt" {
+                    #[qobject]
+                    #[qml_named_element = "MyQmlElement"]
+                    #[qml_extended = MyObjectExtension]
+                    #[qml_added_in = "1.2"]
+                    #[qml_removed_in = "2.0"]
+                    type MyObject = super::MyObjectRust;
+
+                    #[qobject]
+                    type MyObjectExtension = super::MyObjectExtensionRust;
+                }
+            }
+        };
+        let parser = Parser::from(module).unwrap();
+        let structures = Structures::new(&parser.cxx_qt_data).unwrap();
+
+        let cpp = GeneratedCppQObject::from(
+            structures.qobjects.first().unwrap(),
+            &parser.type_names,
+            &GeneratedOpt::default(),
+        )
+        .unwrap();
+        assert_eq!(
+            cpp.blocks.metaobjects,
+            vec![
+                "Q_CLASSINFO(\"QML.Element\", \"MyQmlElement\")",
+                "QML_EXTENDED(cxx_qt::MyObjectExtension)",
+                "Q_CLASSINFO(\"QML.AddedInVersion\", \"258\")",
+                "Q_CLASSINFO(\"QML.AddedInMinorVersion\", \"2\")",
+                "Q_CLASSINFO(\"QML.RemovedInVersion\", \"512\")",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_generated_cpp_qobject_class_info() {
+        let module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge(namespace = "cxx_qt")]
+            mod ffi {
+                extern "RustQt" {
+                    #[qobject]
+                    #[qml_element]
+                    #[qclassinfo(author = "KDAB", "Custom.Key" = "value")]
+                    #[qclassinfo(description = "A \"quoted\" C:\\path\nwith lines")]
+                    #[qml_default_property = children]
+                    #[qml_list_property(MyObject, children, cxx_name = "data")]
+                    type MyObject = super::MyObjectRust;
+                }
+            }
+        };
+        let parser = Parser::from(module).unwrap();
+        let structures = Structures::new(&parser.cxx_qt_data).unwrap();
+
+        let cpp = GeneratedCppQObject::from(
+            structures.qobjects.first().unwrap(),
+            &parser.type_names,
+            &GeneratedOpt::default(),
+        )
+        .unwrap();
+        assert_eq!(
+            cpp.blocks.metaobjects,
+            vec![
+                "Q_CLASSINFO(\"QML.Element\", \"MyObject\")",
+                "Q_CLASSINFO(\"author\", \"KDAB\")",
+                "Q_CLASSINFO(\"Custom.Key\", \"value\")",
+                r#"Q_CLASSINFO("description", "A \"quoted\" C:\\path\nwith lines")"#,
+                "Q_CLASSINFO(\"DefaultProperty\", \"data\")",
+                "Q_PROPERTY(QQmlListProperty<cxx_qt::MyObject> data READ getData CONSTANT)",
+            ]
+        );
+    }
 }
@@ -2,7 +2,7 @@
 // SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
-use crate::parser::qobject::ParsedQObject;
+use crate::parser::{qgadget::ParsedQGadget, qobject::ParsedQObject};
 use quote::format_ident;
 use syn::Ident;
 
@@ -21,6 +21,15 @@ impl From<&ParsedQObject> for NamespaceName {
     }
 }
 
+impl From<&ParsedQGadget> for NamespaceName {
+    fn from(qgadget: &ParsedQGadget) -> Self {
+        NamespaceName::from_namespace_and_ident(
+            qgadget.name.namespace().unwrap_or_default(),
+            qgadget.name.rust_unqualified(),
+        )
+    }
+}
+
 impl NamespaceName {
     /// Build the namespace names from a given module and qobject ident
     pub fn from_namespace_and_ident(namespace: &str, ident: &Ident) -> Self {
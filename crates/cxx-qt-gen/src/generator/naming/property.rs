@@ -4,7 +4,10 @@
 // SPDX-License-Identifier: MIT OR Apache-2.0
 use crate::{
     naming::Name,
-    parser::property::{FlagState, ParsedQProperty},
+    parser::{
+        listproperty::ParsedQmlListProperty,
+        property::{FlagState, ParsedQProperty},
+    },
 };
 use quote::format_ident;
 use syn::Result;
@@ -116,6 +119,54 @@ impl QPropertyNames {
     }
 }
 
+impl QPropertyNames {
+    /// For a Q_GADGET property, create the names of its getter and setter.
+    ///
+    /// Q_GADGETs have no signals and the parser rejects custom functions, so all names are automatic.
+    pub(crate) fn from_gadget_property(property: &ParsedQProperty) -> Self {
+        let property_name = &property.name;
+        Self {
+            getter: NameState::Auto(getter_name_from_property(property_name)),
+            setter: property
+                .flags
+                .write
+                .as_ref()
+                .map(|_| NameState::Auto(setter_name_from_property(property_name))),
+            notify: None,
+            reset: None,
+            name: property_name.clone(),
+        }
+    }
+}
+
+/// Names for parts of a QQmlListProperty
+pub struct QmlListPropertyNames {
+    pub name: Name,
+    pub getter: Name,
+    pub append: Name,
+    pub count: Name,
+    pub at: Name,
+    pub clear: Name,
+    pub replace: Name,
+    pub remove_last: Name,
+}
+
+impl From<&ParsedQmlListProperty> for QmlListPropertyNames {
+    fn from(property: &ParsedQmlListProperty) -> Self {
+        let name = &property.name;
+        Self {
+            getter: getter_name_from_property(name),
+            append: list_function_name_from_property(name, "append"),
+            count: list_function_name_from_property(name, "count"),
+            at: list_function_name_from_property(name, "at"),
+            clear: list_function_name_from_property(name, "clear"),
+            replace: list_function_name_from_property(name, "replace"),
+            remove_last: list_function_name_from_property(name, "remove_last"),
+            name: name.clone(),
+        }
+    }
+}
+
 fn capitalise_first(str: String) -> String {
     let mut out = "".to_owned();
     if let Some(first) = str.chars().next() {
@@ -145,6 +196,17 @@ pub fn notify_name_from_property(name: &Name) -> Name {
         .with_cxx_name(format!("{}Changed", name.cxx_unqualified()))
 }
 
+/// For a given list property name generate the name of a function operating on the list
+fn list_function_name_from_property(name: &Name, function: &str) -> Name {
+    let cxx_function = function
+        .split('_')
+        .map(|part| capitalise_first(part.to_owned()))
+        .collect::<String>();
+    name.clone()
+        .with_rust_name(format_ident!("{}_{function}", name.rust_unqualified()))
+        .with_cxx_name(format!("{}{cxx_function}", name.cxx_unqualified()))
+}
+
 #[cfg(test)]
 pub mod tests {
     use super::*;
@@ -207,6 +269,23 @@ pub mod tests {
         );
     }
 
+    #[test]
+    fn test_list_property_names() {
+        let property = ParsedQmlListProperty {
+            name: Name::mock_name_with_cxx("my_children", "myChildren"),
+            ty: format_ident!("Child"),
+        };
+        let names = QmlListPropertyNames::from(&property);
+        assert_eq!(names.getter.cxx_unqualified(), "getMyChildren");
+        assert_eq!(names.append.cxx_unqualified(), "myChildrenAppend");
+        assert_eq!(names.append.rust_unqualified(), "my_children_append");
+        assert_eq!(names.remove_last.cxx_unqualified(), "myChildrenRemoveLast");
+        assert_eq!(
+            names.remove_last.rust_unqualified(),
+            "my_children_remove_last"
+        );
+    }
+
     #[test]
     fn test_capitalise_first() {
         assert_eq!(capitalise_first("abc".to_owned()), "Abc".to_owned());
@@ -69,6 +69,12 @@ impl GeneratedRustFragment {
                     .iter()
                     .filter(|attr| path_compare_str(attr.meta.path(), &["doc"]))
                     .collect();
+                generated.push(GeneratedRustFragment::generate_qml_extension_impl(
+                    &ty.qml_metadata,
+                    &ty.name,
+                    type_names,
+                    &cfgs,
+                )?);
                 generated.push(GeneratedRustFragment::from_cxx_item(parse_quote! {
                     #extern_block_namespace
                     #unsafety extern "C++" {
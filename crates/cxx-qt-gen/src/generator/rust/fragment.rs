@@ -5,9 +5,10 @@
 
 use crate::generator::naming::qobject::QObjectNames;
 use crate::naming::{Name, TypeNames};
+use crate::parser::qobject::QmlElementMetadata;
 use proc_macro2::Ident;
 use quote::format_ident;
-use syn::{parse_quote, Item, Result};
+use syn::{parse_quote, Attribute, Item, Result};
 
 #[derive(Default, Eq, PartialEq, Debug)]
 pub struct GeneratedRustFragment {
@@ -91,6 +92,33 @@ impl GeneratedRustFragment {
         })
     }
 
+    /// Generate the QmlExtension implementation which allows a `#[qml_extended]` extension
+    /// to reach the object that it extends
+    pub fn generate_qml_extension_impl(
+        qml_metadata: &Option<QmlElementMetadata>,
+        type_name: &Name,
+        type_names: &TypeNames,
+        cfgs: &[&Attribute],
+    ) -> Result<Self> {
+        let Some(extension) = qml_metadata
+            .as_ref()
+            .and_then(|qml_metadata| qml_metadata.extended.as_ref())
+        else {
+            return Ok(Self::default());
+        };
+
+        let extension_qualified = type_names.lookup(extension)?.rust_qualified();
+        let extended_qualified = type_name.rust_qualified();
+
+        Ok(Self {
+            cxx_mod_contents: vec![],
+            cxx_qt_mod_contents: vec![parse_quote! {
+                #(#cfgs)*
+                impl ::cxx_qt::QmlExtension<#extended_qualified> for #extension_qualified {}
+            }],
+        })
+    }
+
     // Create a singular GeneratedRustFragment from a Vector of multiple
     pub fn flatten(others: Vec<Self>) -> Self {
         let mut this = Self::default();
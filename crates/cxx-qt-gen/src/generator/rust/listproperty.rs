@@ -0,0 +1,133 @@
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Joshua Booth <joshua.n.booth@gmail.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::{
+    generator::{
+        naming::{property::QmlListPropertyNames, qobject::QObjectNames},
+        rust::fragment::GeneratedRustFragment,
+    },
+    parser::listproperty::ParsedQmlListProperty,
+};
+use syn::{parse_quote, Result, Type};
+
+pub fn generate_rust_list_properties(
+    properties: &[ParsedQmlListProperty],
+    qobject_names: &QObjectNames,
+) -> Result<GeneratedRustFragment> {
+    let mut generated = GeneratedRustFragment::default();
+
+    let cpp_class_name_rust = qobject_names.name.rust_unqualified();
+    let cxx_namespace = qobject_names.namespace_tokens();
+
+    // The callbacks of the list are implemented by the user on the QObject, in the same way as
+    // invokables, so that the list can be backed by any storage
+    for property in properties {
+        let idents = QmlListPropertyNames::from(property);
+        let element = &property.ty;
+        let cxx_ty: Type = parse_quote! { *mut #element };
+
+        let append_cpp = idents.append.cxx_unqualified();
+        let append_rust = idents.append.rust_unqualified();
+        let count_cpp = idents.count.cxx_unqualified();
+        let count_rust = idents.count.rust_unqualified();
+        let at_cpp = idents.at.cxx_unqualified();
+        let at_rust = idents.at.rust_unqualified();
+        let clear_cpp = idents.clear.cxx_unqualified();
+        let clear_rust = idents.clear.rust_unqualified();
+        let replace_cpp = idents.replace.cxx_unqualified();
+        let replace_rust = idents.replace.rust_unqualified();
+        let remove_last_cpp = idents.remove_last.cxx_unqualified();
+        let remove_last_rust = idents.remove_last.rust_unqualified();
+
+        generated.append(GeneratedRustFragment {
+            cxx_mod_contents: vec![parse_quote! {
+                extern "Rust" {
+                    #[cxx_name = #append_cpp]
+                    #cxx_namespace
+                    unsafe fn #append_rust(self: Pin<&mut #cpp_class_name_rust>, item: #cxx_ty);
+
+                    #[cxx_name = #count_cpp]
+                    #cxx_namespace
+                    fn #count_rust(self: &#cpp_class_name_rust) -> isize;
+
+                    #[cxx_name = #at_cpp]
+                    #cxx_namespace
+                    fn #at_rust(self: &#cpp_class_name_rust, index: isize) -> #cxx_ty;
+
+                    #[cxx_name = #clear_cpp]
+                    #cxx_namespace
+                    fn #clear_rust(self: Pin<&mut #cpp_class_name_rust>);
+
+                    #[cxx_name = #replace_cpp]
+                    #cxx_namespace
+                    unsafe fn #replace_rust(self: Pin<&mut #cpp_class_name_rust>, index: isize, item: #cxx_ty);
+
+                    #[cxx_name = #remove_last_cpp]
+                    #cxx_namespace
+                    fn #remove_last_rust(self: Pin<&mut #cpp_class_name_rust>);
+                }
+            }],
+            cxx_qt_mod_contents: vec![],
+        });
+    }
+
+    Ok(generated)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::naming::TypeNames;
+    use crate::parser::{qobject::ParsedQObject, CaseConversion};
+    use crate::tests::assert_tokens_eq;
+    use quote::quote;
+    use syn::ItemStruct;
+
+    #[test]
+    fn test_generate_rust_list_properties() {
+        let input: ItemStruct = parse_quote! {
+            #[qml_list_property(Child, children)]
+            struct MyStruct;
+        };
+        let properties =
+            vec![ParsedQmlListProperty::parse(&input.attrs[0], CaseConversion::none()).unwrap()];
+
+        let mut type_names = TypeNames::mock();
+        type_names.mock_insert("Child", None, None, None);
+        let qobject_names =
+            QObjectNames::from_qobject(&ParsedQObject::mock(), &type_names).unwrap();
+
+        let generated = generate_rust_list_properties(&properties, &qobject_names).unwrap();
+
+        assert_eq!(generated.cxx_mod_contents.len(), 1);
+        assert!(generated.cxx_qt_mod_contents.is_empty());
+
+        assert_tokens_eq(
+            &generated.cxx_mod_contents[0],
+            quote! {
+                extern "Rust" {
+                    #[cxx_name = "childrenAppend"]
+                    unsafe fn children_append(self: Pin<&mut MyObject>, item: *mut Child);
+
+                    #[cxx_name = "childrenCount"]
+                    fn children_count(self: &MyObject) -> isize;
+
+                    #[cxx_name = "childrenAt"]
+                    fn children_at(self: &MyObject, index: isize) -> *mut Child;
+
+                    #[cxx_name = "childrenClear"]
+                    fn children_clear(self: Pin<&mut MyObject>);
+
+                    #[cxx_name = "childrenReplace"]
+                    unsafe fn children_replace(self: Pin<&mut MyObject>, index: isize, item: *mut Child);
+
+                    #[cxx_name = "childrenRemoveLast"]
+                    fn children_remove_last(self: Pin<&mut MyObject>);
+                }
+            },
+        );
+    }
+}
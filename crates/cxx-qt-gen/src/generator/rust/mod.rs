@@ -8,9 +8,11 @@ pub mod cxxqttype;
 pub mod externcxxqt;
 pub mod fragment;
 pub mod inherit;
+pub mod listproperty;
 pub mod method;
 pub mod property;
 pub mod qenum;
+pub mod qgadget;
 pub mod qobject;
 pub mod signals;
 pub mod threading;
@@ -47,6 +49,13 @@ impl GeneratedRustBlocks {
                 .map(|qobject| GeneratedRustFragment::from_qobject(qobject, &parser.type_names))
                 .collect::<Result<Vec<GeneratedRustFragment>>>()?,
         );
+        fragments.extend(
+            structures
+                .qgadgets
+                .iter()
+                .map(|qgadget| GeneratedRustFragment::from_qgadget(qgadget, &parser.type_names))
+                .collect::<Result<Vec<GeneratedRustFragment>>>()?,
+        );
         fragments.extend(
             parser
                 .cxx_qt_data
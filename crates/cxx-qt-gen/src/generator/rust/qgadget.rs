@@ -0,0 +1,334 @@
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Joshua Booth <joshua.n.booth@gmail.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::generator::structuring::StructuredQGadget;
+use crate::{
+    generator::{
+        naming::{
+            namespace::NamespaceName,
+            property::{NameState, QPropertyNames},
+            qobject::QObjectNames,
+        },
+        rust::{fragment::GeneratedRustFragment, method::generate_rust_methods, property::getter},
+    },
+    naming::rust::{syn_type_cxx_bridge_to_qualified, syn_type_is_cxx_bridge_unsafe},
+    naming::TypeNames,
+};
+use quote::{format_ident, quote};
+use syn::{parse_quote, Attribute, Result, Type};
+
+impl GeneratedRustFragment {
+    pub fn from_qgadget(
+        structured_qgadget: &StructuredQGadget,
+        type_names: &TypeNames,
+    ) -> Result<Self> {
+        let qgadget = structured_qgadget.declaration;
+        // Q_GADGETs share the naming scheme of QObjects
+        let qgadget_names =
+            QObjectNames::from_name_and_ident(&qgadget.name, &qgadget.rust_type, type_names)?;
+        let namespace_idents = NamespaceName::from(qgadget);
+
+        let mut generated = vec![generate_qgadget_definitions(
+            &qgadget_names,
+            &namespace_idents,
+            &qgadget.cfgs,
+        )?];
+
+        for property in &qgadget.properties {
+            let idents = QPropertyNames::from_gadget_property(property);
+
+            if let Some(getter) =
+                getter::generate(&idents, &qgadget_names, &property.ty, type_names)?
+            {
+                generated.push(getter);
+            }
+
+            if let Some(setter) =
+                generate_setter(&idents, &qgadget_names, &property.ty, type_names)?
+            {
+                generated.push(setter);
+            }
+        }
+
+        generated.push(generate_rust_methods(
+            &structured_qgadget.methods,
+            &qgadget_names,
+            type_names,
+        )?);
+
+        Ok(GeneratedRustFragment::flatten(generated))
+    }
+}
+
+/// Generate the CXX definitions for the Q_GADGET and the Rust type which the C++ class wraps
+fn generate_qgadget_definitions(
+    qgadget_idents: &QObjectNames,
+    namespace: &NamespaceName,
+    cfgs: &[Attribute],
+) -> Result<GeneratedRustFragment> {
+    let cpp_class_name_rust = qgadget_idents.name.rust_unqualified();
+    let cpp_class_name_cpp = qgadget_idents.name.cxx_unqualified();
+    let cpp_class_name_qualified = qgadget_idents.name.cxx_qualified();
+
+    let rust_struct_name_rust = qgadget_idents.rust_struct.rust_unqualified();
+    let rust_struct_name_rust_str = rust_struct_name_rust.to_string();
+    let namespace_tokens = qgadget_idents.namespace_tokens();
+    let namespace_internals = &namespace.internal;
+    let cxx_name = if *cpp_class_name_rust == cpp_class_name_cpp {
+        quote! {}
+    } else {
+        quote! {
+            #[doc = "\n\nNote: The C++ name of this Q_GADGET is: "]
+            #[doc = #cpp_class_name_cpp]
+            #[cxx_name = #cpp_class_name_cpp]
+        }
+    };
+
+    let module_name = format_ident!("cxx_qt_private_qgadget_{cpp_class_name_rust}");
+    let create_rs_ident = format_ident!("create_rs_{rust_struct_name_rust}");
+    let clone_rs_ident = format_ident!("clone_rs_{rust_struct_name_rust}");
+
+    Ok(GeneratedRustFragment {
+        cxx_mod_contents: vec![
+            parse_quote! {
+                extern "C++" {
+                    #[doc = "The C++ type for the Q_GADGET "]
+                    #[doc = #rust_struct_name_rust_str]
+                    #[doc = "\n"]
+                    #[doc = "This is a value type which owns a copy of the Rust struct"]
+                    #namespace_tokens
+                    #cxx_name
+                    #(#cfgs)*
+                    type #cpp_class_name_rust = super::#module_name::#cpp_class_name_rust;
+                }
+            },
+            parse_quote! {
+                extern "Rust" {
+                    // Needed for Q_GADGETs to have a namespace on their type or extern block
+                    //
+                    // A Namespace from cxx_qt::bridge would be automatically applied to all children
+                    // but to apply it to only certain types, it is needed here too
+                    #namespace_tokens
+                    #(#cfgs)*
+                    type #rust_struct_name_rust;
+                }
+            },
+            parse_quote! {
+                extern "Rust" {
+                    #[cxx_name = "createRs"]
+                    #[namespace = #namespace_internals]
+                    #(#cfgs)*
+                    fn #create_rs_ident() -> Box<#rust_struct_name_rust>;
+
+                    #[cxx_name = "cloneRs"]
+                    #[namespace = #namespace_internals]
+                    #(#cfgs)*
+                    fn #clone_rs_ident(rust: &#rust_struct_name_rust) -> Box<#rust_struct_name_rust>;
+                }
+            },
+        ],
+        cxx_qt_mod_contents: vec![
+            parse_quote! {
+                #[doc(hidden)]
+                #[allow(clippy::unnecessary_box_returns)]
+                #(#cfgs)*
+                pub fn #create_rs_ident() -> std::boxed::Box<#rust_struct_name_rust> {
+                    // Wrapping the call to Default::default in a Box::new call leads
+                    // to a nicer error message, as it's not trying to infer trait bounds
+                    // on Box, but directly on the given type.
+                    std::boxed::Box::new(core::default::Default::default())
+                }
+            },
+            parse_quote! {
+                #[doc(hidden)]
+                #[allow(clippy::unnecessary_box_returns)]
+                #(#cfgs)*
+                pub fn #clone_rs_ident(rust: &#rust_struct_name_rust) -> std::boxed::Box<#rust_struct_name_rust> {
+                    std::boxed::Box::new(core::clone::Clone::clone(rust))
+                }
+            },
+            parse_quote! {
+                #(#cfgs)*
+                mod #module_name {
+                    #[doc = "The C++ type for the Q_GADGET "]
+                    #[doc = #rust_struct_name_rust_str]
+                    #[repr(transparent)]
+                    pub struct #cpp_class_name_rust {
+                        cxx_qt_rust: std::boxed::Box<super::#rust_struct_name_rust>,
+                    }
+
+                    impl ::core::ops::Deref for #cpp_class_name_rust {
+                        type Target = super::#rust_struct_name_rust;
+
+                        fn deref(&self) -> &Self::Target {
+                            &self.cxx_qt_rust
+                        }
+                    }
+
+                    impl ::core::ops::DerefMut for #cpp_class_name_rust {
+                        fn deref_mut(&mut self) -> &mut Self::Target {
+                            &mut self.cxx_qt_rust
+                        }
+                    }
+
+                    impl ::core::clone::Clone for #cpp_class_name_rust {
+                        fn clone(&self) -> Self {
+                            Self {
+                                cxx_qt_rust: super::#clone_rs_ident(&self.cxx_qt_rust),
+                            }
+                        }
+                    }
+
+                    impl ::core::default::Default for #cpp_class_name_rust {
+                        fn default() -> Self {
+                            Self {
+                                cxx_qt_rust: super::#create_rs_ident(),
+                            }
+                        }
+                    }
+
+                    impl ::core::convert::From<super::#rust_struct_name_rust> for #cpp_class_name_rust {
+                        fn from(rust: super::#rust_struct_name_rust) -> Self {
+                            Self {
+                                cxx_qt_rust: std::boxed::Box::new(rust),
+                            }
+                        }
+                    }
+
+                    // Safety:
+                    //
+                    // The C++ class only contains a rust::Box of the Rust struct and is declared relocatable.
+                    #[automatically_derived]
+                    unsafe impl ::cxx::ExternType for #cpp_class_name_rust {
+                        type Id = ::cxx::type_id!(#cpp_class_name_qualified);
+                        type Kind = ::cxx::kind::Trivial;
+                    }
+                }
+            },
+        ],
+    })
+}
+
+/// Generate the setter of a Q_GADGET property, which writes directly into the Rust struct
+fn generate_setter(
+    idents: &QPropertyNames,
+    qgadget_names: &QObjectNames,
+    cxx_ty: &Type,
+    type_names: &TypeNames,
+) -> Result<Option<GeneratedRustFragment>> {
+    let Some(NameState::Auto(setter)) = &idents.setter else {
+        return Ok(None);
+    };
+
+    let cpp_class_name_rust = qgadget_names.name.rust_unqualified();
+    let setter_cpp = setter.cxx_unqualified();
+    let setter_rust = setter.rust_unqualified();
+    let ident = idents.name.rust_unqualified();
+    let ident_str = ident.to_string();
+
+    let qualified_ty = syn_type_cxx_bridge_to_qualified(cxx_ty, type_names)?;
+    let qualified_impl = type_names.rust_qualified(cpp_class_name_rust)?;
+
+    // Determine if unsafe is required due to an unsafe type
+    let has_unsafe = if syn_type_is_cxx_bridge_unsafe(cxx_ty) {
+        quote! { unsafe }
+    } else {
+        quote! {}
+    };
+
+    let cxx_namespace = qgadget_names.namespace_tokens();
+
+    Ok(Some(GeneratedRustFragment {
+        cxx_mod_contents: vec![parse_quote! {
+            extern "Rust" {
+                #[cxx_name = #setter_cpp]
+                #cxx_namespace
+                #has_unsafe fn #setter_rust(self: &mut #cpp_class_name_rust, value: #cxx_ty);
+            }
+        }],
+        cxx_qt_mod_contents: vec![parse_quote! {
+            impl #qualified_impl {
+                #[doc = "Setter for the Q_PROPERTY "]
+                #[doc = #ident_str]
+                pub fn #setter_rust(&mut self, value: #qualified_ty) {
+                    self.#ident = value;
+                }
+            }
+        }],
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::generator::structuring::Structures;
+    use crate::parser::Parser;
+    use crate::tests::assert_tokens_eq;
+    use syn::{parse_quote, ItemMod};
+
+    #[test]
+    fn test_generated_rust_qgadget_blocks() {
+        let module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge(namespace = "cxx_qt")]
+            mod ffi {
+                extern "RustQt" {
+                    #[qgadget]
+                    #[qproperty(f64, x)]
+                    #[qproperty(f64, y, READ, CONSTANT)]
+                    type Point = super::PointRust;
+                }
+            }
+        };
+        let parser = Parser::from(module).unwrap();
+        let structures = Structures::new(&parser.cxx_qt_data).unwrap();
+
+        let rust = GeneratedRustFragment::from_qgadget(
+            structures.qgadgets.first().unwrap(),
+            &parser.type_names,
+        )
+        .unwrap();
+
+        // Definitions, two getters and one setter
+        assert_eq!(rust.cxx_mod_contents.len(), 6);
+        assert_eq!(rust.cxx_qt_mod_contents.len(), 6);
+
+        assert_tokens_eq(
+            &rust.cxx_mod_contents[0],
+            quote! {
+                extern "C++" {
+                    #[doc = "The C++ type for the Q_GADGET "]
+                    #[doc = "PointRust"]
+                    #[doc = "\n"]
+                    #[doc = "This is a value type which owns a copy of the Rust struct"]
+                    #[namespace = "cxx_qt"]
+                    type Point = super::cxx_qt_private_qgadget_Point::Point;
+                }
+            },
+        );
+        assert_tokens_eq(
+            &rust.cxx_mod_contents[4],
+            quote! {
+                extern "Rust" {
+                    #[cxx_name = "setX"]
+                    #[namespace = "cxx_qt"]
+                    fn set_x(self: &mut Point, value: f64);
+                }
+            },
+        );
+        assert_tokens_eq(
+            &rust.cxx_qt_mod_contents[4],
+            quote! {
+                impl ffi::Point {
+                    #[doc = "Setter for the Q_PROPERTY "]
+                    #[doc = "x"]
+                    pub fn set_x(&mut self, value: f64) {
+                        self.x = value;
+                    }
+                }
+            },
+        );
+    }
+}
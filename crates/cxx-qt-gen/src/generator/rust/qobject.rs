@@ -8,8 +8,8 @@ use crate::{
         naming::{namespace::NamespaceName, qobject::QObjectNames},
         rust::{
             constructor, cxxqttype, fragment::GeneratedRustFragment, inherit,
-            method::generate_rust_methods, property::generate_rust_properties,
-            signals::generate_rust_signals, threading,
+            listproperty::generate_rust_list_properties, method::generate_rust_methods,
+            property::generate_rust_properties, signals::generate_rust_signals, threading,
         },
     },
     naming::TypeNames,
@@ -35,14 +35,15 @@ impl GeneratedRustFragment {
                 type_names,
                 structured_qobject,
             )?,
+            generate_rust_list_properties(&qobject.list_properties, &qobject_names)?,
             generate_rust_methods(&structured_qobject.methods, &qobject_names, type_names)?,
             inherit::generate(&qobject_names, &structured_qobject.inherited_methods)?,
             generate_rust_signals(&structured_qobject.signals, &qobject_names, type_names)?,
         ];
 
-        // If this type is a singleton then we need to add an include
+        // If this type uses any of the QML macros then we need to add an include
         if let Some(qml_metadata) = &qobject.qml_metadata {
-            if qml_metadata.singleton {
+            if qml_metadata.requires_qml_include() {
                 generated.push(GeneratedRustFragment::from_cxx_item(parse_quote! {
                     unsafe extern "C++" {
                         include!(<QtQml/QQmlEngine>);
@@ -51,6 +52,13 @@ impl GeneratedRustFragment {
             }
         }
 
+        generated.push(GeneratedRustFragment::generate_qml_extension_impl(
+            &qobject.qml_metadata,
+            &qobject.name,
+            type_names,
+            &qobject.cfgs.iter().collect::<Vec<_>>(),
+        )?);
+
         // If this type has threading enabled then add generation
         if structured_qobject.threading {
             generated.push(threading::generate(
@@ -12,20 +12,25 @@
 /// This mostly means grouping QObjects with their QEnums, QSignals, etc...
 ///
 /// All resulting structures are listed in the `Structures` struct.
+pub mod qgadget;
 pub mod qobject;
 
 use crate::parser::{
+    constructor::Constructor,
     cxxqtdata::ParsedCxxQtData,
     trait_impl::{TraitImpl, TraitKind},
 };
+pub use qgadget::StructuredQGadget;
 pub use qobject::StructuredQObject;
-use syn::{Error, Ident, Result};
+use syn::{Error, Ident, Result, Type};
 
 /// The list of all structures that could be associated from the parsed data.
 /// Most importantly, this includes the list of qobjects.
 pub struct Structures<'a> {
     /// The list of qobjects
     pub qobjects: Vec<StructuredQObject<'a>>,
+    /// The list of Q_GADGETs
+    pub qgadgets: Vec<StructuredQGadget<'a>>,
 }
 
 /// Error for looking up against a QObject which isn't registered in Structures
@@ -47,7 +52,60 @@ fn find_qobject<'a, 'b>(
         .ok_or_else(|| unknown_qobject(ident))
 }
 
+/// Returns true if the constructor takes only a `*mut QObject`, which QML uses to pass the attachee
+/// to an attached object
+fn is_attachee_constructor(constructor: &Constructor) -> bool {
+    match constructor.arguments.as_slice() {
+        [Type::Ptr(ptr)] if ptr.mutability.is_some() => match &*ptr.elem {
+            Type::Path(path) => path
+                .path
+                .segments
+                .last()
+                .is_some_and(|segment| segment.ident == "QObject"),
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
 impl<'a> Structures<'a> {
+    /// Returns an error if a QObject of this bridge is used as QML attached type of another
+    /// QObject, but cannot be constructed with the attachee
+    fn check_qml_attached(qobjects: &[StructuredQObject<'a>]) -> Result<()> {
+        for qobject in qobjects {
+            let Some(attached) = qobject
+                .declaration
+                .qml_metadata
+                .as_ref()
+                .and_then(|metadata| metadata.attached.as_ref())
+            else {
+                continue;
+            };
+
+            // Types declared outside of this bridge are checked by the C++ compiler
+            let Some(attached_qobject) = qobjects
+                .iter()
+                .find(|attached_qobject| attached_qobject.has_qobject_name(attached))
+            else {
+                continue;
+            };
+
+            if !attached_qobject
+                .constructors
+                .iter()
+                .any(|constructor| is_attachee_constructor(constructor))
+            {
+                return Err(Error::new_spanned(
+                    attached,
+                    format!(
+                        "The QML attached type {attached} must implement cxx_qt::Constructor<(*mut QObject,)>, which is called with the attachee!"
+                    ),
+                ));
+            }
+        }
+        Ok(())
+    }
+
     fn structure_trait_impls(
         qobjects: &mut [StructuredQObject<'a>],
         trait_impls: &'a [TraitImpl],
@@ -91,8 +149,27 @@ impl<'a> Structures<'a> {
             }
         }
 
-        // Associate each method parsed with its appropriate qobject
+        let mut qgadgets: Vec<_> = cxxqtdata
+            .qgadgets()
+            .into_iter()
+            .map(StructuredQGadget::from_qgadget)
+            .collect();
+
+        // Associate each method parsed with its appropriate qobject or Q_GADGET
         for method in cxxqtdata.methods() {
+            if let Some(qgadget) = qgadgets
+                .iter_mut()
+                .find(|qgadget| qgadget.has_qgadget_name(&method.qobject_ident))
+            {
+                if method.mutable {
+                    return Err(Error::new_spanned(
+                        &method.method,
+                        "Methods on a Q_GADGET must take `self: &T`, as they are called on a copy of the value!",
+                    ));
+                }
+                qgadget.methods.push(method);
+                continue;
+            }
             let qobject = find_qobject(&mut qobjects, &method.qobject_ident)?;
             qobject.methods.push(method);
         }
@@ -111,7 +188,12 @@ impl<'a> Structures<'a> {
 
         Self::structure_trait_impls(&mut qobjects, &cxxqtdata.trait_impls)?;
 
-        Ok(Structures { qobjects })
+        for qobject in &qobjects {
+            qobject.check_list_property_names()?;
+        }
+        Self::check_qml_attached(&qobjects)?;
+
+        Ok(Structures { qobjects, qgadgets })
     }
 }
 
@@ -240,6 +322,68 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_qgadget_structures() {
+        let module = parse_quote! {
+            #[cxx_qt::bridge]
+            mod ffi {
+                extern "RustQt" {
+                    #[qobject]
+                    type MyObject = super::MyObjectRust;
+
+                    #[qgadget]
+                    #[qproperty(f64, x)]
+                    type Point = super::PointRust;
+                }
+
+                unsafe extern "RustQt" {
+                    #[qinvokable]
+                    fn length(self: &Point) -> f64;
+
+                    #[qinvokable]
+                    fn test_fn(self: Pin<&mut MyObject>);
+                }
+            }
+        };
+
+        let parser = Parser::from(module).unwrap();
+        let structures = Structures::new(&parser.cxx_qt_data).unwrap();
+
+        assert_eq!(structures.qobjects.len(), 1);
+        assert_eq!(structures.qgadgets.len(), 1);
+        assert_eq!(structures.qobjects[0].methods.len(), 1);
+        assert_eq!(structures.qgadgets[0].methods.len(), 1);
+        assert_eq!(
+            *structures.qgadgets[0].methods[0].name.rust_unqualified(),
+            format_ident!("length")
+        );
+    }
+
+    #[test]
+    fn test_qml_attached_constructor() {
+        let module = parse_quote! {
+            #[cxx_qt::bridge]
+            mod ffi {
+                extern "RustQt" {
+                    #[qobject]
+                    #[qml_element]
+                    #[qml_attached = MyObjectAttached]
+                    type MyObject = super::MyObjectRust;
+
+                    #[qobject]
+                    type MyObjectAttached = super::MyObjectAttachedRust;
+                }
+
+                impl cxx_qt::Constructor<()> for MyObjectAttached {}
+                impl cxx_qt::Constructor<(*mut QObject,)> for MyObjectAttached {}
+            }
+        };
+
+        let parser = Parser::from(module).unwrap();
+        let structures = Structures::new(&parser.cxx_qt_data).unwrap();
+        assert_eq!(structures.qobjects[1].constructors.len(), 2);
+    }
+
     fn mock_bridge() -> ItemMod {
         parse_quote! {
             #[cxx_qt::bridge]
@@ -286,6 +430,72 @@ mod tests {
                 }
             }
 
+            {
+                // Method with the same name as a QML list property callback
+                #[cxx_qt::bridge]
+                mod ffi {
+                    extern "RustQt" {
+                        #[qobject]
+                        #[qml_list_property(Child, children)]
+                        type MyObject = super::MyObjectRust;
+                    }
+
+                    unsafe extern "RustQt" {
+                        #[qinvokable]
+                        fn children_count(self: &MyObject) -> isize;
+                    }
+                }
+            }
+
+            {
+                // Signal with the same C++ name as a QML list property callback
+                #[cxx_qt::bridge]
+                mod ffi {
+                    extern "RustQt" {
+                        #[qobject]
+                        #[qml_list_property(Child, children)]
+                        type MyObject = super::MyObjectRust;
+                    }
+
+                    unsafe extern "RustQt" {
+                        #[qsignal]
+                        #[cxx_name = "childrenClear"]
+                        fn cleared(self: Pin<&mut MyObject>);
+                    }
+                }
+            }
+
+            {
+                // Property with the same getter as a QML list property
+                #[cxx_qt::bridge]
+                mod ffi {
+                    extern "RustQt" {
+                        #[qobject]
+                        #[qproperty(i32, children)]
+                        #[qml_list_property(Child, children, rust_name = "child_list")]
+                        type MyObject = super::MyObjectRust;
+                    }
+                }
+            }
+
+            {
+                // QML attached type without a constructor taking the attachee
+                #[cxx_qt::bridge]
+                mod ffi {
+                    extern "RustQt" {
+                        #[qobject]
+                        #[qml_element]
+                        #[qml_attached = MyObjectAttached]
+                        type MyObject = super::MyObjectRust;
+
+                        #[qobject]
+                        type MyObjectAttached = super::MyObjectAttachedRust;
+                    }
+
+                    impl cxx_qt::Constructor<(i32,)> for MyObjectAttached {}
+                }
+            }
+
             {
                 // Invalid QObject for QEnum
                 #[cxx_qt::bridge]
@@ -308,6 +518,38 @@ mod tests {
                 }
             }
 
+            {
+                // Mutable method on a Q_GADGET
+                #[cxx_qt::bridge]
+                mod ffi {
+                    extern "RustQt" {
+                        #[qgadget]
+                        type Point = super::PointRust;
+                    }
+
+                    unsafe extern "RustQt" {
+                        #[qinvokable]
+                        fn reset(self: Pin<&mut Point>);
+                    }
+                }
+            }
+
+            {
+                // Signal on a Q_GADGET
+                #[cxx_qt::bridge]
+                mod ffi {
+                    extern "RustQt" {
+                        #[qgadget]
+                        type Point = super::PointRust;
+                    }
+
+                    unsafe extern "RustQt" {
+                        #[qsignal]
+                        fn changed(self: Pin<&mut Point>);
+                    }
+                }
+            }
+
             {
                 // Undeclared QObject for signal
                 #[cxx_qt::bridge]
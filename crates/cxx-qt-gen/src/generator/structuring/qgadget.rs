@@ -0,0 +1,28 @@
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Joshua Booth <joshua.n.booth@gmail.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::parser::method::ParsedMethod;
+use crate::parser::qgadget::ParsedQGadget;
+use proc_macro2::Ident;
+
+/// The StructuredQGadget contains the parsed Q_GADGET and its invokables.
+pub struct StructuredQGadget<'a> {
+    pub declaration: &'a ParsedQGadget,
+    pub methods: Vec<&'a ParsedMethod>,
+}
+
+impl<'a> StructuredQGadget<'a> {
+    pub fn has_qgadget_name(&self, ident: &Ident) -> bool {
+        self.declaration.name.rust_unqualified() == ident
+    }
+
+    /// Creates a [StructuredQGadget] from a [ParsedQGadget] with an empty method collection
+    pub fn from_qgadget(qgadget: &'a ParsedQGadget) -> Self {
+        Self {
+            declaration: qgadget,
+            methods: vec![],
+        }
+    }
+}
@@ -3,6 +3,7 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
+use crate::generator::naming::property::QmlListPropertyNames;
 use crate::generator::structuring::not_found_error;
 use crate::naming::Name;
 use crate::parser::constructor::Constructor;
@@ -11,7 +12,7 @@ use crate::parser::method::ParsedMethod;
 use crate::parser::signals::ParsedSignal;
 use crate::parser::{qenum::ParsedQEnum, qobject::ParsedQObject};
 use proc_macro2::Ident;
-use syn::Result;
+use syn::{Error, Result};
 
 /// The StructuredQObject contains the parsed QObject and all members.
 /// This includes QEnums, QSignals, methods, etc.
@@ -82,6 +83,67 @@ impl<'a> StructuredQObject<'a> {
             .ok_or_else(|| not_found_error("Signal", id))
     }
 
+    /// Returns an error if a function generated for a QML list property has the same name as
+    /// a method or signal of the QObject
+    pub fn check_list_property_names(&self) -> Result<()> {
+        let existing = self
+            .methods
+            .iter()
+            .map(|method| &method.name)
+            .chain(
+                self.inherited_methods
+                    .iter()
+                    .map(|inherited| &inherited.name),
+            )
+            .chain(self.signals.iter().map(|signal| &signal.name))
+            .chain(self.pending_methods.iter())
+            .chain(self.pending_signals.iter())
+            .collect::<Vec<_>>();
+
+        for property in &self.declaration.list_properties {
+            let names = QmlListPropertyNames::from(property);
+            // The getter only exists in C++, the other functions are implemented in Rust
+            let getter = names.getter.cxx_unqualified();
+            let callbacks = [
+                &names.append,
+                &names.count,
+                &names.at,
+                &names.clear,
+                &names.replace,
+                &names.remove_last,
+            ];
+
+            for name in existing.iter() {
+                let collision = if name.cxx_unqualified() == getter {
+                    Some(getter.clone())
+                } else {
+                    callbacks.iter().find_map(|callback| {
+                        if callback.rust_unqualified() == name.rust_unqualified() {
+                            Some(callback.rust_unqualified().to_string())
+                        } else if callback.cxx_unqualified() == name.cxx_unqualified() {
+                            Some(callback.cxx_unqualified())
+                        } else {
+                            None
+                        }
+                    })
+                };
+
+                if let Some(collision) = collision {
+                    return Err(Error::new_spanned(
+                        name.rust_unqualified(),
+                        format!(
+                            "`{collision}` is already used by the QML list property `{property}` of QObject {qobject}!",
+                            property = property.name.rust_unqualified(),
+                            qobject = self.declaration.name.rust_unqualified(),
+                        ),
+                    ));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
     #[cfg(test)]
     pub fn mock(obj: &'a ParsedQObject) -> Self {
         Self::from_qobject(obj)
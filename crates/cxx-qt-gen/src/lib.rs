@@ -256,6 +256,16 @@ mod tests {
         test_code_generation!("qenum");
     }
 
+    #[test]
+    fn generates_qgadget() {
+        test_code_generation!("qgadget");
+    }
+
+    #[test]
+    fn generates_qml() {
+        test_code_generation!("qml");
+    }
+
     #[test]
     #[should_panic]
     fn fail_token_assert() {
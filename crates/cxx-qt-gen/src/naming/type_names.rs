@@ -165,6 +165,17 @@ impl TypeNames {
             self.populate_qobject(qobject)?;
         }
 
+        // Find and register the Q_GADGETs in the bridge
+        for qgadget in cxx_qt_data.qgadgets() {
+            self.insert(qgadget.name.clone())?;
+            self.insert(Name {
+                rust: qgadget.rust_type.clone(),
+                cxx: None,
+                namespace: None,
+                module: qgadget.name.module.clone(),
+            })?;
+        }
+
         // Find and register the names of any QEnums in the bridge
         for qenum in &cxx_qt_data.qenums {
             self.insert(qenum.name.clone())?;
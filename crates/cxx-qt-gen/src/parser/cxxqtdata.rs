@@ -9,7 +9,7 @@ use crate::parser::externrustqt::ParsedExternRustQt;
 use crate::{
     parser::{
         externcxxqt::ParsedExternCxxQt, inherit::ParsedInheritedMethod, method::ParsedMethod,
-        qenum::ParsedQEnum, qobject::ParsedQObject, signals::ParsedSignal,
+        qenum::ParsedQEnum, qgadget::ParsedQGadget, qobject::ParsedQObject, signals::ParsedSignal,
     },
     syntax::{attribute::attribute_get_path, path::path_compare_str},
 };
@@ -71,6 +71,10 @@ impl ParsedCxxQtData {
         self.flatten_rust_blocks(|block| &block.qobjects)
     }
 
+    pub fn qgadgets(&self) -> Vec<&ParsedQGadget> {
+        self.flatten_rust_blocks(|block| &block.qgadgets)
+    }
+
     /// Determine if the given [syn::Item] is a CXX-Qt related item
     /// If it is then add the [syn::Item] into qobjects BTreeMap
     /// Otherwise return the [syn::Item] to pass through to CXX
@@ -176,6 +180,7 @@ mod tests {
         cxx_qt_data.extern_rustqt_blocks.push(ParsedExternRustQt {
             unsafety: None,
             qobjects: vec![create_parsed_qobject(), create_parsed_qobject()],
+            qgadgets: vec![],
             methods: vec![],
             signals: vec![],
             inherited_methods: vec![],
@@ -131,6 +131,7 @@ mod tests {
     use super::*;
     use quote::format_ident;
 
+    use crate::parser::qobject::QmlVersion;
     use crate::tests::assert_parse_errors;
     use syn::parse_quote;
 
@@ -182,6 +183,45 @@ mod tests {
         assert!(extern_cxx_qt.unsafety.is_none());
     }
 
+    #[test]
+    fn test_extern_cxxqt_type_qml_foreign() {
+        let extern_cxx_qt = ParsedExternCxxQt::parse(
+            parse_quote! {
+                extern "C++Qt" {
+                    #[qobject]
+                    #[qml_foreign]
+                    type QTimer;
+
+                    #[qobject]
+                    #[qml_foreign]
+                    #[qml_named_element = "PushButton"]
+                    #[qml_extended = PushButtonExtension]
+                    #[qml_added_in = "1.1"]
+                    type QPushButton;
+                }
+            },
+            &format_ident!("qobject"),
+            None,
+        )
+        .unwrap();
+        assert_eq!(extern_cxx_qt.qobjects.len(), 2);
+
+        let qml_metadata = extern_cxx_qt.qobjects[0].qml_metadata.as_ref().unwrap();
+        assert_eq!(qml_metadata.name, "QTimer");
+        assert!(qml_metadata.extended.is_none());
+
+        let qml_metadata = extern_cxx_qt.qobjects[1].qml_metadata.as_ref().unwrap();
+        assert_eq!(qml_metadata.name, "PushButton");
+        assert_eq!(
+            qml_metadata.extended,
+            Some(format_ident!("PushButtonExtension"))
+        );
+        assert_eq!(
+            qml_metadata.added_in,
+            Some(QmlVersion { major: 1, minor: 1 })
+        );
+    }
+
     #[test]
     fn test_extern_cxxqt_type_non_type() {
         let extern_cxx_qt = ParsedExternCxxQt::parse(
@@ -226,6 +266,32 @@ mod tests {
                     type QPushButton;
                 }
             }
+
+            // QML attributes require the type to be registered as foreign
+            {
+                unsafe extern "C++Qt" {
+                    #[qobject]
+                    #[qml_named_element = "PushButton"]
+                    type QPushButton;
+                }
+            }
+            {
+                unsafe extern "C++Qt" {
+                    #[qobject]
+                    #[qml_extended = PushButtonExtension]
+                    type QPushButton;
+                }
+            }
+
+            // Extern types cannot be singletons
+            {
+                unsafe extern "C++Qt" {
+                    #[qobject]
+                    #[qml_foreign]
+                    #[qml_singleton]
+                    type QPushButton;
+                }
+            }
         );
     }
 }
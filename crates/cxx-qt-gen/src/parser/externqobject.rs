@@ -3,8 +3,10 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 use crate::naming::Name;
-use crate::parser::{parse_base_type, require_attributes, CaseConversion};
-use syn::{ForeignItemType, Ident, Result};
+use crate::parser::{
+    parse_base_type, qobject::QmlElementMetadata, require_attributes, CaseConversion,
+};
+use syn::{Error, ForeignItemType, Ident, Result};
 
 /// A representation of a QObject to be generated in an extern C++ block
 pub struct ParsedExternQObject {
@@ -14,10 +16,12 @@ pub struct ParsedExternQObject {
     pub declaration: ForeignItemType,
     /// The base class of the struct
     pub base_class: Option<Ident>,
+    /// Metadata for registering the type in QML through a QML_FOREIGN type
+    pub qml_metadata: Option<QmlElementMetadata>,
 }
 
 impl ParsedExternQObject {
-    const ALLOWED_ATTRS: [&'static str; 7] = [
+    const ALLOWED_ATTRS: [&'static str; 13] = [
         "cxx_name",
         "rust_name",
         "namespace",
@@ -25,6 +29,12 @@ impl ParsedExternQObject {
         "doc",
         "qobject",
         "base",
+        "qml_foreign",
+        "qml_named_element",
+        "qml_uncreatable",
+        "qml_extended",
+        "qml_added_in",
+        "qml_removed_in",
     ];
 
     pub fn parse(
@@ -36,16 +46,38 @@ impl ParsedExternQObject {
 
         let base_class = parse_base_type(&attributes)?;
 
+        let name = Name::from_ident_and_attrs(
+            &ty.ident,
+            &ty.attrs,
+            parent_namespace,
+            Some(module_ident),
+            CaseConversion::none(),
+        )?;
+
+        // A foreign type is registered under its C++ name unless a #[qml_named_element] is given
+        let qml_metadata = if attributes.contains_key("qml_foreign") {
+            let qml_name = QmlElementMetadata::parse_name(&attributes, &name)?
+                .unwrap_or_else(|| name.cxx_unqualified());
+            Some(QmlElementMetadata::parse(&attributes, qml_name)?)
+        } else {
+            QmlElementMetadata::require_unregistered(&attributes)?;
+            if let Some(attr) = attributes
+                .get("qml_named_element")
+                .or_else(|| attributes.get("qml_uncreatable"))
+            {
+                return Err(Error::new_spanned(
+                    attr,
+                    "QML attributes on an extern \"C++Qt\" type require a #[qml_foreign] attribute!",
+                ));
+            }
+            None
+        };
+
         Ok(Self {
-            name: Name::from_ident_and_attrs(
-                &ty.ident,
-                &ty.attrs,
-                parent_namespace,
-                Some(module_ident),
-                CaseConversion::none(),
-            )?,
+            name,
             declaration: ty,
             base_class,
+            qml_metadata,
         })
     }
 }
@@ -6,6 +6,7 @@
 use crate::naming::cpp::err_unsupported_item;
 use crate::parser::inherit::ParsedInheritedMethod;
 use crate::parser::method::ParsedMethod;
+use crate::parser::qgadget::ParsedQGadget;
 use crate::parser::qobject::ParsedQObject;
 use crate::parser::signals::ParsedSignal;
 use crate::parser::{require_attributes, CaseConversion};
@@ -23,6 +24,8 @@ pub struct ParsedExternRustQt {
     pub unsafety: Option<Token![unsafe]>,
     /// List of QObjects defined in the module
     pub qobjects: Vec<ParsedQObject>,
+    /// List of Q_GADGETs defined in the module
+    pub qgadgets: Vec<ParsedQGadget>,
     /// List of methods and Q_INVOKABLES found
     pub methods: Vec<ParsedMethod>,
     /// List of the Q_SIGNALS found
@@ -64,6 +67,18 @@ impl ParsedExternRustQt {
                 ForeignItem::Verbatim(tokens) => {
                     let foreign_alias: ForeignTypeIdentAlias = syn::parse2(tokens.clone())?;
 
+                    // Load the Q_GADGET
+                    if attribute_get_path(&foreign_alias.attrs, &["qgadget"]).is_some() {
+                        let qgadget = ParsedQGadget::parse(
+                            foreign_alias,
+                            namespace.as_deref(),
+                            module_ident,
+                            auto_case,
+                        )?;
+                        extern_rustqt_block.qgadgets.push(qgadget);
+                        continue;
+                    }
+
                     // Load the QObject
                     let qobject = ParsedQObject::parse(
                         foreign_alias,
@@ -211,6 +226,26 @@ mod tests {
         assert!(!methods[1].is_qinvokable);
     }
 
+    #[test]
+    fn test_parse_qgadget() {
+        let block: ItemForeignMod = parse_quote! {
+            extern "RustQt" {
+                #[qobject]
+                type MyObject = super::MyObjectRust;
+
+                #[qgadget]
+                #[qproperty(f64, x)]
+                type Point = super::PointRust;
+            }
+        };
+        let parsed_rust_qt =
+            ParsedExternRustQt::parse(block, &format_ident!("qobject"), None).unwrap();
+
+        assert_eq!(parsed_rust_qt.qobjects.len(), 1);
+        assert_eq!(parsed_rust_qt.qgadgets.len(), 1);
+        assert_eq!(parsed_rust_qt.qgadgets[0].rust_type, "PointRust");
+    }
+
     #[test]
     fn test_parse_invalid() {
         assert_parse_errors!(
@@ -0,0 +1,115 @@
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Joshua Booth <joshua.n.booth@gmail.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::naming::Name;
+use crate::parser::CaseConversion;
+use crate::syntax::expr::expr_to_string;
+use syn::{
+    parse::ParseStream, punctuated::Punctuated, Attribute, Error, Ident, MetaNameValue, Result,
+    Token,
+};
+
+/// Describes a single QQmlListProperty for a QObject
+///
+/// The callbacks which QML uses to access the list are implemented in Rust on the QObject.
+pub struct ParsedQmlListProperty {
+    /// The name of the property
+    pub name: Name,
+    /// The QObject type of the elements in the list
+    pub ty: Ident,
+}
+
+impl ParsedQmlListProperty {
+    pub fn parse(attr: &Attribute, auto_case: CaseConversion) -> Result<Self> {
+        attr.parse_args_with(|input: ParseStream| -> Result<Self> {
+            let ty = input.parse()?;
+            let _comma = input.parse::<Token![,]>()?;
+            let ident = input.parse()?;
+
+            let mut cxx_name = None;
+            let mut rust_name = None;
+
+            if !input.is_empty() {
+                let _comma = input.parse::<Token![,]>()?;
+                let options: Punctuated<MetaNameValue, Token![,]> =
+                    Punctuated::parse_terminated(input)?;
+                for option in options {
+                    let value = expr_to_string(&option.value)?;
+                    if option.path.is_ident("cxx_name") {
+                        cxx_name = Some(value);
+                    } else if option.path.is_ident("rust_name") {
+                        rust_name = Some(syn::parse_str::<Ident>(&value)?);
+                    } else {
+                        return Err(Error::new_spanned(
+                            option.path,
+                            "Invalid option, expected `cxx_name = \"myName\"` or `rust_name = \"my_name\"`!",
+                        ));
+                    }
+                }
+            }
+
+            Ok(Self {
+                name: Name::new(ident).with_options(cxx_name, rust_name, auto_case),
+                ty,
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use quote::format_ident;
+    use syn::{parse_quote, ItemStruct};
+
+    fn parse_list_property(input: ItemStruct) -> Result<ParsedQmlListProperty> {
+        ParsedQmlListProperty::parse(&input.attrs[0], CaseConversion::none())
+    }
+
+    #[test]
+    fn test_parse_list_property() {
+        let property = parse_list_property(parse_quote! {
+            #[qml_list_property(Child, children)]
+            struct MyStruct;
+        })
+        .unwrap();
+        assert_eq!(property.name.rust_unqualified(), "children");
+        assert_eq!(property.name.cxx_unqualified(), "children");
+        assert_eq!(property.ty, format_ident!("Child"));
+
+        let property = parse_list_property(parse_quote! {
+            #[qml_list_property(Child, my_children, cxx_name = "data")]
+            struct MyStruct;
+        })
+        .unwrap();
+        assert_eq!(property.name.rust_unqualified(), "my_children");
+        assert_eq!(property.name.cxx_unqualified(), "data");
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(parse_list_property(parse_quote! {
+            #[qml_list_property(Child)]
+            struct MyStruct;
+        })
+        .is_err());
+        assert!(parse_list_property(parse_quote! {
+            #[qml_list_property(Vec<Child>, children)]
+            struct MyStruct;
+        })
+        .is_err());
+        assert!(parse_list_property(parse_quote! {
+            #[qml_list_property(Child, children, READ)]
+            struct MyStruct;
+        })
+        .is_err());
+        assert!(parse_list_property(parse_quote! {
+            #[qml_list_property(Child, children, notify = "childrenChanged")]
+            struct MyStruct;
+        })
+        .is_err());
+    }
+}
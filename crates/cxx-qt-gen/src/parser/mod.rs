@@ -9,10 +9,12 @@ pub mod externcxxqt;
 pub mod externqobject;
 mod externrustqt;
 pub mod inherit;
+pub mod listproperty;
 pub mod method;
 pub mod parameter;
 pub mod property;
 pub mod qenum;
+pub mod qgadget;
 pub mod qnamespace;
 pub mod qobject;
 pub mod signals;
@@ -0,0 +1,265 @@
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Joshua Booth <joshua.n.booth@gmail.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::{
+    naming::Name,
+    parser::{
+        extract_cfgs,
+        property::{FlagState, ParsedQProperty},
+        require_attributes, CaseConversion,
+    },
+    syntax::{expr::expr_to_string, foreignmod::ForeignTypeIdentAlias, path::path_compare_str},
+};
+use proc_macro2::TokenTree;
+use syn::{Attribute, Error, Ident, Meta, Result};
+
+/// A representation of a Q_GADGET within a CXX-Qt [syn::ItemMod]
+///
+/// A Q_GADGET is a C++ value type which owns a copy of its Rust struct,
+/// so unlike a QObject it can be copied and stored in a QVariant.
+pub struct ParsedQGadget {
+    /// The name of the Q_GADGET
+    pub name: Name,
+    /// The ident of the inner type of the Q_GADGET
+    pub rust_type: Ident,
+    /// List of fields of the Rust struct that are exposed as Q_PROPERTY
+    pub properties: Vec<ParsedQProperty>,
+    /// The name to register the type with in QML as a value type
+    pub qml_value_type: Option<String>,
+    /// Whether a QList of the Q_GADGET is registered in QML as a sequence type
+    pub qml_sequential_container: bool,
+    /// The original declaration entered by the user, i.e. a type alias with a list of attributes
+    pub declaration: ForeignTypeIdentAlias,
+    /// Cfgs for the gadget
+    pub cfgs: Vec<Attribute>,
+}
+
+impl ParsedQGadget {
+    const ALLOWED_ATTRS: [&'static str; 9] = [
+        "cxx_name",
+        "rust_name",
+        "namespace",
+        "cfg",
+        "doc",
+        "qgadget",
+        "qml_value_type",
+        "qml_sequential_container",
+        "qproperty",
+    ];
+
+    /// Parse a ForeignTypeIdentAlias with a #[qgadget] attribute into a [ParsedQGadget]
+    pub fn parse(
+        declaration: ForeignTypeIdentAlias,
+        namespace: Option<&str>,
+        module: &Ident,
+        auto_case: CaseConversion,
+    ) -> Result<Self> {
+        let attributes = require_attributes(&declaration.attrs, &Self::ALLOWED_ATTRS)?;
+        let cfgs = extract_cfgs(&declaration.attrs);
+
+        let name = Name::from_ident_and_attrs(
+            &declaration.ident_left,
+            &declaration.attrs,
+            namespace,
+            Some(module),
+            CaseConversion::none(),
+        )?;
+
+        let qml_value_type = attributes
+            .get("qml_value_type")
+            .map(|attr| match &attr.meta {
+                Meta::NameValue(name_value) => expr_to_string(&name_value.value),
+                // QML requires value type names to start with a lowercase letter
+                _ => Ok(lowercase_first(&name.cxx_unqualified())),
+            })
+            .transpose()?;
+
+        let qml_sequential_container = match attributes.get("qml_sequential_container") {
+            Some(attr) if qml_value_type.is_none() => {
+                return Err(Error::new_spanned(
+                    attr,
+                    "#[qml_sequential_container] requires the type to also have a #[qml_value_type] attribute!",
+                ))
+            }
+            attr => attr.is_some(),
+        };
+
+        let properties = declaration
+            .attrs
+            .iter()
+            .filter(|attr| path_compare_str(attr.meta.path(), &["qproperty"]))
+            .map(|attr| Self::parse_property(attr, auto_case))
+            .collect::<Result<Vec<_>>>()?;
+
+        for property in &properties {
+            let flags = &property.flags;
+            let has_custom_function = matches!(flags.read, FlagState::Custom(_))
+                || matches!(flags.write, Some(FlagState::Custom(_)));
+            if has_custom_function || flags.reset.is_some() || flags.required {
+                return Err(Error::new_spanned(
+                    property.name.rust_unqualified(),
+                    "Q_GADGET properties only support the READ, WRITE, CONSTANT and FINAL flags without custom functions!",
+                ));
+            }
+        }
+
+        let rust_type = declaration.ident_right.clone();
+
+        Ok(Self {
+            name,
+            rust_type,
+            properties,
+            qml_value_type,
+            qml_sequential_container,
+            declaration,
+            cfgs,
+        })
+    }
+
+    /// Parse a #[qproperty] attribute of a Q_GADGET, which has no signals to notify changes with
+    fn parse_property(attr: &Attribute, auto_case: CaseConversion) -> Result<ParsedQProperty> {
+        let mut property = ParsedQProperty::parse(attr, auto_case)?;
+        if property.flags.notify.is_some() {
+            // The shorthand #[qproperty(T, ident)] desugars to READ, WRITE and NOTIFY,
+            // so only reject a NOTIFY flag which was passed explicitly
+            let notify = attr
+                .meta
+                .require_list()?
+                .tokens
+                .clone()
+                .into_iter()
+                .find(|token| matches!(token, TokenTree::Ident(ident) if ident == "NOTIFY"));
+            if let Some(notify) = notify {
+                return Err(Error::new_spanned(
+                    notify,
+                    "Q_GADGET properties cannot have a NOTIFY signal!",
+                ));
+            }
+            property.flags.notify = None;
+        }
+        Ok(property)
+    }
+}
+
+fn lowercase_first(name: &str) -> String {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .map(|first| first.to_lowercase().chain(chars).collect())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::parser::tests::f64_type;
+    use crate::tests::assert_parse_errors;
+    use quote::format_ident;
+    use syn::parse_quote;
+
+    fn parse_qgadget(input: ForeignTypeIdentAlias) -> Result<ParsedQGadget> {
+        ParsedQGadget::parse(
+            input,
+            Some("cxx_qt"),
+            &format_ident!("qobject"),
+            CaseConversion::none(),
+        )
+    }
+
+    #[test]
+    fn test_parse_qgadget() {
+        let qgadget = parse_qgadget(parse_quote! {
+            #[qgadget]
+            #[qproperty(f64, x)]
+            #[qproperty(f64, y, cxx_name = "yPosition")]
+            type Point = super::PointRust;
+        })
+        .unwrap();
+
+        assert_eq!(qgadget.name.rust_unqualified(), "Point");
+        assert_eq!(qgadget.name.namespace(), Some("cxx_qt"));
+        assert_eq!(qgadget.rust_type, "PointRust");
+        assert!(qgadget.qml_value_type.is_none());
+        assert!(!qgadget.qml_sequential_container);
+        assert_eq!(qgadget.properties.len(), 2);
+        assert_eq!(qgadget.properties[0].ty, f64_type());
+        assert_eq!(qgadget.properties[1].name.cxx_unqualified(), "yPosition");
+        assert!(qgadget
+            .properties
+            .iter()
+            .all(|property| property.flags.notify.is_none()));
+    }
+
+    #[test]
+    fn test_parse_qml_value_type() {
+        let qgadget = parse_qgadget(parse_quote! {
+            #[qgadget]
+            #[qml_value_type]
+            type Point = super::PointRust;
+        })
+        .unwrap();
+        assert_eq!(qgadget.qml_value_type.as_deref(), Some("point"));
+
+        let qgadget = parse_qgadget(parse_quote! {
+            #[qgadget]
+            #[qml_value_type = "vec2"]
+            #[qml_sequential_container]
+            type Point = super::PointRust;
+        })
+        .unwrap();
+        assert_eq!(qgadget.qml_value_type.as_deref(), Some("vec2"));
+        assert!(qgadget.qml_sequential_container);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_parse_errors! {
+            parse_qgadget =>
+
+            // Gadgets cannot have a base class
+            {
+                #[qgadget]
+                #[base = QObject]
+                type Point = super::PointRust;
+            }
+            // Gadgets cannot have notify signals
+            {
+                #[qgadget]
+                #[qproperty(f64, x, READ, NOTIFY = x_changed)]
+                type Point = super::PointRust;
+            }
+            {
+                #[qgadget]
+                #[qproperty(f64, x, READ, WRITE, NOTIFY)]
+                type Point = super::PointRust;
+            }
+            // Gadgets cannot have custom getters
+            {
+                #[qgadget]
+                #[qproperty(f64, x, READ = get_x)]
+                type Point = super::PointRust;
+            }
+            // Gadgets cannot have reset functions
+            {
+                #[qgadget]
+                #[qproperty(f64, x, READ, RESET = reset_x)]
+                type Point = super::PointRust;
+            }
+            // Sequence types require a value type
+            {
+                #[qgadget]
+                #[qml_sequential_container]
+                type Point = super::PointRust;
+            }
+            // Value type names must be strings
+            {
+                #[qgadget]
+                #[qml_value_type = point]
+                type Point = super::PointRust;
+            }
+        }
+    }
+}
@@ -5,14 +5,32 @@
 
 use crate::{
     naming::Name,
-    parser::{extract_cfgs, property::ParsedQProperty, require_attributes},
+    parser::{
+        extract_cfgs, listproperty::ParsedQmlListProperty, property::ParsedQProperty,
+        require_attributes,
+    },
     syntax::{expr::expr_to_string, foreignmod::ForeignTypeIdentAlias, path::path_compare_str},
 };
 #[cfg(test)]
 use quote::format_ident;
 
 use crate::parser::{parse_base_type, CaseConversion};
-use syn::{Attribute, Error, Ident, Meta, Result};
+use std::collections::BTreeMap;
+use syn::{parse::ParseStream, Attribute, Error, Expr, Ident, LitStr, Meta, Result, Token};
+
+/// A QML version, as given to the qml_added_in and qml_removed_in attributes
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct QmlVersion {
+    pub major: u8,
+    pub minor: u8,
+}
+
+impl QmlVersion {
+    /// The version encoded in the same way as QTypeRevision, as used by the QML class infos
+    pub fn encoded(self) -> u16 {
+        (u16::from(self.major) << 8) | u16::from(self.minor)
+    }
+}
 
 /// Metadata for registering QML element
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
@@ -20,7 +38,169 @@ pub struct QmlElementMetadata {
     pub name: String,
     pub uncreatable: bool,
     pub singleton: bool,
+    /// The type which provides the attached properties of this element
+    pub attached: Option<Ident>,
+    /// The type which extends this element with further properties and invokables
+    pub extended: Option<Ident>,
+    /// The version of the QML module in which this element was added
+    pub added_in: Option<QmlVersion>,
+    /// The version of the QML module in which this element was removed
+    pub removed_in: Option<QmlVersion>,
+}
+
+impl QmlElementMetadata {
+    /// Attributes which are only valid on a type which is registered as a QML element
+    ///
+    /// Note that #[qml_uncreatable] and #[qml_singleton] are ignored without a QML element
+    const REQUIRES_ELEMENT_ATTRS: [&'static str; 4] = [
+        "qml_attached",
+        "qml_extended",
+        "qml_added_in",
+        "qml_removed_in",
+    ];
+
+    /// Parse the name of the QML element from a #[qml_element] or #[qml_named_element] attribute
+    ///
+    /// If #[qml_element] has no value, the C++ name of the type is used.
+    pub fn parse_name(
+        attributes: &BTreeMap<&str, &Attribute>,
+        name: &Name,
+    ) -> Result<Option<String>> {
+        match (
+            attributes.get("qml_element"),
+            attributes.get("qml_named_element"),
+        ) {
+            (Some(_), Some(named)) => Err(Error::new_spanned(
+                named,
+                "#[qml_named_element] cannot be used together with #[qml_element]!",
+            )),
+            (Some(attr), None) => match &attr.meta {
+                // Extract the name of the qml_element from macro, else use the c++ name
+                // This will use the name provided by cxx_name if that attr was present
+                Meta::NameValue(name_value) => expr_to_string(&name_value.value).map(Some),
+                _ => Ok(Some(name.cxx_unqualified())),
+            },
+            (None, Some(attr)) => expr_to_string(&attr.meta.require_name_value()?.value).map(Some),
+            (None, None) => Ok(None),
+        }
+    }
+
+    /// Parse the metadata of a type registered as a QML element with the given name
+    pub fn parse(attributes: &BTreeMap<&str, &Attribute>, name: String) -> Result<Self> {
+        Ok(Self {
+            name,
+            uncreatable: attributes.contains_key("qml_uncreatable"),
+            singleton: attributes.contains_key("qml_singleton"),
+            attached: parse_type_attribute(attributes, "qml_attached")?,
+            extended: parse_type_attribute(attributes, "qml_extended")?,
+            added_in: parse_version_attribute(attributes, "qml_added_in")?,
+            removed_in: parse_version_attribute(attributes, "qml_removed_in")?,
+        })
+    }
+
+    /// Ensure that no attributes which require a QML element are present on an unregistered type
+    pub fn require_unregistered(attributes: &BTreeMap<&str, &Attribute>) -> Result<()> {
+        for key in &Self::REQUIRES_ELEMENT_ATTRS {
+            if let Some(attr) = attributes.get(key) {
+                return Err(Error::new_spanned(
+                    attr,
+                    format!("#[{key}] requires the type to also be registered as a QML element!"),
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Whether the generated code uses macros from the QtQml headers
+    pub fn requires_qml_include(&self) -> bool {
+        self.singleton
+            || self.attached.is_some()
+            || self.extended.is_some()
+            || self.added_in.is_some()
+            || self.removed_in.is_some()
+    }
 }
+
+fn parse_type_attribute(
+    attributes: &BTreeMap<&str, &Attribute>,
+    key: &str,
+) -> Result<Option<Ident>> {
+    attributes
+        .get(key)
+        .map(|attr| -> Result<Ident> {
+            let expr = &attr.meta.require_name_value()?.value;
+            if let Expr::Path(path_expr) = expr {
+                Ok(path_expr.path.require_ident()?.clone())
+            } else {
+                Err(Error::new_spanned(
+                    expr,
+                    format!("#[{key}] must be an identifier and cannot be empty!"),
+                ))
+            }
+        })
+        .transpose()
+}
+
+fn parse_version_attribute(
+    attributes: &BTreeMap<&str, &Attribute>,
+    key: &str,
+) -> Result<Option<QmlVersion>> {
+    attributes
+        .get(key)
+        .map(|attr| -> Result<QmlVersion> {
+            let expr = &attr.meta.require_name_value()?.value;
+            let version = expr_to_string(expr)?;
+            version
+                .split_once('.')
+                .and_then(|(major, minor)| {
+                    Some(QmlVersion {
+                        major: major.parse().ok()?,
+                        minor: minor.parse().ok()?,
+                    })
+                })
+                .ok_or_else(|| {
+                    Error::new_spanned(
+                        expr,
+                        format!("#[{key}] must be a version in the form \"MAJOR.MINOR\"!"),
+                    )
+                })
+        })
+        .transpose()
+}
+
+/// A key-value pair of Q_CLASSINFO on a QObject
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct QClassInfo {
+    pub key: String,
+    pub value: String,
+}
+
+impl QClassInfo {
+    /// Parse the key-value pairs of a #[qclassinfo(key = "value", "Other.Key" = "value")] attribute
+    ///
+    /// Keys may be given as identifiers or as string literals, as some keys contain dots.
+    fn parse(attr: &Attribute) -> Result<Vec<Self>> {
+        attr.parse_args_with(|input: ParseStream| -> Result<Vec<Self>> {
+            let mut class_infos = vec![];
+            while !input.is_empty() {
+                let key = if input.peek(LitStr) {
+                    input.parse::<LitStr>()?.value()
+                } else {
+                    input.parse::<Ident>()?.to_string()
+                };
+                input.parse::<Token![=]>()?;
+                let value = input.parse::<LitStr>()?.value();
+                class_infos.push(Self { key, value });
+
+                if !input.is_empty() {
+                    input.parse::<Token![,]>()?;
+                }
+            }
+            Ok(class_infos)
+        })
+    }
+}
+
 /// A representation of a QObject within a CXX-Qt [syn::ItemMod]
 ///
 /// This has initial splitting of [syn::Item]'s into relevant blocks, other phases will
@@ -36,8 +216,12 @@ pub struct ParsedQObject {
     ///
     /// These will be exposed as Q_PROPERTY on the C++ object
     pub properties: Vec<ParsedQProperty>,
+    /// List of QQmlListProperty properties that need to be implemented on the C++ object
+    pub list_properties: Vec<ParsedQmlListProperty>,
     /// List of specifiers to register with in QML
     pub qml_metadata: Option<QmlElementMetadata>,
+    /// List of Q_CLASSINFO key-value pairs, including the default property
+    pub class_infos: Vec<QClassInfo>,
     /// Whether this type has a #[qobject] / Q_OBJECT macro
     pub has_qobject_macro: bool,
     /// The original declaration entered by the user, i.e. a type alias with a list of attributes
@@ -47,7 +231,7 @@ pub struct ParsedQObject {
 }
 
 impl ParsedQObject {
-    const ALLOWED_ATTRS: [&'static str; 11] = [
+    const ALLOWED_ATTRS: [&'static str; 19] = [
         "cxx_name",
         "rust_name",
         "namespace",
@@ -56,9 +240,17 @@ impl ParsedQObject {
         "qobject",
         "base",
         "qml_element",
+        "qml_named_element",
         "qml_uncreatable",
         "qml_singleton",
+        "qml_attached",
+        "qml_extended",
+        "qml_added_in",
+        "qml_removed_in",
+        "qml_default_property",
+        "qclassinfo",
         "qproperty",
+        "qml_list_property",
     ];
     #[cfg(test)]
     pub fn mock() -> Self {
@@ -67,7 +259,9 @@ impl ParsedQObject {
             name: Name::new(format_ident!("MyObject")),
             rust_type: format_ident!("MyObjectRust"),
             properties: vec![],
+            list_properties: vec![],
             qml_metadata: None,
+            class_infos: vec![],
             has_qobject_macro: false,
             declaration: ForeignTypeIdentAlias {
                 attrs: vec![],
@@ -115,6 +309,14 @@ impl ParsedQObject {
         // Parse any properties in the type
         // and remove the #[qproperty] attribute
         let properties = Self::parse_property_attributes(&declaration.attrs, auto_case)?;
+        let list_properties = declaration
+            .attrs
+            .iter()
+            .filter(|attr| path_compare_str(attr.meta.path(), &["qml_list_property"]))
+            .map(|attr| ParsedQmlListProperty::parse(attr, auto_case))
+            .collect::<Result<Vec<_>>>()?;
+        let class_infos =
+            Self::parse_class_infos(&declaration.attrs, &properties, &list_properties)?;
         let inner = declaration.ident_right.clone();
 
         Ok(Self {
@@ -123,7 +325,9 @@ impl ParsedQObject {
             name,
             rust_type: inner,
             properties,
+            list_properties,
             qml_metadata,
+            class_infos,
             has_qobject_macro,
             cfgs,
         })
@@ -131,24 +335,70 @@ impl ParsedQObject {
 
     fn parse_qml_metadata(name: &Name, attrs: &[Attribute]) -> Result<Option<QmlElementMetadata>> {
         let attributes = require_attributes(attrs, &Self::ALLOWED_ATTRS)?;
-        if let Some(attr) = attributes.get("qml_element") {
-            // Extract the name of the qml_element from macro, else use the c++ name
-            // This will use the name provided by cxx_name if that attr was present
-            let name = match &attr.meta {
-                Meta::NameValue(name_value) => expr_to_string(&name_value.value)?,
-                _ => name.cxx_unqualified(),
-            };
-            let uncreatable = attributes.contains_key("qml_uncreatable");
-            let singleton = attributes.contains_key("qml_singleton");
-            return Ok(Some(QmlElementMetadata {
-                name,
-                uncreatable,
-                singleton,
-            }));
+        if let Some(qml_name) = QmlElementMetadata::parse_name(&attributes, name)? {
+            return QmlElementMetadata::parse(&attributes, qml_name).map(Some);
         }
+
+        QmlElementMetadata::require_unregistered(&attributes)?;
         Ok(None)
     }
 
+    /// Parse the #[qclassinfo] attributes and the #[qml_default_property] attribute
+    ///
+    /// The default property must be one of the properties of the QObject.
+    fn parse_class_infos(
+        attrs: &[Attribute],
+        properties: &[ParsedQProperty],
+        list_properties: &[ParsedQmlListProperty],
+    ) -> Result<Vec<QClassInfo>> {
+        let mut class_infos: Vec<QClassInfo> = vec![];
+        let mut push_class_info = |attr: &Attribute, class_info: QClassInfo| -> Result<()> {
+            if class_infos.iter().any(|other| other.key == class_info.key) {
+                return Err(Error::new_spanned(
+                    attr,
+                    format!("Duplicate Q_CLASSINFO key \"{}\"!", class_info.key),
+                ));
+            }
+            class_infos.push(class_info);
+            Ok(())
+        };
+
+        for attr in attrs {
+            if path_compare_str(attr.meta.path(), &["qclassinfo"]) {
+                for class_info in QClassInfo::parse(attr)? {
+                    push_class_info(attr, class_info)?;
+                }
+            }
+        }
+
+        let attributes = require_attributes(attrs, &Self::ALLOWED_ATTRS)?;
+        if let (Some(attr), Some(ident)) = (
+            attributes.get("qml_default_property"),
+            parse_type_attribute(&attributes, "qml_default_property")?,
+        ) {
+            let name = properties
+                .iter()
+                .map(|property| &property.name)
+                .chain(list_properties.iter().map(|property| &property.name))
+                .find(|name| *name.rust_unqualified() == ident)
+                .ok_or_else(|| {
+                    Error::new_spanned(
+                        &ident,
+                        "#[qml_default_property] must be a #[qproperty] or #[qml_list_property] of this type!",
+                    )
+                })?;
+            push_class_info(
+                attr,
+                QClassInfo {
+                    key: "DefaultProperty".to_owned(),
+                    value: name.cxx_unqualified(),
+                },
+            )?;
+        }
+
+        Ok(class_infos)
+    }
+
     fn parse_property_attributes(
         attrs: &[Attribute],
         auto_case: CaseConversion,
@@ -233,6 +483,20 @@ pub mod tests {
         assert_eq!(qobject.properties.len(), 2);
     }
 
+    #[test]
+    fn test_from_struct_list_properties() {
+        let qobject = parse_qobject! {
+            #[qobject]
+            #[qproperty(i32, int_property)]
+            #[qml_list_property(Child, children)]
+            type MyObject = super::MyObjectRust;
+        };
+
+        assert_eq!(qobject.properties.len(), 1);
+        assert_eq!(qobject.list_properties.len(), 1);
+        assert_eq!(qobject.list_properties[0].ty, "Child");
+    }
+
     #[test]
     fn test_from_struct_fields() {
         let qobject = create_parsed_qobject();
@@ -258,6 +522,33 @@ pub mod tests {
         assert_eq!(properties[1].ty, f64_type());
     }
 
+    #[test]
+    fn test_class_infos() {
+        let qobject = parse_qobject! {
+            #[qobject]
+            #[qclassinfo(RegisterEnumClassesUnscoped = "false")]
+            #[qclassinfo("QML.Custom" = "value", ParentProperty = "parent_item")]
+            #[qml_default_property = items]
+            #[qproperty(i32, number)]
+            #[qml_list_property(Item, items, cxx_name = "data")]
+            type MyObject = super::MyObjectRust;
+        };
+
+        let class_info = |key: &str, value: &str| QClassInfo {
+            key: key.to_owned(),
+            value: value.to_owned(),
+        };
+        assert_eq!(
+            qobject.class_infos,
+            vec![
+                class_info("RegisterEnumClassesUnscoped", "false"),
+                class_info("QML.Custom", "value"),
+                class_info("ParentProperty", "parent_item"),
+                class_info("DefaultProperty", "data"),
+            ]
+        );
+    }
+
     fn assert_qml_name(obj: ParsedQObject, str_name: &str) {
         assert_eq!(
             obj.qml_metadata,
@@ -265,6 +556,7 @@ pub mod tests {
                 name: str_name.to_owned(),
                 uncreatable: false,
                 singleton: false,
+                ..Default::default()
             })
         );
     }
@@ -321,6 +613,7 @@ pub mod tests {
                 name: "MyObject".to_owned(),
                 uncreatable: false,
                 singleton: true,
+                ..Default::default()
             })
         );
     }
@@ -339,6 +632,49 @@ pub mod tests {
                 name: "MyObject".to_owned(),
                 uncreatable: true,
                 singleton: false,
+                ..Default::default()
+            })
+        );
+    }
+
+    #[test]
+    fn test_qml_metadata_attached() {
+        let qobject = parse_qobject! {
+            #[qobject]
+            #[qml_element]
+            #[qml_attached = MyObjectAttached]
+            type MyObject = super::MyObjectRust;
+        };
+        assert_eq!(
+            qobject.qml_metadata,
+            Some(QmlElementMetadata {
+                name: "MyObject".to_owned(),
+                uncreatable: false,
+                singleton: false,
+                attached: Some(format_ident!("MyObjectAttached")),
+                ..Default::default()
+            })
+        );
+    }
+
+    #[test]
+    fn test_qml_metadata_extended_versioned() {
+        let qobject = parse_qobject! {
+            #[qobject]
+            #[qml_named_element = "OtherName"]
+            #[qml_extended = MyObjectExtension]
+            #[qml_added_in = "1.2"]
+            #[qml_removed_in = "2.0"]
+            type MyObject = super::MyObjectRust;
+        };
+        assert_eq!(
+            qobject.qml_metadata,
+            Some(QmlElementMetadata {
+                name: "OtherName".to_owned(),
+                extended: Some(format_ident!("MyObjectExtension")),
+                added_in: Some(QmlVersion { major: 1, minor: 2 }),
+                removed_in: Some(QmlVersion { major: 2, minor: 0 }),
+                ..Default::default()
             })
         );
     }
@@ -354,6 +690,69 @@ pub mod tests {
                 type MyObject = super::T;
             }
             { type MyObject = super::T; }
+            {
+                #[qobject]
+                #[qml_attached = MyObjectAttached]
+                type MyObject = super::T;
+            }
+            {
+                #[qobject]
+                #[qml_element]
+                #[qml_attached = "MyObjectAttached"]
+                type MyObject = super::T;
+            }
+            {
+                #[qobject]
+                #[qml_element]
+                #[qml_named_element = "OtherName"]
+                type MyObject = super::T;
+            }
+            {
+                #[qobject]
+                #[qml_extended = MyObjectExtension]
+                type MyObject = super::T;
+            }
+            {
+                #[qobject]
+                #[qml_element]
+                #[qml_added_in = "1"]
+                type MyObject = super::T;
+            }
+            {
+                #[qobject]
+                #[qml_element]
+                #[qml_removed_in = 2.0]
+                type MyObject = super::T;
+            }
+            {
+                #[qobject]
+                #[qml_default_property = items]
+                #[qproperty(i32, number)]
+                type MyObject = super::T;
+            }
+            {
+                #[qobject]
+                #[qml_default_property = "number"]
+                #[qproperty(i32, number)]
+                type MyObject = super::T;
+            }
+            {
+                #[qobject]
+                #[qclassinfo(DefaultProperty = "number")]
+                #[qml_default_property = number]
+                #[qproperty(i32, number)]
+                type MyObject = super::T;
+            }
+            {
+                #[qobject]
+                #[qclassinfo(author)]
+                type MyObject = super::T;
+            }
+            {
+                #[qobject]
+                #[qclassinfo(author = KDAB)]
+                type MyObject = super::T;
+            }
         }
     }
 }
@@ -45,10 +45,14 @@ pub fn try_inline_self_invokables(
 pub fn qualify_self_types(parser: &mut Parser) -> Result<()> {
     // Inlining `extern "RustQt"` blocks
     for rust_block in &mut parser.cxx_qt_data.extern_rustqt_blocks {
-        let mut iter = rust_block.qobjects.iter();
+        let mut iter = rust_block
+            .qobjects
+            .iter()
+            .map(|obj| &obj.declaration)
+            .chain(rust_block.qgadgets.iter().map(|gadget| &gadget.declaration));
         let mut inline_ident = iter
             .next()
-            .map(|obj| format_ident!("{}", obj.declaration.ident_left));
+            .map(|declaration| format_ident!("{}", declaration.ident_left));
 
         if iter.next().is_some() {
             inline_ident = None;
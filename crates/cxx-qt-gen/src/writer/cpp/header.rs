@@ -35,9 +35,15 @@ fn create_block(block: &str, items: &[String]) -> String {
 // Note that this is needed incase ObjectA refers to ObjectB in it's class
 fn forward_declare(generated: &GeneratedCppBlocks) -> Vec<String> {
     generated
-        .qobjects
+        .qgadgets
         .iter()
-        .map(|qobject| {
+        .map(|qgadget| {
+            namespaced(
+                qgadget.name.namespace().unwrap_or_default(),
+                &format!("class {ident};", ident = qgadget.name.cxx_unqualified()),
+            )
+        })
+        .chain(generated.qobjects.iter().map(|qobject| {
             let forward_declares = namespaced(
                 qobject.name.namespace().unwrap_or_default(),
                 &formatdoc! {r#"
@@ -53,7 +59,7 @@ fn forward_declare(generated: &GeneratedCppBlocks) -> Vec<String> {
                 {forward_declares}
                 {forward_declares_namespaced}
             "#}
-        })
+        }))
         .chain(generated.forward_declares.iter().cloned())
         .chain(
             generated
@@ -64,6 +70,51 @@ fn forward_declare(generated: &GeneratedCppBlocks) -> Vec<String> {
         .collect::<Vec<String>>()
 }
 
+/// For a given GeneratedCppBlocks write the Q_GADGET classes
+fn qgadgets_header(generated: &GeneratedCppBlocks) -> Vec<String> {
+    generated.qgadgets.iter().map(|qgadget| {
+        let ident = &qgadget.name.cxx_unqualified();
+        let class_definition = namespaced(
+            qgadget.name.namespace().unwrap_or_default(),
+            &formatdoc! { r#"
+                class {ident}
+                {{
+                  Q_GADGET
+                public:
+                  {metaobjects}
+
+                {public_methods}
+                {private_methods}
+                }};"#,
+            metaobjects = qgadget.blocks.metaobjects.join("\n  "),
+            public_methods = create_block("public", &qgadget.blocks.methods.iter().filter_map(pair_as_header).collect::<Vec<String>>()),
+            private_methods = create_block("private", &qgadget.blocks.private_methods.iter().filter_map(pair_as_header).collect::<Vec<String>>()),
+        });
+        let ty = qgadget.name.cxx_qualified();
+        let post_fragments = qgadget
+            .blocks
+            .post_fragments
+            .iter()
+            .filter_map(pair_as_header)
+            .collect::<Vec<String>>()
+            .join("\n");
+
+        formatdoc! {r#"
+            {class_definition}
+
+            Q_DECLARE_METATYPE({ty})
+
+            namespace rust {{
+            template<>
+            struct IsRelocatable<::{ty}> : ::std::true_type
+            {{}};
+            }} // namespace rust
+            {post_fragments}
+            "#
+        }
+    }).collect::<Vec<String>>()
+}
+
 /// For a given GeneratedCppBlocks write the classes
 fn qobjects_header(generated: &GeneratedCppBlocks) -> Vec<String> {
     generated.qobjects.iter().map(|qobject| {
@@ -142,6 +193,12 @@ pub fn write_cpp_header(generated: &GeneratedCppBlocks, include_path: &str) -> S
             generated
                 .includes
                 .iter()
+                .chain(
+                    generated
+                        .qgadgets
+                        .iter()
+                        .flat_map(|qgadget| &qgadget.blocks.includes),
+                )
                 .chain(
                     generated
                         .qobjects
@@ -175,7 +232,12 @@ pub fn write_cpp_header(generated: &GeneratedCppBlocks, include_path: &str) -> S
         {qobjects}
     "#,
     forward_declare = forward_declare(generated).join("\n"),
-    qobjects = qobjects_header(generated).join("\n"),
+    // Q_GADGETs are written first, as QObjects may use them as values
+    qobjects = qgadgets_header(generated)
+        .into_iter()
+        .chain(qobjects_header(generated))
+        .collect::<Vec<String>>()
+        .join("\n"),
     }
 }
 
@@ -96,6 +96,7 @@ mod tests {
             forward_declares: vec![],
             includes: BTreeSet::default(),
             extern_cxx_qt: vec![],
+            qgadgets: vec![],
             qobjects: vec![
                 GeneratedCppQObject {
                     name: if let Some(namespace) = namespace {
@@ -226,6 +227,7 @@ mod tests {
             forward_declares: vec![],
             includes: BTreeSet::default(),
             extern_cxx_qt: vec![],
+            qgadgets: vec![],
             qobjects: vec![
                 GeneratedCppQObject {
                     name: Name::mock_namespaced("FirstObject", "cxx_qt"),
@@ -7,6 +7,24 @@ use crate::generator::cpp::GeneratedCppBlocks;
 use crate::writer::cpp::{extract_extern_qt, namespaced, pair_as_source};
 use indoc::formatdoc;
 
+/// For a given GeneratedCppBlocks write the Q_GADGET implementations
+fn qgadgets_source(generated: &GeneratedCppBlocks) -> Vec<String> {
+    generated
+        .qgadgets
+        .iter()
+        .map(|qgadget| {
+            let methods = qgadget
+                .blocks
+                .methods
+                .iter()
+                .filter_map(pair_as_source)
+                .collect::<Vec<String>>()
+                .join("\n");
+            namespaced(qgadget.name.namespace().unwrap_or_default(), &methods)
+        })
+        .collect::<Vec<String>>()
+}
+
 /// For a given GeneratedCppBlocks write the implementations
 fn qobjects_source(generated: &GeneratedCppBlocks) -> Vec<String> {
     generated
@@ -45,7 +63,12 @@ pub fn write_cpp_source(generated: &GeneratedCppBlocks, include_path: &str) -> S
         {extern_cxx_qt}
         {qobjects}
     "#,
-    qobjects = qobjects_source(generated).join("\n"),
+    // Q_GADGETs are written first, as QObjects may use them as values
+    qobjects = qgadgets_source(generated)
+        .into_iter()
+        .chain(qobjects_source(generated))
+        .collect::<Vec<String>>()
+        .join("\n"),
     }
 }
 
@@ -0,0 +1,44 @@
+#[cxx_qt::bridge(namespace = "cxx_qt::my_object")]
+mod ffi {
+    #[namespace = ""]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+    }
+
+    extern "RustQt" {
+        #[qgadget]
+        #[qml_value_type]
+        #[qml_sequential_container]
+        #[qproperty(f64, x)]
+        #[qproperty(f64, y, cxx_name = "yPosition")]
+        #[qproperty(QString, label, READ, CONSTANT)]
+        type Point = super::PointRust;
+
+        #[qinvokable]
+        fn length(self: &Point) -> f64;
+
+        #[qinvokable]
+        #[cxx_name = "toString"]
+        fn to_string(self: &Point) -> QString;
+    }
+
+    extern "RustQt" {
+        #[qgadget]
+        #[qml_value_type = "rgbColor"]
+        #[namespace = "other_namespace"]
+        #[cxx_name = "RgbColor"]
+        #[qproperty(u8, red)]
+        #[qproperty(u8, green)]
+        #[qproperty(u8, blue)]
+        type Color = super::ColorRust;
+    }
+
+    extern "RustQt" {
+        #[qobject]
+        type MyObject = super::MyObjectRust;
+
+        #[qinvokable]
+        fn color_at(self: &MyObject, point: &Point) -> Color;
+    }
+}
@@ -0,0 +1,36 @@
+#[cxx_qt::bridge(namespace = "cxx_qt::my_object")]
+mod ffi {
+    extern "RustQt" {
+        #[qobject]
+        #[qml_named_element = "MyQmlObject"]
+        #[qml_extended = MyObjectExtension]
+        #[qml_added_in = "1.2"]
+        #[qml_removed_in = "2.0"]
+        #[qml_list_property(QTimer, timers)]
+        #[qml_attached = MyObjectAttached]
+        type MyObject = super::MyObjectRust;
+
+        #[qobject]
+        type MyObjectExtension = super::MyObjectExtensionRust;
+
+        #[qobject]
+        type MyObjectAttached = super::MyObjectAttachedRust;
+
+        #[qobject]
+        type TimerExtension = super::TimerExtensionRust;
+    }
+
+    unsafe extern "C++Qt" {
+        include!(<QtCore/QTimer>);
+
+        #[qobject]
+        #[namespace = ""]
+        #[qml_foreign]
+        #[qml_named_element = "Timer"]
+        #[qml_extended = TimerExtension]
+        #[qml_added_in = "1.1"]
+        type QTimer;
+    }
+
+    impl cxx_qt::Constructor<(*mut QObject,), BaseArguments = (*mut QObject,)> for MyObjectAttached {}
+}
@@ -0,0 +1,59 @@
+#include "directory/file_ident.cxxqt.h"
+
+namespace cxx_qt::my_object {
+Point::Point()
+  : m_rustObj(::cxx_qt::my_object::cxx_qt_Point::createRs())
+{
+}
+
+Point::Point(Point const& other)
+  : m_rustObj(::cxx_qt::my_object::cxx_qt_Point::cloneRs(*other.m_rustObj))
+{
+}
+
+Point::~Point() = default;
+
+Point&
+Point::operator=(Point const& other)
+{
+  if (this != &other) {
+    m_rustObj = ::cxx_qt::my_object::cxx_qt_Point::cloneRs(*other.m_rustObj);
+  }
+  return *this;
+}
+
+} // namespace cxx_qt::my_object
+
+namespace other_namespace {
+RgbColor::RgbColor()
+  : m_rustObj(::other_namespace::cxx_qt_Color::createRs())
+{
+}
+
+RgbColor::RgbColor(RgbColor const& other)
+  : m_rustObj(::other_namespace::cxx_qt_Color::cloneRs(*other.m_rustObj))
+{
+}
+
+RgbColor::~RgbColor() = default;
+
+RgbColor&
+RgbColor::operator=(RgbColor const& other)
+{
+  if (this != &other) {
+    m_rustObj = ::other_namespace::cxx_qt_Color::cloneRs(*other.m_rustObj);
+  }
+  return *this;
+}
+
+} // namespace other_namespace
+
+namespace cxx_qt::my_object {
+MyObject::MyObject(QObject* parent)
+  : QObject(parent)
+  , ::rust::cxxqt1::CxxQtType<MyObjectRust>(
+      ::cxx_qt::my_object::cxx_qt_MyObject::createRs())
+{
+}
+
+} // namespace cxx_qt::my_object
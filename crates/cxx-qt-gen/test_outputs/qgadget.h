@@ -0,0 +1,124 @@
+#pragma once
+
+#include <QtCore/QList>
+#include <QtCore/QMetaType>
+#include <QtCore/QObject>
+#include <QtQml/QQmlEngine>
+#include <cxx-qt/casting.h>
+#include <cxx-qt/type.h>
+
+namespace cxx_qt::my_object {
+class Point;
+} // namespace cxx_qt::my_object
+
+namespace other_namespace {
+class RgbColor;
+} // namespace other_namespace
+
+namespace cxx_qt::my_object {
+class MyObject;
+
+} // namespace cxx_qt::my_object
+
+#include "directory/file_ident.cxx.h"
+
+namespace cxx_qt::my_object {
+class Point
+{
+  Q_GADGET
+public:
+  Q_CLASSINFO("QML.Element", "point")
+  Q_PROPERTY(double x READ getX WRITE setX)
+  Q_PROPERTY(double yPosition READ getYPosition WRITE setYPosition)
+  Q_PROPERTY(QString label READ getLabel CONSTANT)
+
+public:
+  Point();
+  Point(Point const& other);
+  ~Point();
+  Point& operator=(Point const& other);
+  double const& getX() const noexcept;
+  void setX(double value) noexcept;
+  double const& getYPosition() const noexcept;
+  void setYPosition(double value) noexcept;
+  QString const& getLabel() const noexcept;
+  Q_INVOKABLE double length() const noexcept;
+  Q_INVOKABLE QString toString() const noexcept;
+
+private:
+  ::rust::Box<PointRust> m_rustObj;
+};
+} // namespace cxx_qt::my_object
+
+Q_DECLARE_METATYPE(cxx_qt::my_object::Point)
+
+namespace rust {
+template<>
+struct IsRelocatable<::cxx_qt::my_object::Point> : ::std::true_type
+{};
+} // namespace rust
+namespace cxx_qt::my_object {
+struct PointQmlSequence
+{
+  Q_GADGET
+  QML_FOREIGN(QList<cxx_qt::my_object::Point>)
+  QML_ANONYMOUS
+  QML_SEQUENTIAL_CONTAINER(cxx_qt::my_object::Point)
+};
+} // namespace cxx_qt::my_object
+
+namespace other_namespace {
+class RgbColor
+{
+  Q_GADGET
+public:
+  Q_CLASSINFO("QML.Element", "rgbColor")
+  Q_PROPERTY(::std::uint8_t red READ getRed WRITE setRed)
+  Q_PROPERTY(::std::uint8_t green READ getGreen WRITE setGreen)
+  Q_PROPERTY(::std::uint8_t blue READ getBlue WRITE setBlue)
+
+public:
+  RgbColor();
+  RgbColor(RgbColor const& other);
+  ~RgbColor();
+  RgbColor& operator=(RgbColor const& other);
+  ::std::uint8_t const& getRed() const noexcept;
+  void setRed(::std::uint8_t value) noexcept;
+  ::std::uint8_t const& getGreen() const noexcept;
+  void setGreen(::std::uint8_t value) noexcept;
+  ::std::uint8_t const& getBlue() const noexcept;
+  void setBlue(::std::uint8_t value) noexcept;
+
+private:
+  ::rust::Box<ColorRust> m_rustObj;
+};
+} // namespace other_namespace
+
+Q_DECLARE_METATYPE(other_namespace::RgbColor)
+
+namespace rust {
+template<>
+struct IsRelocatable<::other_namespace::RgbColor> : ::std::true_type
+{};
+} // namespace rust
+
+namespace cxx_qt::my_object {
+class MyObject
+  : public QObject
+  , public ::rust::cxxqt1::CxxQtType<MyObjectRust>
+{
+  Q_OBJECT
+public:
+  virtual ~MyObject() = default;
+
+public:
+  Q_INVOKABLE other_namespace::RgbColor color_at(
+    cxx_qt::my_object::Point const& point) const noexcept;
+  explicit MyObject(QObject* parent = nullptr);
+};
+
+static_assert(::std::is_base_of<QObject, MyObject>::value,
+              "MyObject must inherit from QObject");
+} // namespace cxx_qt::my_object
+
+Q_DECLARE_METATYPE(cxx_qt::my_object::MyObject*)
@@ -0,0 +1,403 @@
+#[cxx::bridge(namespace = "cxx_qt::my_object")]
+#[allow(unused_unsafe)]
+mod ffi {
+    #[namespace = ""]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+    }
+    unsafe extern "C++" {
+        include ! (< QtCore / QObject >);
+        include!("cxx-qt/connection.h");
+        #[doc(hidden)]
+        #[namespace = "Qt"]
+        #[rust_name = "CxxQtConnectionType"]
+        #[allow(dead_code)]
+        type ConnectionType = cxx_qt::ConnectionType;
+        #[doc(hidden)]
+        #[namespace = "rust::cxxqt1"]
+        #[rust_name = "CxxQtQMetaObjectConnection"]
+        #[allow(dead_code)]
+        type QMetaObjectConnection = cxx_qt::QMetaObjectConnection;
+    }
+    unsafe extern "C++" {
+        include!("directory/file_ident.cxxqt.h");
+    }
+    unsafe extern "C++" {
+        #[doc = "The C++ type for the QObject "]
+        #[doc = "MyObjectRust"]
+        #[doc = "\n"]
+        #[doc = "Use this type when referring to the QObject as a pointer"]
+        #[doc = "\n"]
+        #[doc = "See the book for more information: <https://kdab.github.io/cxx-qt/book/concepts/generated_qobject.html>"]
+        #[namespace = "cxx_qt::my_object"]
+        type MyObject;
+    }
+    extern "Rust" {
+        #[namespace = "cxx_qt::my_object"]
+        type MyObjectRust;
+    }
+    extern "Rust" {
+        #[cxx_name = "color_at"]
+        #[namespace = "cxx_qt::my_object"]
+        #[doc(hidden)]
+        fn color_at(self: &MyObject, point: &Point) -> Color;
+    }
+    extern "C++" {
+        #[doc(hidden)]
+        #[cxx_name = "upcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn cxx_qt_ffi_MyObject_upcastPtr(thiz: *const MyObject) -> *const QObject;
+        #[doc(hidden)]
+        #[cxx_name = "downcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn cxx_qt_ffi_MyObject_downcastPtr(base: *const QObject) -> *const MyObject;
+    }
+    extern "Rust" {
+        #[cxx_name = "createRs"]
+        #[namespace = "cxx_qt::my_object::cxx_qt_MyObject"]
+        fn create_rs_MyObjectRust() -> Box<MyObjectRust>;
+    }
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[cxx_name = "unsafeRust"]
+        #[namespace = "rust::cxxqt1"]
+        fn cxx_qt_ffi_MyObject_unsafeRust(outer: &MyObject) -> &MyObjectRust;
+    }
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[cxx_name = "unsafeRustMut"]
+        #[namespace = "rust::cxxqt1"]
+        fn cxx_qt_ffi_MyObject_unsafeRustMut(outer: Pin<&mut MyObject>) -> Pin<&mut MyObjectRust>;
+    }
+    extern "C++" {
+        #[doc = "The C++ type for the Q_GADGET "]
+        #[doc = "PointRust"]
+        #[doc = "\n"]
+        #[doc = "This is a value type which owns a copy of the Rust struct"]
+        #[namespace = "cxx_qt::my_object"]
+        type Point = super::cxx_qt_private_qgadget_Point::Point;
+    }
+    extern "Rust" {
+        #[namespace = "cxx_qt::my_object"]
+        type PointRust;
+    }
+    extern "Rust" {
+        #[cxx_name = "createRs"]
+        #[namespace = "cxx_qt::my_object::cxx_qt_Point"]
+        fn create_rs_PointRust() -> Box<PointRust>;
+        #[cxx_name = "cloneRs"]
+        #[namespace = "cxx_qt::my_object::cxx_qt_Point"]
+        fn clone_rs_PointRust(rust: &PointRust) -> Box<PointRust>;
+    }
+    extern "Rust" {
+        #[cxx_name = "getX"]
+        #[namespace = "cxx_qt::my_object"]
+        unsafe fn x<'a>(self: &'a Point) -> &'a f64;
+    }
+    extern "Rust" {
+        #[cxx_name = "setX"]
+        #[namespace = "cxx_qt::my_object"]
+        fn set_x(self: &mut Point, value: f64);
+    }
+    extern "Rust" {
+        #[cxx_name = "getYPosition"]
+        #[namespace = "cxx_qt::my_object"]
+        unsafe fn y<'a>(self: &'a Point) -> &'a f64;
+    }
+    extern "Rust" {
+        #[cxx_name = "setYPosition"]
+        #[namespace = "cxx_qt::my_object"]
+        fn set_y(self: &mut Point, value: f64);
+    }
+    extern "Rust" {
+        #[cxx_name = "getLabel"]
+        #[namespace = "cxx_qt::my_object"]
+        unsafe fn label<'a>(self: &'a Point) -> &'a QString;
+    }
+    extern "Rust" {
+        #[cxx_name = "length"]
+        #[namespace = "cxx_qt::my_object"]
+        #[doc(hidden)]
+        fn length(self: &Point) -> f64;
+    }
+    extern "Rust" {
+        #[cxx_name = "toString"]
+        #[namespace = "cxx_qt::my_object"]
+        #[doc(hidden)]
+        fn to_string(self: &Point) -> QString;
+    }
+    extern "C++" {
+        #[doc = "The C++ type for the Q_GADGET "]
+        #[doc = "ColorRust"]
+        #[doc = "\n"]
+        #[doc = "This is a value type which owns a copy of the Rust struct"]
+        #[namespace = "other_namespace"]
+        #[doc = "\n\nNote: The C++ name of this Q_GADGET is: "]
+        #[doc = "RgbColor"]
+        #[cxx_name = "RgbColor"]
+        type Color = super::cxx_qt_private_qgadget_Color::Color;
+    }
+    extern "Rust" {
+        #[namespace = "other_namespace"]
+        type ColorRust;
+    }
+    extern "Rust" {
+        #[cxx_name = "createRs"]
+        #[namespace = "other_namespace::cxx_qt_Color"]
+        fn create_rs_ColorRust() -> Box<ColorRust>;
+        #[cxx_name = "cloneRs"]
+        #[namespace = "other_namespace::cxx_qt_Color"]
+        fn clone_rs_ColorRust(rust: &ColorRust) -> Box<ColorRust>;
+    }
+    extern "Rust" {
+        #[cxx_name = "getRed"]
+        #[namespace = "other_namespace"]
+        unsafe fn red<'a>(self: &'a Color) -> &'a u8;
+    }
+    extern "Rust" {
+        #[cxx_name = "setRed"]
+        #[namespace = "other_namespace"]
+        fn set_red(self: &mut Color, value: u8);
+    }
+    extern "Rust" {
+        #[cxx_name = "getGreen"]
+        #[namespace = "other_namespace"]
+        unsafe fn green<'a>(self: &'a Color) -> &'a u8;
+    }
+    extern "Rust" {
+        #[cxx_name = "setGreen"]
+        #[namespace = "other_namespace"]
+        fn set_green(self: &mut Color, value: u8);
+    }
+    extern "Rust" {
+        #[cxx_name = "getBlue"]
+        #[namespace = "other_namespace"]
+        unsafe fn blue<'a>(self: &'a Color) -> &'a u8;
+    }
+    extern "Rust" {
+        #[cxx_name = "setBlue"]
+        #[namespace = "other_namespace"]
+        fn set_blue(self: &mut Color, value: u8);
+    }
+    extern "C++" {
+        #[doc(hidden)]
+        #[namespace = ""]
+        type QObject = cxx_qt::QObject;
+    }
+}
+unsafe impl ::cxx_qt::casting::Upcast<::cxx_qt::QObject> for ffi::MyObject {
+    unsafe fn upcast_ptr(this: *const Self) -> *const ::cxx_qt::QObject {
+        ffi::cxx_qt_ffi_MyObject_upcastPtr(this)
+    }
+    unsafe fn from_base_ptr(base: *const ::cxx_qt::QObject) -> *const Self {
+        ffi::cxx_qt_ffi_MyObject_downcastPtr(base)
+    }
+}
+#[doc(hidden)]
+#[allow(clippy::unnecessary_box_returns)]
+pub fn create_rs_MyObjectRust() -> std::boxed::Box<MyObjectRust> {
+    std::boxed::Box::new(core::default::Default::default())
+}
+impl ::core::ops::Deref for ffi::MyObject {
+    type Target = MyObjectRust;
+    fn deref(&self) -> &Self::Target {
+        ffi::cxx_qt_ffi_MyObject_unsafeRust(self)
+    }
+}
+impl ::cxx_qt::CxxQtType for ffi::MyObject {
+    type Rust = MyObjectRust;
+    fn rust(&self) -> &Self::Rust {
+        ffi::cxx_qt_ffi_MyObject_unsafeRust(self)
+    }
+    fn rust_mut(self: core::pin::Pin<&mut Self>) -> core::pin::Pin<&mut Self::Rust> {
+        ffi::cxx_qt_ffi_MyObject_unsafeRustMut(self)
+    }
+}
+#[doc(hidden)]
+#[allow(clippy::unnecessary_box_returns)]
+pub fn create_rs_PointRust() -> std::boxed::Box<PointRust> {
+    std::boxed::Box::new(core::default::Default::default())
+}
+#[doc(hidden)]
+#[allow(clippy::unnecessary_box_returns)]
+pub fn clone_rs_PointRust(rust: &PointRust) -> std::boxed::Box<PointRust> {
+    std::boxed::Box::new(core::clone::Clone::clone(rust))
+}
+mod cxx_qt_private_qgadget_Point {
+    #[doc = "The C++ type for the Q_GADGET "]
+    #[doc = "PointRust"]
+    #[repr(transparent)]
+    pub struct Point {
+        cxx_qt_rust: std::boxed::Box<super::PointRust>,
+    }
+    impl ::core::ops::Deref for Point {
+        type Target = super::PointRust;
+        fn deref(&self) -> &Self::Target {
+            &self.cxx_qt_rust
+        }
+    }
+    impl ::core::ops::DerefMut for Point {
+        fn deref_mut(&mut self) -> &mut Self::Target {
+            &mut self.cxx_qt_rust
+        }
+    }
+    impl ::core::clone::Clone for Point {
+        fn clone(&self) -> Self {
+            Self {
+                cxx_qt_rust: super::clone_rs_PointRust(&self.cxx_qt_rust),
+            }
+        }
+    }
+    impl ::core::default::Default for Point {
+        fn default() -> Self {
+            Self {
+                cxx_qt_rust: super::create_rs_PointRust(),
+            }
+        }
+    }
+    impl ::core::convert::From<super::PointRust> for Point {
+        fn from(rust: super::PointRust) -> Self {
+            Self {
+                cxx_qt_rust: std::boxed::Box::new(rust),
+            }
+        }
+    }
+    #[automatically_derived]
+    unsafe impl ::cxx::ExternType for Point {
+        type Id = ::cxx::type_id!("cxx_qt::my_object::Point");
+        type Kind = ::cxx::kind::Trivial;
+    }
+}
+impl ffi::Point {
+    #[doc = "Getter for the Q_PROPERTY "]
+    #[doc = "x"]
+    pub fn x(&self) -> &f64 {
+        &self.x
+    }
+}
+impl ffi::Point {
+    #[doc = "Setter for the Q_PROPERTY "]
+    #[doc = "x"]
+    pub fn set_x(&mut self, value: f64) {
+        self.x = value;
+    }
+}
+impl ffi::Point {
+    #[doc = "Getter for the Q_PROPERTY "]
+    #[doc = "y"]
+    pub fn y(&self) -> &f64 {
+        &self.y
+    }
+}
+impl ffi::Point {
+    #[doc = "Setter for the Q_PROPERTY "]
+    #[doc = "y"]
+    pub fn set_y(&mut self, value: f64) {
+        self.y = value;
+    }
+}
+impl ffi::Point {
+    #[doc = "Getter for the Q_PROPERTY "]
+    #[doc = "label"]
+    pub fn label(&self) -> &ffi::QString {
+        &self.label
+    }
+}
+#[doc(hidden)]
+#[allow(clippy::unnecessary_box_returns)]
+pub fn create_rs_ColorRust() -> std::boxed::Box<ColorRust> {
+    std::boxed::Box::new(core::default::Default::default())
+}
+#[doc(hidden)]
+#[allow(clippy::unnecessary_box_returns)]
+pub fn clone_rs_ColorRust(rust: &ColorRust) -> std::boxed::Box<ColorRust> {
+    std::boxed::Box::new(core::clone::Clone::clone(rust))
+}
+mod cxx_qt_private_qgadget_Color {
+    #[doc = "The C++ type for the Q_GADGET "]
+    #[doc = "ColorRust"]
+    #[repr(transparent)]
+    pub struct Color {
+        cxx_qt_rust: std::boxed::Box<super::ColorRust>,
+    }
+    impl ::core::ops::Deref for Color {
+        type Target = super::ColorRust;
+        fn deref(&self) -> &Self::Target {
+            &self.cxx_qt_rust
+        }
+    }
+    impl ::core::ops::DerefMut for Color {
+        fn deref_mut(&mut self) -> &mut Self::Target {
+            &mut self.cxx_qt_rust
+        }
+    }
+    impl ::core::clone::Clone for Color {
+        fn clone(&self) -> Self {
+            Self {
+                cxx_qt_rust: super::clone_rs_ColorRust(&self.cxx_qt_rust),
+            }
+        }
+    }
+    impl ::core::default::Default for Color {
+        fn default() -> Self {
+            Self {
+                cxx_qt_rust: super::create_rs_ColorRust(),
+            }
+        }
+    }
+    impl ::core::convert::From<super::ColorRust> for Color {
+        fn from(rust: super::ColorRust) -> Self {
+            Self {
+                cxx_qt_rust: std::boxed::Box::new(rust),
+            }
+        }
+    }
+    #[automatically_derived]
+    unsafe impl ::cxx::ExternType for Color {
+        type Id = ::cxx::type_id!("other_namespace::RgbColor");
+        type Kind = ::cxx::kind::Trivial;
+    }
+}
+impl ffi::Color {
+    #[doc = "Getter for the Q_PROPERTY "]
+    #[doc = "red"]
+    pub fn red(&self) -> &u8 {
+        &self.red
+    }
+}
+impl ffi::Color {
+    #[doc = "Setter for the Q_PROPERTY "]
+    #[doc = "red"]
+    pub fn set_red(&mut self, value: u8) {
+        self.red = value;
+    }
+}
+impl ffi::Color {
+    #[doc = "Getter for the Q_PROPERTY "]
+    #[doc = "green"]
+    pub fn green(&self) -> &u8 {
+        &self.green
+    }
+}
+impl ffi::Color {
+    #[doc = "Setter for the Q_PROPERTY "]
+    #[doc = "green"]
+    pub fn set_green(&mut self, value: u8) {
+        self.green = value;
+    }
+}
+impl ffi::Color {
+    #[doc = "Getter for the Q_PROPERTY "]
+    #[doc = "blue"]
+    pub fn blue(&self) -> &u8 {
+        &self.blue
+    }
+}
+impl ffi::Color {
+    #[doc = "Setter for the Q_PROPERTY "]
+    #[doc = "blue"]
+    pub fn set_blue(&mut self, value: u8) {
+        self.blue = value;
+    }
+}
@@ -0,0 +1,86 @@
+#include "directory/file_ident.cxxqt.h"
+
+namespace cxx_qt::my_object {
+cxx_qt::my_object::MyObjectAttached*
+MyObject::qmlAttachedProperties(QObject* object)
+{
+  return new cxx_qt::my_object::MyObjectAttached(object);
+}
+
+QQmlListProperty<QTimer>
+MyObject::getTimers()
+{
+  using List = QQmlListProperty<QTimer>;
+  using Index = decltype(::std::declval<List::CountFunction>()(nullptr));
+  return List(
+    this,
+    nullptr,
+    [](List* list, QTimer* item) {
+      static_cast<MyObject*>(list->object)->timersAppend(item);
+    },
+    [](List* list) {
+      return static_cast<Index>(
+        static_cast<MyObject*>(list->object)->timersCount());
+    },
+    [](List* list, Index index) {
+      return static_cast<MyObject*>(list->object)->timersAt(index);
+    },
+    [](List* list) { static_cast<MyObject*>(list->object)->timersClear(); },
+    [](List* list, Index index, QTimer* item) {
+      static_cast<MyObject*>(list->object)->timersReplace(index, item);
+    },
+    [](List* list) {
+      static_cast<MyObject*>(list->object)->timersRemoveLast();
+    });
+}
+
+MyObject::MyObject(QObject* parent)
+  : QObject(parent)
+  , ::rust::cxxqt1::CxxQtType<MyObjectRust>(
+      ::cxx_qt::my_object::cxx_qt_MyObject::createRs())
+{
+}
+
+} // namespace cxx_qt::my_object
+
+namespace cxx_qt::my_object {
+MyObjectExtension::MyObjectExtension(QObject* parent)
+  : QObject(parent)
+  , ::rust::cxxqt1::CxxQtType<MyObjectExtensionRust>(
+      ::cxx_qt::my_object::cxx_qt_MyObjectExtension::createRs())
+{
+}
+
+} // namespace cxx_qt::my_object
+
+namespace cxx_qt::my_object {
+MyObjectAttached::MyObjectAttached(QObject* arg0)
+  : MyObjectAttached(
+      ::cxx_qt::my_object::cxx_qt_MyObjectAttached::routeArguments0(
+        ::std::move(arg0)))
+{
+}
+
+MyObjectAttached::MyObjectAttached(
+  ::cxx_qt::my_object::cxx_qt_MyObjectAttached::CxxQtConstructorArguments0&&
+    args)
+  : QObject(::std::move(args.base.arg0))
+  , ::rust::cxxqt1::CxxQtType<MyObjectAttachedRust>(
+      ::cxx_qt::my_object::cxx_qt_MyObjectAttached::newRs0(
+        ::std::move(args.new_)))
+{
+  ::cxx_qt::my_object::cxx_qt_MyObjectAttached::initialize0(
+    *this, ::std::move(args.initialize));
+}
+
+} // namespace cxx_qt::my_object
+
+namespace cxx_qt::my_object {
+TimerExtension::TimerExtension(QObject* parent)
+  : QObject(parent)
+  , ::rust::cxxqt1::CxxQtType<TimerExtensionRust>(
+      ::cxx_qt::my_object::cxx_qt_TimerExtension::createRs())
+{
+}
+
+} // namespace cxx_qt::my_object
@@ -0,0 +1,138 @@
+#pragma once
+
+#include <QtQml/QQmlEngine>
+#include <QtQml/QQmlListProperty>
+#include <cxx-qt/casting.h>
+#include <cxx-qt/type.h>
+
+namespace cxx_qt::my_object {
+class MyObject;
+
+} // namespace cxx_qt::my_object
+
+namespace cxx_qt::my_object {
+class MyObjectExtension;
+
+} // namespace cxx_qt::my_object
+
+namespace cxx_qt::my_object {
+class MyObjectAttached;
+
+} // namespace cxx_qt::my_object
+
+namespace cxx_qt::my_object {
+class TimerExtension;
+
+} // namespace cxx_qt::my_object
+
+#include "directory/file_ident.cxx.h"
+
+struct QTimerQmlForeign
+{
+  Q_GADGET
+  QML_FOREIGN(QTimer)
+  Q_CLASSINFO("QML.Element", "Timer")
+  QML_EXTENDED(cxx_qt::my_object::TimerExtension)
+  Q_CLASSINFO("QML.AddedInVersion", "257")
+  Q_CLASSINFO("QML.AddedInMinorVersion", "1")
+};
+
+namespace cxx_qt::my_object {
+class MyObject
+  : public QObject
+  , public ::rust::cxxqt1::CxxQtType<MyObjectRust>
+{
+  Q_OBJECT
+public:
+  Q_CLASSINFO("QML.Element", "MyQmlObject")
+  QML_EXTENDED(cxx_qt::my_object::MyObjectExtension)
+  Q_CLASSINFO("QML.AddedInVersion", "258")
+  Q_CLASSINFO("QML.AddedInMinorVersion", "2")
+  Q_CLASSINFO("QML.RemovedInVersion", "512")
+  QML_ATTACHED(cxx_qt::my_object::MyObjectAttached)
+  Q_PROPERTY(QQmlListProperty<QTimer> timers READ getTimers CONSTANT)
+
+  virtual ~MyObject() = default;
+
+public:
+  static cxx_qt::my_object::MyObjectAttached* qmlAttachedProperties(
+    QObject* object);
+  QQmlListProperty<QTimer> getTimers();
+  explicit MyObject(QObject* parent = nullptr);
+
+private:
+  void timersAppend(QTimer* item) noexcept;
+  ::rust::isize timersCount() const noexcept;
+  QTimer* timersAt(::rust::isize index) const noexcept;
+  void timersClear() noexcept;
+  void timersReplace(::rust::isize index, QTimer* item) noexcept;
+  void timersRemoveLast() noexcept;
+};
+
+static_assert(::std::is_base_of<QObject, MyObject>::value,
+              "MyObject must inherit from QObject");
+} // namespace cxx_qt::my_object
+
+Q_DECLARE_METATYPE(cxx_qt::my_object::MyObject*)
+
+namespace cxx_qt::my_object {
+class MyObjectExtension
+  : public QObject
+  , public ::rust::cxxqt1::CxxQtType<MyObjectExtensionRust>
+{
+  Q_OBJECT
+public:
+  virtual ~MyObjectExtension() = default;
+
+public:
+  explicit MyObjectExtension(QObject* parent = nullptr);
+};
+
+static_assert(::std::is_base_of<QObject, MyObjectExtension>::value,
+              "MyObjectExtension must inherit from QObject");
+} // namespace cxx_qt::my_object
+
+Q_DECLARE_METATYPE(cxx_qt::my_object::MyObjectExtension*)
+
+namespace cxx_qt::my_object {
+class MyObjectAttached
+  : public QObject
+  , public ::rust::cxxqt1::CxxQtType<MyObjectAttachedRust>
+{
+  Q_OBJECT
+public:
+  virtual ~MyObjectAttached() = default;
+
+public:
+  explicit MyObjectAttached(QObject* arg0);
+
+private:
+  explicit MyObjectAttached(
+    ::cxx_qt::my_object::cxx_qt_MyObjectAttached::CxxQtConstructorArguments0&&
+      args);
+};
+
+static_assert(::std::is_base_of<QObject, MyObjectAttached>::value,
+              "MyObjectAttached must inherit from QObject");
+} // namespace cxx_qt::my_object
+
+Q_DECLARE_METATYPE(cxx_qt::my_object::MyObjectAttached*)
+
+namespace cxx_qt::my_object {
+class TimerExtension
+  : public QObject
+  , public ::rust::cxxqt1::CxxQtType<TimerExtensionRust>
+{
+  Q_OBJECT
+public:
+  virtual ~TimerExtension() = default;
+
+public:
+  explicit TimerExtension(QObject* parent = nullptr);
+};
+
+static_assert(::std::is_base_of<QObject, TimerExtension>::value,
+              "TimerExtension must inherit from QObject");
+} // namespace cxx_qt::my_object
+
+Q_DECLARE_METATYPE(cxx_qt::my_object::TimerExtension*)
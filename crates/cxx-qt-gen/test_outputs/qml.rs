@@ -0,0 +1,447 @@
+#[cxx::bridge(namespace = "cxx_qt::my_object")]
+#[allow(unused_unsafe)]
+mod ffi {
+    unsafe extern "C++" {
+        include ! (< QtCore / QObject >);
+        include!("cxx-qt/connection.h");
+        #[doc(hidden)]
+        #[namespace = "Qt"]
+        #[rust_name = "CxxQtConnectionType"]
+        #[allow(dead_code)]
+        type ConnectionType = cxx_qt::ConnectionType;
+        #[doc(hidden)]
+        #[namespace = "rust::cxxqt1"]
+        #[rust_name = "CxxQtQMetaObjectConnection"]
+        #[allow(dead_code)]
+        type QMetaObjectConnection = cxx_qt::QMetaObjectConnection;
+    }
+    unsafe extern "C++" {
+        include!("directory/file_ident.cxxqt.h");
+    }
+    unsafe extern "C++" {
+        #[doc = "The C++ type for the QObject "]
+        #[doc = "MyObjectRust"]
+        #[doc = "\n"]
+        #[doc = "Use this type when referring to the QObject as a pointer"]
+        #[doc = "\n"]
+        #[doc = "See the book for more information: <https://kdab.github.io/cxx-qt/book/concepts/generated_qobject.html>"]
+        #[namespace = "cxx_qt::my_object"]
+        type MyObject;
+    }
+    extern "Rust" {
+        #[namespace = "cxx_qt::my_object"]
+        type MyObjectRust;
+    }
+    extern "Rust" {
+        #[cxx_name = "timersAppend"]
+        #[namespace = "cxx_qt::my_object"]
+        unsafe fn timers_append(self: Pin<&mut MyObject>, item: *mut QTimer);
+        #[cxx_name = "timersCount"]
+        #[namespace = "cxx_qt::my_object"]
+        fn timers_count(self: &MyObject) -> isize;
+        #[cxx_name = "timersAt"]
+        #[namespace = "cxx_qt::my_object"]
+        fn timers_at(self: &MyObject, index: isize) -> *mut QTimer;
+        #[cxx_name = "timersClear"]
+        #[namespace = "cxx_qt::my_object"]
+        fn timers_clear(self: Pin<&mut MyObject>);
+        #[cxx_name = "timersReplace"]
+        #[namespace = "cxx_qt::my_object"]
+        unsafe fn timers_replace(self: Pin<&mut MyObject>, index: isize, item: *mut QTimer);
+        #[cxx_name = "timersRemoveLast"]
+        #[namespace = "cxx_qt::my_object"]
+        fn timers_remove_last(self: Pin<&mut MyObject>);
+    }
+    unsafe extern "C++" {
+        include ! (< QtQml / QQmlEngine >);
+    }
+    extern "C++" {
+        #[doc(hidden)]
+        #[cxx_name = "upcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn cxx_qt_ffi_MyObject_upcastPtr(thiz: *const MyObject) -> *const QObject;
+        #[doc(hidden)]
+        #[cxx_name = "downcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn cxx_qt_ffi_MyObject_downcastPtr(base: *const QObject) -> *const MyObject;
+    }
+    extern "Rust" {
+        #[cxx_name = "createRs"]
+        #[namespace = "cxx_qt::my_object::cxx_qt_MyObject"]
+        fn create_rs_MyObjectRust() -> Box<MyObjectRust>;
+    }
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[cxx_name = "unsafeRust"]
+        #[namespace = "rust::cxxqt1"]
+        fn cxx_qt_ffi_MyObject_unsafeRust(outer: &MyObject) -> &MyObjectRust;
+    }
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[cxx_name = "unsafeRustMut"]
+        #[namespace = "rust::cxxqt1"]
+        fn cxx_qt_ffi_MyObject_unsafeRustMut(outer: Pin<&mut MyObject>) -> Pin<&mut MyObjectRust>;
+    }
+    unsafe extern "C++" {
+        #[doc = "The C++ type for the QObject "]
+        #[doc = "MyObjectExtensionRust"]
+        #[doc = "\n"]
+        #[doc = "Use this type when referring to the QObject as a pointer"]
+        #[doc = "\n"]
+        #[doc = "See the book for more information: <https://kdab.github.io/cxx-qt/book/concepts/generated_qobject.html>"]
+        #[namespace = "cxx_qt::my_object"]
+        type MyObjectExtension;
+    }
+    extern "Rust" {
+        #[namespace = "cxx_qt::my_object"]
+        type MyObjectExtensionRust;
+    }
+    extern "C++" {
+        #[doc(hidden)]
+        #[cxx_name = "upcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn cxx_qt_ffi_MyObjectExtension_upcastPtr(
+            thiz: *const MyObjectExtension,
+        ) -> *const QObject;
+        #[doc(hidden)]
+        #[cxx_name = "downcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn cxx_qt_ffi_MyObjectExtension_downcastPtr(
+            base: *const QObject,
+        ) -> *const MyObjectExtension;
+    }
+    extern "Rust" {
+        #[cxx_name = "createRs"]
+        #[namespace = "cxx_qt::my_object::cxx_qt_MyObjectExtension"]
+        fn create_rs_MyObjectExtensionRust() -> Box<MyObjectExtensionRust>;
+    }
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[cxx_name = "unsafeRust"]
+        #[namespace = "rust::cxxqt1"]
+        fn cxx_qt_ffi_MyObjectExtension_unsafeRust(
+            outer: &MyObjectExtension,
+        ) -> &MyObjectExtensionRust;
+    }
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[cxx_name = "unsafeRustMut"]
+        #[namespace = "rust::cxxqt1"]
+        fn cxx_qt_ffi_MyObjectExtension_unsafeRustMut(
+            outer: Pin<&mut MyObjectExtension>,
+        ) -> Pin<&mut MyObjectExtensionRust>;
+    }
+    unsafe extern "C++" {
+        #[doc = "The C++ type for the QObject "]
+        #[doc = "MyObjectAttachedRust"]
+        #[doc = "\n"]
+        #[doc = "Use this type when referring to the QObject as a pointer"]
+        #[doc = "\n"]
+        #[doc = "See the book for more information: <https://kdab.github.io/cxx-qt/book/concepts/generated_qobject.html>"]
+        #[namespace = "cxx_qt::my_object"]
+        type MyObjectAttached;
+    }
+    extern "Rust" {
+        #[namespace = "cxx_qt::my_object"]
+        type MyObjectAttachedRust;
+    }
+    extern "C++" {
+        #[doc(hidden)]
+        #[cxx_name = "upcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn cxx_qt_ffi_MyObjectAttached_upcastPtr(
+            thiz: *const MyObjectAttached,
+        ) -> *const QObject;
+        #[doc(hidden)]
+        #[cxx_name = "downcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn cxx_qt_ffi_MyObjectAttached_downcastPtr(
+            base: *const QObject,
+        ) -> *const MyObjectAttached;
+    }
+    #[namespace = "cxx_qt::my_object::cxx_qt_MyObjectAttached"]
+    #[cxx_name = "CxxQtConstructorArguments0"]
+    #[doc(hidden)]
+    struct CxxQtConstructorArgumentsMyObjectAttached0 {
+        base: CxxQtConstructorBaseArgumentsMyObjectAttached0,
+        #[cxx_name = "new_"]
+        new: CxxQtConstructorNewArgumentsMyObjectAttached0,
+        initialize: CxxQtConstructorInitializeArgumentsMyObjectAttached0,
+    }
+    #[namespace = "cxx_qt::my_object::cxx_qt_MyObjectAttached"]
+    #[cxx_name = "CxxQtConstructorBaseArguments0"]
+    #[doc(hidden)]
+    struct CxxQtConstructorBaseArgumentsMyObjectAttached0 {
+        arg0: *mut QObject,
+    }
+    #[namespace = "cxx_qt::my_object::cxx_qt_MyObjectAttached"]
+    #[cxx_name = "CxxQtConstructorNewArguments0"]
+    #[doc(hidden)]
+    struct CxxQtConstructorNewArgumentsMyObjectAttached0 {
+        not_empty: i8,
+    }
+    #[namespace = "cxx_qt::my_object::cxx_qt_MyObjectAttached"]
+    #[cxx_name = "CxxQtConstructorInitializeArguments0"]
+    #[doc(hidden)]
+    struct CxxQtConstructorInitializeArgumentsMyObjectAttached0 {
+        not_empty: i8,
+    }
+    #[allow(clippy::needless_lifetimes)]
+    extern "Rust" {
+        #[namespace = "cxx_qt::my_object::cxx_qt_MyObjectAttached"]
+        #[cxx_name = "routeArguments0"]
+        unsafe fn route_arguments_MyObjectAttached_0(
+            arg0: *mut QObject,
+        ) -> CxxQtConstructorArgumentsMyObjectAttached0;
+        #[namespace = "cxx_qt::my_object::cxx_qt_MyObjectAttached"]
+        #[cxx_name = "newRs0"]
+        fn new_rs_MyObjectAttached_0(
+            args: CxxQtConstructorNewArgumentsMyObjectAttached0,
+        ) -> Box<MyObjectAttachedRust>;
+        #[namespace = "cxx_qt::my_object::cxx_qt_MyObjectAttached"]
+        #[cxx_name = "initialize0"]
+        fn initialize_MyObjectAttached_0(
+            qobject: Pin<&mut MyObjectAttached>,
+            args: CxxQtConstructorInitializeArgumentsMyObjectAttached0,
+        );
+    }
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[cxx_name = "unsafeRust"]
+        #[namespace = "rust::cxxqt1"]
+        fn cxx_qt_ffi_MyObjectAttached_unsafeRust(
+            outer: &MyObjectAttached,
+        ) -> &MyObjectAttachedRust;
+    }
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[cxx_name = "unsafeRustMut"]
+        #[namespace = "rust::cxxqt1"]
+        fn cxx_qt_ffi_MyObjectAttached_unsafeRustMut(
+            outer: Pin<&mut MyObjectAttached>,
+        ) -> Pin<&mut MyObjectAttachedRust>;
+    }
+    unsafe extern "C++" {
+        #[doc = "The C++ type for the QObject "]
+        #[doc = "TimerExtensionRust"]
+        #[doc = "\n"]
+        #[doc = "Use this type when referring to the QObject as a pointer"]
+        #[doc = "\n"]
+        #[doc = "See the book for more information: <https://kdab.github.io/cxx-qt/book/concepts/generated_qobject.html>"]
+        #[namespace = "cxx_qt::my_object"]
+        type TimerExtension;
+    }
+    extern "Rust" {
+        #[namespace = "cxx_qt::my_object"]
+        type TimerExtensionRust;
+    }
+    extern "C++" {
+        #[doc(hidden)]
+        #[cxx_name = "upcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn cxx_qt_ffi_TimerExtension_upcastPtr(
+            thiz: *const TimerExtension,
+        ) -> *const QObject;
+        #[doc(hidden)]
+        #[cxx_name = "downcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn cxx_qt_ffi_TimerExtension_downcastPtr(
+            base: *const QObject,
+        ) -> *const TimerExtension;
+    }
+    extern "Rust" {
+        #[cxx_name = "createRs"]
+        #[namespace = "cxx_qt::my_object::cxx_qt_TimerExtension"]
+        fn create_rs_TimerExtensionRust() -> Box<TimerExtensionRust>;
+    }
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[cxx_name = "unsafeRust"]
+        #[namespace = "rust::cxxqt1"]
+        fn cxx_qt_ffi_TimerExtension_unsafeRust(outer: &TimerExtension) -> &TimerExtensionRust;
+    }
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[cxx_name = "unsafeRustMut"]
+        #[namespace = "rust::cxxqt1"]
+        fn cxx_qt_ffi_TimerExtension_unsafeRustMut(
+            outer: Pin<&mut TimerExtension>,
+        ) -> Pin<&mut TimerExtensionRust>;
+    }
+    extern "C++" {
+        #[doc(hidden)]
+        #[cxx_name = "upcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn cxx_qt_ffi_QTimer_upcastPtr(thiz: *const QTimer) -> *const QObject;
+        #[doc(hidden)]
+        #[cxx_name = "downcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn cxx_qt_ffi_QTimer_downcastPtr(base: *const QObject) -> *const QTimer;
+    }
+    unsafe extern "C++" {
+        type QTimer;
+    }
+    unsafe extern "C++" {
+        include ! (< QtCore / QTimer >);
+    }
+    extern "C++" {
+        #[doc(hidden)]
+        #[namespace = ""]
+        type QObject = cxx_qt::QObject;
+    }
+}
+impl ::cxx_qt::QmlExtension<ffi::MyObject> for ffi::MyObjectExtension {}
+unsafe impl ::cxx_qt::casting::Upcast<::cxx_qt::QObject> for ffi::MyObject {
+    unsafe fn upcast_ptr(this: *const Self) -> *const ::cxx_qt::QObject {
+        ffi::cxx_qt_ffi_MyObject_upcastPtr(this)
+    }
+    unsafe fn from_base_ptr(base: *const ::cxx_qt::QObject) -> *const Self {
+        ffi::cxx_qt_ffi_MyObject_downcastPtr(base)
+    }
+}
+#[doc(hidden)]
+#[allow(clippy::unnecessary_box_returns)]
+pub fn create_rs_MyObjectRust() -> std::boxed::Box<MyObjectRust> {
+    std::boxed::Box::new(core::default::Default::default())
+}
+impl ::core::ops::Deref for ffi::MyObject {
+    type Target = MyObjectRust;
+    fn deref(&self) -> &Self::Target {
+        ffi::cxx_qt_ffi_MyObject_unsafeRust(self)
+    }
+}
+impl ::cxx_qt::CxxQtType for ffi::MyObject {
+    type Rust = MyObjectRust;
+    fn rust(&self) -> &Self::Rust {
+        ffi::cxx_qt_ffi_MyObject_unsafeRust(self)
+    }
+    fn rust_mut(self: core::pin::Pin<&mut Self>) -> core::pin::Pin<&mut Self::Rust> {
+        ffi::cxx_qt_ffi_MyObject_unsafeRustMut(self)
+    }
+}
+unsafe impl ::cxx_qt::casting::Upcast<::cxx_qt::QObject> for ffi::MyObjectExtension {
+    unsafe fn upcast_ptr(this: *const Self) -> *const ::cxx_qt::QObject {
+        ffi::cxx_qt_ffi_MyObjectExtension_upcastPtr(this)
+    }
+    unsafe fn from_base_ptr(base: *const ::cxx_qt::QObject) -> *const Self {
+        ffi::cxx_qt_ffi_MyObjectExtension_downcastPtr(base)
+    }
+}
+#[doc(hidden)]
+#[allow(clippy::unnecessary_box_returns)]
+pub fn create_rs_MyObjectExtensionRust() -> std::boxed::Box<MyObjectExtensionRust> {
+    std::boxed::Box::new(core::default::Default::default())
+}
+impl ::core::ops::Deref for ffi::MyObjectExtension {
+    type Target = MyObjectExtensionRust;
+    fn deref(&self) -> &Self::Target {
+        ffi::cxx_qt_ffi_MyObjectExtension_unsafeRust(self)
+    }
+}
+impl ::cxx_qt::CxxQtType for ffi::MyObjectExtension {
+    type Rust = MyObjectExtensionRust;
+    fn rust(&self) -> &Self::Rust {
+        ffi::cxx_qt_ffi_MyObjectExtension_unsafeRust(self)
+    }
+    fn rust_mut(self: core::pin::Pin<&mut Self>) -> core::pin::Pin<&mut Self::Rust> {
+        ffi::cxx_qt_ffi_MyObjectExtension_unsafeRustMut(self)
+    }
+}
+unsafe impl ::cxx_qt::casting::Upcast<::cxx_qt::QObject> for ffi::MyObjectAttached {
+    unsafe fn upcast_ptr(this: *const Self) -> *const ::cxx_qt::QObject {
+        ffi::cxx_qt_ffi_MyObjectAttached_upcastPtr(this)
+    }
+    unsafe fn from_base_ptr(base: *const ::cxx_qt::QObject) -> *const Self {
+        ffi::cxx_qt_ffi_MyObjectAttached_downcastPtr(base)
+    }
+}
+#[doc(hidden)]
+pub fn route_arguments_MyObjectAttached_0(
+    arg0: *mut ffi::QObject,
+) -> ffi::CxxQtConstructorArgumentsMyObjectAttached0 {
+    #[allow(unused_variables)]
+    #[allow(clippy::let_unit_value)]
+    let (new_arguments, base_arguments, initialize_arguments) =
+        <ffi::MyObjectAttached as cxx_qt::Constructor<(*mut ffi::QObject,)>>::route_arguments((
+            arg0,
+        ));
+    ffi::CxxQtConstructorArgumentsMyObjectAttached0 {
+        base: ffi::CxxQtConstructorBaseArgumentsMyObjectAttached0 {
+            arg0: base_arguments.0,
+        },
+        initialize: ffi::CxxQtConstructorInitializeArgumentsMyObjectAttached0 { not_empty: 0 },
+        new: ffi::CxxQtConstructorNewArgumentsMyObjectAttached0 { not_empty: 0 },
+    }
+}
+#[doc(hidden)]
+#[allow(unused_variables)]
+#[allow(clippy::extra_unused_lifetimes)]
+#[allow(clippy::unnecessary_box_returns)]
+pub fn new_rs_MyObjectAttached_0(
+    new_arguments: ffi::CxxQtConstructorNewArgumentsMyObjectAttached0,
+) -> std::boxed::Box<MyObjectAttachedRust> {
+    std::boxed::Box::new(<ffi::MyObjectAttached as cxx_qt::Constructor<(
+        *mut ffi::QObject,
+    )>>::new(()))
+}
+#[doc(hidden)]
+#[allow(unused_variables)]
+#[allow(clippy::extra_unused_lifetimes)]
+pub fn initialize_MyObjectAttached_0(
+    qobject: core::pin::Pin<&mut ffi::MyObjectAttached>,
+    initialize_arguments: ffi::CxxQtConstructorInitializeArgumentsMyObjectAttached0,
+) {
+    <ffi::MyObjectAttached as cxx_qt::Constructor<(*mut ffi::QObject,)>>::initialize(qobject, ());
+}
+impl ::core::ops::Deref for ffi::MyObjectAttached {
+    type Target = MyObjectAttachedRust;
+    fn deref(&self) -> &Self::Target {
+        ffi::cxx_qt_ffi_MyObjectAttached_unsafeRust(self)
+    }
+}
+impl ::cxx_qt::CxxQtType for ffi::MyObjectAttached {
+    type Rust = MyObjectAttachedRust;
+    fn rust(&self) -> &Self::Rust {
+        ffi::cxx_qt_ffi_MyObjectAttached_unsafeRust(self)
+    }
+    fn rust_mut(self: core::pin::Pin<&mut Self>) -> core::pin::Pin<&mut Self::Rust> {
+        ffi::cxx_qt_ffi_MyObjectAttached_unsafeRustMut(self)
+    }
+}
+unsafe impl ::cxx_qt::casting::Upcast<::cxx_qt::QObject> for ffi::TimerExtension {
+    unsafe fn upcast_ptr(this: *const Self) -> *const ::cxx_qt::QObject {
+        ffi::cxx_qt_ffi_TimerExtension_upcastPtr(this)
+    }
+    unsafe fn from_base_ptr(base: *const ::cxx_qt::QObject) -> *const Self {
+        ffi::cxx_qt_ffi_TimerExtension_downcastPtr(base)
+    }
+}
+#[doc(hidden)]
+#[allow(clippy::unnecessary_box_returns)]
+pub fn create_rs_TimerExtensionRust() -> std::boxed::Box<TimerExtensionRust> {
+    std::boxed::Box::new(core::default::Default::default())
+}
+impl ::core::ops::Deref for ffi::TimerExtension {
+    type Target = TimerExtensionRust;
+    fn deref(&self) -> &Self::Target {
+        ffi::cxx_qt_ffi_TimerExtension_unsafeRust(self)
+    }
+}
+impl ::cxx_qt::CxxQtType for ffi::TimerExtension {
+    type Rust = TimerExtensionRust;
+    fn rust(&self) -> &Self::Rust {
+        ffi::cxx_qt_ffi_TimerExtension_unsafeRust(self)
+    }
+    fn rust_mut(self: core::pin::Pin<&mut Self>) -> core::pin::Pin<&mut Self::Rust> {
+        ffi::cxx_qt_ffi_TimerExtension_unsafeRustMut(self)
+    }
+}
+unsafe impl ::cxx_qt::casting::Upcast<::cxx_qt::QObject> for ffi::QTimer {
+    unsafe fn upcast_ptr(this: *const Self) -> *const ::cxx_qt::QObject {
+        ffi::cxx_qt_ffi_QTimer_upcastPtr(this)
+    }
+    unsafe fn from_base_ptr(base: *const ::cxx_qt::QObject) -> *const Self {
+        ffi::cxx_qt_ffi_QTimer_downcastPtr(base)
+    }
+}
+impl ::cxx_qt::QmlExtension<ffi::QTimer> for ffi::TimerExtension {}
@@ -13,6 +13,10 @@ fn qt_qml_enabled() -> bool {
     std::env::var("CARGO_FEATURE_QT_QML").is_ok()
 }
 
+fn qt_quick_enabled() -> bool {
+    std::env::var("CARGO_FEATURE_QT_QUICK").is_ok()
+}
+
 fn qt_quickcontrols_enabled() -> bool {
     std::env::var("CARGO_FEATURE_QT_QUICKCONTROLS").is_ok()
 }
@@ -40,6 +44,10 @@ fn write_definitions_header() {
         definitions.push_str("#define CXX_QT_QML_FEATURE\n");
     }
 
+    if qt_quick_enabled() {
+        definitions.push_str("#define CXX_QT_QUICK_FEATURE\n");
+    }
+
     if qt_quickcontrols_enabled() {
         definitions.push_str("#define CXX_QT_QUICKCONTROLS_FEATURE\n");
     }
@@ -62,10 +70,15 @@ fn main() {
 
     let mut rust_bridges = vec![
         "core/qbytearray",
+        "core/qcollator",
         "core/qcoreapplication",
         "core/qdate",
+        "core/qdir",
+        "core/qfileinfo",
+        "core/qfilesystemwatcher",
         "core/qhash/qhash_i32_qbytearray",
         "core/qhash/qhash_qstring_qvariant",
+        "core/qiodevice",
         "core/qline",
         "core/qlinef",
         "core/qlist/qlist_bool",
@@ -97,6 +110,7 @@ fn main() {
         "core/qlist/qlist_u16",
         "core/qlist/qlist_u32",
         "core/qlist/qlist_u64",
+        "core/qlocale",
         "core/qmap/qmap_qstring_qvariant",
         "core/qmargins",
         "core/qmarginsf",
@@ -107,6 +121,7 @@ fn main() {
         "core/qpointf",
         "core/qrect",
         "core/qrectf",
+        "core/qrunnable",
         "core/qset/qset_bool",
         "core/qset/qset_f32",
         "core/qset/qset_f64",
@@ -125,13 +140,18 @@ fn main() {
         "core/qset/qset_u16",
         "core/qset/qset_u32",
         "core/qset/qset_u64",
+        "core/qsettings/mod",
         "core/qsize",
         "core/qsizef",
+        "core/qstandardpaths",
         "core/qstring",
         "core/qstringlist",
         "core/qt",
+        "core/qthread",
+        "core/qthreadpool",
         "core/qtime",
         "core/qtlogging",
+        "core/qtranslator",
         "core/qtypes",
         "core/qurl",
         "core/quuid",
@@ -208,7 +228,10 @@ fn main() {
             "gui/qcolor",
             "gui/qfont",
             "gui/qguiapplication",
+            "gui/qhoverevent",
             "gui/qimage",
+            "gui/qkeyevent",
+            "gui/qmouseevent",
             "gui/qpainterpath",
             "gui/qpainter",
             "gui/qpen",
@@ -222,7 +245,29 @@ fn main() {
     }
 
     if qt_qml_enabled() {
-        rust_bridges.extend(["qml/qqmlapplicationengine", "qml/qqmlengine"]);
+        rust_bridges.extend([
+            "qml/qjsengine",
+            "qml/qjsvalue/mod",
+            "qml/qqmlapplicationengine",
+            "qml/qqmlcomponent",
+            "qml/qqmlcontext",
+            "qml/qqmlengine",
+            "qml/qqmlerror",
+            "qml/qqmlincubator",
+        ]);
+
+        let version = qtbuild.version();
+        if version.major > 6 || (version.major == 6 && version.minor >= 1) {
+            rust_bridges.extend(["qml/qjsmanagedvalue"]);
+        }
+    }
+
+    if qt_quick_enabled() {
+        rust_bridges.extend([
+            "quick/qquickimageprovider",
+            "quick/qquickitem",
+            "quick/qquickpainteditem",
+        ]);
     }
 
     if qt_quickcontrols_enabled() {
@@ -232,6 +277,8 @@ fn main() {
     if !emscripten_targeted {
         rust_bridges.extend([
             "core/qdatetime",
+            "core/qprocess",
+            "core/qprocessenvironment",
             "core/qtimezone",
             "core/qlist/qlist_qdatetime",
             "core/qset/qset_qdatetime",
@@ -242,12 +289,18 @@ fn main() {
 
     let mut cpp_files = vec![
         "core/qbytearray",
+        "core/qcollator",
         "core/qcoreapplication",
         "core/qdate",
+        "core/qdir",
+        "core/qfileinfo",
+        "core/qfilesystemwatcher",
         "core/qhash/qhash",
+        "core/qiodevice",
         "core/qline",
         "core/qlinef",
         "core/qlist/qlist",
+        "core/qlocale",
         "core/qmap/qmap",
         "core/qmargins",
         "core/qmarginsf",
@@ -258,12 +311,16 @@ fn main() {
         "core/qrect",
         "core/qrectf",
         "core/qset/qset",
+        "core/qsettings/qsettings",
         "core/qsize",
         "core/qsizef",
         "core/qstring",
         "core/qstringlist",
+        "core/qthread",
+        "core/qthreadpool",
         "core/qtime",
         "core/qtlogging",
+        "core/qtranslator",
         "core/qtypes",
         "core/qurl",
         "core/quuid",
@@ -280,7 +337,9 @@ fn main() {
             "gui/qcolor",
             "gui/qfont",
             "gui/qguiapplication",
+            "gui/qhoverevent",
             "gui/qimage",
+            "gui/qmouseevent",
             "gui/qpainterpath",
             "gui/qpainter",
             "gui/qpen",
@@ -294,11 +353,30 @@ fn main() {
     }
 
     if qt_qml_enabled() {
-        cpp_files.extend(["qml/qqmlapplicationengine", "qml/qqmlengine"]);
+        cpp_files.extend([
+            "qml/qjsengine",
+            "qml/qjsvalue/qjsvalue",
+            "qml/qqmlapplicationengine",
+            "qml/qqmlcomponent",
+            "qml/qqmlcontext",
+            "qml/qqmlengine",
+            "qml/qqmlerror",
+            "qml/qqmlincubator",
+        ]);
+
+        let version = qtbuild.version();
+        if version.major > 6 || (version.major == 6 && version.minor >= 1) {
+            cpp_files.extend(["qml/qjsmanagedvalue"]);
+        }
     }
 
     if !emscripten_targeted {
-        cpp_files.extend(["core/qdatetime", "core/qtimezone"]);
+        cpp_files.extend([
+            "core/qdatetime",
+            "core/qprocess",
+            "core/qprocessenvironment",
+            "core/qtimezone",
+        ]);
     }
 
     let mut builder = CxxQtBuilder::new()
@@ -325,6 +403,10 @@ fn main() {
         builder = builder.qt_module("Qml");
     }
 
+    if qt_quick_enabled() {
+        builder = builder.qt_module("Quick");
+    }
+
     if qt_quickcontrols_enabled() {
         builder = builder.qt_module("QuickControls2");
     }
@@ -0,0 +1,28 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Joshua Booth <joshua.n.booth@gmail.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/QCollator>
+#include <QtCore/QCollatorSortKey>
+#include <QtCore/QLocale>
+#include <QtCore/QString>
+
+#include "rust/cxx.h"
+
+// Define namespace otherwise we hit a GCC bug
+// https://gcc.gnu.org/bugzilla/show_bug.cgi?id=56480
+namespace rust {
+
+template<>
+struct IsRelocatable<QCollator> : ::std::true_type
+{};
+
+template<>
+struct IsRelocatable<QCollatorSortKey> : ::std::true_type
+{};
+
+} // namespace rust
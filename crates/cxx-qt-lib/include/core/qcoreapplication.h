@@ -13,8 +13,11 @@
 #include <QtCore/QByteArray>
 #include <QtCore/QCoreApplication>
 #include <QtCore/QStringList>
+#include <QtCore/QTranslator>
 #include <QtCore/QVector>
 
+#include "rust/cxx.h"
+
 namespace rust {
 namespace cxxqtlib1 {
 
@@ -36,6 +39,12 @@ private:
 ::std::unique_ptr<QCoreApplication>
 qcoreapplicationNew(const QVector<QByteArray>& args);
 
+QString
+qcoreapplicationTranslate(::rust::Str context,
+                          ::rust::Str sourceText,
+                          ::rust::Str disambiguation,
+                          ::std::int32_t n);
+
 template<typename T>
 void
 qapplicationAddLibraryPath(T& app, const QString& path)
@@ -64,6 +73,13 @@ qapplicationExec(T& app)
   return static_cast<::std::int32_t>(app.exec());
 }
 
+template<typename T>
+bool
+qapplicationInstallTranslator(T& app, QTranslator& translator)
+{
+  return app.installTranslator(&translator);
+}
+
 template<typename T>
 QStringList
 qapplicationLibraryPaths(const T& app)
@@ -127,5 +143,12 @@ qapplicationRemoveLibraryPath(T& app, const QString& path)
   app.removeLibraryPath(path);
 }
 
+template<typename T>
+bool
+qapplicationRemoveTranslator(T& app, QTranslator& translator)
+{
+  return app.removeTranslator(&translator);
+}
+
 }
 }
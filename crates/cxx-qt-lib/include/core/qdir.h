@@ -0,0 +1,66 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Joshua Booth <joshua.n.booth@gmail.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <memory>
+
+#include <QtCore/QDir>
+#include <QtCore/QDirIterator>
+#include <QtCore/QFileInfo>
+#include <QtCore/QString>
+#include <QtCore/QStringList>
+
+#include "rust/cxx.h"
+
+// Define namespace otherwise we hit a GCC bug
+// https://gcc.gnu.org/bugzilla/show_bug.cgi?id=56480
+namespace rust {
+
+template<>
+struct IsRelocatable<QDir> : ::std::true_type
+{};
+
+} // namespace rust
+
+namespace rust {
+namespace cxxqtlib1 {
+
+using QDirFilter = QDir::Filter;
+using QDirFilters = QDir::Filters;
+using QDirSortFlag = QDir::SortFlag;
+using QDirSortFlags = QDir::SortFlags;
+using QDirIteratorFlag = QDirIterator::IteratorFlag;
+using QDirIteratorFlags = QDirIterator::IteratorFlags;
+
+inline void (*qdirAddSearchPath)(const QString&,
+                                 const QString&) = QDir::addSearchPath;
+inline QString (*qdirCleanPath)(const QString&) = QDir::cleanPath;
+inline QString (*qdirCurrentPath)() = QDir::currentPath;
+inline QString (*qdirFromNativeSeparators)(const QString&) =
+  QDir::fromNativeSeparators;
+inline QString (*qdirHomePath)() = QDir::homePath;
+inline bool (*qdirIsAbsolutePath)(const QString&) = QDir::isAbsolutePath;
+inline bool (*qdirIsRelativePath)(const QString&) = QDir::isRelativePath;
+inline QString (*qdirRootPath)() = QDir::rootPath;
+inline QStringList (*qdirSearchPaths)(const QString&) = QDir::searchPaths;
+inline bool (*qdirSetCurrent)(const QString&) = QDir::setCurrent;
+inline void (*qdirSetSearchPaths)(const QString&,
+                                  const QStringList&) = QDir::setSearchPaths;
+inline QString (*qdirTempPath)() = QDir::tempPath;
+inline QString (*qdirToNativeSeparators)(const QString&) =
+  QDir::toNativeSeparators;
+
+::std::unique_ptr<QDirIterator>
+qdiriteratorNew(const QString& path,
+                QDirFilters filters,
+                QDirIteratorFlags flags);
+
+::std::unique_ptr<QDirIterator>
+qdiriteratorNewFromDir(const QDir& dir, QDirIteratorFlags flags);
+
+}
+}
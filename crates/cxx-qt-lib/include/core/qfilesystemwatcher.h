@@ -0,0 +1,25 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Joshua Booth <joshua.n.booth@gmail.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <memory>
+
+#include <QtCore/QFileSystemWatcher>
+#include <QtCore/QString>
+#include <QtCore/QStringList>
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QFileSystemWatcher>
+qfilesystemwatcherNew();
+
+::std::unique_ptr<QFileSystemWatcher>
+qfilesystemwatcherNewWithPaths(const QStringList& paths);
+
+}
+}
@@ -0,0 +1,28 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Joshua Booth <joshua.n.booth@gmail.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <cstdint>
+
+#include <QtCore/QIODevice>
+
+#include "rust/cxx.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+using QIODeviceOpenModeFlag = QIODevice::OpenModeFlag;
+using QIODeviceOpenMode = QIODevice::OpenMode;
+
+::std::int64_t
+qiodeviceRead(QIODevice& device, ::rust::Slice<::std::uint8_t> data);
+
+::std::int64_t
+qiodeviceWrite(QIODevice& device, ::rust::Slice<const ::std::uint8_t> data);
+
+}
+}
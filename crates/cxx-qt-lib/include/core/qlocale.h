@@ -0,0 +1,108 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Joshua Booth <joshua.n.booth@gmail.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/QDate>
+#include <QtCore/QDateTime>
+#include <QtCore/QLocale>
+#include <QtCore/QString>
+#include <QtCore/QStringList>
+#include <QtCore/QTime>
+
+#include "rust/cxx.h"
+
+// Define namespace otherwise we hit a GCC bug
+// https://gcc.gnu.org/bugzilla/show_bug.cgi?id=56480
+namespace rust {
+
+template<>
+struct IsRelocatable<QLocale> : ::std::true_type
+{};
+
+} // namespace rust
+
+namespace rust {
+namespace cxxqtlib1 {
+
+using QLocaleCurrencySymbolFormat = QLocale::CurrencySymbolFormat;
+using QLocaleDataSizeFormat = QLocale::DataSizeFormat;
+using QLocaleDataSizeFormats = QLocale::DataSizeFormats;
+using QLocaleFormatType = QLocale::FormatType;
+
+QString
+qlocaleFormattedDataSize(const QLocale& locale,
+                         qint64 bytes,
+                         int precision,
+                         QLocaleDataSizeFormats format);
+QString
+qlocaleNativeTerritoryName(const QLocale& locale);
+QStringList
+qlocaleUiLanguages(const QLocale& locale);
+
+qint64
+qlocaleToI64(const QLocale& locale, const QString& string, bool& ok);
+quint64
+qlocaleToU64(const QLocale& locale, const QString& string, bool& ok);
+double
+qlocaleToF64(const QLocale& locale, const QString& string, bool& ok);
+
+QDate
+qlocaleToDate(const QLocale& locale,
+              const QString& string,
+              QLocaleFormatType format);
+QDate
+qlocaleToDateWithFormat(const QLocale& locale,
+                        const QString& string,
+                        const QString& format);
+QDateTime
+qlocaleToDateTime(const QLocale& locale,
+                  const QString& string,
+                  QLocaleFormatType format);
+QDateTime
+qlocaleToDateTimeWithFormat(const QLocale& locale,
+                            const QString& string,
+                            const QString& format);
+QTime
+qlocaleToTime(const QLocale& locale,
+              const QString& string,
+              QLocaleFormatType format);
+QTime
+qlocaleToTimeWithFormat(const QLocale& locale,
+                        const QString& string,
+                        const QString& format);
+
+QString
+qlocaleToStringDate(const QLocale& locale,
+                    const QDate& date,
+                    QLocaleFormatType format);
+QString
+qlocaleToStringDateWithFormat(const QLocale& locale,
+                              const QDate& date,
+                              const QString& format);
+QString
+qlocaleToStringDateTime(const QLocale& locale,
+                        const QDateTime& dateTime,
+                        QLocaleFormatType format);
+QString
+qlocaleToStringDateTimeWithFormat(const QLocale& locale,
+                                  const QDateTime& dateTime,
+                                  const QString& format);
+QString
+qlocaleToStringTime(const QLocale& locale,
+                    const QTime& time,
+                    QLocaleFormatType format);
+QString
+qlocaleToStringTimeWithFormat(const QLocale& locale,
+                              const QTime& time,
+                              const QString& format);
+
+inline QLocale (*qlocaleC)() = QLocale::c;
+inline void (*qlocaleSetDefault)(const QLocale&) = QLocale::setDefault;
+inline QLocale (*qlocaleSystem)() = QLocale::system;
+
+}
+}
@@ -6,4 +6,22 @@
 // SPDX-License-Identifier: MIT OR Apache-2.0
 #pragma once
 
-#include <QtCore/QObject>
\ No newline at end of file
+#include <QtCore/QObject>
+#include <QtCore/QThread>
+
+namespace rust {
+namespace cxxqtlib1 {
+
+inline bool
+qobjectMoveToThread(QObject& object, QThread* thread)
+{
+#if (QT_VERSION >= QT_VERSION_CHECK(6, 7, 0))
+  return object.moveToThread(thread);
+#else
+  object.moveToThread(thread);
+  return object.thread() == thread;
+#endif
+}
+
+}
+}
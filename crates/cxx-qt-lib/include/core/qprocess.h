@@ -0,0 +1,33 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Joshua Booth <joshua.n.booth@gmail.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <memory>
+
+#include <QtCore/QProcess>
+#include <QtCore/QString>
+#include <QtCore/QStringList>
+
+namespace rust {
+namespace cxxqtlib1 {
+
+using QProcessError = QProcess::ProcessError;
+using QProcessExitStatus = QProcess::ExitStatus;
+using QProcessInputChannelMode = QProcess::InputChannelMode;
+using QProcessChannel = QProcess::ProcessChannel;
+using QProcessChannelMode = QProcess::ProcessChannelMode;
+using QProcessState = QProcess::ProcessState;
+
+::std::unique_ptr<QProcess>
+qprocessNew();
+
+inline int (*qprocessExecute)(const QString&,
+                              const QStringList&) = QProcess::execute;
+inline QString (*qprocessNullDevice)() = QProcess::nullDevice;
+
+}
+}
@@ -0,0 +1,45 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Joshua Booth <joshua.n.booth@gmail.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <memory>
+
+#include <QtCore/QRunnable>
+
+#include "rust/cxx.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+template<typename T>
+class QRunnableClosureRunner final : public QRunnable
+{
+public:
+  inline QRunnableClosureRunner(::rust::Box<T> context,
+                                ::rust::Fn<void(T&)> closure)
+    : context(::std::move(context))
+    , closure(closure)
+  {
+  }
+
+  void run() override { (*closure)(*context); }
+
+private:
+  ::rust::Box<T> context;
+  ::rust::Fn<void(T&)> closure;
+};
+
+template<typename T>
+::std::unique_ptr<QRunnable>
+qrunnableNew(::rust::Box<T> context, ::rust::Fn<void(T&)> closure)
+{
+  return ::std::make_unique<QRunnableClosureRunner<T>>(::std::move(context),
+                                                       closure);
+}
+
+}
+}
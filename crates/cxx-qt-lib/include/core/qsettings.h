@@ -0,0 +1,83 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Joshua Booth <joshua.n.booth@gmail.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <cstdint>
+#include <memory>
+
+#include <QtCore/QSettings>
+#include <QtCore/QString>
+#include <QtCore/QVariant>
+
+namespace rust {
+namespace cxxqtlib1 {
+
+using QSettingsFormat = QSettings::Format;
+using QSettingsScope = QSettings::Scope;
+using QSettingsStatus = QSettings::Status;
+
+::std::unique_ptr<QSettings>
+qsettingsNew();
+
+::std::unique_ptr<QSettings>
+qsettingsNewWithOrganization(const QString& organization,
+                             const QString& application);
+
+::std::unique_ptr<QSettings>
+qsettingsNewWithScope(QSettingsScope scope,
+                      const QString& organization,
+                      const QString& application);
+
+::std::unique_ptr<QSettings>
+qsettingsNewWithFormat(QSettingsFormat format,
+                       QSettingsScope scope,
+                       const QString& organization,
+                       const QString& application);
+
+::std::unique_ptr<QSettings>
+qsettingsNewFromFile(const QString& fileName, QSettingsFormat format);
+
+// Since Qt 6.4 the key arguments are QAnyStringView, so we wrap the methods
+// taking keys to keep a single signature across Qt versions.
+void
+qsettingsBeginGroup(QSettings& settings, const QString& prefix);
+
+::std::int32_t
+qsettingsBeginReadArray(QSettings& settings, const QString& prefix);
+
+void
+qsettingsBeginWriteArray(QSettings& settings,
+                         const QString& prefix,
+                         ::std::int32_t size);
+
+bool
+qsettingsContains(const QSettings& settings, const QString& key);
+
+void
+qsettingsRemove(QSettings& settings, const QString& key);
+
+void
+qsettingsSetValue(QSettings& settings,
+                  const QString& key,
+                  const QVariant& value);
+
+QVariant
+qsettingsValue(const QSettings& settings, const QString& key);
+
+QSettingsFormat
+qsettingsDefaultFormat();
+
+void
+qsettingsSetDefaultFormat(QSettingsFormat format);
+
+void
+qsettingsSetPath(QSettingsFormat format,
+                 QSettingsScope scope,
+                 const QString& path);
+
+}
+}
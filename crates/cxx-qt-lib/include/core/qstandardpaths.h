@@ -0,0 +1,41 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Joshua Booth <joshua.n.booth@gmail.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/QStandardPaths>
+#include <QtCore/QString>
+#include <QtCore/QStringList>
+
+namespace rust {
+namespace cxxqtlib1 {
+
+using QStandardPathsStandardLocation = QStandardPaths::StandardLocation;
+using QStandardPathsLocateOption = QStandardPaths::LocateOption;
+using QStandardPathsLocateOptions = QStandardPaths::LocateOptions;
+
+inline QString (*qstandardpathsDisplayName)(QStandardPathsStandardLocation) =
+  QStandardPaths::displayName;
+inline QString (*qstandardpathsFindExecutable)(const QString&,
+                                               const QStringList&) =
+  QStandardPaths::findExecutable;
+inline QString (*qstandardpathsLocate)(QStandardPathsStandardLocation,
+                                       const QString&,
+                                       QStandardPathsLocateOptions) =
+  QStandardPaths::locate;
+inline QStringList (*qstandardpathsLocateAll)(QStandardPathsStandardLocation,
+                                              const QString&,
+                                              QStandardPathsLocateOptions) =
+  QStandardPaths::locateAll;
+inline void (*qstandardpathsSetTestModeEnabled)(bool) =
+  QStandardPaths::setTestModeEnabled;
+inline QStringList (*qstandardpathsStandardLocations)(
+  QStandardPathsStandardLocation) = QStandardPaths::standardLocations;
+inline QString (*qstandardpathsWritableLocation)(
+  QStandardPathsStandardLocation) = QStandardPaths::writableLocation;
+
+}
+}
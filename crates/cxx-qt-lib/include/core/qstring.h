@@ -27,6 +27,12 @@ namespace cxxqtlib1 {
 
 QString
 qstringInitFromRustString(::rust::Str string);
+QString
+qstringInitFromUtf16(::rust::Slice<const ::std::uint16_t> string);
+QString
+qstringDecodeFileName(::rust::Slice<const ::std::uint8_t> name);
+QByteArray
+qstringEncodeFileName(const QString& string);
 
 ::rust::Slice<const ::std::uint16_t>
 qstringAsSlice(const QString& string);
@@ -0,0 +1,31 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Joshua Booth <joshua.n.booth@gmail.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <memory>
+
+#include <QtCore/QThread>
+
+namespace rust {
+namespace cxxqtlib1 {
+
+using QThreadPriority = QThread::Priority;
+
+::std::unique_ptr<QThread>
+qthreadNew();
+
+bool
+qthreadWait(QThread& thread, int msecs);
+
+bool
+qthreadMoveObject(QThread& thread, QObject& object);
+
+inline QThread* (*qthreadCurrentThread)() = QThread::currentThread;
+inline int (*qthreadIdealThreadCount)() = QThread::idealThreadCount;
+
+}
+}
@@ -0,0 +1,32 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Joshua Booth <joshua.n.booth@gmail.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <memory>
+
+#include <QtCore/QRunnable>
+#include <QtCore/QThreadPool>
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QThreadPool>
+qthreadpoolNew();
+
+void
+qthreadpoolStart(QThreadPool& pool,
+                 ::std::unique_ptr<QRunnable> runnable,
+                 int priority);
+
+bool
+qthreadpoolTryStart(QThreadPool& pool, ::std::unique_ptr<QRunnable> runnable);
+
+inline QThreadPool* (*qthreadpoolGlobalInstance)() =
+  QThreadPool::globalInstance;
+
+}
+}
@@ -0,0 +1,19 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Joshua Booth <joshua.n.booth@gmail.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/QPointF>
+#include <QtGui/QHoverEvent>
+
+namespace rust {
+namespace cxxqtlib1 {
+
+QPointF
+qhovereventPosition(const QHoverEvent& event);
+
+}
+}
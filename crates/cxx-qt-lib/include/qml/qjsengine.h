@@ -0,0 +1,39 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Joshua Booth <joshua.n.booth@gmail.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+// The definitions file is auto-generated by the build script
+#include <cxx-qt-lib/definitions.h>
+
+#ifdef CXX_QT_QML_FEATURE
+
+#include <memory>
+
+#include <QtCore/QString>
+#include <QtCore/QVariant>
+#include <QtQml/QJSEngine>
+#include <QtQml/QJSValue>
+
+namespace rust {
+namespace cxxqtlib1 {
+
+QJSValue
+qjsengineEvaluate(QJSEngine& engine,
+                  const QString& program,
+                  const QString& fileName,
+                  ::std::int32_t lineNumber);
+
+::std::unique_ptr<QJSEngine>
+qjsengineNew();
+
+QJSValue
+qjsengineToScriptValue(QJSEngine& engine, const QVariant& value);
+
+}
+}
+
+#endif
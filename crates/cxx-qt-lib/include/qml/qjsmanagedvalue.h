@@ -0,0 +1,43 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Joshua Booth <joshua.n.booth@gmail.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+// The definitions file is auto-generated by the build script
+#include <cxx-qt-lib/definitions.h>
+
+#ifdef CXX_QT_QML_FEATURE
+
+#include <memory>
+
+#include <QtQml/QJSEngine>
+#include <QtQml/QJSManagedValue>
+#include <QtQml/QJSValue>
+
+#include "rust/cxx.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+using QJSManagedValueType = QJSManagedValue::Type;
+
+QJSValue
+qjsmanagedvalueCall(const QJSManagedValue& value,
+                    ::rust::Slice<const QJSValue> args);
+
+::std::unique_ptr<QJSManagedValue>
+qjsmanagedvalueNew(const QJSValue& value, QJSEngine& engine);
+
+QJSValue
+qjsengineCatchError(QJSEngine& engine);
+
+bool
+qjsengineHasError(const QJSEngine& engine);
+
+}
+}
+
+#endif
@@ -0,0 +1,49 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Joshua Booth <joshua.n.booth@gmail.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+// The definitions file is auto-generated by the build script
+#include <cxx-qt-lib/definitions.h>
+
+#ifdef CXX_QT_QML_FEATURE
+
+#include <QtCore/QStringList>
+#include <QtQml/QJSValue>
+
+#include "rust/cxx.h"
+
+// Define namespace otherwise we hit a GCC bug
+// https://gcc.gnu.org/bugzilla/show_bug.cgi?id=56480
+namespace rust {
+
+// QJSValue holds either an encoded value or a pointer into the persistent
+// storage of its engine, neither of which refer back to the QJSValue itself
+template<>
+struct IsRelocatable<QJSValue> : ::std::true_type
+{};
+
+namespace cxxqtlib1 {
+
+using QJSValueErrorType = QJSValue::ErrorType;
+using QJSValueSpecialValue = QJSValue::SpecialValue;
+
+QJSValue
+qjsvalueCall(const QJSValue& value, ::rust::Slice<const QJSValue> args);
+QJSValue
+qjsvalueCallAsConstructor(const QJSValue& value,
+                          ::rust::Slice<const QJSValue> args);
+QJSValue
+qjsvalueCallWithInstance(const QJSValue& value,
+                         const QJSValue& instance,
+                         ::rust::Slice<const QJSValue> args);
+QStringList
+qjsvaluePropertyNames(const QJSValue& value);
+
+}
+} // namespace rust
+
+#endif
@@ -16,12 +16,26 @@
 #include <QtQml/QQmlApplicationEngine>
 #include <QtQml/QQmlEngine>
 
+#include "cxx-qt-lib/qqmlerror.h"
+#include "rust/cxx.h"
+
 namespace rust {
 namespace cxxqtlib1 {
 
 ::std::unique_ptr<QQmlApplicationEngine>
 qqmlapplicationengineNew();
 
+::std::unique_ptr<QQmlErrorList>
+qqmlapplicationengineLoadChecked(QQmlApplicationEngine& engine,
+                                 const QUrl& url);
+
+QObject*
+qqmlapplicationengineRootObject(const QQmlApplicationEngine& engine,
+                                ::rust::isize index);
+
+::rust::isize
+qqmlapplicationengineRootObjectsLen(const QQmlApplicationEngine& engine);
+
 }
 }
 
@@ -0,0 +1,38 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Joshua Booth <joshua.n.booth@gmail.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+// The definitions file is auto-generated by the build script
+#include <cxx-qt-lib/definitions.h>
+
+#ifdef CXX_QT_QML_FEATURE
+
+#include <memory>
+
+#include <QtQml/QQmlComponent>
+#include <QtQml/QQmlEngine>
+
+#include "cxx-qt-lib/qqmlerror.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+using QQmlComponentStatus = QQmlComponent::Status;
+
+::std::unique_ptr<QQmlErrorList>
+qqmlcomponentErrors(const QQmlComponent& component);
+
+::std::unique_ptr<QQmlComponent>
+qqmlcomponentNew(QQmlEngine& engine);
+
+::std::unique_ptr<QQmlComponent>
+qqmlcomponentFromUrl(QQmlEngine& engine, const QUrl& url);
+
+}
+}
+
+#endif
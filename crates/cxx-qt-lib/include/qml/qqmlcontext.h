@@ -0,0 +1,27 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Joshua Booth <joshua.n.booth@gmail.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+// The definitions file is auto-generated by the build script
+#include <cxx-qt-lib/definitions.h>
+
+#ifdef CXX_QT_QML_FEATURE
+
+#include <memory>
+
+#include <QtQml/QQmlContext>
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QQmlContext>
+qqmlcontextNew(QQmlContext& parentContext);
+
+}
+}
+
+#endif
@@ -0,0 +1,39 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Joshua Booth <joshua.n.booth@gmail.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+// The definitions file is auto-generated by the build script
+#include <cxx-qt-lib/definitions.h>
+
+#ifdef CXX_QT_QML_FEATURE
+
+#include <QtCore/QList>
+#include <QtQml/QQmlError>
+
+#include "rust/cxx.h"
+
+// Define namespace otherwise we hit a GCC bug
+// https://gcc.gnu.org/bugzilla/show_bug.cgi?id=56480
+namespace rust {
+
+template<>
+struct IsRelocatable<QQmlError> : ::std::true_type
+{};
+
+namespace cxxqtlib1 {
+
+using QQmlErrorList = QList<QQmlError>;
+
+QQmlError
+qqmlerrorlistGet(const QQmlErrorList& list, ::rust::isize index);
+::rust::isize
+qqmlerrorlistLen(const QQmlErrorList& list);
+
+}
+} // namespace rust
+
+#endif
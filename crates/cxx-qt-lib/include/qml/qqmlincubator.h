@@ -0,0 +1,30 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Joshua Booth <joshua.n.booth@gmail.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+// The definitions file is auto-generated by the build script
+#include <cxx-qt-lib/definitions.h>
+
+#ifdef CXX_QT_QML_FEATURE
+
+#include <memory>
+
+#include <QtQml/QQmlIncubator>
+
+namespace rust {
+namespace cxxqtlib1 {
+
+using QQmlIncubatorIncubationMode = QQmlIncubator::IncubationMode;
+using QQmlIncubatorStatus = QQmlIncubator::Status;
+
+::std::unique_ptr<QQmlIncubator>
+qqmlincubatorNew(QQmlIncubatorIncubationMode mode);
+
+}
+}
+
+#endif
@@ -0,0 +1,207 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Joshua Booth <joshua.n.booth@gmail.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+// The definitions file is auto-generated by the build script
+#include <cxx-qt-lib/definitions.h>
+
+#ifdef CXX_QT_QUICK_FEATURE
+
+#include <memory>
+#include <mutex>
+
+#include <QtCore/QMetaObject>
+#include <QtCore/QRunnable>
+#include <QtCore/QSize>
+#include <QtCore/QString>
+#include <QtCore/QThreadPool>
+#include <QtGui/QImage>
+#include <QtQml/QQmlEngine>
+#include <QtQuick/QQuickAsyncImageProvider>
+#include <QtQuick/QQuickImageProvider>
+
+#include "rust/cxx.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+template<typename T>
+using QQuickImageProviderRequest =
+  ::rust::Fn<QImage(const T&, const QString&, const QSize&)>;
+
+template<typename T>
+class QQuickImageProviderRust final : public QQuickImageProvider
+{
+public:
+  inline QQuickImageProviderRust(::rust::Box<T> provider,
+                                 QQuickImageProviderRequest<T> request)
+    : QQuickImageProvider(QQuickImageProvider::Image)
+    , provider(::std::move(provider))
+    , request(request)
+  {
+  }
+
+  QImage requestImage(const QString& id,
+                      QSize* size,
+                      const QSize& requestedSize) override
+  {
+    QImage image = (*request)(*provider, id, requestedSize);
+    if (size != nullptr) {
+      *size = image.size();
+    }
+    return image;
+  }
+
+private:
+  ::rust::Box<T> provider;
+  QQuickImageProviderRequest<T> request;
+};
+
+class QQuickImageResponseRust final : public QQuickImageResponse
+{
+public:
+  // Shared with the runnable which requests the image, so that it does not
+  // access the response once it has been cancelled or deleted by QML
+  struct Guard
+  {
+    ::std::mutex mutex;
+    QQuickImageResponseRust* response;
+
+    inline bool isDetached()
+    {
+      const ::std::lock_guard<::std::mutex> lock(mutex);
+      return response == nullptr;
+    }
+
+    // Returns false if the response was already detached, i.e. it has
+    // finished, been cancelled or been deleted
+    inline bool detach()
+    {
+      const ::std::lock_guard<::std::mutex> lock(mutex);
+      const bool attached = response != nullptr;
+      response = nullptr;
+      return attached;
+    }
+
+    inline void finish(QImage image)
+    {
+      const ::std::lock_guard<::std::mutex> lock(mutex);
+      if (response == nullptr) {
+        return;
+      }
+
+      // Store the image and emit finished in the thread of the response,
+      // unless the response has been cancelled in the meantime
+      QQuickImageResponseRust* target = response;
+      QMetaObject::invokeMethod(
+        target,
+        [target, image = ::std::move(image)]() mutable {
+          if (!target->guard->detach()) {
+            return;
+          }
+
+          target->image = ::std::move(image);
+          Q_EMIT target->finished();
+        },
+        Qt::QueuedConnection);
+    }
+  };
+
+  inline QQuickImageResponseRust()
+    : guard(::std::make_shared<Guard>())
+  {
+    guard->response = this;
+  }
+
+  inline ~QQuickImageResponseRust() override { guard->detach(); }
+
+  void cancel() override
+  {
+    // A cancelled response must still emit finished, so that QML deletes it
+    if (guard->detach()) {
+      Q_EMIT finished();
+    }
+  }
+
+  QQuickTextureFactory* textureFactory() const override
+  {
+    return QQuickTextureFactory::textureFactoryForImage(image);
+  }
+
+  ::std::shared_ptr<Guard> guard;
+
+private:
+  QImage image;
+};
+
+template<typename T>
+class QQuickAsyncImageProviderRust final : public QQuickAsyncImageProvider
+{
+public:
+  inline QQuickAsyncImageProviderRust(::rust::Box<T> provider,
+                                      QQuickImageProviderRequest<T> request)
+    : provider(::std::move(provider))
+    , request(request)
+  {
+  }
+
+  QQuickImageResponse* requestImageResponse(
+    const QString& id,
+    const QSize& requestedSize) override
+  {
+    auto* response = new QQuickImageResponseRust();
+    pool.start(QRunnable::create([guard = response->guard,
+                                  provider = &*this->provider,
+                                  request = this->request,
+                                  id,
+                                  requestedSize]() {
+      // Skip the request if the response was cancelled while it was queued
+      if (guard->isDetached()) {
+        return;
+      }
+
+      guard->finish((*request)(*provider, id, requestedSize));
+    }));
+    return response;
+  }
+
+private:
+  ::rust::Box<T> provider;
+  QQuickImageProviderRequest<T> request;
+  // Declared last so that it is destroyed first, which waits for any running
+  // requests before the provider is dropped
+  QThreadPool pool;
+};
+
+template<typename T>
+void
+qqmlengineAddImageProvider(QQmlEngine& engine,
+                           const QString& id,
+                           ::rust::Box<T> provider,
+                           QQuickImageProviderRequest<T> request)
+{
+  // The engine takes ownership of the provider
+  engine.addImageProvider(
+    id, new QQuickImageProviderRust<T>(::std::move(provider), request));
+}
+
+template<typename T>
+void
+qqmlengineAddAsyncImageProvider(QQmlEngine& engine,
+                                const QString& id,
+                                ::rust::Box<T> provider,
+                                QQuickImageProviderRequest<T> request)
+{
+  // The engine takes ownership of the provider
+  engine.addImageProvider(
+    id, new QQuickAsyncImageProviderRust<T>(::std::move(provider), request));
+}
+
+}
+}
+
+#endif
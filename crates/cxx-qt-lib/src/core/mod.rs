@@ -8,6 +8,9 @@ pub use qbytearray::{
     QByteArray, QByteArrayBase64Option, QByteArrayBase64Options, QByteArrayFromBase64Error,
 };
 
+mod qcollator;
+pub use qcollator::{QCollator, QCollatorSortKey};
+
 mod qcoreapplication;
 pub use qcoreapplication::QCoreApplication;
 
@@ -19,12 +22,27 @@ mod qdatetime;
 #[cfg(not(target_os = "emscripten"))]
 pub use qdatetime::QDateTime;
 
+mod qdir;
+pub use qdir::{
+    QDir, QDirFilter, QDirFilters, QDirIterator, QDirIteratorFlag, QDirIteratorFlags, QDirSortFlag,
+    QDirSortFlags,
+};
+
+mod qfileinfo;
+pub use qfileinfo::QFileInfo;
+
+mod qfilesystemwatcher;
+pub use qfilesystemwatcher::QFileSystemWatcher;
+
 mod qflags;
 pub use qflags::{QFlag, QFlagRepr, QFlags};
 
 mod qhash;
 pub use qhash::{QHash, QHashPair, QHashPair_QString_QVariant, QHashPair_i32_QByteArray};
 
+mod qiodevice;
+pub use qiodevice::{QIODevice, QIODeviceOpenMode, QIODeviceOpenModeFlag};
+
 mod qline;
 pub use qline::QLine;
 
@@ -34,6 +52,12 @@ pub use qlinef::QLineF;
 mod qlist;
 pub use qlist::{QList, QListElement};
 
+mod qlocale;
+pub use qlocale::{
+    QLocale, QLocaleCurrencySymbolFormat, QLocaleDataSizeFormat, QLocaleDataSizeFormats,
+    QLocaleFormatType,
+};
+
 mod qmap;
 pub use qmap::{QMap, QMapPair, QMapPair_QString_QVariant};
 
@@ -55,21 +79,51 @@ pub use qobject::QObjectExt;
 mod qpersistentmodelindex;
 pub use qpersistentmodelindex::QPersistentModelIndex;
 
+#[cfg(not(target_os = "emscripten"))]
+mod qprocess;
+#[cfg(not(target_os = "emscripten"))]
+pub use qprocess::{
+    QProcess, QProcessChannel, QProcessChannelMode, QProcessError, QProcessExitStatus,
+    QProcessInputChannelMode, QProcessState,
+};
+
+#[cfg(not(target_os = "emscripten"))]
+mod qprocessenvironment;
+#[cfg(not(target_os = "emscripten"))]
+pub use qprocessenvironment::QProcessEnvironment;
+
 mod qrect;
 pub use qrect::QRect;
 
 mod qrectf;
 pub use qrectf::QRectF;
 
+mod qrunnable;
+pub use qrunnable::QRunnable;
+
 mod qset;
 pub use qset::{QSet, QSetElement};
 
+mod qsettings;
+#[cfg(feature = "serde")]
+pub use qsettings::QSettingsSerdeError;
+pub use qsettings::{
+    QSettings, QSettingsArrayGuard, QSettingsFormat, QSettingsGroupGuard, QSettingsScope,
+    QSettingsStatus,
+};
+
 mod qsize;
 pub use qsize::QSize;
 
 mod qsizef;
 pub use qsizef::QSizeF;
 
+mod qstandardpaths;
+pub use qstandardpaths::{
+    QStandardPaths, QStandardPathsLocateOption, QStandardPathsLocateOptions,
+    QStandardPathsStandardLocation,
+};
+
 mod qstring;
 pub use qstring::QString;
 
@@ -89,9 +143,18 @@ pub use qt::{
     TransformationMode,
 };
 
+mod qthread;
+pub use qthread::{QThread, QThreadPriority};
+
+mod qthreadpool;
+pub use qthreadpool::QThreadPool;
+
 mod qtime;
 pub use qtime::QTime;
 
+mod qtranslator;
+pub use qtranslator::QTranslator;
+
 mod qtypes;
 pub use qtypes::{qint64, qintptr, qreal, qsizetype, quint64, quintptr};
 
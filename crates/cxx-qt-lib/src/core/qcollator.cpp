@@ -0,0 +1,32 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Joshua Booth <joshua.n.booth@gmail.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#include "cxx-qt-lib/qcollator.h"
+
+#include <cxx-qt-lib/assertion_utils.h>
+
+// QCollator has a single pointer as it's member
+//
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/corelib/text/qcollator.h?h=v5.15.6-lts-lgpl
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/corelib/text/qcollator.h?h=v6.2.4
+assert_alignment_and_size(QCollator, { ::std::size_t a0; });
+
+static_assert(!::std::is_trivially_copy_assignable<QCollator>::value);
+static_assert(!::std::is_trivially_copy_constructible<QCollator>::value);
+
+static_assert(!::std::is_trivially_destructible<QCollator>::value);
+
+static_assert(QTypeInfo<QCollator>::isRelocatable);
+
+// QCollatorSortKey has a single QExplicitlySharedDataPointer as it's member
+assert_alignment_and_size(QCollatorSortKey, { ::std::size_t a0; });
+
+static_assert(!::std::is_trivially_copy_assignable<QCollatorSortKey>::value);
+static_assert(!::std::is_trivially_copy_constructible<QCollatorSortKey>::value);
+
+static_assert(!::std::is_trivially_destructible<QCollatorSortKey>::value);
+
+static_assert(QTypeInfo<QCollatorSortKey>::isRelocatable);
@@ -0,0 +1,222 @@
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Joshua Booth <joshua.n.booth@gmail.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+use cxx::{type_id, ExternType};
+use std::cmp::Ordering;
+use std::mem::MaybeUninit;
+
+use crate::{QLocale, QString};
+
+#[cxx::bridge]
+mod ffi {
+    #[namespace = "Qt"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qt.h");
+        type CaseSensitivity = crate::CaseSensitivity;
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qlocale.h");
+        type QLocale = crate::QLocale;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+        include!("cxx-qt-lib/qcollator.h");
+        type QCollator = super::QCollator;
+        type QCollatorSortKey = super::QCollatorSortKey;
+
+        /// Returns case sensitivity of the collator.
+        ///
+        /// This defaults to case-sensitive until set.
+        #[rust_name = "case_sensitivity"]
+        fn caseSensitivity(self: &QCollator) -> CaseSensitivity;
+
+        #[doc(hidden)]
+        #[rust_name = "compare_i32"]
+        fn compare(self: &QCollator, s1: &QString, s2: &QString) -> i32;
+
+        /// Returns `true` if punctuation and symbols are ignored when collating.
+        #[rust_name = "ignore_punctuation"]
+        fn ignorePunctuation(self: &QCollator) -> bool;
+
+        /// Returns the locale of the collator.
+        fn locale(self: &QCollator) -> QLocale;
+
+        /// Returns `true` if numeric sorting is enabled, `false` otherwise.
+        #[rust_name = "numeric_mode"]
+        fn numericMode(self: &QCollator) -> bool;
+
+        /// Sets the case-sensitivity of the collator to `sensitivity`.
+        #[rust_name = "set_case_sensitivity"]
+        fn setCaseSensitivity(self: &mut QCollator, sensitivity: CaseSensitivity);
+
+        /// Ignores punctuation and symbols if `on` is `true`, attends to them if `false`.
+        #[rust_name = "set_ignore_punctuation"]
+        fn setIgnorePunctuation(self: &mut QCollator, on: bool);
+
+        /// Sets the locale of the collator to `locale`.
+        #[rust_name = "set_locale"]
+        fn setLocale(self: &mut QCollator, locale: &QLocale);
+
+        /// Enables numeric sorting mode when `on` is `true`.
+        ///
+        /// Numeric sorting mode remedies shortcomings of pure alphabetical sorting (e.g. for sorting `["100", "99"]`) by treating sequences of decimal digits as numbers and ordering them by numeric value.
+        #[rust_name = "set_numeric_mode"]
+        fn setNumericMode(self: &mut QCollator, on: bool);
+
+        /// Returns a sort key for the string `string`.
+        ///
+        /// Sort keys can be used to speed up sorting of many strings, as the comparison of two sort keys is generally cheaper than comparing the strings with the collator. The returned key is only valid as long as the collator's settings are unchanged.
+        #[rust_name = "sort_key"]
+        fn sortKey(self: &QCollator, string: &QString) -> QCollatorSortKey;
+
+        #[doc(hidden)]
+        #[rust_name = "compare_i32"]
+        fn compare(self: &QCollatorSortKey, other: &QCollatorSortKey) -> i32;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/common.h");
+
+        #[doc(hidden)]
+        #[rust_name = "qcollator_drop"]
+        fn drop(collator: &mut QCollator);
+
+        #[doc(hidden)]
+        #[rust_name = "qcollator_init_default"]
+        fn construct() -> QCollator;
+        #[doc(hidden)]
+        #[rust_name = "qcollator_init_from_qcollator"]
+        fn construct(collator: &QCollator) -> QCollator;
+        #[doc(hidden)]
+        #[rust_name = "qcollator_init_from_qlocale"]
+        fn construct(locale: &QLocale) -> QCollator;
+
+        #[doc(hidden)]
+        #[rust_name = "qcollatorsortkey_drop"]
+        fn drop(key: &mut QCollatorSortKey);
+
+        #[doc(hidden)]
+        #[rust_name = "qcollatorsortkey_init_from_qcollatorsortkey"]
+        fn construct(key: &QCollatorSortKey) -> QCollatorSortKey;
+    }
+}
+
+/// The `QCollator` class compares strings according to a localized collation algorithm.
+///
+/// Qt Documentation: [QCollator](https://doc.qt.io/qt/qcollator.html#details)
+#[repr(C)]
+pub struct QCollator {
+    _space: MaybeUninit<usize>,
+}
+
+impl QCollator {
+    /// Compares `s1` with `s2`, according to the collation rules of this collator.
+    pub fn compare(&self, s1: &QString, s2: &QString) -> Ordering {
+        self.compare_i32(s1, s2).cmp(&0)
+    }
+}
+
+impl Clone for QCollator {
+    /// Creates a copy of `self`.
+    fn clone(&self) -> Self {
+        ffi::qcollator_init_from_qcollator(self)
+    }
+}
+
+impl Default for QCollator {
+    /// Constructs a `QCollator` using the default locale's collation locale.
+    fn default() -> Self {
+        ffi::qcollator_init_default()
+    }
+}
+
+impl Drop for QCollator {
+    /// Destroys the collator.
+    fn drop(&mut self) {
+        ffi::qcollator_drop(self)
+    }
+}
+
+impl From<&QLocale> for QCollator {
+    /// Constructs a `QCollator` using the given `locale`.
+    fn from(locale: &QLocale) -> Self {
+        ffi::qcollator_init_from_qlocale(locale)
+    }
+}
+
+// Safety:
+//
+// Static checks on the C++ side to ensure the size is the same.
+unsafe impl ExternType for QCollator {
+    type Id = type_id!("QCollator");
+    type Kind = cxx::kind::Trivial;
+}
+
+/// The `QCollatorSortKey` class can be used to speed up string collation.
+///
+/// Sort keys are created with [`QCollator::sort_key`], and compare in the same order as [`QCollator::compare`] would order the strings they were created from.
+///
+/// Qt Documentation: [QCollatorSortKey](https://doc.qt.io/qt/qcollatorsortkey.html#details)
+#[repr(C)]
+pub struct QCollatorSortKey {
+    _space: MaybeUninit<usize>,
+}
+
+impl Clone for QCollatorSortKey {
+    /// Creates a copy of `self`.
+    fn clone(&self) -> Self {
+        ffi::qcollatorsortkey_init_from_qcollatorsortkey(self)
+    }
+}
+
+impl Drop for QCollatorSortKey {
+    /// Destroys the collator key.
+    fn drop(&mut self) {
+        ffi::qcollatorsortkey_drop(self)
+    }
+}
+
+impl PartialEq for QCollatorSortKey {
+    fn eq(&self, other: &Self) -> bool {
+        self.compare_i32(other) == 0
+    }
+}
+
+impl Eq for QCollatorSortKey {}
+
+impl PartialOrd for QCollatorSortKey {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for QCollatorSortKey {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.compare_i32(other).cmp(&0)
+    }
+}
+
+// Safety:
+//
+// Static checks on the C++ side to ensure the size is the same.
+unsafe impl ExternType for QCollatorSortKey {
+    type Id = type_id!("QCollatorSortKey");
+    type Kind = cxx::kind::Trivial;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn qcollator_numeric_mode() {
+        let mut collator = QCollator::from(&QLocale::from_name(&QString::from("en_US")));
+        let a = QString::from("file99");
+        let b = QString::from("file100");
+        collator.set_numeric_mode(true);
+        assert_eq!(collator.compare(&a, &b), Ordering::Less);
+        assert!(collator.sort_key(&a) < collator.sort_key(&b));
+    }
+}
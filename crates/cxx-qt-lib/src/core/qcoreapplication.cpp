@@ -7,6 +7,8 @@
 // SPDX-License-Identifier: MIT OR Apache-2.0
 #include "cxx-qt-lib/qcoreapplication.h"
 
+#include <string>
+
 namespace rust {
 namespace cxxqtlib1 {
 
@@ -50,5 +52,22 @@ qcoreapplicationNew(const QVector<QByteArray>& args)
   return ptr;
 }
 
+QString
+qcoreapplicationTranslate(::rust::Str context,
+                          ::rust::Str sourceText,
+                          ::rust::Str disambiguation,
+                          ::std::int32_t n)
+{
+  // The Rust strings are not null terminated, so copy them into std::string
+  const auto contextString = static_cast<::std::string>(context);
+  const auto sourceTextString = static_cast<::std::string>(sourceText);
+  const auto disambiguationString = static_cast<::std::string>(disambiguation);
+  return QCoreApplication::translate(
+    contextString.c_str(),
+    sourceTextString.c_str(),
+    disambiguationString.empty() ? nullptr : disambiguationString.c_str(),
+    static_cast<int>(n));
+}
+
 }
 }
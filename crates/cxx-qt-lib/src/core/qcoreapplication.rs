@@ -4,7 +4,7 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
-use crate::{QByteArray, QString, QStringList, QVector};
+use crate::{QByteArray, QString, QStringList, QTranslator, QVector};
 use core::pin::Pin;
 
 #[cxx_qt::bridge]
@@ -16,6 +16,8 @@ mod ffi {
         type QString = crate::QString;
         include!("cxx-qt-lib/qstringlist.h");
         type QStringList = crate::QStringList;
+        include!("cxx-qt-lib/qtranslator.h");
+        type QTranslator = crate::QTranslator;
         include!("cxx-qt-lib/core/qvector/qvector_QByteArray.h");
         type QVector_QByteArray = crate::QVector<QByteArray>;
 
@@ -31,6 +33,14 @@ mod ffi {
         #[doc(hidden)]
         #[rust_name = "qcoreapplication_new"]
         fn qcoreapplicationNew(args: &QVector_QByteArray) -> UniquePtr<QCoreApplication>;
+        #[doc(hidden)]
+        #[rust_name = "qcoreapplication_translate"]
+        fn qcoreapplicationTranslate(
+            context: &str,
+            source_text: &str,
+            disambiguation: &str,
+            n: i32,
+        ) -> QString;
     }
 
     unsafe extern "C++Qt" {
@@ -56,6 +66,12 @@ mod ffi {
         #[rust_name = "qcoreapplication_exec"]
         fn qapplicationExec(app: Pin<&mut QCoreApplication>) -> i32;
         #[doc(hidden)]
+        #[rust_name = "qcoreapplication_install_translator"]
+        fn qapplicationInstallTranslator(
+            app: Pin<&mut QCoreApplication>,
+            translator: Pin<&mut QTranslator>,
+        ) -> bool;
+        #[doc(hidden)]
         #[rust_name = "qcoreapplication_library_paths"]
         fn qapplicationLibraryPaths(app: &QCoreApplication) -> QStringList;
         #[doc(hidden)]
@@ -68,6 +84,12 @@ mod ffi {
         #[rust_name = "qcoreapplication_remove_library_path"]
         fn qapplicationRemoveLibraryPath(app: &QCoreApplication, path: &QString);
         #[doc(hidden)]
+        #[rust_name = "qcoreapplication_remove_translator"]
+        fn qapplicationRemoveTranslator(
+            app: Pin<&mut QCoreApplication>,
+            translator: Pin<&mut QTranslator>,
+        ) -> bool;
+        #[doc(hidden)]
         #[rust_name = "qcoreapplication_set_application_name"]
         fn qapplicationSetApplicationName(app: Pin<&mut QCoreApplication>, name: &QString);
         #[doc(hidden)]
@@ -120,6 +142,16 @@ impl QCoreApplication {
         ffi::qcoreapplication_exec(self)
     }
 
+    /// Adds `translator` to the list of translation files to be used for translations.
+    /// Returns `true` on success and `false` on failure.
+    ///
+    /// Multiple translation files can be installed. Translations are searched for in the reverse order in which they were installed, so the most recently installed translation file is searched first.
+    ///
+    /// The translator is removed from the application when it is dropped.
+    pub fn install_translator(self: Pin<&mut Self>, translator: Pin<&mut QTranslator>) -> bool {
+        ffi::qcoreapplication_install_translator(self, translator)
+    }
+
     /// Returns a list of paths that the application will search when dynamically loading libraries.
     pub fn library_paths(&self) -> QStringList {
         ffi::qcoreapplication_library_paths(self)
@@ -164,6 +196,12 @@ impl QCoreApplication {
         ffi::qcoreapplication_remove_library_path(self, path)
     }
 
+    /// Removes `translator` from the list of translation files used by this application.
+    /// Returns `true` on success and `false` on failure.
+    pub fn remove_translator(self: Pin<&mut Self>, translator: Pin<&mut QTranslator>) -> bool {
+        ffi::qcoreapplication_remove_translator(self, translator)
+    }
+
     /// Set the `name` of this application.
     pub fn set_application_name(self: Pin<&mut Self>, name: &QString) {
         ffi::qcoreapplication_set_application_name(self, name);
@@ -189,4 +227,24 @@ impl QCoreApplication {
     pub fn set_organization_name(self: Pin<&mut Self>, name: &QString) {
         ffi::qcoreapplication_set_organization_name(self, name);
     }
+    /// Returns the translation text for `source_text`, by querying the installed translation files. The translation files are searched from the most recently installed file back to the first installed file.
+    ///
+    /// `context` is typically a class name, and `disambiguation` is an identifying string, for when the same `source_text` is used in different roles within the same context. If `n` is given, it is used to choose an appropriate form for the translation, for example `"%n file(s) found"`.
+    ///
+    /// If none of the translation files contain a translation for `source_text` in `context`, this function returns a QString equivalent of `source_text`.
+    ///
+    /// Note that strings passed directly to this function are not found by `lupdate`, use the [`tr!`](crate::tr) macro instead.
+    pub fn translate(
+        context: &str,
+        source_text: &str,
+        disambiguation: Option<&str>,
+        n: Option<i32>,
+    ) -> QString {
+        ffi::qcoreapplication_translate(
+            context,
+            source_text,
+            disambiguation.unwrap_or(""),
+            n.unwrap_or(-1),
+        )
+    }
 }
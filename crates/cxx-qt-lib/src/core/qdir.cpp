@@ -0,0 +1,42 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Joshua Booth <joshua.n.booth@gmail.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#include "cxx-qt-lib/qdir.h"
+
+#include <cxx-qt-lib/assertion_utils.h>
+
+// QDir has a single pointer as it's member
+//
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/corelib/io/qdir.h?h=v5.15.6-lts-lgpl
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/corelib/io/qdir.h?h=v6.2.4
+assert_alignment_and_size(QDir, { ::std::size_t a0; });
+
+static_assert(!::std::is_trivially_copy_assignable<QDir>::value);
+static_assert(!::std::is_trivially_copy_constructible<QDir>::value);
+
+static_assert(!::std::is_trivially_destructible<QDir>::value);
+
+static_assert(QTypeInfo<QDir>::isRelocatable);
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QDirIterator>
+qdiriteratorNew(const QString& path,
+                QDirFilters filters,
+                QDirIteratorFlags flags)
+{
+  return ::std::make_unique<QDirIterator>(path, filters, flags);
+}
+
+::std::unique_ptr<QDirIterator>
+qdiriteratorNewFromDir(const QDir& dir, QDirIteratorFlags flags)
+{
+  return ::std::make_unique<QDirIterator>(dir, flags);
+}
+
+}
+}
@@ -0,0 +1,647 @@
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Joshua Booth <joshua.n.booth@gmail.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+use cxx::{type_id, ExternType, UniquePtr};
+use std::fmt;
+use std::mem::MaybeUninit;
+use std::path::Path;
+
+use crate::{unsafe_impl_qflag, QFileInfo, QFlags, QString, QStringList};
+
+#[cxx::bridge]
+mod ffi {
+    /// This enum describes the filtering options available to [`QDir`]; e.g. for [`entry_list`](QDir::entry_list).
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QDirFilter {
+        /// List directories that match the filters.
+        Dirs = 0x001,
+        /// List files.
+        Files = 0x002,
+        /// List disk drives (ignored under Unix).
+        Drives = 0x004,
+        /// Do not list symbolic links (ignored by operating systems that don't support symbolic links).
+        NoSymLinks = 0x008,
+        /// List directories, files, drives and symlinks (this does not list broken symlinks unless you specify [`System`](Self::System)).
+        AllEntries = 0x007,
+        /// List files for which the application has read access. The `Readable` value needs to be combined with [`Dirs`](Self::Dirs) or [`Files`](Self::Files).
+        Readable = 0x010,
+        /// List files for which the application has write access. The `Writable` value needs to be combined with [`Dirs`](Self::Dirs) or [`Files`](Self::Files).
+        Writable = 0x020,
+        /// List files for which the application has execute access. The `Executable` value needs to be combined with [`Dirs`](Self::Dirs) or [`Files`](Self::Files).
+        Executable = 0x040,
+        /// Only list files that have been modified (ignored on Unix).
+        Modified = 0x080,
+        /// List hidden files (on Unix, files starting with a ".").
+        Hidden = 0x100,
+        /// List system files (on Unix, FIFOs, sockets and device files are included; on Windows, `.lnk` files are included).
+        System = 0x200,
+        /// List all directories; i.e. don't apply the filters to directory names.
+        AllDirs = 0x400,
+        /// The filter should be case sensitive.
+        CaseSensitive = 0x800,
+        /// Do not list the special entry ".".
+        NoDot = 0x2000,
+        /// Do not list the special entry "..".
+        NoDotDot = 0x4000,
+        /// Do not list the special entries "." and "..".
+        NoDotAndDotDot = 0x6000,
+        /// Use the filters of the [`QDir`] itself.
+        NoFilter = -1,
+    }
+
+    /// This enum describes the sort options available to [`QDir`], e.g. for [`entry_list`](QDir::entry_list).
+    ///
+    /// You can only specify one of the first four.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QDirSortFlag {
+        /// Sort by name.
+        Name = 0x00,
+        /// Sort by time (modification time).
+        Time = 0x01,
+        /// Sort by file size.
+        Size = 0x02,
+        /// Do not sort.
+        Unsorted = 0x03,
+        /// Put the directories first, then the files.
+        DirsFirst = 0x04,
+        /// Reverse the sort order.
+        Reversed = 0x08,
+        /// Sort case-insensitively.
+        IgnoreCase = 0x10,
+        /// Put the files first, then the directories.
+        DirsLast = 0x20,
+        /// Sort items appropriately using the current locale settings.
+        LocaleAware = 0x40,
+        /// Sort by file type (extension).
+        Type = 0x80,
+        /// Use the sorting of the [`QDir`] itself.
+        NoSort = -1,
+    }
+
+    /// This enum describes flags that you can combine to configure the behavior of [`QDirIterator`](crate::QDirIterator).
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QDirIteratorFlag {
+        /// The default value, representing no flags. The iterator will return entries for the assigned path.
+        NoIteratorFlags = 0x0,
+        /// When combined with [`Subdirectories`](Self::Subdirectories), this flag enables iterating through all subdirectories of the assigned path, following all symbolic links. Symbolic link loops (e.g., "link" => "." or "link" => "..") are automatically detected and ignored.
+        FollowSymlinks = 0x1,
+        /// List entries inside all subdirectories as well.
+        Subdirectories = 0x2,
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qdir.h");
+        type QDirFilter;
+        type QDirFilters = super::QDirFilters;
+        type QDirSortFlag;
+        type QDirSortFlags = super::QDirSortFlags;
+        type QDirIteratorFlag;
+        type QDirIteratorFlags = super::QDirIteratorFlags;
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qfileinfo.h");
+        type QFileInfo = crate::QFileInfo;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+        include!("cxx-qt-lib/qstringlist.h");
+        type QStringList = crate::QStringList;
+        type QDir = super::QDir;
+
+        /// Returns the absolute path name of a file in the directory. Does not check if the file actually exists in the directory; but see [`exists_entry`](Self::exists_entry). Redundant multiple separators or "." and ".." directories in `file_name` are not removed (see [`QDir::clean_path`]).
+        #[rust_name = "absolute_file_path"]
+        fn absoluteFilePath(self: &QDir, file_name: &QString) -> QString;
+
+        /// Returns the absolute path (a path that starts with "/" or with a drive specification), which may contain symbolic links, but never contains redundant ".", ".." or multiple separators.
+        #[rust_name = "absolute_path"]
+        fn absolutePath(self: &QDir) -> QString;
+
+        /// Returns the canonical path, i.e. a path without symbolic links or redundant "." or ".." elements.
+        ///
+        /// On systems that do not have symbolic links this function will always return the same string that [`absolute_path`](Self::absolute_path) returns. If the canonical path does not exist (normally due to dangling symbolic links) this function returns an empty string.
+        #[rust_name = "canonical_path"]
+        fn canonicalPath(self: &QDir) -> QString;
+
+        /// Changes the `QDir`'s directory to `dir_name`.
+        ///
+        /// Returns `true` if the new directory exists; otherwise returns `false`. Note that the logical [`cd`](Self::cd) operation is not performed if the new directory does not exist.
+        fn cd(self: &mut QDir, dir_name: &QString) -> bool;
+
+        /// Changes directory by moving one directory up from the `QDir`'s current directory.
+        ///
+        /// Returns `true` if the new directory exists; otherwise returns `false`. Note that the logical [`cd_up`](Self::cd_up) operation is not performed if the new directory does not exist.
+        #[rust_name = "cd_up"]
+        fn cdUp(self: &mut QDir) -> bool;
+
+        /// Returns the name of the directory; this is not the same as the path, e.g. a directory with the name "mail", might have the path "/var/spool/mail". If the directory has no name (e.g. it is the root directory) an empty string is returned.
+        #[rust_name = "dir_name"]
+        fn dirName(self: &QDir) -> QString;
+
+        /// Returns a list of the names of all the files and directories in the directory, ordered according to the name and attribute filters previously set with [`set_name_filters`](Self::set_name_filters) and [`set_filter`](Self::set_filter), and sorted according to the flags set with [`set_sorting`](Self::set_sorting).
+        ///
+        /// The attribute filter and sorting specifications can be overridden using the `filters` and `sort` arguments. Pass [`QDirFilter::NoFilter`] and [`QDirSortFlag::NoSort`] to use the settings of the `QDir`.
+        #[rust_name = "entry_list"]
+        fn entryList(self: &QDir, filters: QDirFilters, sort: QDirSortFlags) -> QStringList;
+
+        /// Returns `true` if the directory exists; otherwise returns `false`. (If a file with the same name is found this function will return `false`).
+        fn exists(self: &QDir) -> bool;
+
+        /// Returns `true` if the file called `name` exists; otherwise returns `false`.
+        ///
+        /// Unless `name` contains an absolute file path, the file name is assumed to be relative to the directory itself, so this function is typically used to check for the presence of files within a directory.
+        #[rust_name = "exists_entry"]
+        fn exists(self: &QDir, name: &QString) -> bool;
+
+        /// Returns the path name of a file in the directory. Does not check if the file actually exists in the directory; but see [`exists_entry`](Self::exists_entry). If the `QDir` is relative the returned path name will also be relative. Redundant multiple separators or "." and ".." directories in `file_name` are not removed (see [`QDir::clean_path`]).
+        #[rust_name = "file_path"]
+        fn filePath(self: &QDir, file_name: &QString) -> QString;
+
+        /// Returns the value set by [`set_filter`](Self::set_filter).
+        fn filter(self: &QDir) -> QDirFilters;
+
+        /// Returns `true` if the directory's path is absolute; otherwise returns `false`.
+        #[rust_name = "is_absolute"]
+        fn isAbsolute(self: &QDir) -> bool;
+
+        /// Returns whether the directory is empty.
+        ///
+        /// Equivalent to `self.entry_list(filters, QDirSortFlag::NoSort.into()).is_empty()`, but faster as it just checks whether the directory contains at least one entry.
+        #[rust_name = "is_empty"]
+        fn isEmpty(self: &QDir, filters: QDirFilters) -> bool;
+
+        /// Returns `true` if the directory is readable and we can open files by name; otherwise returns `false`.
+        #[rust_name = "is_readable"]
+        fn isReadable(self: &QDir) -> bool;
+
+        /// Returns `true` if the directory path is relative; otherwise returns `false`. (Under Unix a path is relative if it does not start with a "/").
+        #[rust_name = "is_relative"]
+        fn isRelative(self: &QDir) -> bool;
+
+        /// Returns `true` if the directory is the root directory; otherwise returns `false`.
+        #[rust_name = "is_root"]
+        fn isRoot(self: &QDir) -> bool;
+
+        /// Converts the directory path to an absolute path. If it is already absolute nothing happens. Returns `true` if the conversion succeeded; otherwise returns `false`.
+        #[rust_name = "make_absolute"]
+        fn makeAbsolute(self: &mut QDir) -> bool;
+
+        /// Creates a sub-directory called `dir_name`.
+        ///
+        /// Returns `true` on success; otherwise returns `false`. If the directory already exists when this function is called, it will return `false`.
+        fn mkdir(self: &QDir, dir_name: &QString) -> bool;
+
+        /// Creates the directory path `dir_path`.
+        ///
+        /// The function will create all parent directories necessary to create the directory.
+        ///
+        /// Returns `true` if successful; otherwise returns `false`. If the path already exists when this function is called, it will return `true`.
+        fn mkpath(self: &QDir, dir_path: &QString) -> bool;
+
+        /// Returns the string list set by [`set_name_filters`](Self::set_name_filters).
+        #[rust_name = "name_filters"]
+        fn nameFilters(self: &QDir) -> QStringList;
+
+        /// Returns the path. This may contain symbolic links, but never contains redundant ".", ".." or multiple separators.
+        ///
+        /// The returned path can be either absolute or relative (see [`set_path`](Self::set_path)).
+        fn path(self: &QDir) -> QString;
+
+        /// Refreshes the directory information.
+        fn refresh(self: &QDir);
+
+        /// Returns the path to `file_name` relative to the directory.
+        #[rust_name = "relative_file_path"]
+        fn relativeFilePath(self: &QDir, file_name: &QString) -> QString;
+
+        /// Removes the file, `file_name`.
+        ///
+        /// Returns `true` if the file is removed successfully; otherwise returns `false`.
+        fn remove(self: &mut QDir, file_name: &QString) -> bool;
+
+        /// Removes the directory, including all its contents.
+        ///
+        /// Returns `true` if successful, otherwise `false`.
+        ///
+        /// If a file or directory cannot be removed, `remove_recursively` keeps going and attempts to delete as many files and sub-directories as possible, then returns `false`.
+        ///
+        /// If the directory was already removed, the method returns `true` (expected result already reached).
+        #[rust_name = "remove_recursively"]
+        fn removeRecursively(self: &mut QDir) -> bool;
+
+        /// Renames a file or directory from `old_name` to `new_name`, and returns `true` if successful; otherwise returns `false`.
+        fn rename(self: &mut QDir, old_name: &QString, new_name: &QString) -> bool;
+
+        /// Removes the directory specified by `dir_name`.
+        ///
+        /// The directory must be empty for `rmdir` to succeed.
+        ///
+        /// Returns `true` if successful; otherwise returns `false`.
+        fn rmdir(self: &QDir, dir_name: &QString) -> bool;
+
+        /// Removes the directory path `dir_path`.
+        ///
+        /// The function will remove all parent directories in `dir_path`, provided that they are empty. This is the opposite of [`mkpath`](Self::mkpath).
+        ///
+        /// Returns `true` if successful; otherwise returns `false`.
+        fn rmpath(self: &QDir, dir_path: &QString) -> bool;
+
+        /// Sets the filter used by [`entry_list`](Self::entry_list) to `filters`. The filter is used to specify the kind of files that should be returned by [`entry_list`](Self::entry_list).
+        #[rust_name = "set_filter"]
+        fn setFilter(self: &mut QDir, filters: QDirFilters);
+
+        /// Sets the name filters used by [`entry_list`](Self::entry_list) to the list of filters specified by `name_filters`.
+        ///
+        /// Each name filter is a wildcard (globbing) filter that understands `*` and `?` wildcards.
+        #[rust_name = "set_name_filters"]
+        fn setNameFilters(self: &mut QDir, name_filters: &QStringList);
+
+        /// Sets the path of the directory to `path`. The path is cleaned of redundant ".", ".." and of multiple separators. No check is made to see whether a directory with this path actually exists; but you can check for yourself using [`exists`](Self::exists).
+        ///
+        /// The path can be either absolute or relative. Absolute paths begin with the directory separator "/" (optionally preceded by a drive specification under Windows). Relative file names begin with a directory name or a file name and specify a path relative to the current directory.
+        #[rust_name = "set_path"]
+        fn setPath(self: &mut QDir, path: &QString);
+
+        /// Sets the sort order used by [`entry_list`](Self::entry_list).
+        #[rust_name = "set_sorting"]
+        fn setSorting(self: &mut QDir, sort: QDirSortFlags);
+
+        /// Returns the value set by [`set_sorting`](Self::set_sorting).
+        fn sorting(self: &QDir) -> QDirSortFlags;
+    }
+
+    // These are all static, so we need to create bindings until CXX supports statics
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qdir_add_search_path"]
+        fn qdirAddSearchPath(prefix: &QString, path: &QString);
+        #[doc(hidden)]
+        #[rust_name = "qdir_clean_path"]
+        fn qdirCleanPath(path: &QString) -> QString;
+        #[doc(hidden)]
+        #[rust_name = "qdir_current_path"]
+        fn qdirCurrentPath() -> QString;
+        #[doc(hidden)]
+        #[rust_name = "qdir_from_native_separators"]
+        fn qdirFromNativeSeparators(path_name: &QString) -> QString;
+        #[doc(hidden)]
+        #[rust_name = "qdir_home_path"]
+        fn qdirHomePath() -> QString;
+        #[doc(hidden)]
+        #[rust_name = "qdir_is_absolute_path"]
+        fn qdirIsAbsolutePath(path: &QString) -> bool;
+        #[doc(hidden)]
+        #[rust_name = "qdir_is_relative_path"]
+        fn qdirIsRelativePath(path: &QString) -> bool;
+        #[doc(hidden)]
+        #[rust_name = "qdir_root_path"]
+        fn qdirRootPath() -> QString;
+        #[doc(hidden)]
+        #[rust_name = "qdir_search_paths"]
+        fn qdirSearchPaths(prefix: &QString) -> QStringList;
+        #[doc(hidden)]
+        #[rust_name = "qdir_set_current"]
+        fn qdirSetCurrent(path: &QString) -> bool;
+        #[doc(hidden)]
+        #[rust_name = "qdir_set_search_paths"]
+        fn qdirSetSearchPaths(prefix: &QString, search_paths: &QStringList);
+        #[doc(hidden)]
+        #[rust_name = "qdir_temp_path"]
+        fn qdirTempPath() -> QString;
+        #[doc(hidden)]
+        #[rust_name = "qdir_to_native_separators"]
+        fn qdirToNativeSeparators(path_name: &QString) -> QString;
+    }
+
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        type QDirIterator;
+
+        #[doc(hidden)]
+        #[rust_name = "file_info"]
+        fn fileInfo(self: &QDirIterator) -> QFileInfo;
+        #[doc(hidden)]
+        #[rust_name = "has_next"]
+        fn hasNext(self: &QDirIterator) -> bool;
+        #[doc(hidden)]
+        #[rust_name = "advance"]
+        fn next(self: Pin<&mut QDirIterator>) -> QString;
+        #[doc(hidden)]
+        fn path(self: &QDirIterator) -> QString;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qdiriterator_new"]
+        fn qdiriteratorNew(
+            path: &QString,
+            filters: QDirFilters,
+            flags: QDirIteratorFlags,
+        ) -> UniquePtr<QDirIterator>;
+        #[doc(hidden)]
+        #[rust_name = "qdiriterator_new_from_dir"]
+        fn qdiriteratorNewFromDir(dir: &QDir, flags: QDirIteratorFlags) -> UniquePtr<QDirIterator>;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/common.h");
+
+        #[doc(hidden)]
+        #[rust_name = "qdir_drop"]
+        fn drop(dir: &mut QDir);
+
+        #[doc(hidden)]
+        #[rust_name = "qdir_init_default"]
+        fn construct() -> QDir;
+        #[doc(hidden)]
+        #[rust_name = "qdir_init_from_qstring"]
+        fn construct(path: &QString) -> QDir;
+        #[doc(hidden)]
+        #[rust_name = "qdir_init_from_qdir"]
+        fn construct(dir: &QDir) -> QDir;
+
+        #[doc(hidden)]
+        #[rust_name = "qdir_eq"]
+        fn operatorEq(a: &QDir, b: &QDir) -> bool;
+
+        #[doc(hidden)]
+        #[rust_name = "qdir_to_debug_qstring"]
+        fn toDebugQString(dir: &QDir) -> QString;
+    }
+
+    // QDirIterator is not copyable, so we need to use a UniquePtr
+    impl UniquePtr<QDirIterator> {}
+}
+
+pub use ffi::{QDirFilter, QDirIteratorFlag, QDirSortFlag};
+
+/// [`QFlags`] of [`QDirFilter`].
+pub type QDirFilters = QFlags<QDirFilter>;
+unsafe_impl_qflag!(QDirFilter, "rust::cxxqtlib1::QDirFilters", i32);
+
+/// [`QFlags`] of [`QDirSortFlag`].
+pub type QDirSortFlags = QFlags<QDirSortFlag>;
+unsafe_impl_qflag!(QDirSortFlag, "rust::cxxqtlib1::QDirSortFlags", i32);
+
+/// [`QFlags`] of [`QDirIteratorFlag`].
+pub type QDirIteratorFlags = QFlags<QDirIteratorFlag>;
+unsafe_impl_qflag!(QDirIteratorFlag, "rust::cxxqtlib1::QDirIteratorFlags", i32);
+
+/// The `QDir` class provides access to directory structures and their contents.
+///
+/// Qt Documentation: [QDir](https://doc.qt.io/qt/qdir.html#details)
+#[repr(C)]
+pub struct QDir {
+    _space: MaybeUninit<usize>,
+}
+
+impl QDir {
+    /// Adds `path` to the search path for `prefix`.
+    ///
+    /// Search paths allow files to be opened with a `prefix:` scheme, for example `"icons:undo.png"` will be looked up in every path registered for the `"icons"` prefix.
+    pub fn add_search_path(prefix: &QString, path: &QString) {
+        ffi::qdir_add_search_path(prefix, path)
+    }
+
+    /// Returns `path` with directory separators normalized (that is, platform-native separators converted to "/") and redundant ones removed, and "."s and ".."s resolved (as far as possible).
+    ///
+    /// Symbolic links are kept. This function does not return the canonical path, but rather the simplest version of the input. For example, "./local" becomes "local", "local/../bin" becomes "bin" and "/local/usr/../bin" becomes "/local/bin".
+    pub fn clean_path(path: &QString) -> QString {
+        ffi::qdir_clean_path(path)
+    }
+
+    /// Returns the absolute path of the application's current directory.
+    pub fn current_path() -> QString {
+        ffi::qdir_current_path()
+    }
+
+    /// Returns an iterator over the entries of this directory, using the name filters and attribute filters of this `QDir`.
+    pub fn entries(&self) -> QDirIterator {
+        QDirIterator::from_dir(self, QDirIteratorFlags::default())
+    }
+
+    /// Returns `path_name` using "/" as file separator. On Windows, for instance, `from_native_separators("c:\\winnt\\system32")` returns "c:/winnt/system32".
+    ///
+    /// The returned string may be the same as the argument on some operating systems, for example on Unix.
+    pub fn from_native_separators(path_name: &QString) -> QString {
+        ffi::qdir_from_native_separators(path_name)
+    }
+
+    /// Returns the absolute path of the user's home directory.
+    ///
+    /// Under Windows this function will return the directory of the current user's profile. Under non-Windows operating systems the `HOME` environment variable is used if it exists, otherwise the path returned by [`root_path`](Self::root_path).
+    pub fn home_path() -> QString {
+        ffi::qdir_home_path()
+    }
+
+    /// Returns `true` if `path` is absolute; returns `false` if it is relative.
+    ///
+    /// **Note:** If `path` starts with ':', this function will return `true` because paths starting with ':' are treated specially as they denote a [QResource](https://doc.qt.io/qt/qresource.html).
+    pub fn is_absolute_path(path: &QString) -> bool {
+        ffi::qdir_is_absolute_path(path)
+    }
+
+    /// Returns `true` if `path` is relative; returns `false` if it is absolute.
+    pub fn is_relative_path(path: &QString) -> bool {
+        ffi::qdir_is_relative_path(path)
+    }
+
+    /// Returns the absolute path of the root directory.
+    ///
+    /// For Unix operating systems this returns "/". For Windows file systems this normally returns "c:/".
+    pub fn root_path() -> QString {
+        ffi::qdir_root_path()
+    }
+
+    /// Returns the search paths for `prefix`.
+    pub fn search_paths(prefix: &QString) -> QStringList {
+        ffi::qdir_search_paths(prefix)
+    }
+
+    /// Sets the application's current working directory to `path`. Returns `true` if the directory was successfully changed; otherwise returns `false`.
+    pub fn set_current(path: &QString) -> bool {
+        ffi::qdir_set_current(path)
+    }
+
+    /// Sets or replaces Qt's search paths for file names with the prefix `prefix` to `search_paths`.
+    ///
+    /// To specify a prefix for a file name, prepend the prefix followed by a single colon (e.g., "images:undo.png", "xmldocs:books.xml"). `prefix` can only contain letters or numbers (e.g., it cannot contain a colon, nor a slash).
+    ///
+    /// Qt uses this search path to locate files with a known prefix. The search path entries are tested in order, starting with the first entry.
+    pub fn set_search_paths(prefix: &QString, search_paths: &QStringList) {
+        ffi::qdir_set_search_paths(prefix, search_paths)
+    }
+
+    /// Returns the absolute canonical path of the system's temporary directory.
+    ///
+    /// On Unix/Linux systems this is the path in the `TMPDIR` environment variable or `/tmp` if `TMPDIR` is not defined. On Windows this is usually the path in the `TEMP` or `TMP` environment variable.
+    pub fn temp_path() -> QString {
+        ffi::qdir_temp_path()
+    }
+
+    /// Returns `path_name` with the '/' separators converted to separators that are appropriate for the underlying operating system.
+    ///
+    /// On Windows, `to_native_separators("c:/winnt/system32")` returns "c:\\winnt\\system32".
+    ///
+    /// The returned string may be the same as the argument on some operating systems, for example on Unix.
+    pub fn to_native_separators(path_name: &QString) -> QString {
+        ffi::qdir_to_native_separators(path_name)
+    }
+}
+
+impl Clone for QDir {
+    /// Constructs a `QDir` object that is a copy of the `QDir` object for directory `self`.
+    fn clone(&self) -> Self {
+        ffi::qdir_init_from_qdir(self)
+    }
+}
+
+impl Default for QDir {
+    /// Constructs a `QDir` pointing to the program's working directory (".").
+    fn default() -> Self {
+        ffi::qdir_init_default()
+    }
+}
+
+impl std::cmp::PartialEq for QDir {
+    /// Returns `true` if directory `self` and directory `other` have the same path and their sort and filter settings are the same; otherwise returns `false`.
+    fn eq(&self, other: &Self) -> bool {
+        ffi::qdir_eq(self, other)
+    }
+}
+
+impl std::cmp::Eq for QDir {}
+
+impl fmt::Debug for QDir {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        ffi::qdir_to_debug_qstring(self).fmt(f)
+    }
+}
+
+impl Drop for QDir {
+    /// Destroys the `QDir` object frees up its resources. This has no effect on the underlying directory in the file system.
+    fn drop(&mut self) {
+        ffi::qdir_drop(self)
+    }
+}
+
+impl From<&QString> for QDir {
+    /// Constructs a `QDir` pointing to the given directory `path`. If `path` is empty the program's working directory, ("."), is used.
+    fn from(path: &QString) -> Self {
+        ffi::qdir_init_from_qstring(path)
+    }
+}
+
+impl From<&Path> for QDir {
+    /// Constructs a `QDir` pointing to the given directory `path`.
+    fn from(path: &Path) -> Self {
+        Self::from(&QString::from(path))
+    }
+}
+
+impl<'a> IntoIterator for &'a QDir {
+    type Item = QFileInfo;
+    type IntoIter = QDirIterator;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.entries()
+    }
+}
+
+// Safety:
+//
+// Static checks on the C++ side to ensure the size is the same.
+unsafe impl ExternType for QDir {
+    type Id = type_id!("QDir");
+    type Kind = cxx::kind::Trivial;
+}
+
+/// An iterator over the entries of a directory, yielding a [`QFileInfo`] for each entry.
+///
+/// Qt Documentation: [QDirIterator](https://doc.qt.io/qt/qdiriterator.html#details)
+pub struct QDirIterator {
+    inner: UniquePtr<ffi::QDirIterator>,
+}
+
+impl QDirIterator {
+    /// Constructs a `QDirIterator` that can iterate over `path`, with no name filtering and `filters` for entry filtering. You can pass options via `flags` to decide how the directory should be iterated.
+    ///
+    /// Passing [`QDirFilter::NoFilter`] as `filters` lists all entries except the special entries "." and "..".
+    pub fn new(path: &QString, filters: QDirFilters, flags: QDirIteratorFlags) -> Self {
+        Self {
+            inner: ffi::qdiriterator_new(path, filters, flags),
+        }
+    }
+
+    /// Constructs a `QDirIterator` that can iterate over `dir`'s entries, using `dir`'s name filters and regular filters. You can pass options via `flags` to decide how the directory should be iterated.
+    ///
+    /// The sorting in `dir` is ignored.
+    pub fn from_dir(dir: &QDir, flags: QDirIteratorFlags) -> Self {
+        Self {
+            inner: ffi::qdiriterator_new_from_dir(dir, flags),
+        }
+    }
+
+    /// Returns the base directory of the iterator.
+    pub fn path(&self) -> QString {
+        self.inner.path()
+    }
+}
+
+impl Iterator for QDirIterator {
+    type Item = QFileInfo;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if !self.inner.has_next() {
+            return None;
+        }
+        self.inner.pin_mut().advance();
+        Some(self.inner.file_info())
+    }
+}
+
+impl std::iter::FusedIterator for QDirIterator {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn qdir_iterates_entries() {
+        let root = std::env::temp_dir().join("cxx-qt-lib-qdir-test");
+        let _ = std::fs::remove_dir_all(&root);
+        std::fs::create_dir_all(root.join("sub")).unwrap();
+        std::fs::write(root.join("a.txt"), "a").unwrap();
+        std::fs::write(root.join("sub").join("b.txt"), "b").unwrap();
+
+        let dir = QDir::from(root.as_path());
+        assert!(dir.exists());
+        assert!(dir.exists_entry(&QString::from("a.txt")));
+
+        let mut names: Vec<String> = dir
+            .entries()
+            .map(|info| String::from(&info.file_name()))
+            .collect();
+        names.sort();
+        assert_eq!(names, ["a.txt", "sub"]);
+
+        let recursive = QDirIterator::new(
+            &dir.path(),
+            QDirFilter::Files.into(),
+            QDirIteratorFlag::Subdirectories.into(),
+        );
+        assert_eq!(recursive.count(), 2);
+
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,22 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Joshua Booth <joshua.n.booth@gmail.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#include "cxx-qt-lib/qfileinfo.h"
+
+#include <cxx-qt-lib/assertion_utils.h>
+
+// QFileInfo has a single pointer as it's member
+//
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/corelib/io/qfileinfo.h?h=v5.15.6-lts-lgpl
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/corelib/io/qfileinfo.h?h=v6.2.4
+assert_alignment_and_size(QFileInfo, { ::std::size_t a0; });
+
+static_assert(!::std::is_trivially_copy_assignable<QFileInfo>::value);
+static_assert(!::std::is_trivially_copy_constructible<QFileInfo>::value);
+
+static_assert(!::std::is_trivially_destructible<QFileInfo>::value);
+
+static_assert(QTypeInfo<QFileInfo>::isRelocatable);
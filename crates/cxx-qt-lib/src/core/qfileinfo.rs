@@ -0,0 +1,302 @@
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Joshua Booth <joshua.n.booth@gmail.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+use cxx::{type_id, ExternType};
+use std::fmt;
+use std::mem::MaybeUninit;
+use std::path::Path;
+
+use crate::{QDir, QString};
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qdir.h");
+        type QDir = crate::QDir;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+        include!("cxx-qt-lib/qtypes.h");
+        type qint64 = crate::qint64;
+        include!("cxx-qt-lib/qfileinfo.h");
+        type QFileInfo = super::QFileInfo;
+
+        /// Returns the file's absolute path as a [`QDir`].
+        #[rust_name = "absolute_dir"]
+        fn absoluteDir(self: &QFileInfo) -> QDir;
+
+        /// Returns an absolute path including the file name.
+        ///
+        /// The absolute path name consists of the full path and the file name. On Unix this will always begin with the root, '/', directory. On Windows this will always begin 'D:/' where D is a drive letter, except for network shares that are not mapped to a drive letter, in which case the path will begin '//sharename/'.
+        ///
+        /// This function returns the same as [`file_path`](Self::file_path), unless [`is_relative`](Self::is_relative) is `true`. In contrast to [`canonical_file_path`](Self::canonical_file_path), symbolic links or redundant "." or ".." elements are not necessarily removed.
+        #[rust_name = "absolute_file_path"]
+        fn absoluteFilePath(self: &QFileInfo) -> QString;
+
+        /// Returns the file's path absolute path. This doesn't include the file name.
+        #[rust_name = "absolute_path"]
+        fn absolutePath(self: &QFileInfo) -> QString;
+
+        /// Returns the base name of the file without the path.
+        ///
+        /// The base name consists of all characters in the file up to (but not including) the first '.' character.
+        #[rust_name = "base_name"]
+        fn baseName(self: &QFileInfo) -> QString;
+
+        /// Returns the name of the bundle.
+        ///
+        /// On macOS and iOS this returns the proper localized name for a bundle if the path [`is_bundle`](Self::is_bundle). On all other platforms an empty string is returned.
+        #[rust_name = "bundle_name"]
+        fn bundleName(self: &QFileInfo) -> QString;
+
+        /// Returns `true` if caching is enabled; otherwise returns `false`.
+        fn caching(self: &QFileInfo) -> bool;
+
+        /// Returns the file system entry's canonical path, including the entry's name, that is, an absolute path without symbolic links or redundant '.' or '..' elements.
+        ///
+        /// If the entry does not exist, this returns an empty string.
+        #[rust_name = "canonical_file_path"]
+        fn canonicalFilePath(self: &QFileInfo) -> QString;
+
+        /// Returns the file's path canonical path (excluding the file name), i.e. an absolute path without symbolic links or redundant "." or ".." elements.
+        ///
+        /// If the file does not exist, this returns an empty string.
+        #[rust_name = "canonical_path"]
+        fn canonicalPath(self: &QFileInfo) -> QString;
+
+        /// Returns the complete base name of the file without the path.
+        ///
+        /// The complete base name consists of all characters in the file up to (but not including) the last '.' character.
+        #[rust_name = "complete_base_name"]
+        fn completeBaseName(self: &QFileInfo) -> QString;
+
+        /// Returns the complete suffix (extension) of the file.
+        ///
+        /// The complete suffix consists of all characters in the file after (but not including) the first '.'.
+        #[rust_name = "complete_suffix"]
+        fn completeSuffix(self: &QFileInfo) -> QString;
+
+        /// Returns a [`QDir`] object representing the path of the parent directory of the file system entry that this `QFileInfo` refers to.
+        fn dir(self: &QFileInfo) -> QDir;
+
+        /// Returns `true` if the file system entry this `QFileInfo` refers to exists; otherwise returns `false`.
+        ///
+        /// **Note:** If the entry is a symlink that points to a non-existing target, this method returns `false`.
+        fn exists(self: &QFileInfo) -> bool;
+
+        /// Returns the name of the file system entry this `QFileInfo` refers to, excluding the path.
+        ///
+        /// **Note:** If this `QFileInfo` is given a path ending in a directory separator '/', the entry's name part is considered empty.
+        #[rust_name = "file_name"]
+        fn fileName(self: &QFileInfo) -> QString;
+
+        /// Returns the path of the file system entry this `QFileInfo` refers to; the path may be absolute or relative.
+        #[rust_name = "file_path"]
+        fn filePath(self: &QFileInfo) -> QString;
+
+        /// Returns the group of the file. On Windows, on systems where files do not have groups, or if an error occurs, an empty string is returned.
+        fn group(self: &QFileInfo) -> QString;
+
+        /// Returns `true` if the file system entry's path is absolute, otherwise returns `false` (that is, the path is relative).
+        ///
+        /// **Note:** Paths starting with a colon (:) are always considered absolute, as they denote a [QResource](https://doc.qt.io/qt/qresource.html).
+        #[rust_name = "is_absolute"]
+        fn isAbsolute(self: &QFileInfo) -> bool;
+
+        /// Returns `true` if this object points to a bundle or to a symbolic link to a bundle on macOS and iOS; otherwise returns `false`.
+        #[rust_name = "is_bundle"]
+        fn isBundle(self: &QFileInfo) -> bool;
+
+        /// Returns `true` if this object points to a directory or to a symbolic link to a directory. If the file system entry this object refers to does not exist, or is a symbolic link to an entry that does not exist, returns `false`.
+        #[rust_name = "is_dir"]
+        fn isDir(self: &QFileInfo) -> bool;
+
+        /// Returns `true` if the file system entry this `QFileInfo` refers to is executable; otherwise returns `false`.
+        #[rust_name = "is_executable"]
+        fn isExecutable(self: &QFileInfo) -> bool;
+
+        /// Returns `true` if this object points to a file or to a symbolic link to a file. Returns `false` if the object points to something that is not a file (such as a directory) or that does not exist.
+        #[rust_name = "is_file"]
+        fn isFile(self: &QFileInfo) -> bool;
+
+        /// Returns `true` if the file system entry this `QFileInfo` refers to is "hidden"; otherwise returns `false`.
+        ///
+        /// On Unix-like operating systems, including macOS, the file is hidden if its name begins with ".". On Windows, the file is hidden if its hidden attribute is set.
+        #[rust_name = "is_hidden"]
+        fn isHidden(self: &QFileInfo) -> bool;
+
+        /// Returns `true` if the file system entry this `QFileInfo` refers to is readable; otherwise returns `false`.
+        #[rust_name = "is_readable"]
+        fn isReadable(self: &QFileInfo) -> bool;
+
+        /// Returns `true` if the file system entry's path is relative, otherwise returns `false` (that is, the path is absolute).
+        #[rust_name = "is_relative"]
+        fn isRelative(self: &QFileInfo) -> bool;
+
+        /// Returns `true` if the object points to a directory or to a symbolic link to a directory, and that directory is the root directory; otherwise returns `false`.
+        #[rust_name = "is_root"]
+        fn isRoot(self: &QFileInfo) -> bool;
+
+        /// Returns `true` if this object points to a symbolic link, shortcut, or alias; otherwise returns `false`.
+        #[rust_name = "is_sym_link"]
+        fn isSymLink(self: &QFileInfo) -> bool;
+
+        /// Returns `true` if the file system entry this `QFileInfo` refers to is writable; otherwise returns `false`.
+        #[rust_name = "is_writable"]
+        fn isWritable(self: &QFileInfo) -> bool;
+
+        /// If the file system entry's path is relative, this method converts it to an absolute path and returns `true`; if the path is already absolute, this method returns `false`.
+        #[rust_name = "make_absolute"]
+        fn makeAbsolute(self: &mut QFileInfo) -> bool;
+
+        /// Returns the owner of the file. On systems where files do not have owners, or if an error occurs, an empty string is returned.
+        fn owner(self: &QFileInfo) -> QString;
+
+        /// Returns the path of the file system entry this `QFileInfo` refers to, excluding the entry's name.
+        ///
+        /// **Note:** If this `QFileInfo` is given a path ending in a directory separator '/', the entry's name part is considered empty. In this case, this function will return the entire path.
+        fn path(self: &QFileInfo) -> QString;
+
+        /// Refreshes the information about the file system entry this `QFileInfo` refers to, that is, reads in information from the file system the next time a cached property is fetched.
+        fn refresh(self: &mut QFileInfo);
+
+        /// If `enable` is `true`, enables caching of file information. If `enable` is `false` caching is disabled.
+        ///
+        /// When caching is enabled, `QFileInfo` reads the file information from the file system the first time it's needed, but generally not later.
+        ///
+        /// Caching is enabled by default.
+        #[rust_name = "set_caching"]
+        fn setCaching(self: &mut QFileInfo, enable: bool);
+
+        /// Sets the path of the file system entry that this `QFileInfo` provides information about to `path` that can be absolute or relative.
+        #[rust_name = "set_file"]
+        fn setFile(self: &mut QFileInfo, path: &QString);
+
+        #[doc(hidden)]
+        #[rust_name = "size_qint64"]
+        fn size(self: &QFileInfo) -> qint64;
+
+        /// Returns the suffix (extension) of the file.
+        ///
+        /// The suffix consists of all characters in the file after (but not including) the last '.'.
+        fn suffix(self: &QFileInfo) -> QString;
+
+        /// Returns the absolute path to the file or directory a symbolic link points to, or an empty string if the object isn't a symbolic link.
+        ///
+        /// This name may not represent an existing file; it is only a string.
+        #[rust_name = "sym_link_target"]
+        fn symLinkTarget(self: &QFileInfo) -> QString;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/common.h");
+
+        #[doc(hidden)]
+        #[rust_name = "qfileinfo_drop"]
+        fn drop(info: &mut QFileInfo);
+
+        #[doc(hidden)]
+        #[rust_name = "qfileinfo_init_default"]
+        fn construct() -> QFileInfo;
+        #[doc(hidden)]
+        #[rust_name = "qfileinfo_init_from_qstring"]
+        fn construct(path: &QString) -> QFileInfo;
+        #[doc(hidden)]
+        #[rust_name = "qfileinfo_init_from_qdir_qstring"]
+        fn construct(dir: &QDir, path: &QString) -> QFileInfo;
+        #[doc(hidden)]
+        #[rust_name = "qfileinfo_init_from_qfileinfo"]
+        fn construct(info: &QFileInfo) -> QFileInfo;
+
+        #[doc(hidden)]
+        #[rust_name = "qfileinfo_eq"]
+        fn operatorEq(a: &QFileInfo, b: &QFileInfo) -> bool;
+
+        #[doc(hidden)]
+        #[rust_name = "qfileinfo_to_debug_qstring"]
+        fn toDebugQString(info: &QFileInfo) -> QString;
+    }
+}
+
+/// The `QFileInfo` class provides an OS-independent API to retrieve information about file system entries.
+///
+/// Qt Documentation: [QFileInfo](https://doc.qt.io/qt/qfileinfo.html#details)
+#[repr(C)]
+pub struct QFileInfo {
+    _space: MaybeUninit<usize>,
+}
+
+impl QFileInfo {
+    /// Constructs a new `QFileInfo` that gives information about the given file system entry `path` that is relative to the directory `dir`.
+    ///
+    /// If `dir` has a relative path, the `QFileInfo` will also have a relative path.
+    ///
+    /// If `path` is absolute, then the directory specified by `dir` will be disregarded.
+    pub fn from_dir(dir: &QDir, path: &QString) -> Self {
+        ffi::qfileinfo_init_from_qdir_qstring(dir, path)
+    }
+
+    /// Returns the file size in bytes. If the file does not exist or cannot be fetched, 0 is returned.
+    pub fn size(&self) -> i64 {
+        self.size_qint64().into()
+    }
+}
+
+impl Clone for QFileInfo {
+    /// Constructs a new `QFileInfo` that is a copy of the given `QFileInfo`.
+    fn clone(&self) -> Self {
+        ffi::qfileinfo_init_from_qfileinfo(self)
+    }
+}
+
+impl Default for QFileInfo {
+    /// Constructs an empty `QFileInfo` object that doesn't refer to any file system entry.
+    fn default() -> Self {
+        ffi::qfileinfo_init_default()
+    }
+}
+
+impl std::cmp::PartialEq for QFileInfo {
+    /// Returns `true` if this `QFileInfo` refers to the same file system entry as the one referred to by `other`; otherwise returns `false`.
+    fn eq(&self, other: &Self) -> bool {
+        ffi::qfileinfo_eq(self, other)
+    }
+}
+
+impl std::cmp::Eq for QFileInfo {}
+
+impl fmt::Debug for QFileInfo {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        ffi::qfileinfo_to_debug_qstring(self).fmt(f)
+    }
+}
+
+impl Drop for QFileInfo {
+    /// Destroys the `QFileInfo` and frees its resources.
+    fn drop(&mut self) {
+        ffi::qfileinfo_drop(self)
+    }
+}
+
+impl From<&QString> for QFileInfo {
+    /// Constructs a `QFileInfo` that gives information about a file system entry located at `path` that can be absolute or relative.
+    fn from(path: &QString) -> Self {
+        ffi::qfileinfo_init_from_qstring(path)
+    }
+}
+
+impl From<&Path> for QFileInfo {
+    /// Constructs a `QFileInfo` that gives information about a file system entry located at `path` that can be absolute or relative.
+    fn from(path: &Path) -> Self {
+        Self::from(&QString::from(path))
+    }
+}
+
+// Safety:
+//
+// Static checks on the C++ side to ensure the size is the same.
+unsafe impl ExternType for QFileInfo {
+    type Id = type_id!("QFileInfo");
+    type Kind = cxx::kind::Trivial;
+}
@@ -0,0 +1,25 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Joshua Booth <joshua.n.booth@gmail.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#include "cxx-qt-lib/qfilesystemwatcher.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QFileSystemWatcher>
+qfilesystemwatcherNew()
+{
+  return ::std::make_unique<QFileSystemWatcher>();
+}
+
+::std::unique_ptr<QFileSystemWatcher>
+qfilesystemwatcherNewWithPaths(const QStringList& paths)
+{
+  return ::std::make_unique<QFileSystemWatcher>(paths);
+}
+
+}
+}
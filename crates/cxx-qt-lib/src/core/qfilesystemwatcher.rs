@@ -0,0 +1,101 @@
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Joshua Booth <joshua.n.booth@gmail.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::QStringList;
+
+#[cxx_qt::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+        include!("cxx-qt-lib/qstringlist.h");
+        type QStringList = crate::QStringList;
+    }
+
+    unsafe extern "C++Qt" {
+        include!("cxx-qt-lib/qfilesystemwatcher.h");
+        /// The `QFileSystemWatcher` class provides an interface for monitoring files and directories for modifications.
+        ///
+        /// Qt Documentation: [QFileSystemWatcher](https://doc.qt.io/qt/qfilesystemwatcher.html#details)
+        #[qobject]
+        type QFileSystemWatcher;
+
+        /// Adds `file` to the file system watcher if it exists. The path is not added if it does not exist, or if it is already being monitored by the file system watcher.
+        ///
+        /// If `file` specifies a directory, the [`directory_changed`](Self::directory_changed) signal will be emitted when `file` is modified or removed from disk; otherwise the [`file_changed`](Self::file_changed) signal is emitted when `file` is modified, renamed or removed.
+        ///
+        /// If the watch was successful, `true` is returned.
+        #[rust_name = "add_path"]
+        fn addPath(self: Pin<&mut QFileSystemWatcher>, file: &QString) -> bool;
+
+        /// Adds each path in `files` to the file system watcher. Paths are not added if they do not exist, or if they are already being monitored by the file system watcher.
+        ///
+        /// Returns a list of paths that could not be watched.
+        #[rust_name = "add_paths"]
+        fn addPaths(self: Pin<&mut QFileSystemWatcher>, files: &QStringList) -> QStringList;
+
+        /// Returns a list of paths to directories that are being watched.
+        fn directories(self: &QFileSystemWatcher) -> QStringList;
+
+        /// Returns a list of paths to files that are being watched.
+        fn files(self: &QFileSystemWatcher) -> QStringList;
+
+        /// Removes the specified path `file` from the file system watcher.
+        ///
+        /// If the watch is successfully removed, `true` is returned.
+        #[rust_name = "remove_path"]
+        fn removePath(self: Pin<&mut QFileSystemWatcher>, file: &QString) -> bool;
+
+        /// Removes the specified `files` from the file system watcher.
+        ///
+        /// Returns a list of paths which could not be removed.
+        #[rust_name = "remove_paths"]
+        fn removePaths(self: Pin<&mut QFileSystemWatcher>, files: &QStringList) -> QStringList;
+
+        /// This signal is emitted when the directory at a specified `path` is modified (e.g., when a file is added or deleted) or removed from disk. Note that if there are several changes during a short period of time, some of the changes might not emit this signal. However, the last change in the sequence of changes will always generate this signal.
+        #[qsignal]
+        #[rust_name = "directory_changed"]
+        fn directoryChanged(self: Pin<&mut QFileSystemWatcher>, path: &QString);
+
+        /// This signal is emitted when the file at the specified `path` is modified, renamed or removed from disk.
+        ///
+        /// **Note:** As a safety measure, many applications save an open file by writing a new file and then deleting the old one. In your slot function, you can check `watcher.files().contains(path)`. If it returns `false`, check whether the file still exists and then call [`add_path`](Self::add_path) to continue watching it.
+        #[qsignal]
+        #[rust_name = "file_changed"]
+        fn fileChanged(self: Pin<&mut QFileSystemWatcher>, path: &QString);
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qfilesystemwatcher_new"]
+        fn qfilesystemwatcherNew() -> UniquePtr<QFileSystemWatcher>;
+        #[doc(hidden)]
+        #[rust_name = "qfilesystemwatcher_new_with_paths"]
+        fn qfilesystemwatcherNewWithPaths(paths: &QStringList) -> UniquePtr<QFileSystemWatcher>;
+    }
+
+    impl UniquePtr<QFileSystemWatcher> {}
+}
+
+pub use ffi::QFileSystemWatcher;
+
+// Safety:
+//
+// A QFileSystemWatcher has no state which is tied to the thread that it was created on,
+// its watcher engines are children which are moved along with it.
+unsafe impl cxx_qt::MoveToThread for QFileSystemWatcher {}
+
+impl QFileSystemWatcher {
+    /// Constructs a new file system watcher object.
+    pub fn new() -> cxx::UniquePtr<Self> {
+        ffi::qfilesystemwatcher_new()
+    }
+
+    /// Constructs a new file system watcher object which monitors the given `paths` list.
+    pub fn from_paths(paths: &QStringList) -> cxx::UniquePtr<Self> {
+        ffi::qfilesystemwatcher_new_with_paths(paths)
+    }
+}
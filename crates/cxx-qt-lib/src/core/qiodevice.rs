@@ -0,0 +1,249 @@
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Joshua Booth <joshua.n.booth@gmail.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::{unsafe_impl_qflag, QByteArray, QFlags};
+use core::pin::Pin;
+use std::io;
+
+#[cxx_qt::bridge]
+mod ffi {
+    /// This enum is used with [`QIODevice::open`] to describe the mode in which a device is opened.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QIODeviceOpenModeFlag {
+        /// The device is not open.
+        NotOpen = 0x0000,
+        /// The device is open for reading.
+        ReadOnly = 0x0001,
+        /// The device is open for writing. Note that, for file-system subclasses (e.g. `QFile`), this mode implies [`Truncate`](Self::Truncate) unless combined with [`ReadOnly`](Self::ReadOnly), [`Append`](Self::Append) or [`NewOnly`](Self::NewOnly).
+        WriteOnly = 0x0002,
+        /// The device is open for reading and writing.
+        ReadWrite = 0x0003,
+        /// The device is opened in append mode so that all data is written to the end of the file.
+        Append = 0x0004,
+        /// If possible, the device is truncated before it is opened. All earlier contents of the device are lost.
+        Truncate = 0x0008,
+        /// When reading, the end-of-line terminators are translated to '\n'. When writing, the end-of-line terminators are translated to the local encoding, for example '\r\n' for Win32.
+        Text = 0x0010,
+        /// Any buffer in the device is bypassed.
+        Unbuffered = 0x0020,
+        /// Fail if the file to be opened already exists. Create and open the file only if it does not exist.
+        NewOnly = 0x0040,
+        /// Fail if the file to be opened does not exist.
+        ExistingOnly = 0x0080,
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qiodevice.h");
+        type QIODeviceOpenModeFlag;
+        type QIODeviceOpenMode = super::QIODeviceOpenMode;
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qbytearray.h");
+        type QByteArray = crate::QByteArray;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+        include!("cxx-qt-lib/qtypes.h");
+        type qint64 = crate::qint64;
+    }
+
+    unsafe extern "C++Qt" {
+        /// The `QIODevice` class is the base interface class of all I/O devices in Qt.
+        ///
+        /// A pinned `QIODevice` implements [`std::io::Read`] and [`std::io::Write`].
+        ///
+        /// Qt Documentation: [QIODevice](https://doc.qt.io/qt/qiodevice.html#details)
+        #[qobject]
+        type QIODevice;
+
+        /// Returns `true` if the current read and write position is at the end of the device (i.e. there is no more data available for reading on the device); otherwise returns `false`.
+        #[rust_name = "at_end"]
+        fn atEnd(self: &QIODevice) -> bool;
+
+        #[doc(hidden)]
+        #[rust_name = "bytes_available_qint64"]
+        fn bytesAvailable(self: &QIODevice) -> qint64;
+
+        #[doc(hidden)]
+        #[rust_name = "bytes_to_write_qint64"]
+        fn bytesToWrite(self: &QIODevice) -> qint64;
+
+        /// Returns `true` if a complete line of data can be read from the device; otherwise returns `false`.
+        #[rust_name = "can_read_line"]
+        fn canReadLine(self: &QIODevice) -> bool;
+
+        /// First emits [`about_to_close`](Self::about_to_close), then closes the device and sets its open mode to [`QIODeviceOpenModeFlag::NotOpen`]. The error string is also reset.
+        fn close(self: Pin<&mut QIODevice>);
+
+        /// Returns a human-readable description of the last device error that occurred.
+        #[rust_name = "error_string"]
+        fn errorString(self: &QIODevice) -> QString;
+
+        /// Returns `true` if the device is open; otherwise returns `false`. A device is open if it can be read from and/or written to.
+        #[rust_name = "is_open"]
+        fn isOpen(self: &QIODevice) -> bool;
+
+        /// Returns `true` if data can be read from the device; otherwise returns `false`. Use [`bytes_available`](Self::bytes_available) to determine how many bytes can be read.
+        #[rust_name = "is_readable"]
+        fn isReadable(self: &QIODevice) -> bool;
+
+        /// Returns `true` if this device is sequential; otherwise returns `false`.
+        ///
+        /// Sequential devices, as opposed to a random-access devices, have no concept of a start, an end, a size, or a current position, and they do not support seeking.
+        #[rust_name = "is_sequential"]
+        fn isSequential(self: &QIODevice) -> bool;
+
+        /// Returns `true` if the [`QIODeviceOpenModeFlag::Text`] flag is enabled; otherwise returns `false`.
+        #[rust_name = "is_text_mode_enabled"]
+        fn isTextModeEnabled(self: &QIODevice) -> bool;
+
+        /// Returns `true` if data can be written to the device; otherwise returns `false`.
+        #[rust_name = "is_writable"]
+        fn isWritable(self: &QIODevice) -> bool;
+
+        /// Opens the device and sets its open mode to `mode`. Returns `true` if successful; otherwise returns `false`.
+        fn open(self: Pin<&mut QIODevice>, mode: QIODeviceOpenMode) -> bool;
+
+        /// Returns the mode in which the device has been opened; i.e. [`QIODeviceOpenModeFlag::ReadOnly`] or [`QIODeviceOpenModeFlag::WriteOnly`].
+        #[rust_name = "open_mode"]
+        fn openMode(self: &QIODevice) -> QIODeviceOpenMode;
+
+        /// Reads all remaining data from the device, and returns it as a byte array.
+        ///
+        /// This function has no way of reporting errors; returning an empty [`QByteArray`] can mean either that no data was currently available for reading, or that an error occurred.
+        #[rust_name = "read_all"]
+        fn readAll(self: Pin<&mut QIODevice>) -> QByteArray;
+
+        #[doc(hidden)]
+        #[rust_name = "read_line_qint64"]
+        fn readLine(self: Pin<&mut QIODevice>, max_size: qint64) -> QByteArray;
+
+        /// If `enabled` is `true`, this function sets the [`QIODeviceOpenModeFlag::Text`] flag on the device; otherwise the flag is removed.
+        #[rust_name = "set_text_mode_enabled"]
+        fn setTextModeEnabled(self: Pin<&mut QIODevice>, enabled: bool);
+
+        /// For buffered devices, this function waits until a payload of buffered written data has been written to the device and the [`bytes_written`](Self::bytes_written) signal has been emitted, or until `msecs` milliseconds have passed. If `msecs` is -1, this function will not time out. For unbuffered devices, it returns immediately.
+        ///
+        /// Returns `true` if a payload of data was written to the device; otherwise returns `false` (i.e. if the operation timed out, or if an error occurred).
+        #[rust_name = "wait_for_bytes_written"]
+        fn waitForBytesWritten(self: Pin<&mut QIODevice>, msecs: i32) -> bool;
+
+        /// Blocks until new data is available for reading and the [`ready_read`](Self::ready_read) signal has been emitted, or until `msecs` milliseconds have passed. If `msecs` is -1, this function will not time out.
+        ///
+        /// Returns `true` if new data is available for reading; otherwise returns `false` (if the operation timed out or if an error occurred).
+        #[rust_name = "wait_for_ready_read"]
+        fn waitForReadyRead(self: Pin<&mut QIODevice>, msecs: i32) -> bool;
+
+        /// This signal is emitted when the device is about to close. Connect this signal if you have operations that need to be performed before the device closes (e.g., if you have data in a separate buffer that needs to be written to the device).
+        #[qsignal]
+        #[rust_name = "about_to_close"]
+        fn aboutToClose(self: Pin<&mut QIODevice>);
+
+        /// This signal is emitted every time a payload of data has been written to the device's current write channel. The `bytes` argument is set to the number of bytes that were written in this payload.
+        #[qsignal]
+        #[rust_name = "bytes_written"]
+        fn bytesWritten(self: Pin<&mut QIODevice>, bytes: qint64);
+
+        /// This signal is emitted when the input (reading) stream is closed in this device. It is emitted as soon as the closing is detected, which means that there might still be data available for reading with [`read_all`](Self::read_all).
+        #[qsignal]
+        #[rust_name = "read_channel_finished"]
+        fn readChannelFinished(self: Pin<&mut QIODevice>);
+
+        /// This signal is emitted once every time new data is available for reading from the device's current read channel. It will only be emitted again once new data is available, such as when a new payload of network data has arrived on your network socket, or when a new block of data has been appended to your device.
+        #[qsignal]
+        #[rust_name = "ready_read"]
+        fn readyRead(self: Pin<&mut QIODevice>);
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qiodevice_read"]
+        fn qiodeviceRead(device: Pin<&mut QIODevice>, data: &mut [u8]) -> i64;
+        #[doc(hidden)]
+        #[rust_name = "qiodevice_write"]
+        fn qiodeviceWrite(device: Pin<&mut QIODevice>, data: &[u8]) -> i64;
+    }
+}
+
+pub use ffi::{QIODevice, QIODeviceOpenModeFlag};
+
+/// [`QFlags`] of [`QIODeviceOpenModeFlag`].
+pub type QIODeviceOpenMode = QFlags<QIODeviceOpenModeFlag>;
+unsafe_impl_qflag!(
+    QIODeviceOpenModeFlag,
+    "rust::cxxqtlib1::QIODeviceOpenMode",
+    i32
+);
+
+impl QIODevice {
+    /// Returns the number of bytes that are available for reading.
+    pub fn bytes_available(&self) -> i64 {
+        self.bytes_available_qint64().into()
+    }
+
+    /// For buffered devices, this function returns the number of bytes waiting to be written. For devices with no buffer, this function returns 0.
+    pub fn bytes_to_write(&self) -> i64 {
+        self.bytes_to_write_qint64().into()
+    }
+
+    /// Reads a line from the device and returns the result as a byte array, including the trailing newline if one was read.
+    ///
+    /// This function has no way of reporting errors; returning an empty [`QByteArray`] can mean either that no data was currently available for reading, or that an error occurred.
+    pub fn read_line(self: Pin<&mut Self>) -> QByteArray {
+        self.read_line_qint64(0.into())
+    }
+
+    fn io_error(&self) -> io::Error {
+        io::Error::other(String::from(&self.error_string()))
+    }
+}
+
+impl io::Read for Pin<&mut QIODevice> {
+    /// Reads up to `buf.len()` bytes from the device.
+    ///
+    /// If no data is currently available, this blocks until new data arrives with [`QIODevice::wait_for_ready_read`]. `Ok(0)` is returned once no more data can arrive and the device is at its end, e.g. when a process has finished.
+    ///
+    /// If waiting fails otherwise, e.g. because the device is not open for reading, an error with the [`error_string`](QIODevice::error_string) of the device is returned.
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+        if self.bytes_available() == 0 && !self.as_mut().wait_for_ready_read(-1) {
+            // A device which is not open also reports that it is at its end
+            if self.is_readable() && self.at_end() {
+                return Ok(0);
+            }
+            return Err(self.io_error());
+        }
+        match ffi::qiodevice_read(self.as_mut(), buf) {
+            -1 => Err(self.io_error()),
+            read => Ok(read as usize),
+        }
+    }
+}
+
+impl io::Write for Pin<&mut QIODevice> {
+    /// Writes the contents of `buf` to the device.
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        match ffi::qiodevice_write(self.as_mut(), buf) {
+            -1 => Err(self.io_error()),
+            written => Ok(written as usize),
+        }
+    }
+
+    /// Blocks with [`QIODevice::wait_for_bytes_written`] until the write buffer is empty, or until the device stops making progress.
+    fn flush(&mut self) -> io::Result<()> {
+        while self.bytes_to_write() > 0 {
+            if !self.as_mut().wait_for_bytes_written(-1) {
+                break;
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,172 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Joshua Booth <joshua.n.booth@gmail.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#include "cxx-qt-lib/qlocale.h"
+
+#include <cxx-qt-lib/assertion_utils.h>
+
+// QLocale has a single pointer as it's member
+//
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/corelib/text/qlocale.h?h=v5.15.6-lts-lgpl
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/corelib/text/qlocale.h?h=v6.2.4
+assert_alignment_and_size(QLocale, { ::std::size_t a0; });
+
+static_assert(!::std::is_trivially_copy_assignable<QLocale>::value);
+static_assert(!::std::is_trivially_copy_constructible<QLocale>::value);
+
+static_assert(!::std::is_trivially_destructible<QLocale>::value);
+
+static_assert(QTypeInfo<QLocale>::isRelocatable);
+
+namespace rust {
+namespace cxxqtlib1 {
+
+QString
+qlocaleFormattedDataSize(const QLocale& locale,
+                         qint64 bytes,
+                         int precision,
+                         QLocaleDataSizeFormats format)
+{
+#if (QT_VERSION >= QT_VERSION_CHECK(6, 0, 0))
+  return locale.formattedDataSize(bytes, precision, format);
+#else
+  // Older Qt 5 versions only have a non-const overload
+  return QLocale(locale).formattedDataSize(bytes, precision, format);
+#endif
+}
+
+QString
+qlocaleNativeTerritoryName(const QLocale& locale)
+{
+#if (QT_VERSION >= QT_VERSION_CHECK(6, 2, 0))
+  return locale.nativeTerritoryName();
+#else
+  return locale.nativeCountryName();
+#endif
+}
+
+QStringList
+qlocaleUiLanguages(const QLocale& locale)
+{
+  return locale.uiLanguages();
+}
+
+qint64
+qlocaleToI64(const QLocale& locale, const QString& string, bool& ok)
+{
+  return locale.toLongLong(string, &ok);
+}
+
+quint64
+qlocaleToU64(const QLocale& locale, const QString& string, bool& ok)
+{
+  return locale.toULongLong(string, &ok);
+}
+
+double
+qlocaleToF64(const QLocale& locale, const QString& string, bool& ok)
+{
+  return locale.toDouble(string, &ok);
+}
+
+QDate
+qlocaleToDate(const QLocale& locale,
+              const QString& string,
+              QLocaleFormatType format)
+{
+  return locale.toDate(string, format);
+}
+
+QDate
+qlocaleToDateWithFormat(const QLocale& locale,
+                        const QString& string,
+                        const QString& format)
+{
+  return locale.toDate(string, format);
+}
+
+QDateTime
+qlocaleToDateTime(const QLocale& locale,
+                  const QString& string,
+                  QLocaleFormatType format)
+{
+  return locale.toDateTime(string, format);
+}
+
+QDateTime
+qlocaleToDateTimeWithFormat(const QLocale& locale,
+                            const QString& string,
+                            const QString& format)
+{
+  return locale.toDateTime(string, format);
+}
+
+QTime
+qlocaleToTime(const QLocale& locale,
+              const QString& string,
+              QLocaleFormatType format)
+{
+  return locale.toTime(string, format);
+}
+
+QTime
+qlocaleToTimeWithFormat(const QLocale& locale,
+                        const QString& string,
+                        const QString& format)
+{
+  return locale.toTime(string, format);
+}
+
+QString
+qlocaleToStringDate(const QLocale& locale,
+                    const QDate& date,
+                    QLocaleFormatType format)
+{
+  return locale.toString(date, format);
+}
+
+QString
+qlocaleToStringDateWithFormat(const QLocale& locale,
+                              const QDate& date,
+                              const QString& format)
+{
+  return locale.toString(date, format);
+}
+
+QString
+qlocaleToStringDateTime(const QLocale& locale,
+                        const QDateTime& dateTime,
+                        QLocaleFormatType format)
+{
+  return locale.toString(dateTime, format);
+}
+
+QString
+qlocaleToStringDateTimeWithFormat(const QLocale& locale,
+                                  const QDateTime& dateTime,
+                                  const QString& format)
+{
+  return locale.toString(dateTime, format);
+}
+
+QString
+qlocaleToStringTime(const QLocale& locale,
+                    const QTime& time,
+                    QLocaleFormatType format)
+{
+  return locale.toString(time, format);
+}
+
+QString
+qlocaleToStringTimeWithFormat(const QLocale& locale,
+                              const QTime& time,
+                              const QString& format)
+{
+  return locale.toString(time, format);
+}
+
+}
+}
@@ -0,0 +1,664 @@
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Joshua Booth <joshua.n.booth@gmail.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+use cxx::{type_id, ExternType};
+use std::ffi::c_char;
+use std::fmt;
+use std::mem::MaybeUninit;
+
+#[cfg(not(target_os = "emscripten"))]
+use crate::QDateTime;
+use crate::{unsafe_impl_qflag, QDate, QFlags, QString, QStringList, QTime};
+
+#[cxx::bridge]
+mod ffi {
+    /// This enum describes the types of format that can be used when converting [`QDate`](crate::QDate), [`QTime`](crate::QTime), and [`QDateTime`](crate::QDateTime) objects to strings.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QLocaleFormatType {
+        /// Longer format.
+        LongFormat,
+        /// Shorter format.
+        ShortFormat,
+        /// An even shorter format that emphasizes brevity over clarity. This may produce the same output as [`ShortFormat`](Self::ShortFormat).
+        NarrowFormat,
+    }
+
+    /// Specifies the format of the currency symbol.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QLocaleCurrencySymbolFormat {
+        /// A ISO-4217 code of the currency.
+        CurrencyIsoCode,
+        /// A currency symbol.
+        CurrencySymbol,
+        /// A user readable name of the currency.
+        CurrencyDisplayName,
+    }
+
+    /// Specifies the format for representation of data quantities.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QLocaleDataSizeFormat {
+        /// Format based on powers of 1024 and IEC prefixes: KiB, MiB, etc.
+        DataSizeIecFormat = 0,
+        /// Use base 1000 instead of base 1024.
+        DataSizeBase1000 = 1,
+        /// Use SI quantifiers: k, M, etc. This is also the traditional format: powers of 1024 with SI prefixes.
+        DataSizeSIQuantifiers = 2,
+        /// Format based on powers of 1000 and SI prefixes: kB, MB, etc.
+        DataSizeSIFormat = 3,
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qlocale.h");
+        type QLocaleFormatType;
+        type QLocaleCurrencySymbolFormat;
+        type QLocaleDataSizeFormat;
+        type QLocaleDataSizeFormats = super::QLocaleDataSizeFormats;
+    }
+
+    #[namespace = "Qt"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qt.h");
+        type LayoutDirection = crate::LayoutDirection;
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qdate.h");
+        type QDate = crate::QDate;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+        include!("cxx-qt-lib/qstringlist.h");
+        type QStringList = crate::QStringList;
+        include!("cxx-qt-lib/qtime.h");
+        type QTime = crate::QTime;
+        include!("cxx-qt-lib/qtypes.h");
+        type qint64 = crate::qint64;
+        type quint64 = crate::quint64;
+    }
+
+    #[cfg(not(target_os = "emscripten"))]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qdatetime.h");
+        type QDateTime = crate::QDateTime;
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qlocale.h");
+        type QLocale = super::QLocale;
+
+        /// Returns the BCP47 field names joined with dashes.
+        ///
+        /// This combines as many of language, script and territory (and possibly other BCP47 fields) for this locale as are needed to uniquely specify it. Note that fields may be omitted if the Unicode consortium's Likely Subtag Rules imply the omitted fields when given those retained.
+        #[rust_name = "bcp47_name"]
+        fn bcp47Name(self: &QLocale) -> QString;
+
+        /// Returns a string that represents a join of a given list of strings with a separator defined by the locale.
+        #[rust_name = "create_separated_list"]
+        fn createSeparatedList(self: &QLocale, list: &QStringList) -> QString;
+
+        /// Returns a currency symbol according to the `format`.
+        #[rust_name = "currency_symbol"]
+        fn currencySymbol(self: &QLocale, format: QLocaleCurrencySymbolFormat) -> QString;
+
+        /// Returns the date format used for the current locale.
+        ///
+        /// If `format` is [`QLocaleFormatType::LongFormat`], the format will be elaborate, otherwise it will be short. For example, `LongFormat` for the `en_US` locale is `"dddd, MMMM d, yyyy"`, `ShortFormat` is `"M/d/yy"`.
+        #[rust_name = "date_format"]
+        fn dateFormat(self: &QLocale, format: QLocaleFormatType) -> QString;
+
+        /// Returns the date time format used for the current locale.
+        #[rust_name = "date_time_format"]
+        fn dateTimeFormat(self: &QLocale, format: QLocaleFormatType) -> QString;
+
+        /// The short name of this locale.
+        ///
+        /// Returns the language and territory of this locale as a string of the form `"language_territory"`, where language is a lowercase, two-letter ISO 639 language code, and territory is an uppercase, two- or three-letter ISO 3166 territory code. If the locale has no specified territory, only the language name is returned.
+        fn name(self: &QLocale) -> QString;
+
+        /// Returns a native name of the language for the locale. For example `"Schweizer Hochdeutsch"` for the Swiss-German locale.
+        #[rust_name = "native_language_name"]
+        fn nativeLanguageName(self: &QLocale) -> QString;
+
+        /// Returns the text direction of the language.
+        #[rust_name = "text_direction"]
+        fn textDirection(self: &QLocale) -> LayoutDirection;
+
+        /// Returns the time format used for the current locale.
+        #[rust_name = "time_format"]
+        fn timeFormat(self: &QLocale, format: QLocaleFormatType) -> QString;
+
+        #[doc(hidden)]
+        #[rust_name = "to_currency_string_qint64"]
+        fn toCurrencyString(self: &QLocale, value: qint64, symbol: &QString) -> QString;
+
+        /// Returns a localized string representation of `value` as a currency. If the `symbol` is provided it is used instead of the default currency symbol. The `precision` is the number of decimal places; if it is -1, the locale's default currency precision is used.
+        #[rust_name = "to_currency_string_f64"]
+        fn toCurrencyString(
+            self: &QLocale,
+            value: f64,
+            symbol: &QString,
+            precision: i32,
+        ) -> QString;
+
+        /// Returns a lowercase copy of `string`.
+        ///
+        /// If Qt Core is using the ICU libraries, they will be used to perform the transformation according to the rules of the current locale. Otherwise the conversion may be done in a platform-dependent manner, with [`QString::to_lower`](crate::QString::to_lower) as a generic fallback.
+        #[rust_name = "to_lower"]
+        fn toLower(self: &QLocale, string: &QString) -> QString;
+
+        #[doc(hidden)]
+        #[rust_name = "to_string_qint64"]
+        fn toString(self: &QLocale, value: qint64) -> QString;
+
+        #[doc(hidden)]
+        #[rust_name = "to_string_quint64"]
+        fn toString(self: &QLocale, value: quint64) -> QString;
+
+        #[doc(hidden)]
+        #[rust_name = "to_string_f64_c_char"]
+        fn toString(self: &QLocale, value: f64, format: c_char, precision: i32) -> QString;
+
+        /// Returns an uppercase copy of `string`.
+        ///
+        /// If Qt Core is using the ICU libraries, they will be used to perform the transformation according to the rules of the current locale. Otherwise the conversion may be done in a platform-dependent manner, with [`QString::to_upper`](crate::QString::to_upper) as a generic fallback.
+        #[rust_name = "to_upper"]
+        fn toUpper(self: &QLocale, string: &QString) -> QString;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qlocale_formatted_data_size"]
+        fn qlocaleFormattedDataSize(
+            locale: &QLocale,
+            bytes: qint64,
+            precision: i32,
+            format: QLocaleDataSizeFormats,
+        ) -> QString;
+        #[doc(hidden)]
+        #[rust_name = "qlocale_native_territory_name"]
+        fn qlocaleNativeTerritoryName(locale: &QLocale) -> QString;
+        #[doc(hidden)]
+        #[rust_name = "qlocale_ui_languages"]
+        fn qlocaleUiLanguages(locale: &QLocale) -> QStringList;
+
+        #[doc(hidden)]
+        #[rust_name = "qlocale_to_i64"]
+        fn qlocaleToI64(locale: &QLocale, string: &QString, ok: &mut bool) -> qint64;
+        #[doc(hidden)]
+        #[rust_name = "qlocale_to_u64"]
+        fn qlocaleToU64(locale: &QLocale, string: &QString, ok: &mut bool) -> quint64;
+        #[doc(hidden)]
+        #[rust_name = "qlocale_to_f64"]
+        fn qlocaleToF64(locale: &QLocale, string: &QString, ok: &mut bool) -> f64;
+
+        #[doc(hidden)]
+        #[rust_name = "qlocale_to_date"]
+        fn qlocaleToDate(locale: &QLocale, string: &QString, format: QLocaleFormatType) -> QDate;
+        #[doc(hidden)]
+        #[rust_name = "qlocale_to_date_with_format"]
+        fn qlocaleToDateWithFormat(locale: &QLocale, string: &QString, format: &QString) -> QDate;
+        #[doc(hidden)]
+        #[rust_name = "qlocale_to_time"]
+        fn qlocaleToTime(locale: &QLocale, string: &QString, format: QLocaleFormatType) -> QTime;
+        #[doc(hidden)]
+        #[rust_name = "qlocale_to_time_with_format"]
+        fn qlocaleToTimeWithFormat(locale: &QLocale, string: &QString, format: &QString) -> QTime;
+
+        #[doc(hidden)]
+        #[rust_name = "qlocale_to_string_date"]
+        fn qlocaleToStringDate(
+            locale: &QLocale,
+            date: &QDate,
+            format: QLocaleFormatType,
+        ) -> QString;
+        #[doc(hidden)]
+        #[rust_name = "qlocale_to_string_date_with_format"]
+        fn qlocaleToStringDateWithFormat(
+            locale: &QLocale,
+            date: &QDate,
+            format: &QString,
+        ) -> QString;
+        #[doc(hidden)]
+        #[rust_name = "qlocale_to_string_time"]
+        fn qlocaleToStringTime(
+            locale: &QLocale,
+            time: &QTime,
+            format: QLocaleFormatType,
+        ) -> QString;
+        #[doc(hidden)]
+        #[rust_name = "qlocale_to_string_time_with_format"]
+        fn qlocaleToStringTimeWithFormat(
+            locale: &QLocale,
+            time: &QTime,
+            format: &QString,
+        ) -> QString;
+    }
+
+    #[cfg(not(target_os = "emscripten"))]
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qlocale_to_date_time"]
+        fn qlocaleToDateTime(
+            locale: &QLocale,
+            string: &QString,
+            format: QLocaleFormatType,
+        ) -> QDateTime;
+        #[doc(hidden)]
+        #[rust_name = "qlocale_to_date_time_with_format"]
+        fn qlocaleToDateTimeWithFormat(
+            locale: &QLocale,
+            string: &QString,
+            format: &QString,
+        ) -> QDateTime;
+        #[doc(hidden)]
+        #[rust_name = "qlocale_to_string_date_time"]
+        fn qlocaleToStringDateTime(
+            locale: &QLocale,
+            date_time: &QDateTime,
+            format: QLocaleFormatType,
+        ) -> QString;
+        #[doc(hidden)]
+        #[rust_name = "qlocale_to_string_date_time_with_format"]
+        fn qlocaleToStringDateTimeWithFormat(
+            locale: &QLocale,
+            date_time: &QDateTime,
+            format: &QString,
+        ) -> QString;
+    }
+
+    // These are all static, so we need to create bindings until CXX supports statics
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qlocale_c"]
+        fn qlocaleC() -> QLocale;
+        #[doc(hidden)]
+        #[rust_name = "qlocale_set_default"]
+        fn qlocaleSetDefault(locale: &QLocale);
+        #[doc(hidden)]
+        #[rust_name = "qlocale_system"]
+        fn qlocaleSystem() -> QLocale;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/common.h");
+
+        #[doc(hidden)]
+        #[rust_name = "qlocale_drop"]
+        fn drop(locale: &mut QLocale);
+
+        #[doc(hidden)]
+        #[rust_name = "qlocale_init_default"]
+        fn construct() -> QLocale;
+        #[doc(hidden)]
+        #[rust_name = "qlocale_init_from_qlocale"]
+        fn construct(locale: &QLocale) -> QLocale;
+        #[doc(hidden)]
+        #[rust_name = "qlocale_init_from_qstring"]
+        fn construct(name: &QString) -> QLocale;
+
+        #[doc(hidden)]
+        #[rust_name = "qlocale_eq"]
+        fn operatorEq(a: &QLocale, b: &QLocale) -> bool;
+
+        #[doc(hidden)]
+        #[rust_name = "qlocale_to_debug_qstring"]
+        fn toDebugQString(locale: &QLocale) -> QString;
+    }
+}
+
+pub use ffi::{QLocaleCurrencySymbolFormat, QLocaleDataSizeFormat, QLocaleFormatType};
+
+/// [`QFlags`] of [`QLocaleDataSizeFormat`].
+pub type QLocaleDataSizeFormats = QFlags<QLocaleDataSizeFormat>;
+unsafe_impl_qflag!(
+    QLocaleDataSizeFormat,
+    "rust::cxxqtlib1::QLocaleDataSizeFormats",
+    i32
+);
+
+/// The `QLocale` class converts between numbers and their string representations in various languages.
+///
+/// Qt Documentation: [QLocale](https://doc.qt.io/qt/qlocale.html#details)
+#[repr(C)]
+pub struct QLocale {
+    _space: MaybeUninit<usize>,
+}
+
+impl QLocale {
+    /// Returns a `QLocale` object initialized to the "C" locale.
+    ///
+    /// This locale is based on en_US but with various quirks of its own, such as simplified number formatting and its own date formatting. It implements the POSIX standards that describe the behavior of standard library functions of the "C" programming language.
+    pub fn c() -> Self {
+        ffi::qlocale_c()
+    }
+
+    /// Constructs a `QLocale` object with the specified `name`.
+    ///
+    /// The name has the format `"language[_script][_territory][.codeset][@modifier]"` or `"C"`. If the string violates the locale format, or language is not a valid ISO 639 code, the "C" locale is used instead. If territory is not present, or is not a valid ISO 3166 code, the most appropriate territory is chosen for the specified language.
+    pub fn from_name(name: &QString) -> Self {
+        ffi::qlocale_init_from_qstring(name)
+    }
+
+    /// Returns a string representing the data size `bytes` in a human-readable format, with `precision` decimal places.
+    ///
+    /// For example, 1234567 bytes is formatted as `"1.18 MiB"` with [`QLocaleDataSizeFormat::DataSizeIecFormat`] and a precision of 2.
+    pub fn formatted_data_size(
+        &self,
+        bytes: i64,
+        precision: i32,
+        format: QLocaleDataSizeFormats,
+    ) -> QString {
+        ffi::qlocale_formatted_data_size(self, bytes.into(), precision, format)
+    }
+
+    /// Returns a native name of the territory for the locale. For example `"España"` for the Spanish/Spain locale.
+    pub fn native_territory_name(&self) -> QString {
+        ffi::qlocale_native_territory_name(self)
+    }
+
+    /// Sets the global default locale to `locale`.
+    ///
+    /// This also determines the default locale used by `QString::arg()`. This function does not change the locale used by the system.
+    ///
+    /// **Warning:** In a multithreaded application, the default locale should be set at application startup, before any non-GUI threads are created.
+    pub fn set_default(locale: &Self) {
+        ffi::qlocale_set_default(locale)
+    }
+
+    /// Returns a `QLocale` object initialized to the system locale.
+    ///
+    /// The system locale may use system-specific sources for locale data, where available, otherwise falling back on QLocale's built-in database entry for the language, script and territory the system reports.
+    pub fn system() -> Self {
+        ffi::qlocale_system()
+    }
+
+    /// Returns a localized string representation of `value` as a currency. If the `symbol` is provided it is used instead of the default currency symbol.
+    pub fn to_currency_string_i64(&self, value: i64, symbol: &QString) -> QString {
+        self.to_currency_string_qint64(value.into(), symbol)
+    }
+
+    /// Returns the date represented by the localized `string`, using the date format `format`, or `None` if the string could not be parsed.
+    pub fn to_date(&self, string: &QString, format: QLocaleFormatType) -> Option<QDate> {
+        let date = ffi::qlocale_to_date(self, string, format);
+        if date.is_valid() {
+            Some(date)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the date represented by the localized `string`, using the given `format`, or `None` if the string could not be parsed. See [`QDate::from_string`] for the format syntax.
+    pub fn to_date_with_format(&self, string: &QString, format: &QString) -> Option<QDate> {
+        let date = ffi::qlocale_to_date_with_format(self, string, format);
+        if date.is_valid() {
+            Some(date)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the date time represented by the localized `string`, using the format `format`, or `None` if the string could not be parsed.
+    #[cfg(not(target_os = "emscripten"))]
+    pub fn to_date_time(&self, string: &QString, format: QLocaleFormatType) -> Option<QDateTime> {
+        let date_time = ffi::qlocale_to_date_time(self, string, format);
+        if date_time.is_valid() {
+            Some(date_time)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the date time represented by the localized `string`, using the given `format`, or `None` if the string could not be parsed.
+    #[cfg(not(target_os = "emscripten"))]
+    pub fn to_date_time_with_format(
+        &self,
+        string: &QString,
+        format: &QString,
+    ) -> Option<QDateTime> {
+        let date_time = ffi::qlocale_to_date_time_with_format(self, string, format);
+        if date_time.is_valid() {
+            Some(date_time)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the `f64` represented by the localized `string`, or `None` if the conversion fails.
+    ///
+    /// This function ignores leading and trailing whitespace.
+    pub fn to_f64(&self, string: &QString) -> Option<f64> {
+        let mut ok = false;
+        let value = ffi::qlocale_to_f64(self, string, &mut ok);
+        if ok {
+            Some(value)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the `i64` represented by the localized `string`, or `None` if the conversion fails.
+    ///
+    /// This function ignores leading and trailing whitespace.
+    pub fn to_i64(&self, string: &QString) -> Option<i64> {
+        let mut ok = false;
+        let value = ffi::qlocale_to_i64(self, string, &mut ok);
+        if ok {
+            Some(value.into())
+        } else {
+            None
+        }
+    }
+
+    /// Returns a localized string representation of the given `date` in the specified `format`.
+    pub fn to_string_date(&self, date: &QDate, format: QLocaleFormatType) -> QString {
+        ffi::qlocale_to_string_date(self, date, format)
+    }
+
+    /// Returns a localized string representation of the given `date` according to the specified `format`. See [`QDate::format`] for the format syntax.
+    pub fn to_string_date_with_format(&self, date: &QDate, format: &QString) -> QString {
+        ffi::qlocale_to_string_date_with_format(self, date, format)
+    }
+
+    /// Returns a localized string representation of the given `date_time` in the specified `format`.
+    #[cfg(not(target_os = "emscripten"))]
+    pub fn to_string_date_time(&self, date_time: &QDateTime, format: QLocaleFormatType) -> QString {
+        ffi::qlocale_to_string_date_time(self, date_time, format)
+    }
+
+    /// Returns a localized string representation of the given `date_time` according to the specified `format`.
+    #[cfg(not(target_os = "emscripten"))]
+    pub fn to_string_date_time_with_format(
+        &self,
+        date_time: &QDateTime,
+        format: &QString,
+    ) -> QString {
+        ffi::qlocale_to_string_date_time_with_format(self, date_time, format)
+    }
+
+    /// Returns a string representing the floating-point number `value`.
+    ///
+    /// The `format` is one of `'e'`, `'E'`, `'f'`, `'g'` or `'G'`, with the same meaning as for `QString::number()`. The `precision` is the number of digits after the decimal point for `'e'`, `'E'` and `'f'`, or the maximum number of significant digits for `'g'` and `'G'`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `format` is not an ASCII character.
+    pub fn to_string_f64(&self, value: f64, format: char, precision: i32) -> QString {
+        assert!(format.is_ascii(), "format must be an ASCII character");
+        self.to_string_f64_c_char(value, format as c_char, precision)
+    }
+
+    /// Returns a localized string representation of `value`.
+    pub fn to_string_i64(&self, value: i64) -> QString {
+        self.to_string_qint64(value.into())
+    }
+
+    /// Returns a localized string representation of the given `time` in the specified `format`.
+    pub fn to_string_time(&self, time: &QTime, format: QLocaleFormatType) -> QString {
+        ffi::qlocale_to_string_time(self, time, format)
+    }
+
+    /// Returns a localized string representation of the given `time` according to the specified `format`. See [`QTime::format`] for the format syntax.
+    pub fn to_string_time_with_format(&self, time: &QTime, format: &QString) -> QString {
+        ffi::qlocale_to_string_time_with_format(self, time, format)
+    }
+
+    /// Returns a localized string representation of `value`.
+    pub fn to_string_u64(&self, value: u64) -> QString {
+        self.to_string_quint64(value.into())
+    }
+
+    /// Returns the time represented by the localized `string`, using the time format `format`, or `None` if the string could not be parsed.
+    pub fn to_time(&self, string: &QString, format: QLocaleFormatType) -> Option<QTime> {
+        let time = ffi::qlocale_to_time(self, string, format);
+        if time.is_valid() {
+            Some(time)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the time represented by the localized `string`, using the given `format`, or `None` if the string could not be parsed.
+    pub fn to_time_with_format(&self, string: &QString, format: &QString) -> Option<QTime> {
+        let time = ffi::qlocale_to_time_with_format(self, string, format);
+        if time.is_valid() {
+            Some(time)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the `u64` represented by the localized `string`, or `None` if the conversion fails.
+    ///
+    /// This function ignores leading and trailing whitespace.
+    pub fn to_u64(&self, string: &QString) -> Option<u64> {
+        let mut ok = false;
+        let value = ffi::qlocale_to_u64(self, string, &mut ok);
+        if ok {
+            Some(value.into())
+        } else {
+            None
+        }
+    }
+
+    /// List of locale names for use in selecting translations.
+    ///
+    /// Each entry in the returned list is the name of a locale suitable to the user's preferences for what to translate the UI into. The first item in the list is the most preferred one.
+    pub fn ui_languages(&self) -> QStringList {
+        ffi::qlocale_ui_languages(self)
+    }
+}
+
+impl Clone for QLocale {
+    /// Constructs a `QLocale` object as a copy of `self`.
+    fn clone(&self) -> Self {
+        ffi::qlocale_init_from_qlocale(self)
+    }
+}
+
+impl Default for QLocale {
+    /// Constructs a `QLocale` object initialized with the default locale.
+    ///
+    /// If no default locale was set using [`set_default`](Self::set_default), this locale will be the same as the one returned by [`system`](Self::system).
+    fn default() -> Self {
+        ffi::qlocale_init_default()
+    }
+}
+
+impl std::cmp::PartialEq for QLocale {
+    /// Returns `true` if the `QLocale` object is the same as the `other` locale specified; otherwise returns `false`.
+    fn eq(&self, other: &Self) -> bool {
+        ffi::qlocale_eq(self, other)
+    }
+}
+
+impl std::cmp::Eq for QLocale {}
+
+impl fmt::Debug for QLocale {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        ffi::qlocale_to_debug_qstring(self).fmt(f)
+    }
+}
+
+impl Drop for QLocale {
+    /// Destroys the locale.
+    fn drop(&mut self) {
+        ffi::qlocale_drop(self)
+    }
+}
+
+impl From<&QString> for QLocale {
+    /// Constructs a `QLocale` object with the specified `name`. See [`from_name`](Self::from_name).
+    fn from(name: &QString) -> Self {
+        Self::from_name(name)
+    }
+}
+
+// Safety:
+//
+// Static checks on the C++ side to ensure the size is the same.
+unsafe impl ExternType for QLocale {
+    type Id = type_id!("QLocale");
+    type Kind = cxx::kind::Trivial;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn qlocale_numbers() {
+        let locale = QLocale::from_name(&QString::from("en_US"));
+        assert_eq!(locale.to_string_i64(-1234567).to_string(), "-1,234,567");
+        assert_eq!(locale.to_string_u64(1234567).to_string(), "1,234,567");
+        assert_eq!(locale.to_string_f64(1234.5, 'f', 2).to_string(), "1,234.50");
+        assert_eq!(locale.to_i64(&QString::from("1,234")), Some(1234));
+        assert_eq!(locale.to_u64(&QString::from("-1")), None);
+        assert_eq!(locale.to_f64(&QString::from("2.5")), Some(2.5));
+
+        let german = QLocale::from_name(&QString::from("de_DE"));
+        assert_eq!(german.to_string_f64(1.5, 'f', 1).to_string(), "1,5");
+        assert_eq!(german.to_f64(&QString::from("1,5")), Some(1.5));
+    }
+
+    #[test]
+    fn qlocale_formatted_data_size() {
+        let locale = QLocale::c();
+        assert_eq!(
+            locale
+                .formatted_data_size(1024, 2, QLocaleDataSizeFormats::default())
+                .to_string(),
+            "1.00 KiB"
+        );
+        assert_eq!(
+            locale
+                .formatted_data_size(1000, 1, QLocaleDataSizeFormat::DataSizeSIFormat.into())
+                .to_string(),
+            "1.0 kB"
+        );
+    }
+
+    #[test]
+    fn qlocale_dates() {
+        let locale = QLocale::c();
+        let date = QDate::new(2025, 3, 4);
+        let format = QString::from("yyyy-MM-dd");
+        let formatted = locale.to_string_date_with_format(&date, &format);
+        assert_eq!(formatted.to_string(), "2025-03-04");
+        assert_eq!(locale.to_date_with_format(&formatted, &format), Some(date));
+        assert_eq!(
+            locale.to_date_with_format(&QString::from("not a date"), &format),
+            None
+        );
+    }
+}
@@ -4,6 +4,7 @@
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
 use cxx_qt::casting::Upcast;
+use cxx_qt::MoveToThread;
 pub use cxx_qt::QObject;
 use std::pin::Pin;
 use std::ptr;
@@ -13,6 +14,8 @@ pub mod ffi {
     unsafe extern "C++" {
         include!("cxx-qt-lib/qstring.h");
         type QString = crate::QString;
+        include!("cxx-qt-lib/qthread.h");
+        type QThread = crate::QThread;
     }
 
     unsafe extern "C++" {
@@ -35,10 +38,22 @@ pub mod ffi {
 
         #[rust_name = "set_parent"]
         pub unsafe fn setParent(self: Pin<&mut Self>, parent: *mut QObjectExternal);
+
+        pub fn thread(&self) -> *mut QThread;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qobject_move_to_thread"]
+        unsafe fn qobjectMoveToThread(
+            object: Pin<&mut QObjectExternal>,
+            thread: *mut QThread,
+        ) -> bool;
     }
 }
 
-use ffi::{QObjectExternal, QString};
+use ffi::{QObjectExternal, QString, QThread};
 
 /// Trait which exposes methods available on a `QObject`.
 /// Exposes some basic signals and methods for now, more to be added.
@@ -71,6 +86,26 @@ pub trait QObjectExt {
 
     /// Makes the object parentless.
     fn unset_parent(self: Pin<&mut Self>);
+
+    /// Returns the thread in which the object lives.
+    fn thread(&self) -> *mut QThread;
+
+    /// Changes the thread affinity for this object and its children and returns `true` on success. The object cannot be moved if it has a parent. Event processing will continue in the `target_thread`.
+    ///
+    /// This function can only push an object from the current thread to another thread, it cannot pull an object from any arbitrary thread to the current thread.
+    ///
+    /// The object must implement [`MoveToThread`], which CXX-Qt implements automatically when the Rust struct backing the object is [`Send`].
+    ///
+    /// See [`QThread::move_object`](crate::QThread::move_object) for a safe alternative, which takes ownership of the object and returns a [`CxxQtThread`](cxx_qt::CxxQtThread) for queueing closures onto it.
+    ///
+    /// # Safety
+    ///
+    /// If this function returns `true`, the object must not be accessed from the current thread anymore,
+    /// as its slots and queued closures now run in `target_thread`. Any further access, including
+    /// destroying the object, must happen in `target_thread`, for example from a closure queued with a [`CxxQtThread`](cxx_qt::CxxQtThread).
+    unsafe fn move_to_thread(self: Pin<&mut Self>, target_thread: Pin<&mut QThread>) -> bool
+    where
+        Self: MoveToThread;
 }
 
 /// Used to convert the QObject type from the library type to the C++ type, as a pin
@@ -121,4 +156,16 @@ where
     fn unset_parent(self: Pin<&mut Self>) {
         unsafe { cast_pin(self.upcast_pin()).set_parent(ptr::null_mut()) }
     }
+
+    fn thread(&self) -> *mut QThread {
+        cast(self.upcast()).thread()
+    }
+
+    unsafe fn move_to_thread(self: Pin<&mut Self>, target_thread: Pin<&mut QThread>) -> bool
+    where
+        Self: MoveToThread,
+    {
+        let target_thread = unsafe { target_thread.get_unchecked_mut() };
+        unsafe { ffi::qobject_move_to_thread(cast_pin(self.upcast_pin()), target_thread) }
+    }
 }
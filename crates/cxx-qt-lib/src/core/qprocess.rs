@@ -0,0 +1,449 @@
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Joshua Booth <joshua.n.booth@gmail.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::{QIODevice, QIODeviceOpenModeFlag, QString, QStringList};
+use core::pin::Pin;
+use cxx_qt::casting::Upcast;
+use std::io;
+
+#[cxx_qt::bridge]
+mod ffi {
+    /// This enum describes the different types of errors that are reported by [`QProcess`].
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QProcessError {
+        /// The process failed to start. Either the invoked program is missing, or you may have insufficient permissions or resources to invoke the program.
+        FailedToStart,
+        /// The process crashed some time after starting successfully.
+        Crashed,
+        /// The last `wait_for...` function timed out. The state of [`QProcess`] is unchanged, and you can try calling `wait_for...` again.
+        Timedout,
+        /// An error occurred when attempting to read from the process. For example, the process may not be running.
+        ReadError,
+        /// An error occurred when attempting to write to the process. For example, the process may not be running, or it may have closed its input channel.
+        WriteError,
+        /// An unknown error occurred. This is the default return value of [`QProcess::error`].
+        UnknownError,
+    }
+
+    /// This enum describes the different exit statuses of [`QProcess`].
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QProcessExitStatus {
+        /// The process exited normally.
+        NormalExit,
+        /// The process crashed.
+        CrashExit,
+    }
+
+    /// This enum describes the process input channel modes of [`QProcess`].
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QProcessInputChannelMode {
+        /// [`QProcess`] manages the input of the running process. This is the default input channel mode of `QProcess`.
+        ManagedInputChannel,
+        /// [`QProcess`] forwards the input of the main process onto the running process. The child process reads its standard input from the same source as the main process.
+        ForwardedInputChannel,
+    }
+
+    /// This enum describes the process output channels used by the running process.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QProcessChannel {
+        /// The standard output (stdout) of the running process.
+        StandardOutput,
+        /// The standard error (stderr) of the running process.
+        StandardError,
+    }
+
+    /// This enum describes the process output channel modes of [`QProcess`].
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QProcessChannelMode {
+        /// [`QProcess`] manages the output of the running process, keeping standard output and standard error data in separate internal buffers. This is the default channel mode of `QProcess`.
+        SeparateChannels,
+        /// [`QProcess`] merges the output of the running process into the standard output channel (stdout). The standard error channel (stderr) will not receive any data.
+        MergedChannels,
+        /// [`QProcess`] forwards the output of the running process onto the main process. Anything the child process writes to its standard output and standard error will be written to the standard output and standard error of the main process.
+        ForwardedChannels,
+        /// [`QProcess`] manages the standard error of the running process, but forwards its standard output onto the main process.
+        ForwardedOutputChannel,
+        /// [`QProcess`] manages the standard output of the running process, but forwards its standard error onto the main process.
+        ForwardedErrorChannel,
+    }
+
+    /// This enum describes the different states of [`QProcess`].
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QProcessState {
+        /// The process is not running.
+        NotRunning,
+        /// The process is starting, but the program has not yet been invoked.
+        Starting,
+        /// The process is running and is ready for reading and writing.
+        Running,
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qprocess.h");
+        type QProcessError;
+        type QProcessExitStatus;
+        type QProcessInputChannelMode;
+        type QProcessChannel;
+        type QProcessChannelMode;
+        type QProcessState;
+
+        include!("cxx-qt-lib/qiodevice.h");
+        type QIODeviceOpenMode = crate::QIODeviceOpenMode;
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qbytearray.h");
+        type QByteArray = crate::QByteArray;
+        include!("cxx-qt-lib/qiodevice.h");
+        type QIODevice = crate::QIODevice;
+        include!("cxx-qt-lib/qprocessenvironment.h");
+        type QProcessEnvironment = crate::QProcessEnvironment;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+        include!("cxx-qt-lib/qstringlist.h");
+        type QStringList = crate::QStringList;
+        include!("cxx-qt-lib/qtypes.h");
+        type qint64 = crate::qint64;
+    }
+
+    unsafe extern "C++Qt" {
+        /// The `QProcess` class is used to start external programs and to communicate with them.
+        ///
+        /// A pinned `QProcess` implements [`std::io::Read`] and [`std::io::Write`] through its [`QIODevice`] base, reading from the current [`read_channel`](Self::read_channel) and writing to the standard input of the process.
+        ///
+        /// Qt Documentation: [QProcess](https://doc.qt.io/qt/qprocess.html#details)
+        #[qobject]
+        #[base = QIODevice]
+        type QProcess;
+
+        /// Returns the command line arguments the process was last started with.
+        fn arguments(self: &QProcess) -> QStringList;
+
+        /// Closes the read channel `channel`. After calling this function, [`QProcess`] will no longer receive data on the channel. Any data that has already been received is still available for reading.
+        ///
+        /// Call this function to save memory, if you are not interested in the output of the process.
+        #[rust_name = "close_read_channel"]
+        fn closeReadChannel(self: Pin<&mut QProcess>, channel: QProcessChannel);
+
+        /// Schedules the write channel of [`QProcess`] to be closed. The channel will close once all data has been written to the process. After calling this function, any attempts to write to the process will fail.
+        ///
+        /// Closing the write channel is necessary for programs that read input data until the channel has been closed.
+        #[rust_name = "close_write_channel"]
+        fn closeWriteChannel(self: Pin<&mut QProcess>);
+
+        /// Returns the type of error that occurred last.
+        fn error(self: &QProcess) -> QProcessError;
+
+        /// Returns the exit code of the last process that finished.
+        ///
+        /// This value is not valid unless [`exit_status`](Self::exit_status) returns [`QProcessExitStatus::NormalExit`].
+        #[rust_name = "exit_code"]
+        fn exitCode(self: &QProcess) -> i32;
+
+        /// Returns the exit status of the last process that finished.
+        ///
+        /// On Windows, if the process was terminated with `TerminateProcess()` from another application, this function will still return [`QProcessExitStatus::NormalExit`] unless the exit code is less than 0.
+        #[rust_name = "exit_status"]
+        fn exitStatus(self: &QProcess) -> QProcessExitStatus;
+
+        /// Returns the channel mode of the [`QProcess`] standard input channel.
+        #[rust_name = "input_channel_mode"]
+        fn inputChannelMode(self: &QProcess) -> QProcessInputChannelMode;
+
+        /// Kills the current process, causing it to exit immediately.
+        ///
+        /// On Windows, `kill` uses `TerminateProcess`, and on Unix and macOS, the `SIGKILL` signal is sent to the process.
+        fn kill(self: Pin<&mut QProcess>);
+
+        /// Returns the channel mode of the [`QProcess`] standard output and standard error channels.
+        #[rust_name = "process_channel_mode"]
+        fn processChannelMode(self: &QProcess) -> QProcessChannelMode;
+
+        /// Returns the environment that [`QProcess`] will pass to its child process. If no environment was set using [`set_process_environment`](Self::set_process_environment), this method returns an object indicating that the environment will be inherited from the parent.
+        #[rust_name = "process_environment"]
+        fn processEnvironment(self: &QProcess) -> QProcessEnvironment;
+
+        #[doc(hidden)]
+        #[rust_name = "process_id_qint64"]
+        fn processId(self: &QProcess) -> qint64;
+
+        /// Returns the program the process was last started with.
+        fn program(self: &QProcess) -> QString;
+
+        /// This function returns all data available from the standard error of the process.
+        #[rust_name = "read_all_standard_error"]
+        fn readAllStandardError(self: Pin<&mut QProcess>) -> QByteArray;
+
+        /// This function returns all data available from the standard output of the process.
+        #[rust_name = "read_all_standard_output"]
+        fn readAllStandardOutput(self: Pin<&mut QProcess>) -> QByteArray;
+
+        /// Returns the current read channel of the [`QProcess`].
+        #[rust_name = "read_channel"]
+        fn readChannel(self: &QProcess) -> QProcessChannel;
+
+        /// Set the `arguments` to pass to the called program when starting the process. This function must be called before [`start`](Self::start).
+        #[rust_name = "set_arguments"]
+        fn setArguments(self: Pin<&mut QProcess>, arguments: &QStringList);
+
+        /// Sets the channel mode of the [`QProcess`] standard input channel to the `mode` specified. This mode will be used the next time [`start`](Self::start) is called.
+        #[rust_name = "set_input_channel_mode"]
+        fn setInputChannelMode(self: Pin<&mut QProcess>, mode: QProcessInputChannelMode);
+
+        /// Sets the channel mode of the [`QProcess`] standard output and standard error channels to the `mode` specified. This mode will be used the next time [`start`](Self::start) is called.
+        #[rust_name = "set_process_channel_mode"]
+        fn setProcessChannelMode(self: Pin<&mut QProcess>, mode: QProcessChannelMode);
+
+        /// Sets the `environment` that [`QProcess`] will pass to the child process.
+        #[rust_name = "set_process_environment"]
+        fn setProcessEnvironment(self: Pin<&mut QProcess>, environment: &QProcessEnvironment);
+
+        /// Set the `program` to use when starting the process. This function must be called before [`start`](Self::start).
+        ///
+        /// If `program` is an absolute path, it specifies the exact executable that will be launched. Relative paths will be resolved in a platform-specific manner.
+        #[rust_name = "set_program"]
+        fn setProgram(self: Pin<&mut QProcess>, program: &QString);
+
+        /// Sets the current read channel of the [`QProcess`] to the given `channel`. The current input channel is used by the [`std::io::Read`] implementation and by [`QIODevice::read_all`].
+        #[rust_name = "set_read_channel"]
+        fn setReadChannel(self: Pin<&mut QProcess>, channel: QProcessChannel);
+
+        /// Sets the working directory to `dir`. [`QProcess`] will start the process in this directory. The default behavior is to start the process in the working directory of the calling process.
+        #[rust_name = "set_working_directory"]
+        fn setWorkingDirectory(self: Pin<&mut QProcess>, dir: &QString);
+
+        /// Starts the given `program` in a new process, passing the command line arguments in `arguments`, and opens the [`QProcess`] in the given `mode`.
+        ///
+        /// The [`QProcess`] object will immediately enter the [`QProcessState::Starting`] state. If the process starts successfully, `QProcess` will emit [`started`](Self::started); otherwise, [`error_occurred`](Self::error_occurred) will be emitted.
+        #[rust_name = "start_with_mode"]
+        fn start(
+            self: Pin<&mut QProcess>,
+            program: &QString,
+            arguments: &QStringList,
+            mode: QIODeviceOpenMode,
+        );
+
+        /// Returns the current state of the process.
+        fn state(self: &QProcess) -> QProcessState;
+
+        /// Attempts to terminate the process.
+        ///
+        /// The process may not exit as a result of calling this function (it is given the chance to prompt the user for any unsaved files, etc).
+        ///
+        /// On Windows, `terminate` posts a `WM_CLOSE` message to all top-level windows of the process and then to the main thread of the process itself. On Unix and macOS the `SIGTERM` signal is sent.
+        fn terminate(self: Pin<&mut QProcess>);
+
+        /// Blocks until the process has finished and the [`finished`](Self::finished) signal has been emitted, or until `msecs` milliseconds have passed.
+        ///
+        /// Returns `true` if the process finished; otherwise returns `false` (if the operation timed out, if an error occurred, or if this [`QProcess`] is already finished).
+        ///
+        /// If `msecs` is -1, this function will not time out.
+        #[rust_name = "wait_for_finished"]
+        fn waitForFinished(self: Pin<&mut QProcess>, msecs: i32) -> bool;
+
+        /// Blocks until the process has started and the [`started`](Self::started) signal has been emitted, or until `msecs` milliseconds have passed.
+        ///
+        /// Returns `true` if the process was started successfully; otherwise returns `false` (if the operation timed out or if an error occurred). If the process had already started successfully before this function was called, it returns immediately.
+        ///
+        /// If `msecs` is -1, this function will not time out.
+        #[rust_name = "wait_for_started"]
+        fn waitForStarted(self: Pin<&mut QProcess>, msecs: i32) -> bool;
+
+        /// If [`QProcess`] has been assigned a working directory, this function returns the working directory that the `QProcess` will enter before the program has started. Otherwise, (i.e., no directory has been assigned,) an empty string is returned, and `QProcess` will use the application's current working directory instead.
+        #[rust_name = "working_directory"]
+        fn workingDirectory(self: &QProcess) -> QString;
+
+        /// This signal is emitted when an error occurs with the process. The specified `error` describes the type of error that occurred.
+        #[qsignal]
+        #[rust_name = "error_occurred"]
+        fn errorOccurred(self: Pin<&mut QProcess>, error: QProcessError);
+
+        /// This signal is emitted when the process finishes. `exit_code` is the exit code of the process (only valid for normal exits), and `exit_status` is the exit status. After the process has finished, the buffers in [`QProcess`] are still intact. You can still read any data that the process may have written before it finished.
+        ///
+        /// This signal is only available with Qt 6, as Qt 5 overloads it with a deprecated single-argument version.
+        #[qsignal]
+        #[cfg(cxxqt_qt_version_at_least_6)]
+        fn finished(self: Pin<&mut QProcess>, exit_code: i32, exit_status: QProcessExitStatus);
+
+        /// This signal is emitted when the process has made new data available through its standard error channel (stderr). It is emitted regardless of the current [`read_channel`](Self::read_channel).
+        #[qsignal]
+        #[rust_name = "ready_read_standard_error"]
+        fn readyReadStandardError(self: Pin<&mut QProcess>);
+
+        /// This signal is emitted when the process has made new data available through its standard output channel (stdout). It is emitted regardless of the current [`read_channel`](Self::read_channel).
+        #[qsignal]
+        #[rust_name = "ready_read_standard_output"]
+        fn readyReadStandardOutput(self: Pin<&mut QProcess>);
+
+        /// This signal is emitted by [`QProcess`] when the process has started, and [`state`](Self::state) returns [`QProcessState::Running`].
+        #[qsignal]
+        fn started(self: Pin<&mut QProcess>);
+
+        /// This signal is emitted whenever the state of [`QProcess`] changes. The `new_state` argument is the state [`QProcess`] changed to.
+        #[qsignal]
+        #[rust_name = "state_changed"]
+        fn stateChanged(self: Pin<&mut QProcess>, new_state: QProcessState);
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qprocess_new"]
+        fn qprocessNew() -> UniquePtr<QProcess>;
+    }
+
+    // These are all static, so we need to create bindings until CXX supports statics
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qprocess_execute"]
+        fn qprocessExecute(program: &QString, arguments: &QStringList) -> i32;
+        #[doc(hidden)]
+        #[rust_name = "qprocess_null_device"]
+        fn qprocessNullDevice() -> QString;
+    }
+
+    impl UniquePtr<QProcess> {}
+}
+
+pub use ffi::{
+    QProcess, QProcessChannel, QProcessChannelMode, QProcessError, QProcessExitStatus,
+    QProcessInputChannelMode, QProcessState,
+};
+
+// Safety:
+//
+// A QProcess has no state which is tied to the thread that it was created on,
+// its notifiers are moved along with it.
+unsafe impl cxx_qt::MoveToThread for QProcess {}
+
+impl QProcess {
+    /// Constructs a `QProcess` object.
+    pub fn new() -> cxx::UniquePtr<Self> {
+        ffi::qprocess_new()
+    }
+
+    /// Starts the program `program` with the arguments `arguments` in a new process, waits for it to finish, and then returns the exit code of the process. Any data the new process writes to the console is forwarded to the calling process.
+    ///
+    /// The environment and working directory are inherited from the calling process.
+    ///
+    /// If the process cannot be started, -2 is returned. If the process crashes, -1 is returned. Otherwise, the process' exit code is returned.
+    pub fn execute(program: &QString, arguments: &QStringList) -> i32 {
+        ffi::qprocess_execute(program, arguments)
+    }
+
+    /// The null device of the operating system.
+    ///
+    /// The returned file path uses native directory separators.
+    pub fn null_device() -> QString {
+        ffi::qprocess_null_device()
+    }
+
+    /// Returns the native process identifier for the running process, if available. If no process is currently running, 0 is returned.
+    pub fn process_id(&self) -> i64 {
+        self.process_id_qint64().into()
+    }
+
+    /// Starts the given `program` in a new process, passing the command line arguments in `arguments`, and opens the `QProcess` for reading and writing.
+    ///
+    /// See [`start_with_mode`](Self::start_with_mode) for more details.
+    pub fn start(self: Pin<&mut Self>, program: &QString, arguments: &QStringList) {
+        self.start_with_mode(program, arguments, QIODeviceOpenModeFlag::ReadWrite.into())
+    }
+
+    fn io_device(self: Pin<&mut Self>) -> Pin<&mut QIODevice> {
+        self.upcast_pin()
+    }
+}
+
+impl io::Read for Pin<&mut QProcess> {
+    /// Reads from the current [`read_channel`](QProcess::read_channel) of the process.
+    ///
+    /// See the `QIODevice` implementation for blocking behavior.
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.as_mut().io_device().read(buf)
+    }
+}
+
+impl io::Write for Pin<&mut QProcess> {
+    /// Writes to the standard input of the process.
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.as_mut().io_device().write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.as_mut().io_device().flush()
+    }
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+    use std::io::{Read, Write};
+
+    fn sh(script: &str) -> cxx::UniquePtr<QProcess> {
+        let mut process = QProcess::new();
+        let mut arguments = QStringList::default();
+        arguments.append(QString::from("-c"));
+        arguments.append(QString::from(script));
+        process
+            .pin_mut()
+            .start(&QString::from("/bin/sh"), &arguments);
+        assert!(process.pin_mut().wait_for_started(30000));
+        process
+    }
+
+    #[test]
+    fn qprocess_stdin_stdout_stderr() {
+        let mut process = sh("cat; echo done >&2");
+        process.pin_mut().write_all(b"hello from rust").unwrap();
+        process.pin_mut().close_write_channel();
+        assert!(process.pin_mut().wait_for_finished(30000));
+
+        assert_eq!(process.exit_status(), QProcessExitStatus::NormalExit);
+        assert_eq!(process.exit_code(), 0);
+        assert_eq!(
+            process.pin_mut().read_all_standard_output().as_slice(),
+            b"hello from rust"
+        );
+        assert_eq!(
+            process.pin_mut().read_all_standard_error().as_slice(),
+            b"done\n"
+        );
+    }
+
+    #[test]
+    fn qprocess_read_to_end() {
+        let mut process = sh("printf 'line one\\nline two\\n'; exit 3");
+        let mut output = String::new();
+        process.pin_mut().read_to_string(&mut output).unwrap();
+        process.pin_mut().wait_for_finished(30000);
+        assert_eq!(process.state(), QProcessState::NotRunning);
+
+        assert_eq!(output, "line one\nline two\n");
+        assert_eq!(process.exit_code(), 3);
+    }
+
+    #[test]
+    fn qprocess_read_not_started() {
+        let mut process = QProcess::new();
+        let mut buf = [0; 16];
+        let err = process.pin_mut().read(&mut buf).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::Other);
+    }
+}
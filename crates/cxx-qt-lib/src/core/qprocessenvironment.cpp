@@ -0,0 +1,24 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Joshua Booth <joshua.n.booth@gmail.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#include "cxx-qt-lib/qprocessenvironment.h"
+
+#include <cxx-qt-lib/assertion_utils.h>
+
+// QProcessEnvironment has a single pointer as it's member
+//
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/corelib/io/qprocess.h?h=v5.15.6-lts-lgpl
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/corelib/io/qprocess.h?h=v6.2.4
+assert_alignment_and_size(QProcessEnvironment, { ::std::size_t a0; });
+
+static_assert(
+  !::std::is_trivially_copy_assignable<QProcessEnvironment>::value);
+static_assert(
+  !::std::is_trivially_copy_constructible<QProcessEnvironment>::value);
+
+static_assert(!::std::is_trivially_destructible<QProcessEnvironment>::value);
+
+static_assert(QTypeInfo<QProcessEnvironment>::isRelocatable);
@@ -0,0 +1,150 @@
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Joshua Booth <joshua.n.booth@gmail.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+use cxx::{type_id, ExternType};
+use std::fmt;
+use std::mem::MaybeUninit;
+
+use crate::QString;
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+        include!("cxx-qt-lib/qstringlist.h");
+        type QStringList = crate::QStringList;
+        include!("cxx-qt-lib/qprocessenvironment.h");
+        type QProcessEnvironment = super::QProcessEnvironment;
+
+        /// Removes all key=value pairs from this `QProcessEnvironment` object, making it empty.
+        fn clear(self: &mut QProcessEnvironment);
+
+        /// Returns `true` if the environment variable of name `name` is found in this `QProcessEnvironment` object.
+        ///
+        /// On Windows, variable names are case-insensitive, so the key is converted to uppercase before searching. On other systems, names are case-sensitive so no trasformation is applied.
+        fn contains(self: &QProcessEnvironment, name: &QString) -> bool;
+
+        /// Inserts the environment variable of name `name` and contents `value` into this `QProcessEnvironment` object. If that variable already existed, it is replaced by the new value.
+        fn insert(self: &mut QProcessEnvironment, name: &QString, value: &QString);
+
+        /// Returns `true` if this `QProcessEnvironment` object is empty: that is there are no key=value pairs set.
+        #[rust_name = "is_empty"]
+        fn isEmpty(self: &QProcessEnvironment) -> bool;
+
+        /// Returns a list containing all the variable names in this `QProcessEnvironment` object.
+        fn keys(self: &QProcessEnvironment) -> QStringList;
+
+        /// Removes the environment variable identified by `name` from this `QProcessEnvironment` object. If that variable did not exist before, nothing happens.
+        fn remove(self: &mut QProcessEnvironment, name: &QString);
+
+        /// Converts this `QProcessEnvironment` object into a list of strings, one for each environment variable that is set. The environment variable's name and its value are separated by an equal character ('=').
+        #[rust_name = "to_string_list"]
+        fn toStringList(self: &QProcessEnvironment) -> QStringList;
+
+        /// Searches this `QProcessEnvironment` object for a variable identified by `name` and returns its value. If the variable is not found in this object, then `default_value` is returned instead.
+        #[rust_name = "value_or"]
+        fn value(self: &QProcessEnvironment, name: &QString, default_value: &QString) -> QString;
+    }
+
+    // These are all static, so we need to create bindings until CXX supports statics
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qprocessenvironment_system_environment"]
+        fn qprocessenvironmentSystemEnvironment() -> QProcessEnvironment;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/common.h");
+
+        #[doc(hidden)]
+        #[rust_name = "qprocessenvironment_drop"]
+        fn drop(environment: &mut QProcessEnvironment);
+
+        #[doc(hidden)]
+        #[rust_name = "qprocessenvironment_init_default"]
+        fn construct() -> QProcessEnvironment;
+        #[doc(hidden)]
+        #[rust_name = "qprocessenvironment_init_from_qprocessenvironment"]
+        fn construct(environment: &QProcessEnvironment) -> QProcessEnvironment;
+
+        #[doc(hidden)]
+        #[rust_name = "qprocessenvironment_eq"]
+        fn operatorEq(a: &QProcessEnvironment, b: &QProcessEnvironment) -> bool;
+    }
+}
+
+/// The `QProcessEnvironment` class holds the environment variables that can be passed to a program.
+///
+/// Qt Documentation: [QProcessEnvironment](https://doc.qt.io/qt/qprocessenvironment.html#details)
+#[repr(C)]
+pub struct QProcessEnvironment {
+    _space: MaybeUninit<usize>,
+}
+
+impl QProcessEnvironment {
+    /// Returns the environment of the calling process.
+    ///
+    /// It is returned as a `QProcessEnvironment`. This function does not cache the system environment. Therefore, it's possible to obtain an updated version of the environment if low-level C library functions like `setenv` or `putenv` have been called.
+    pub fn system_environment() -> Self {
+        ffi::qprocessenvironment_system_environment()
+    }
+
+    /// Searches this `QProcessEnvironment` object for a variable identified by `name` and returns its value, or `None` if the variable is not found.
+    pub fn value(&self, name: &QString) -> Option<QString> {
+        if self.contains(name) {
+            Some(self.value_or(name, &QString::default()))
+        } else {
+            None
+        }
+    }
+}
+
+impl Clone for QProcessEnvironment {
+    /// Constructs a `QProcessEnvironment` object that is a copy of `self`.
+    fn clone(&self) -> Self {
+        ffi::qprocessenvironment_init_from_qprocessenvironment(self)
+    }
+}
+
+impl Default for QProcessEnvironment {
+    /// Creates a new `QProcessEnvironment` object. This constructor creates an empty environment. If set on a [`QProcess`](crate::QProcess), this will cause the current environment variables to be removed.
+    fn default() -> Self {
+        ffi::qprocessenvironment_init_default()
+    }
+}
+
+impl std::cmp::PartialEq for QProcessEnvironment {
+    /// Returns `true` if `self` and `other` are equal.
+    ///
+    /// Two `QProcessEnvironment` objects are considered equal if they have the same set of key=value pairs. The comparison of keys is done case-sensitive on platforms where environment variables are case-sensitive.
+    fn eq(&self, other: &Self) -> bool {
+        ffi::qprocessenvironment_eq(self, other)
+    }
+}
+
+impl std::cmp::Eq for QProcessEnvironment {}
+
+impl fmt::Debug for QProcessEnvironment {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.to_string_list().fmt(f)
+    }
+}
+
+impl Drop for QProcessEnvironment {
+    /// Frees the resources associated with this `QProcessEnvironment` object.
+    fn drop(&mut self) {
+        ffi::qprocessenvironment_drop(self)
+    }
+}
+
+// Safety:
+//
+// Static checks on the C++ side to ensure the size is the same.
+unsafe impl ExternType for QProcessEnvironment {
+    type Id = type_id!("QProcessEnvironment");
+    type Kind = cxx::kind::Trivial;
+}
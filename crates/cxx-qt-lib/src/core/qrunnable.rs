@@ -0,0 +1,62 @@
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Joshua Booth <joshua.n.booth@gmail.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qrunnable.h");
+        /// The `QRunnable` class is the base class for all runnable objects.
+        ///
+        /// A `QRunnable` created from Rust wraps a closure, and is handed over to a [`QThreadPool`](crate::QThreadPool) to run it once on one of the pool's threads.
+        ///
+        /// Qt Documentation: [QRunnable](https://doc.qt.io/qt/qrunnable.html#details)
+        type QRunnable;
+    }
+
+    extern "Rust" {
+        type RunnableClosure;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qrunnable_new"]
+        fn qrunnableNew(
+            context: Box<RunnableClosure>,
+            closure: fn(&mut RunnableClosure),
+        ) -> UniquePtr<QRunnable>;
+    }
+
+    impl UniquePtr<QRunnable> {}
+}
+
+pub use ffi::QRunnable;
+
+impl QRunnable {
+    /// Creates a `QRunnable` that calls `closure` when it is run.
+    ///
+    /// The runnable deletes itself after it has been run by a [`QThreadPool`](crate::QThreadPool).
+    pub fn new<F>(closure: F) -> cxx::UniquePtr<Self>
+    where
+        F: FnOnce() + Send + 'static,
+    {
+        let context = Box::new(RunnableClosure {
+            closure: Some(Box::new(closure)),
+        });
+        ffi::qrunnable_new(context, RunnableClosure::run)
+    }
+}
+
+struct RunnableClosure {
+    closure: Option<Box<dyn FnOnce() + Send>>,
+}
+
+impl RunnableClosure {
+    fn run(&mut self) {
+        if let Some(closure) = self.closure.take() {
+            closure();
+        }
+    }
+}
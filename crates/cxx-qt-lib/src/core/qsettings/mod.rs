@@ -0,0 +1,579 @@
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Joshua Booth <joshua.n.booth@gmail.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::{QString, QVariant, QVariantValue};
+use core::pin::Pin;
+use std::ops::Deref;
+
+#[cfg(feature = "serde")]
+mod serde_impl;
+#[cfg(feature = "serde")]
+pub use serde_impl::QSettingsSerdeError;
+
+#[cxx_qt::bridge]
+mod ffi {
+    /// This enum specifies the storage format used by [`QSettings`].
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QSettingsFormat {
+        /// Store the settings using the most appropriate storage format for the platform. On Windows, this means the system registry; on macOS and iOS, this means the CFPreferences API; on Unix, this means textual configuration files in INI format.
+        NativeFormat = 0,
+        /// Store the settings in INI files. Note that INI files lose the distinction between numeric data and the strings used to encode them, so values written as numbers shall be read back as [`QString`].
+        IniFormat = 1,
+        /// Special value returned by [QSettings::register_format](https://doc.qt.io/qt/qsettings.html#registerFormat)().
+        InvalidFormat = 16,
+    }
+
+    /// This enum specifies whether settings are user-specific or shared by all users of the same system.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QSettingsScope {
+        /// Store settings in a location specific to the current user (e.g., in the user's home directory).
+        UserScope,
+        /// Store settings in a global location, so that all users on the same machine access the same set of settings.
+        SystemScope,
+    }
+
+    /// The following status values are possible for [`QSettings::status`].
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QSettingsStatus {
+        /// No error occurred.
+        NoError,
+        /// An access error occurred (e.g. trying to write to a read-only file).
+        AccessError,
+        /// A format error occurred (e.g. loading a malformed INI file).
+        FormatError,
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+        include!("cxx-qt-lib/qstringlist.h");
+        type QStringList = crate::QStringList;
+        include!("cxx-qt-lib/qvariant.h");
+        type QVariant = crate::QVariant;
+    }
+
+    unsafe extern "C++Qt" {
+        include!("cxx-qt-lib/qsettings.h");
+        /// The `QSettings` class provides persistent platform-independent application settings.
+        ///
+        /// Qt Documentation: [QSettings](https://doc.qt.io/qt/qsettings.html#details)
+        #[qobject]
+        type QSettings;
+
+        /// Returns a list of all keys, including subkeys, that can be read using the `QSettings` object.
+        ///
+        /// If a group is set using [`begin_group`](Self::begin_group), only the keys in the group are returned, without the group prefix.
+        #[rust_name = "all_keys"]
+        fn allKeys(self: &QSettings) -> QStringList;
+
+        /// Returns the application name used for storing the settings.
+        #[rust_name = "application_name"]
+        fn applicationName(self: &QSettings) -> QString;
+
+        /// Returns a list of all key top-level groups that contain keys that can be read using the `QSettings` object.
+        ///
+        /// If a group is set using [`begin_group`](Self::begin_group), the first-level keys in that group are returned, without the group prefix.
+        #[rust_name = "child_groups"]
+        fn childGroups(self: &QSettings) -> QStringList;
+
+        /// Returns a list of all top-level keys that can be read using the `QSettings` object.
+        ///
+        /// If a group is set using [`begin_group`](Self::begin_group), the top-level keys in that group are returned, without the group prefix.
+        #[rust_name = "child_keys"]
+        fn childKeys(self: &QSettings) -> QStringList;
+
+        /// Removes all entries in the primary location associated to this `QSettings` object.
+        ///
+        /// Entries in fallback locations are not removed.
+        fn clear(self: Pin<&mut QSettings>);
+
+        /// Closes the array that was started using [`begin_read_array`](Self::begin_read_array) or [`begin_write_array`](Self::begin_write_array).
+        #[rust_name = "end_array"]
+        fn endArray(self: Pin<&mut QSettings>);
+
+        /// Resets the group to what it was before the corresponding [`begin_group`](Self::begin_group) call.
+        #[rust_name = "end_group"]
+        fn endGroup(self: Pin<&mut QSettings>);
+
+        /// Returns `true` if fallbacks are enabled; returns `false` otherwise.
+        ///
+        /// By default, fallbacks are enabled.
+        #[rust_name = "fallbacks_enabled"]
+        fn fallbacksEnabled(self: &QSettings) -> bool;
+
+        /// Returns the path where settings written using this `QSettings` object are stored.
+        ///
+        /// On Windows, if the format is [`QSettingsFormat::NativeFormat`], the return value is a system registry path, not a file path.
+        #[rust_name = "file_name"]
+        fn fileName(self: &QSettings) -> QString;
+
+        /// Returns the format used for storing the settings.
+        fn format(self: &QSettings) -> QSettingsFormat;
+
+        /// Returns the current group.
+        fn group(self: &QSettings) -> QString;
+
+        /// Returns `true` if `QSettings` is only allowed to perform atomic saving and reloading (synchronization) of the settings. Returns `false` if it is allowed to save the settings contents directly to the configuration file.
+        ///
+        /// The default is `true`.
+        #[rust_name = "is_atomic_sync_required"]
+        fn isAtomicSyncRequired(self: &QSettings) -> bool;
+
+        /// Returns `true` if settings can be written using this `QSettings` object; returns `false` otherwise.
+        ///
+        /// One reason why this might return `false` is if `QSettings` operates on a read-only file.
+        #[rust_name = "is_writable"]
+        fn isWritable(self: &QSettings) -> bool;
+
+        /// Returns the organization name used for storing the settings.
+        #[rust_name = "organization_name"]
+        fn organizationName(self: &QSettings) -> QString;
+
+        /// Returns the scope used for storing the settings.
+        fn scope(self: &QSettings) -> QSettingsScope;
+
+        /// Sets the current array index to `i`. Calls to functions such as [`set_value`](Self::set_value), [`value`](Self::value), [`remove`](Self::remove), and [`contains`](Self::contains) will operate on the array entry at that index.
+        ///
+        /// You must call [`begin_read_array`](Self::begin_read_array) or [`begin_write_array`](Self::begin_write_array) before you can call this function.
+        #[rust_name = "set_array_index"]
+        fn setArrayIndex(self: Pin<&mut QSettings>, i: i32);
+
+        /// Configures whether `QSettings` is required to perform atomic saving and reloading (synchronization) of the settings. If the `enable` argument is `true` (the default), [`sync`](Self::sync) will only perform synchronization operations that are atomic. If atomic synchronization is not possible, `QSettings` will fail and [`status`](Self::status) will be an error condition.
+        #[rust_name = "set_atomic_sync_required"]
+        fn setAtomicSyncRequired(self: Pin<&mut QSettings>, enable: bool);
+
+        /// Sets whether fallbacks are enabled to `b`.
+        ///
+        /// By default, fallbacks are enabled.
+        #[rust_name = "set_fallbacks_enabled"]
+        fn setFallbacksEnabled(self: Pin<&mut QSettings>, b: bool);
+
+        /// Returns a status code indicating the first error that was met by `QSettings`, or [`QSettingsStatus::NoError`] if no error occurred.
+        ///
+        /// Be aware that `QSettings` delays performing some operations. For this reason, you might want to call [`sync`](Self::sync) to ensure that the data stored in `QSettings` is written to disk before calling `status`.
+        fn status(self: &QSettings) -> QSettingsStatus;
+
+        /// Writes any unsaved changes to permanent storage, and reloads any settings that have been changed in the meantime by another application.
+        ///
+        /// This function is called automatically from `QSettings`'s destructor and by the event loop at regular intervals, so you normally don't need to call it yourself.
+        fn sync(self: Pin<&mut QSettings>);
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        type QSettingsFormat;
+        type QSettingsScope;
+        type QSettingsStatus;
+
+        #[doc(hidden)]
+        #[rust_name = "qsettings_new"]
+        fn qsettingsNew() -> UniquePtr<QSettings>;
+        #[doc(hidden)]
+        #[rust_name = "qsettings_new_with_organization"]
+        fn qsettingsNewWithOrganization(
+            organization: &QString,
+            application: &QString,
+        ) -> UniquePtr<QSettings>;
+        #[doc(hidden)]
+        #[rust_name = "qsettings_new_with_scope"]
+        fn qsettingsNewWithScope(
+            scope: QSettingsScope,
+            organization: &QString,
+            application: &QString,
+        ) -> UniquePtr<QSettings>;
+        #[doc(hidden)]
+        #[rust_name = "qsettings_new_with_format"]
+        fn qsettingsNewWithFormat(
+            format: QSettingsFormat,
+            scope: QSettingsScope,
+            organization: &QString,
+            application: &QString,
+        ) -> UniquePtr<QSettings>;
+        #[doc(hidden)]
+        #[rust_name = "qsettings_new_from_file"]
+        fn qsettingsNewFromFile(
+            file_name: &QString,
+            format: QSettingsFormat,
+        ) -> UniquePtr<QSettings>;
+
+        #[doc(hidden)]
+        #[rust_name = "qsettings_begin_group"]
+        fn qsettingsBeginGroup(settings: Pin<&mut QSettings>, prefix: &QString);
+        #[doc(hidden)]
+        #[rust_name = "qsettings_begin_read_array"]
+        fn qsettingsBeginReadArray(settings: Pin<&mut QSettings>, prefix: &QString) -> i32;
+        #[doc(hidden)]
+        #[rust_name = "qsettings_begin_write_array"]
+        fn qsettingsBeginWriteArray(settings: Pin<&mut QSettings>, prefix: &QString, size: i32);
+        #[doc(hidden)]
+        #[rust_name = "qsettings_contains"]
+        fn qsettingsContains(settings: &QSettings, key: &QString) -> bool;
+        #[doc(hidden)]
+        #[rust_name = "qsettings_remove"]
+        fn qsettingsRemove(settings: Pin<&mut QSettings>, key: &QString);
+        #[doc(hidden)]
+        #[rust_name = "qsettings_set_value"]
+        fn qsettingsSetValue(settings: Pin<&mut QSettings>, key: &QString, value: &QVariant);
+        #[doc(hidden)]
+        #[rust_name = "qsettings_value"]
+        fn qsettingsValue(settings: &QSettings, key: &QString) -> QVariant;
+    }
+
+    // These are all static, so we need to create bindings until CXX supports statics
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qsettings_default_format"]
+        fn qsettingsDefaultFormat() -> QSettingsFormat;
+        #[doc(hidden)]
+        #[rust_name = "qsettings_set_default_format"]
+        fn qsettingsSetDefaultFormat(format: QSettingsFormat);
+        #[doc(hidden)]
+        #[rust_name = "qsettings_set_path"]
+        fn qsettingsSetPath(format: QSettingsFormat, scope: QSettingsScope, path: &QString);
+    }
+
+    // QSettings is not a trivial to CXX and is not relocatable in Qt
+    // as the following fails in C++. So we cannot mark it as a trivial type
+    // and need to use references or pointers.
+    // static_assert(QTypeInfo<QSettings>::isRelocatable);
+    impl UniquePtr<QSettings> {}
+}
+
+pub use ffi::{QSettings, QSettingsFormat, QSettingsScope, QSettingsStatus};
+
+impl QSettings {
+    /// Constructs a `QSettings` object for accessing settings of the application and organization set previously with a call to [`QCoreApplication::set_organization_name`](crate::QCoreApplication::set_organization_name), [`QCoreApplication::set_organization_domain`](crate::QCoreApplication::set_organization_domain), and [`QCoreApplication::set_application_name`](crate::QCoreApplication::set_application_name).
+    ///
+    /// The scope is [`QSettingsScope::UserScope`] and the format is [`default_format`](Self::default_format).
+    pub fn new() -> cxx::UniquePtr<Self> {
+        ffi::qsettings_new()
+    }
+
+    /// Constructs a `QSettings` object for accessing settings of the application called `application` from the organization called `organization`.
+    ///
+    /// The scope is [`QSettingsScope::UserScope`] and the format is [`default_format`](Self::default_format).
+    pub fn from_organization(
+        organization: &QString,
+        application: &QString,
+    ) -> cxx::UniquePtr<Self> {
+        ffi::qsettings_new_with_organization(organization, application)
+    }
+
+    /// Constructs a `QSettings` object for accessing settings of the application called `application` from the organization called `organization`, in the given `scope`.
+    ///
+    /// The format is [`default_format`](Self::default_format).
+    pub fn from_scope(
+        scope: QSettingsScope,
+        organization: &QString,
+        application: &QString,
+    ) -> cxx::UniquePtr<Self> {
+        ffi::qsettings_new_with_scope(scope, organization, application)
+    }
+
+    /// Constructs a `QSettings` object for accessing settings of the application called `application` from the organization called `organization`, in the given `scope` and `format`.
+    pub fn from_format(
+        format: QSettingsFormat,
+        scope: QSettingsScope,
+        organization: &QString,
+        application: &QString,
+    ) -> cxx::UniquePtr<Self> {
+        ffi::qsettings_new_with_format(format, scope, organization, application)
+    }
+
+    /// Constructs a `QSettings` object for accessing the settings stored in the file called `file_name`. If the file doesn't already exist, it is created.
+    ///
+    /// If `format` is [`QSettingsFormat::NativeFormat`], the meaning of `file_name` depends on the platform. On Unix, `file_name` is the name of an INI file. On macOS and iOS, `file_name` is the name of a `.plist` file. On Windows, `file_name` is a path in the system registry.
+    pub fn from_file(file_name: &QString, format: QSettingsFormat) -> cxx::UniquePtr<Self> {
+        ffi::qsettings_new_from_file(file_name, format)
+    }
+
+    /// Returns the default file format used for storing settings for the `QSettings::new` constructors.
+    ///
+    /// If no default format is set, [`QSettingsFormat::NativeFormat`] is used.
+    pub fn default_format() -> QSettingsFormat {
+        ffi::qsettings_default_format()
+    }
+
+    /// Sets the default file format to the given `format`, which is used for storing settings for the `QSettings::new` constructors.
+    pub fn set_default_format(format: QSettingsFormat) {
+        ffi::qsettings_set_default_format(format)
+    }
+
+    /// Sets the path used for storing settings for the given `format` and `scope`, to `path`. The format can be a custom format.
+    ///
+    /// This function doesn't affect existing `QSettings` objects.
+    pub fn set_path(format: QSettingsFormat, scope: QSettingsScope, path: &QString) {
+        ffi::qsettings_set_path(format, scope, path)
+    }
+
+    /// Appends `prefix` to the current group.
+    ///
+    /// Groups are useful to avoid typing in the same setting paths over and over. Call [`end_group`](Self::end_group) to reset the current group to what it was before the corresponding call. Groups can be nested.
+    ///
+    /// See also [`scoped_group`](Self::scoped_group), which ends the group automatically.
+    pub fn begin_group(self: Pin<&mut Self>, prefix: &QString) {
+        ffi::qsettings_begin_group(self, prefix)
+    }
+
+    /// Adds `prefix` to the current group and starts reading from an array. Returns the size of the array.
+    ///
+    /// Use [`set_array_index`](Self::set_array_index) to navigate the entries and call [`end_array`](Self::end_array) when you are done.
+    ///
+    /// See also [`scoped_read_array`](Self::scoped_read_array), which ends the array automatically.
+    pub fn begin_read_array(self: Pin<&mut Self>, prefix: &QString) -> i32 {
+        ffi::qsettings_begin_read_array(self, prefix)
+    }
+
+    /// Adds `prefix` to the current group and starts writing an array of size `size`. If `size` is -1 (the default), it is automatically determined based on the indexes of the entries written.
+    ///
+    /// See also [`scoped_write_array`](Self::scoped_write_array), which ends the array automatically.
+    pub fn begin_write_array(self: Pin<&mut Self>, prefix: &QString, size: i32) {
+        ffi::qsettings_begin_write_array(self, prefix, size)
+    }
+
+    /// Returns `true` if there exists a setting called `key`; returns `false` otherwise.
+    ///
+    /// If a group is set using [`begin_group`](Self::begin_group), `key` is taken to be relative to that group.
+    pub fn contains(&self, key: &QString) -> bool {
+        ffi::qsettings_contains(self, key)
+    }
+
+    /// Removes the setting `key` and any sub-settings of `key`.
+    ///
+    /// If `key` is an empty string, all keys in the current [`group`](Self::group) are removed.
+    pub fn remove(self: Pin<&mut Self>, key: &QString) {
+        ffi::qsettings_remove(self, key)
+    }
+
+    /// Sets the value of setting `key` to `value`. If the `key` already exists, the previous value is overwritten.
+    pub fn set_value<T: QVariantValue>(self: Pin<&mut Self>, key: &QString, value: &T) {
+        ffi::qsettings_set_value(self, key, &QVariant::from(value))
+    }
+
+    /// Sets the value of setting `key` to the [`QVariant`] `value`. If the `key` already exists, the previous value is overwritten.
+    pub fn set_value_variant(self: Pin<&mut Self>, key: &QString, value: &QVariant) {
+        ffi::qsettings_set_value(self, key, value)
+    }
+
+    /// Returns the value for setting `key` converted to `T`, or `None` if the setting doesn't exist or cannot be converted to `T`.
+    ///
+    /// If a group is set using [`begin_group`](Self::begin_group), `key` is taken to be relative to that group.
+    pub fn value<T: QVariantValue>(&self, key: &QString) -> Option<T> {
+        self.value_variant(key).value()
+    }
+
+    /// Returns the value for setting `key` converted to `T`. If the setting doesn't exist or cannot be converted, returns `default_value`.
+    pub fn value_or<T: QVariantValue>(&self, key: &QString, default_value: T) -> T {
+        self.value(key).unwrap_or(default_value)
+    }
+
+    /// Returns the value for setting `key` as a [`QVariant`]. If the setting doesn't exist, returns an invalid `QVariant`.
+    pub fn value_variant(&self, key: &QString) -> QVariant {
+        ffi::qsettings_value(self, key)
+    }
+
+    /// Appends `prefix` to the current group until the returned guard is dropped.
+    ///
+    /// ```ignore
+    /// let mut group = settings.pin_mut().scoped_group(&QString::from("MainWindow"));
+    /// group.settings().set_value(&QString::from("fullScreen"), &true);
+    /// // end_group is called here
+    /// ```
+    pub fn scoped_group(mut self: Pin<&mut Self>, prefix: &QString) -> QSettingsGroupGuard<'_> {
+        self.as_mut().begin_group(prefix);
+        QSettingsGroupGuard { settings: self }
+    }
+
+    /// Adds `prefix` to the current group and starts reading from an array until the returned guard is dropped.
+    ///
+    /// The size of the array is available from [`QSettingsArrayGuard::len`].
+    pub fn scoped_read_array(
+        mut self: Pin<&mut Self>,
+        prefix: &QString,
+    ) -> QSettingsArrayGuard<'_> {
+        let size = self.as_mut().begin_read_array(prefix);
+        QSettingsArrayGuard {
+            settings: self,
+            size,
+            automatic_size: false,
+        }
+    }
+
+    /// Adds `prefix` to the current group and starts writing an array of size `size` until the returned guard is dropped. If `size` is -1, it is automatically determined based on the indexes of the entries written.
+    pub fn scoped_write_array(
+        mut self: Pin<&mut Self>,
+        prefix: &QString,
+        size: i32,
+    ) -> QSettingsArrayGuard<'_> {
+        self.as_mut().begin_write_array(prefix, size);
+        QSettingsArrayGuard {
+            settings: self,
+            size: size.max(0),
+            automatic_size: size < 0,
+        }
+    }
+}
+
+/// A guard which calls [`QSettings::end_group`] when dropped.
+///
+/// Created by [`QSettings::scoped_group`].
+pub struct QSettingsGroupGuard<'a> {
+    settings: Pin<&'a mut QSettings>,
+}
+
+impl QSettingsGroupGuard<'_> {
+    /// Returns the [`QSettings`] this group belongs to, for writing values into the group.
+    pub fn settings(&mut self) -> Pin<&mut QSettings> {
+        self.settings.as_mut()
+    }
+}
+
+impl Deref for QSettingsGroupGuard<'_> {
+    type Target = QSettings;
+
+    fn deref(&self) -> &QSettings {
+        &self.settings
+    }
+}
+
+impl Drop for QSettingsGroupGuard<'_> {
+    fn drop(&mut self) {
+        self.settings.as_mut().end_group();
+    }
+}
+
+/// A guard which calls [`QSettings::end_array`] when dropped.
+///
+/// Created by [`QSettings::scoped_read_array`] and [`QSettings::scoped_write_array`].
+pub struct QSettingsArrayGuard<'a> {
+    settings: Pin<&'a mut QSettings>,
+    size: i32,
+    // Whether the size is determined by the indexes of the entries written
+    automatic_size: bool,
+}
+
+impl QSettingsArrayGuard<'_> {
+    /// Returns the size of the array, as returned by [`QSettings::begin_read_array`] or passed to [`QSettings::begin_write_array`].
+    ///
+    /// If the size passed to [`QSettings::scoped_write_array`] was -1, this is the size determined by the indexes of the entries accessed with [`at`](Self::at) so far.
+    pub fn len(&self) -> i32 {
+        self.size
+    }
+
+    /// Returns `true` if the array has no entries.
+    pub fn is_empty(&self) -> bool {
+        self.size <= 0
+    }
+
+    /// Sets the current array index to `i` and returns the [`QSettings`] for accessing that entry.
+    pub fn at(&mut self, i: i32) -> Pin<&mut QSettings> {
+        if self.automatic_size {
+            self.size = self.size.max(i + 1);
+        }
+        self.settings.as_mut().set_array_index(i);
+        self.settings.as_mut()
+    }
+
+    /// Returns the [`QSettings`] this array belongs to.
+    pub fn settings(&mut self) -> Pin<&mut QSettings> {
+        self.settings.as_mut()
+    }
+}
+
+impl Deref for QSettingsArrayGuard<'_> {
+    type Target = QSettings;
+
+    fn deref(&self) -> &QSettings {
+        &self.settings
+    }
+}
+
+impl Drop for QSettingsArrayGuard<'_> {
+    fn drop(&mut self) {
+        self.settings.as_mut().end_array();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The path of an INI file, which is unique to the test and the test process
+    pub(super) fn temporary_settings_path(name: &str) -> std::path::PathBuf {
+        std::env::temp_dir().join(format!(
+            "cxx-qt-lib-qsettings-{name}-{}.ini",
+            std::process::id()
+        ))
+    }
+
+    /// Creates settings in a new INI file, which is unique to the test and the test process
+    pub(super) fn temporary_settings(name: &str) -> cxx::UniquePtr<QSettings> {
+        let path = temporary_settings_path(name);
+        let _ = std::fs::remove_file(&path);
+        QSettings::from_file(
+            &QString::from(path.to_str().unwrap()),
+            QSettingsFormat::IniFormat,
+        )
+    }
+
+    #[test]
+    fn qsettings_typed_value() {
+        let mut settings = temporary_settings("typed_value");
+        let key = QString::from("answer");
+        settings.pin_mut().set_value(&key, &42_i32);
+        assert_eq!(settings.value::<i32>(&key), Some(42));
+        assert_eq!(settings.value::<i32>(&QString::from("missing")), None);
+        assert_eq!(settings.value_or(&QString::from("missing"), 7_i32), 7);
+    }
+
+    #[test]
+    fn qsettings_scoped_group() {
+        let mut settings = temporary_settings("scoped_group");
+        {
+            let mut group = settings.pin_mut().scoped_group(&QString::from("window"));
+            assert_eq!(group.group(), QString::from("window"));
+            group
+                .settings()
+                .set_value(&QString::from("width"), &640_i32);
+        }
+        assert_eq!(settings.group(), QString::default());
+        assert_eq!(
+            settings.value::<i32>(&QString::from("window/width")),
+            Some(640)
+        );
+    }
+
+    #[test]
+    fn qsettings_scoped_array() {
+        let mut settings = temporary_settings("scoped_array");
+        {
+            let mut array = settings
+                .pin_mut()
+                .scoped_write_array(&QString::from("recent"), -1);
+            assert_eq!(array.len(), 0);
+            for (i, file) in ["a.txt", "b.txt"].into_iter().enumerate() {
+                array
+                    .at(i as i32)
+                    .set_value(&QString::from("file"), &QString::from(file));
+            }
+            assert_eq!(array.len(), 2);
+        }
+        let mut array = settings
+            .pin_mut()
+            .scoped_read_array(&QString::from("recent"));
+        assert_eq!(array.len(), 2);
+        assert_eq!(
+            array.at(1).value::<QString>(&QString::from("file")),
+            Some(QString::from("b.txt"))
+        );
+    }
+}
@@ -0,0 +1,116 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Joshua Booth <joshua.n.booth@gmail.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#include "cxx-qt-lib/qsettings.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QSettings>
+qsettingsNew()
+{
+  return ::std::make_unique<QSettings>();
+}
+
+::std::unique_ptr<QSettings>
+qsettingsNewWithOrganization(const QString& organization,
+                             const QString& application)
+{
+  return ::std::make_unique<QSettings>(organization, application);
+}
+
+::std::unique_ptr<QSettings>
+qsettingsNewWithScope(QSettingsScope scope,
+                      const QString& organization,
+                      const QString& application)
+{
+  return ::std::make_unique<QSettings>(scope, organization, application);
+}
+
+::std::unique_ptr<QSettings>
+qsettingsNewWithFormat(QSettingsFormat format,
+                       QSettingsScope scope,
+                       const QString& organization,
+                       const QString& application)
+{
+  return ::std::make_unique<QSettings>(
+    format, scope, organization, application);
+}
+
+::std::unique_ptr<QSettings>
+qsettingsNewFromFile(const QString& fileName, QSettingsFormat format)
+{
+  return ::std::make_unique<QSettings>(fileName, format);
+}
+
+void
+qsettingsBeginGroup(QSettings& settings, const QString& prefix)
+{
+  settings.beginGroup(prefix);
+}
+
+::std::int32_t
+qsettingsBeginReadArray(QSettings& settings, const QString& prefix)
+{
+  return static_cast<::std::int32_t>(settings.beginReadArray(prefix));
+}
+
+void
+qsettingsBeginWriteArray(QSettings& settings,
+                         const QString& prefix,
+                         ::std::int32_t size)
+{
+  settings.beginWriteArray(prefix, static_cast<int>(size));
+}
+
+bool
+qsettingsContains(const QSettings& settings, const QString& key)
+{
+  return settings.contains(key);
+}
+
+void
+qsettingsRemove(QSettings& settings, const QString& key)
+{
+  settings.remove(key);
+}
+
+void
+qsettingsSetValue(QSettings& settings,
+                  const QString& key,
+                  const QVariant& value)
+{
+  settings.setValue(key, value);
+}
+
+QVariant
+qsettingsValue(const QSettings& settings, const QString& key)
+{
+  return settings.value(key);
+}
+
+QSettingsFormat
+qsettingsDefaultFormat()
+{
+  return QSettings::defaultFormat();
+}
+
+void
+qsettingsSetDefaultFormat(QSettingsFormat format)
+{
+  QSettings::setDefaultFormat(format);
+}
+
+void
+qsettingsSetPath(QSettingsFormat format,
+                 QSettingsScope scope,
+                 const QString& path)
+{
+  QSettings::setPath(format, scope, path);
+}
+
+}
+}
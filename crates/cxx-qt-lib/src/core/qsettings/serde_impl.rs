@@ -0,0 +1,1040 @@
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Joshua Booth <joshua.n.booth@gmail.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! Stores and loads serde types as groups of settings.
+//!
+//! Struct and map fields become keys in a group, nested structs and maps become nested groups,
+//! and sequences become settings arrays (see [`QSettings::begin_write_array`]). Scalars inside an
+//! array, or at the top level, are stored under the key `value`.
+//!
+//! A struct of scalar fields stored in a group is laid out like the properties of the QML
+//! `Settings` type with that group as its `category`: each field is a key in the group, so the
+//! same preferences can be read and written from both Rust and QML.
+
+use super::{QSettings, QSettingsArrayGuard, QSettingsGroupGuard};
+use crate::{QByteArray, QString, QVariantValue};
+use core::pin::Pin;
+use serde::de::{self, DeserializeOwned, IntoDeserializer, Visitor};
+use serde::ser::{self, Impossible, Serialize};
+use std::fmt;
+
+/// The key used for scalars that are stored in an array entry or at the top level of a group.
+const ENTRY_VALUE_KEY: &str = "value";
+
+/// An error that occurred while storing or loading a value with [`QSettings::store`] or [`QSettings::load`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum QSettingsSerdeError {
+    /// A custom error reported by a `Serialize` or `Deserialize` implementation.
+    Custom(String),
+    /// The setting `key` does not exist or cannot be converted to the `expected` type.
+    InvalidValue { key: String, expected: &'static str },
+    /// A map key could not be converted to a settings key.
+    KeyMustBeAString,
+    /// The value has a shape that cannot be represented in settings, such as a tuple enum variant.
+    Unsupported(&'static str),
+}
+
+impl fmt::Display for QSettingsSerdeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Custom(message) => f.write_str(message),
+            Self::InvalidValue { key, expected } => {
+                write!(
+                    f,
+                    "setting \"{key}\" is missing or is not a valid {expected}"
+                )
+            }
+            Self::KeyMustBeAString => f.write_str("map keys must be strings"),
+            Self::Unsupported(shape) => write!(f, "{shape} cannot be stored in settings"),
+        }
+    }
+}
+
+impl std::error::Error for QSettingsSerdeError {}
+
+impl ser::Error for QSettingsSerdeError {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        Self::Custom(msg.to_string())
+    }
+}
+
+impl de::Error for QSettingsSerdeError {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        Self::Custom(msg.to_string())
+    }
+
+    fn missing_field(field: &'static str) -> Self {
+        Self::InvalidValue {
+            key: field.to_owned(),
+            expected: "value",
+        }
+    }
+}
+
+type Result<T> = std::result::Result<T, QSettingsSerdeError>;
+
+impl QSettings {
+    /// Stores `value` in the settings group `group`, replacing any existing values for the same keys.
+    ///
+    /// Each field of a struct or map becomes a key in the group. Nested structs and maps become nested groups,
+    /// and sequences become settings arrays.
+    ///
+    /// ```ignore
+    /// #[derive(Serialize, Deserialize)]
+    /// struct Window {
+    ///     width: i32,
+    ///     height: i32,
+    /// }
+    ///
+    /// settings.pin_mut().store(&QString::from("window"), &Window { width: 640, height: 480 })?;
+    /// ```
+    pub fn store<T: Serialize + ?Sized>(
+        self: Pin<&mut Self>,
+        group: &QString,
+        value: &T,
+    ) -> Result<()> {
+        let mut group = self.scoped_group(group);
+        value.serialize(ValueSerializer {
+            settings: group.settings(),
+            slot: Slot::Entry,
+        })
+    }
+
+    /// Loads a value of type `T` from the settings group `group`, as stored by [`store`](Self::store).
+    ///
+    /// Missing fields are reported as [`QSettingsSerdeError::InvalidValue`] with the name of the field as the key,
+    /// unless they are `Option`s or have a `#[serde(default)]`.
+    pub fn load<T: DeserializeOwned>(self: Pin<&mut Self>, group: &QString) -> Result<T> {
+        let mut group = self.scoped_group(group);
+        T::deserialize(ValueDeserializer {
+            settings: group.settings(),
+            slot: Slot::Entry,
+        })
+    }
+}
+
+/// Where a value is located relative to the current group.
+#[derive(Clone, Copy)]
+enum Slot<'k> {
+    /// The value is stored under a key of the current group.
+    Key(&'k str),
+    /// The value is the current group itself, such as an array entry.
+    Entry,
+}
+
+impl Slot<'_> {
+    fn key(self) -> QString {
+        QString::from(match self {
+            Self::Key(key) => key,
+            Self::Entry => ENTRY_VALUE_KEY,
+        })
+    }
+
+    fn exists(self, settings: &QSettings) -> bool {
+        match self {
+            Self::Key(key) => {
+                settings.contains(&QString::from(key))
+                    || settings.child_groups().contains(&QString::from(key))
+            }
+            Self::Entry => !settings.all_keys().is_empty(),
+        }
+    }
+}
+
+/// Either a group which was entered for a nested value, or the current group.
+enum Scope<'a> {
+    Group(QSettingsGroupGuard<'a>),
+    Current(Pin<&'a mut QSettings>),
+}
+
+impl<'a> Scope<'a> {
+    fn enter(settings: Pin<&'a mut QSettings>, slot: Slot) -> Self {
+        match slot {
+            Slot::Key(key) => Self::Group(settings.scoped_group(&QString::from(key))),
+            Slot::Entry => Self::Current(settings),
+        }
+    }
+
+    fn settings(&mut self) -> Pin<&mut QSettings> {
+        match self {
+            Self::Group(group) => group.settings(),
+            Self::Current(settings) => settings.as_mut(),
+        }
+    }
+}
+
+struct ValueSerializer<'a, 'k> {
+    settings: Pin<&'a mut QSettings>,
+    slot: Slot<'k>,
+}
+
+impl ValueSerializer<'_, '_> {
+    fn write<T: QVariantValue>(self, value: &T) -> Result<()> {
+        self.settings.set_value(&self.slot.key(), value);
+        Ok(())
+    }
+}
+
+impl<'a> ser::Serializer for ValueSerializer<'a, '_> {
+    type Ok = ();
+    type Error = QSettingsSerdeError;
+
+    type SerializeSeq = SeqSerializer<'a>;
+    type SerializeTuple = SeqSerializer<'a>;
+    type SerializeTupleStruct = SeqSerializer<'a>;
+    type SerializeTupleVariant = Impossible<(), QSettingsSerdeError>;
+    type SerializeMap = MapSerializer<'a>;
+    type SerializeStruct = MapSerializer<'a>;
+    type SerializeStructVariant = Impossible<(), QSettingsSerdeError>;
+
+    fn serialize_bool(self, v: bool) -> Result<()> {
+        self.write(&v)
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<()> {
+        self.write(&v)
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<()> {
+        self.write(&v)
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<()> {
+        self.write(&v)
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<()> {
+        self.write(&v)
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<()> {
+        self.write(&v)
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<()> {
+        self.write(&v)
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<()> {
+        self.write(&v)
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<()> {
+        self.write(&v)
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<()> {
+        self.write(&v)
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<()> {
+        self.write(&v)
+    }
+
+    fn serialize_char(self, v: char) -> Result<()> {
+        self.serialize_str(v.encode_utf8(&mut [0; 4]))
+    }
+
+    fn serialize_str(self, v: &str) -> Result<()> {
+        self.write(&QString::from(v))
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<()> {
+        self.write(&QByteArray::from(v))
+    }
+
+    fn serialize_none(self) -> Result<()> {
+        self.settings.remove(&self.slot.key());
+        Ok(())
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<()> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<()> {
+        self.serialize_none()
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<()> {
+        self.serialize_none()
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> Result<()> {
+        self.serialize_str(variant)
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<()> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _value: &T,
+    ) -> Result<()> {
+        Err(QSettingsSerdeError::Unsupported("a newtype enum variant"))
+    }
+
+    fn serialize_seq(mut self, len: Option<usize>) -> Result<SeqSerializer<'a>> {
+        let key = self.slot.key();
+        // Remove any previous entries, so that a shorter array doesn't leave stale entries behind
+        self.settings.as_mut().remove(&key);
+        let size = len.map_or(-1, |len| i32::try_from(len).unwrap_or(i32::MAX));
+        Ok(SeqSerializer {
+            array: self.settings.scoped_write_array(&key, size),
+            index: 0,
+        })
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<SeqSerializer<'a>> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_struct(self, _name: &'static str, len: usize) -> Result<SeqSerializer<'a>> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleVariant> {
+        Err(QSettingsSerdeError::Unsupported("a tuple enum variant"))
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<MapSerializer<'a>> {
+        Ok(MapSerializer {
+            scope: Scope::enter(self.settings, self.slot),
+            key: None,
+        })
+    }
+
+    fn serialize_struct(self, _name: &'static str, len: usize) -> Result<MapSerializer<'a>> {
+        self.serialize_map(Some(len))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStructVariant> {
+        Err(QSettingsSerdeError::Unsupported("a struct enum variant"))
+    }
+}
+
+/// Writes the elements of a sequence into a settings array.
+///
+/// The array is opened by [`ValueSerializer::serialize_seq`] and closed when the serializer is dropped,
+/// so that it is also closed when serializing an element fails.
+struct SeqSerializer<'a> {
+    array: QSettingsArrayGuard<'a>,
+    index: i32,
+}
+
+impl ser::SerializeSeq for SeqSerializer<'_> {
+    type Ok = ();
+    type Error = QSettingsSerdeError;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+        let index = self.index;
+        self.index += 1;
+        value.serialize(ValueSerializer {
+            settings: self.array.at(index),
+            slot: Slot::Entry,
+        })
+    }
+
+    fn end(self) -> Result<()> {
+        Ok(())
+    }
+}
+
+impl ser::SerializeTuple for SeqSerializer<'_> {
+    type Ok = ();
+    type Error = QSettingsSerdeError;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+        ser::SerializeSeq::serialize_element(self, value)
+    }
+
+    fn end(self) -> Result<()> {
+        ser::SerializeSeq::end(self)
+    }
+}
+
+impl ser::SerializeTupleStruct for SeqSerializer<'_> {
+    type Ok = ();
+    type Error = QSettingsSerdeError;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+        ser::SerializeSeq::serialize_element(self, value)
+    }
+
+    fn end(self) -> Result<()> {
+        ser::SerializeSeq::end(self)
+    }
+}
+
+/// Writes the entries of a map or the fields of a struct as keys of a group.
+struct MapSerializer<'a> {
+    scope: Scope<'a>,
+    key: Option<String>,
+}
+
+impl ser::SerializeMap for MapSerializer<'_> {
+    type Ok = ();
+    type Error = QSettingsSerdeError;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<()> {
+        self.key = Some(key.serialize(MapKeySerializer)?);
+        Ok(())
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+        let key = self.key.take().ok_or_else(|| {
+            QSettingsSerdeError::Custom("serialize_value called before serialize_key".to_owned())
+        })?;
+        value.serialize(ValueSerializer {
+            settings: self.scope.settings(),
+            slot: Slot::Key(&key),
+        })
+    }
+
+    fn end(self) -> Result<()> {
+        Ok(())
+    }
+}
+
+impl ser::SerializeStruct for MapSerializer<'_> {
+    type Ok = ();
+    type Error = QSettingsSerdeError;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<()> {
+        value.serialize(ValueSerializer {
+            settings: self.scope.settings(),
+            slot: Slot::Key(key),
+        })
+    }
+
+    fn end(self) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// Converts map keys into strings which can be used as settings keys.
+struct MapKeySerializer;
+
+impl MapKeySerializer {
+    fn display<T: fmt::Display>(value: T) -> Result<String> {
+        Ok(value.to_string())
+    }
+}
+
+impl ser::Serializer for MapKeySerializer {
+    type Ok = String;
+    type Error = QSettingsSerdeError;
+
+    type SerializeSeq = Impossible<String, QSettingsSerdeError>;
+    type SerializeTuple = Impossible<String, QSettingsSerdeError>;
+    type SerializeTupleStruct = Impossible<String, QSettingsSerdeError>;
+    type SerializeTupleVariant = Impossible<String, QSettingsSerdeError>;
+    type SerializeMap = Impossible<String, QSettingsSerdeError>;
+    type SerializeStruct = Impossible<String, QSettingsSerdeError>;
+    type SerializeStructVariant = Impossible<String, QSettingsSerdeError>;
+
+    fn serialize_bool(self, v: bool) -> Result<String> {
+        Self::display(v)
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<String> {
+        Self::display(v)
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<String> {
+        Self::display(v)
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<String> {
+        Self::display(v)
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<String> {
+        Self::display(v)
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<String> {
+        Self::display(v)
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<String> {
+        Self::display(v)
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<String> {
+        Self::display(v)
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<String> {
+        Self::display(v)
+    }
+
+    fn serialize_f32(self, _v: f32) -> Result<String> {
+        Err(QSettingsSerdeError::KeyMustBeAString)
+    }
+
+    fn serialize_f64(self, _v: f64) -> Result<String> {
+        Err(QSettingsSerdeError::KeyMustBeAString)
+    }
+
+    fn serialize_char(self, v: char) -> Result<String> {
+        Self::display(v)
+    }
+
+    fn serialize_str(self, v: &str) -> Result<String> {
+        Self::display(v)
+    }
+
+    fn serialize_bytes(self, _v: &[u8]) -> Result<String> {
+        Err(QSettingsSerdeError::KeyMustBeAString)
+    }
+
+    fn serialize_none(self) -> Result<String> {
+        Err(QSettingsSerdeError::KeyMustBeAString)
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, _value: &T) -> Result<String> {
+        Err(QSettingsSerdeError::KeyMustBeAString)
+    }
+
+    fn serialize_unit(self) -> Result<String> {
+        Err(QSettingsSerdeError::KeyMustBeAString)
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<String> {
+        Err(QSettingsSerdeError::KeyMustBeAString)
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> Result<String> {
+        Self::display(variant)
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<String> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _value: &T,
+    ) -> Result<String> {
+        Err(QSettingsSerdeError::KeyMustBeAString)
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> Result<Self::SerializeSeq> {
+        Err(QSettingsSerdeError::KeyMustBeAString)
+    }
+
+    fn serialize_tuple(self, _len: usize) -> Result<Self::SerializeTuple> {
+        Err(QSettingsSerdeError::KeyMustBeAString)
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleStruct> {
+        Err(QSettingsSerdeError::KeyMustBeAString)
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleVariant> {
+        Err(QSettingsSerdeError::KeyMustBeAString)
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap> {
+        Err(QSettingsSerdeError::KeyMustBeAString)
+    }
+
+    fn serialize_struct(self, _name: &'static str, _len: usize) -> Result<Self::SerializeStruct> {
+        Err(QSettingsSerdeError::KeyMustBeAString)
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStructVariant> {
+        Err(QSettingsSerdeError::KeyMustBeAString)
+    }
+}
+
+struct ValueDeserializer<'a, 'k> {
+    settings: Pin<&'a mut QSettings>,
+    slot: Slot<'k>,
+}
+
+impl ValueDeserializer<'_, '_> {
+    fn read<T: QVariantValue>(&self, expected: &'static str) -> Result<T> {
+        let key = self.slot.key();
+        self.settings
+            .value(&key)
+            .ok_or_else(|| QSettingsSerdeError::InvalidValue {
+                key: String::from(&key),
+                expected,
+            })
+    }
+
+    fn read_string(&self) -> Result<String> {
+        self.read::<QString>("string").map(String::from)
+    }
+}
+
+macro_rules! deserialize_scalar {
+    ($($method:ident => $visit:ident: $t:ty),* $(,)?) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+                visitor.$visit(self.read::<$t>(stringify!($t))?)
+            }
+        )*
+    };
+}
+
+impl<'de> de::Deserializer<'de> for ValueDeserializer<'_, '_> {
+    type Error = QSettingsSerdeError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        match self.slot {
+            Slot::Key(key) => {
+                let qkey = QString::from(key);
+                if !self.settings.child_groups().contains(&qkey) {
+                    if self.settings.contains(&qkey) {
+                        return self.deserialize_string(visitor);
+                    }
+                    return visitor.visit_none();
+                }
+                if self
+                    .settings
+                    .contains(&QString::from(&format!("{key}/size")))
+                {
+                    self.deserialize_seq(visitor)
+                } else {
+                    self.deserialize_map(visitor)
+                }
+            }
+            Slot::Entry => {
+                if self.settings.contains(&self.slot.key()) {
+                    self.deserialize_string(visitor)
+                } else {
+                    self.deserialize_map(visitor)
+                }
+            }
+        }
+    }
+
+    deserialize_scalar! {
+        deserialize_bool => visit_bool: bool,
+        deserialize_i8 => visit_i8: i8,
+        deserialize_i16 => visit_i16: i16,
+        deserialize_i32 => visit_i32: i32,
+        deserialize_i64 => visit_i64: i64,
+        deserialize_u8 => visit_u8: u8,
+        deserialize_u16 => visit_u16: u16,
+        deserialize_u32 => visit_u32: u32,
+        deserialize_u64 => visit_u64: u64,
+        deserialize_f32 => visit_f32: f32,
+        deserialize_f64 => visit_f64: f64,
+    }
+
+    fn deserialize_char<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        let string = self.read_string()?;
+        let mut chars = string.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => visitor.visit_char(c),
+            _ => visitor.visit_string(string),
+        }
+    }
+
+    fn deserialize_str<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        self.deserialize_string(visitor)
+    }
+
+    fn deserialize_string<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_string(self.read_string()?)
+    }
+
+    fn deserialize_bytes<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        self.deserialize_byte_buf(visitor)
+    }
+
+    fn deserialize_byte_buf<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        let bytes = self.read::<QByteArray>("byte array")?;
+        visitor.visit_byte_buf(Vec::from(&bytes))
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        if self.slot.exists(&self.settings) {
+            visitor.visit_some(self)
+        } else {
+            visitor.visit_none()
+        }
+    }
+
+    fn deserialize_unit<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_unit()
+    }
+
+    fn deserialize_unit_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value> {
+        visitor.visit_unit()
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_seq<V: Visitor<'de>>(mut self, visitor: V) -> Result<V::Value> {
+        let len = self.settings.as_mut().begin_read_array(&self.slot.key());
+        let result = visitor.visit_seq(SeqDeserializer {
+            settings: self.settings.as_mut(),
+            index: 0,
+            len,
+        });
+        self.settings.as_mut().end_array();
+        result
+    }
+
+    fn deserialize_tuple<V: Visitor<'de>>(self, _len: usize, visitor: V) -> Result<V::Value> {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_tuple_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _len: usize,
+        visitor: V,
+    ) -> Result<V::Value> {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_map<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        let mut scope = Scope::enter(self.settings, self.slot);
+        let settings = scope.settings();
+        let mut keys: Vec<String> = settings.child_keys().iter().map(String::from).collect();
+        keys.extend(settings.child_groups().iter().map(String::from));
+        visitor.visit_map(MapDeserializer {
+            scope,
+            keys,
+            index: 0,
+        })
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value> {
+        let mut scope = Scope::enter(self.settings, self.slot);
+        let settings = scope.settings();
+        // Only visit fields which exist, so that serde can apply defaults for the others
+        let keys = fields
+            .iter()
+            .filter(|field| Slot::Key(field).exists(&settings))
+            .map(|field| (*field).to_owned())
+            .collect();
+        visitor.visit_map(MapDeserializer {
+            scope,
+            keys,
+            index: 0,
+        })
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value> {
+        let variant: de::value::StringDeserializer<QSettingsSerdeError> =
+            self.read_string()?.into_deserializer();
+        visitor.visit_enum(variant)
+    }
+
+    fn deserialize_identifier<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        self.deserialize_string(visitor)
+    }
+
+    fn deserialize_ignored_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_unit()
+    }
+}
+
+/// Reads the entries of a settings array as a sequence.
+struct SeqDeserializer<'a> {
+    settings: Pin<&'a mut QSettings>,
+    index: i32,
+    len: i32,
+}
+
+impl<'de> de::SeqAccess<'de> for SeqDeserializer<'_> {
+    type Error = QSettingsSerdeError;
+
+    fn next_element_seed<T: de::DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>> {
+        if self.index >= self.len {
+            return Ok(None);
+        }
+        self.settings.as_mut().set_array_index(self.index);
+        self.index += 1;
+        seed.deserialize(ValueDeserializer {
+            settings: self.settings.as_mut(),
+            slot: Slot::Entry,
+        })
+        .map(Some)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        usize::try_from(self.len - self.index).ok()
+    }
+}
+
+/// Reads the keys of a group as a map or struct.
+struct MapDeserializer<'a> {
+    scope: Scope<'a>,
+    keys: Vec<String>,
+    index: usize,
+}
+
+impl<'de> de::MapAccess<'de> for MapDeserializer<'_> {
+    type Error = QSettingsSerdeError;
+
+    fn next_key_seed<K: de::DeserializeSeed<'de>>(&mut self, seed: K) -> Result<Option<K::Value>> {
+        let Some(key) = self.keys.get(self.index) else {
+            return Ok(None);
+        };
+        let key: de::value::StrDeserializer<QSettingsSerdeError> = key.as_str().into_deserializer();
+        seed.deserialize(key).map(Some)
+    }
+
+    fn next_value_seed<V: de::DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value> {
+        let key = &self.keys[self.index];
+        self.index += 1;
+        seed.deserialize(ValueDeserializer {
+            settings: self.scope.settings(),
+            slot: Slot::Key(key),
+        })
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.keys.len() - self.index)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::{temporary_settings, temporary_settings_path};
+    use super::*;
+    use crate::QSettingsFormat;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+    struct Window {
+        width: i32,
+        height: i32,
+        title: String,
+        maximized: bool,
+        opacity: f64,
+        #[serde(default)]
+        recent: Vec<String>,
+        position: Option<Position>,
+        theme: Theme,
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Position {
+        x: i32,
+        y: i32,
+    }
+
+    #[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+    enum Theme {
+        #[default]
+        Light,
+        Dark,
+    }
+
+    #[test]
+    fn qsettings_store_load_roundtrip() {
+        let mut settings = temporary_settings("serde_roundtrip");
+
+        let window = Window {
+            width: 640,
+            height: 480,
+            title: "Main".to_owned(),
+            maximized: true,
+            opacity: 0.5,
+            recent: vec!["a.txt".to_owned(), "b.txt".to_owned()],
+            position: Some(Position { x: 10, y: 20 }),
+            theme: Theme::Dark,
+        };
+        let group = QString::from("window");
+        settings.pin_mut().store(&group, &window).unwrap();
+        assert_eq!(
+            settings.value::<i32>(&QString::from("window/width")),
+            Some(640)
+        );
+        assert_eq!(
+            settings.value::<i32>(&QString::from("window/position/x")),
+            Some(10)
+        );
+
+        settings.pin_mut().sync();
+        assert_eq!(settings.pin_mut().load::<Window>(&group), Ok(window));
+    }
+
+    #[test]
+    fn qsettings_store_reopen_file() {
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Preferences {
+            width: i32,
+            title: String,
+            maximized: bool,
+        }
+
+        let path = temporary_settings_path("serde_reopen");
+        let _ = std::fs::remove_file(&path);
+        let file_name = QString::from(path.to_str().unwrap());
+        let preferences = Preferences {
+            width: 640,
+            title: "Main".to_owned(),
+            maximized: true,
+        };
+        {
+            let mut settings = QSettings::from_file(&file_name, QSettingsFormat::IniFormat);
+            settings
+                .pin_mut()
+                .store(&QString::from("window"), &preferences)
+                .unwrap();
+            // The settings are written to the file when they are destroyed
+        }
+
+        // Each field is a key in the group, as a QML Settings object with the category "window"
+        // and properties of the same names would write them
+        let contents = std::fs::read_to_string(&path).unwrap();
+        let lines = contents.lines().map(str::trim).collect::<Vec<_>>();
+        for line in ["[window]", "width=640", "title=Main", "maximized=true"] {
+            assert!(lines.contains(&line), "{line} not in {contents}");
+        }
+
+        let mut settings = QSettings::from_file(&file_name, QSettingsFormat::IniFormat);
+        assert_eq!(
+            settings
+                .pin_mut()
+                .load::<Preferences>(&QString::from("window")),
+            Ok(preferences)
+        );
+        drop(settings);
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn qsettings_load_missing_field() {
+        #[derive(Debug, Deserialize)]
+        struct Required {
+            #[allow(dead_code)]
+            value: i32,
+        }
+
+        let mut settings = temporary_settings("serde_missing_field");
+        assert_eq!(
+            settings
+                .pin_mut()
+                .load::<Required>(&QString::from("empty"))
+                .unwrap_err(),
+            QSettingsSerdeError::InvalidValue {
+                key: "value".to_owned(),
+                expected: "value",
+            }
+        );
+    }
+
+    #[test]
+    fn qsettings_store_error_closes_array() {
+        #[derive(Serialize)]
+        enum Shape {
+            Circle(f64),
+        }
+
+        #[derive(Serialize)]
+        struct Drawing {
+            shapes: Vec<Shape>,
+        }
+
+        let mut settings = temporary_settings("serde_store_error");
+        let drawing = Drawing {
+            shapes: vec![Shape::Circle(1.0)],
+        };
+        assert_eq!(
+            settings
+                .pin_mut()
+                .store(&QString::from("drawing"), &drawing)
+                .unwrap_err(),
+            QSettingsSerdeError::Unsupported("a newtype enum variant")
+        );
+        // Both the array and the group were closed, so new values are written at the top level
+        assert_eq!(settings.group(), QString::default());
+        settings
+            .pin_mut()
+            .set_value(&QString::from("after"), &1_i32);
+        assert!(settings.child_keys().contains(&QString::from("after")));
+    }
+}
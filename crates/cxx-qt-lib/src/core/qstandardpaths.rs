@@ -0,0 +1,217 @@
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Joshua Booth <joshua.n.booth@gmail.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::{unsafe_impl_qflag, QFlags, QString, QStringList};
+
+#[cxx::bridge]
+mod ffi {
+    /// This enum describes the different locations that can be queried using methods such as [`QStandardPaths::writable_location`], [`QStandardPaths::standard_locations`], and [`QStandardPaths::display_name`].
+    ///
+    /// Some of the values in this enum represent a user configuration. Such enum values will return the same paths in different applications, so they could be shared. Other values are specific to this application.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QStandardPathsStandardLocation {
+        /// Returns the user's desktop directory. This is a generic value. On systems with no concept of a desktop, this is the same as [`HomeLocation`](Self::HomeLocation).
+        DesktopLocation,
+        /// Returns the directory containing user document files. This is a generic value. The returned path is never empty.
+        DocumentsLocation,
+        /// Returns the directory containing user's fonts. This is a generic value. Note that installing fonts may require additional, platform-specific operations.
+        FontsLocation,
+        /// Returns the directory containing the user applications (either executables, application bundles, or shortcuts to them). This is a generic value. Note that installing applications may require additional, platform-specific operations. Files, folders or shortcuts in this directory are platform-specific.
+        ApplicationsLocation,
+        /// Returns the directory containing the user's music or other audio files. This is a generic value. If no directory specific for music files exists, a sensible fallback for storing user documents is returned.
+        MusicLocation,
+        /// Returns the directory containing the user's movies and videos. This is a generic value. If no directory specific for movie files exists, a sensible fallback for storing user documents is returned.
+        MoviesLocation,
+        /// Returns the directory containing the user's pictures or photos. This is a generic value. If no directory specific for picture files exists, a sensible fallback for storing user documents is returned.
+        PicturesLocation,
+        /// Returns a directory where temporary files can be stored. The returned value might be application-specific, shared among other applications for this user, or even system-wide. The returned path is never empty.
+        TempLocation,
+        /// Returns the user's home directory (the same as [`QDir::home_path`](crate::QDir::home_path)). On Unix systems, this is equal to the `HOME` environment variable. This value might be generic or application-specific, but the returned path is never empty.
+        HomeLocation,
+        /// Returns a directory location where persistent application data can be stored. This is an application-specific directory. To obtain a path to store data to be shared with other applications, use [`GenericDataLocation`](Self::GenericDataLocation). The returned path is never empty. On the Windows operating system, this returns the roaming path.
+        AppLocalDataLocation,
+        /// Returns a directory location where user-specific non-essential (cached) data should be written. This is an application-specific directory. The returned path is never empty.
+        CacheLocation,
+        /// Returns a directory location where persistent data shared across applications can be stored. This is a generic value. The returned path is never empty.
+        GenericDataLocation,
+        /// Returns a directory location where runtime communication files should be written, like Unix local sockets. This is a generic value. The returned path may be empty on some systems.
+        RuntimeLocation,
+        /// Returns a directory location where user-specific configuration files should be written. This may be either a generic value or application-specific, and the returned path is never empty.
+        ConfigLocation,
+        /// Returns a directory for user's downloaded files. This is a generic value. If no directory specific for downloads exists, a sensible fallback for storing user documents is returned.
+        DownloadLocation,
+        /// Returns a directory location where user-specific non-essential (cached) data, shared across applications, should be written. This is a generic value. Note that the returned path may be empty if the system has no concept of shared cache.
+        GenericCacheLocation,
+        /// Returns a directory location where user-specific configuration files shared between multiple applications should be written. This is a generic value and the returned path is never empty.
+        GenericConfigLocation,
+        /// Returns a directory location where persistent application data can be stored. This is an application-specific directory. To obtain a path to store data to be shared with other applications, use [`GenericDataLocation`](Self::GenericDataLocation). The returned path is never empty. On the Windows operating system, this returns the roaming path.
+        AppDataLocation,
+        /// Returns a directory location where user-specific configuration files should be written. This is an application-specific directory, and the returned path is never empty.
+        AppConfigLocation,
+        // Qt 6.4 added PublicShareLocation and TemplatesLocation
+        // Qt 6.7 added StateLocation and GenericStateLocation
+    }
+
+    /// This enum describes the different flags that can be used for controlling the behavior of [`QStandardPaths::locate`] and [`QStandardPaths::locate_all`].
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QStandardPathsLocateOption {
+        /// Return only files.
+        LocateFile = 0x0,
+        /// Return only directories.
+        LocateDirectory = 0x1,
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstandardpaths.h");
+        type QStandardPathsStandardLocation;
+        type QStandardPathsLocateOption;
+        type QStandardPathsLocateOptions = super::QStandardPathsLocateOptions;
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+        include!("cxx-qt-lib/qstringlist.h");
+        type QStringList = crate::QStringList;
+    }
+
+    // These are all static, so we need to create bindings until CXX supports statics
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qstandardpaths_display_name"]
+        fn qstandardpathsDisplayName(location: QStandardPathsStandardLocation) -> QString;
+        #[doc(hidden)]
+        #[rust_name = "qstandardpaths_find_executable"]
+        fn qstandardpathsFindExecutable(executable_name: &QString, paths: &QStringList) -> QString;
+        #[doc(hidden)]
+        #[rust_name = "qstandardpaths_locate"]
+        fn qstandardpathsLocate(
+            location: QStandardPathsStandardLocation,
+            file_name: &QString,
+            options: QStandardPathsLocateOptions,
+        ) -> QString;
+        #[doc(hidden)]
+        #[rust_name = "qstandardpaths_locate_all"]
+        fn qstandardpathsLocateAll(
+            location: QStandardPathsStandardLocation,
+            file_name: &QString,
+            options: QStandardPathsLocateOptions,
+        ) -> QStringList;
+        #[doc(hidden)]
+        #[rust_name = "qstandardpaths_set_test_mode_enabled"]
+        fn qstandardpathsSetTestModeEnabled(test_mode: bool);
+        #[doc(hidden)]
+        #[rust_name = "qstandardpaths_standard_locations"]
+        fn qstandardpathsStandardLocations(location: QStandardPathsStandardLocation)
+            -> QStringList;
+        #[doc(hidden)]
+        #[rust_name = "qstandardpaths_writable_location"]
+        fn qstandardpathsWritableLocation(location: QStandardPathsStandardLocation) -> QString;
+    }
+}
+
+pub use ffi::{QStandardPathsLocateOption, QStandardPathsStandardLocation};
+
+/// [`QFlags`] of [`QStandardPathsLocateOption`].
+pub type QStandardPathsLocateOptions = QFlags<QStandardPathsLocateOption>;
+unsafe_impl_qflag!(
+    QStandardPathsLocateOption,
+    "rust::cxxqtlib1::QStandardPathsLocateOptions",
+    i32
+);
+
+/// The `QStandardPaths` class provides methods for accessing standard paths.
+///
+/// This type only provides associated functions, in the same way as the static members of the C++ class.
+///
+/// Qt Documentation: [QStandardPaths](https://doc.qt.io/qt/qstandardpaths.html#details)
+#[derive(Debug)]
+pub struct QStandardPaths {
+    _private: (),
+}
+
+impl QStandardPaths {
+    /// Returns a localized display name for the given location `location` or an empty string if no relevant location can be found.
+    pub fn display_name(location: QStandardPathsStandardLocation) -> QString {
+        ffi::qstandardpaths_display_name(location)
+    }
+
+    /// Finds the executable named `executable_name` in the specified `paths`, or the system paths if `paths` is empty.
+    ///
+    /// On most operating systems the system path is determined by the `PATH` environment variable. The directories where to search for the executable can be set in the `paths` argument. To search in both your own paths and the system paths, call `find_executable` twice, once with `paths` set and once with `paths` empty. Symlinks are not resolved in order to preserve behavior for the case of executables whose behavior depends on the name they are invoked with.
+    ///
+    /// Returns `None` if the executable could not be found.
+    pub fn find_executable(executable_name: &QString, paths: &QStringList) -> Option<QString> {
+        let path = ffi::qstandardpaths_find_executable(executable_name, paths);
+        if path.is_empty() {
+            None
+        } else {
+            Some(path)
+        }
+    }
+
+    /// Finds a file or directory called `file_name` in the standard locations for `location`.
+    ///
+    /// The `options` flag allows you to specify whether to look for files or directories. By default, this flag is set to [`QStandardPathsLocateOption::LocateFile`].
+    ///
+    /// Returns the absolute path to the first file or directory found, otherwise returns `None`.
+    pub fn locate(
+        location: QStandardPathsStandardLocation,
+        file_name: &QString,
+        options: QStandardPathsLocateOptions,
+    ) -> Option<QString> {
+        let path = ffi::qstandardpaths_locate(location, file_name, options);
+        if path.is_empty() {
+            None
+        } else {
+            Some(path)
+        }
+    }
+
+    /// Finds all files or directories by the name, `file_name`, in the standard locations for `location`.
+    ///
+    /// The `options` flag allows you to specify whether to look for files or directories. By default, this flag is set to [`QStandardPathsLocateOption::LocateFile`].
+    ///
+    /// Returns the list of all the files that were found.
+    pub fn locate_all(
+        location: QStandardPathsStandardLocation,
+        file_name: &QString,
+        options: QStandardPathsLocateOptions,
+    ) -> QStringList {
+        ffi::qstandardpaths_locate_all(location, file_name, options)
+    }
+
+    /// If `test_mode` is `true`, this enables a special "test mode" in `QStandardPaths`, which changes writable locations to point to test directories. This prevents auto tests from reading or writing to the current user's configuration.
+    ///
+    /// It affects the locations into which test programs might write files: [`GenericDataLocation`](QStandardPathsStandardLocation::GenericDataLocation), [`AppDataLocation`](QStandardPathsStandardLocation::AppDataLocation), [`ConfigLocation`](QStandardPathsStandardLocation::ConfigLocation), [`GenericConfigLocation`](QStandardPathsStandardLocation::GenericConfigLocation), [`AppConfigLocation`](QStandardPathsStandardLocation::AppConfigLocation), [`GenericCacheLocation`](QStandardPathsStandardLocation::GenericCacheLocation), and [`CacheLocation`](QStandardPathsStandardLocation::CacheLocation). Other locations are not affected.
+    pub fn set_test_mode_enabled(test_mode: bool) {
+        ffi::qstandardpaths_set_test_mode_enabled(test_mode)
+    }
+
+    /// Returns all the directories where files of `location` belong.
+    ///
+    /// The list of directories is sorted from high to low priority, starting with [`writable_location`](Self::writable_location) if it can be determined. This list is empty if no locations for `location` are defined.
+    pub fn standard_locations(location: QStandardPathsStandardLocation) -> QStringList {
+        ffi::qstandardpaths_standard_locations(location)
+    }
+
+    /// Returns the directory where files of `location` should be written to, or `None` if the location cannot be determined.
+    ///
+    /// **Note:** The storage location returned may not exist; that is, it may need to be created by the system or the user.
+    pub fn writable_location(location: QStandardPathsStandardLocation) -> Option<QString> {
+        let path = ffi::qstandardpaths_writable_location(location);
+        if path.is_empty() {
+            None
+        } else {
+            Some(path)
+        }
+    }
+}
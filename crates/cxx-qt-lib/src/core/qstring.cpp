@@ -7,6 +7,8 @@
 // SPDX-License-Identifier: MIT OR Apache-2.0
 #include "cxx-qt-lib/qstring.h"
 
+#include <QtCore/QFile>
+
 #include <cxx-qt-lib/assertion_utils.h>
 
 // The layout has changed between Qt 5 and Qt 6
@@ -46,6 +48,39 @@ qstringInitFromRustString(::rust::Str string)
   return QString::fromUtf8(string.data(), string.size());
 }
 
+QString
+qstringInitFromUtf16(::rust::Slice<const ::std::uint16_t> string)
+{
+  // Qt 5 has an int Qt 6 has a qsizetype
+#if (QT_VERSION >= QT_VERSION_CHECK(6, 0, 0))
+  return QString(reinterpret_cast<const QChar*>(string.data()),
+                 static_cast<qsizetype>(string.size()));
+#else
+  return QString(reinterpret_cast<const QChar*>(string.data()),
+                 static_cast<int>(string.size()));
+#endif
+}
+
+QString
+qstringDecodeFileName(::rust::Slice<const ::std::uint8_t> name)
+{
+  // Qt 5 has an int Qt 6 has a qsizetype
+#if (QT_VERSION >= QT_VERSION_CHECK(6, 0, 0))
+  return QFile::decodeName(
+    QByteArray(reinterpret_cast<const char*>(name.data()),
+               static_cast<qsizetype>(name.size())));
+#else
+  return QFile::decodeName(QByteArray(
+    reinterpret_cast<const char*>(name.data()), static_cast<int>(name.size())));
+#endif
+}
+
+QByteArray
+qstringEncodeFileName(const QString& string)
+{
+  return QFile::encodeName(string);
+}
+
 ::rust::Slice<const ::std::uint16_t>
 qstringAsSlice(const QString& string)
 {
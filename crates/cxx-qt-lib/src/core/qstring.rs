@@ -7,6 +7,7 @@ use cxx::{type_id, ExternType};
 use std::cmp::Ordering;
 use std::fmt::{self, Write};
 use std::mem::MaybeUninit;
+use std::path::{Path, PathBuf};
 
 use crate::{CaseSensitivity, QByteArray, QStringList, SplitBehaviorFlags};
 
@@ -130,6 +131,15 @@ mod ffi {
         #[rust_name = "qstring_init_from_rust_string"]
         fn qstringInitFromRustString(string: &str) -> QString;
         #[doc(hidden)]
+        #[rust_name = "qstring_init_from_utf16"]
+        fn qstringInitFromUtf16(string: &[u16]) -> QString;
+        #[doc(hidden)]
+        #[rust_name = "qstring_decode_file_name"]
+        fn qstringDecodeFileName(name: &[u8]) -> QString;
+        #[doc(hidden)]
+        #[rust_name = "qstring_encode_file_name"]
+        fn qstringEncodeFileName(string: &QString) -> QByteArray;
+        #[doc(hidden)]
         #[rust_name = "qstring_init_from_qstring"]
         fn construct(string: &QString) -> QString;
 
@@ -340,6 +350,66 @@ impl From<QString> for String {
     }
 }
 
+impl From<&Path> for QString {
+    /// Constructs a `QString` from a file system path.
+    ///
+    /// On Windows the path is converted losslessly from UTF-16. On other platforms the path is
+    /// decoded like [QFile::decodeName](https://doc.qt.io/qt/qfile.html#decodeName), so that it
+    /// refers to the same file in Qt's file APIs, including paths which are not valid UTF-8.
+    fn from(path: &Path) -> Self {
+        #[cfg(windows)]
+        {
+            use std::os::windows::ffi::OsStrExt;
+            let wide: Vec<u16> = path.as_os_str().encode_wide().collect();
+            QString::from_utf16(&wide)
+        }
+        #[cfg(not(windows))]
+        {
+            use std::os::unix::ffi::OsStrExt;
+            ffi::qstring_decode_file_name(path.as_os_str().as_bytes())
+        }
+    }
+}
+
+impl From<&PathBuf> for QString {
+    /// Constructs a `QString` from a file system path.
+    ///
+    /// See the `From<&Path>` implementation for how the path is converted.
+    fn from(path: &PathBuf) -> Self {
+        Self::from(path.as_path())
+    }
+}
+
+impl From<&QString> for PathBuf {
+    /// Constructs a file system path from a `QString`.
+    ///
+    /// On Windows the path is converted losslessly to UTF-16. On other platforms the string is
+    /// encoded like [QFile::encodeName](https://doc.qt.io/qt/qfile.html#encodeName), which is
+    /// the reverse of the `From<&Path>` implementation of [`QString`].
+    fn from(qstring: &QString) -> Self {
+        #[cfg(windows)]
+        {
+            use std::os::windows::ffi::OsStringExt;
+            PathBuf::from(std::ffi::OsString::from_wide(qstring.as_slice()))
+        }
+        #[cfg(not(windows))]
+        {
+            use std::os::unix::ffi::OsStrExt;
+            let encoded = ffi::qstring_encode_file_name(qstring);
+            PathBuf::from(std::ffi::OsStr::from_bytes(encoded.as_slice()))
+        }
+    }
+}
+
+impl From<QString> for PathBuf {
+    /// Constructs a file system path from a `QString`.
+    ///
+    /// See the `From<&QString>` implementation for how the string is converted.
+    fn from(qstring: QString) -> Self {
+        Self::from(&qstring)
+    }
+}
+
 impl QString {
     /// Returns a copy of this string with the lowest numbered place marker replaced by string `a`, i.e., %1, %2, ..., %99.
     ///
@@ -362,6 +432,11 @@ impl QString {
         self.compare_i32(other, cs).cmp(&0)
     }
 
+    /// Constructs a `QString` from a UTF-16 slice. Unpaired surrogates are copied as-is.
+    pub fn from_utf16(utf16: &[u16]) -> Self {
+        ffi::qstring_init_from_utf16(utf16)
+    }
+
     /// Returns the index position of the first occurrence of the string `str` in this string,
     /// searching forward from index position `from`. Returns -1 if `str` is not found.
     ///
@@ -535,4 +610,36 @@ mod test {
             Ordering::Equal
         );
     }
+    #[test]
+    fn path_roundtrip() {
+        let path = Path::new("/tmp/kdab/ünïcödé.txt");
+        let qstring = QString::from(path);
+        assert_eq!(qstring, QString::from("/tmp/kdab/ünïcödé.txt"));
+        assert_eq!(PathBuf::from(&qstring), path);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn path_not_utf8() {
+        use std::os::unix::ffi::OsStrExt;
+
+        let bytes = b"/tmp/kdab/\xFF.txt";
+        let path = Path::new(std::ffi::OsStr::from_bytes(bytes));
+        // The path is decoded like Qt decodes file names, rather than being rejected
+        let qstring = QString::from(path);
+        assert_eq!(qstring, ffi::qstring_decode_file_name(bytes));
+        assert!(String::from(&qstring).starts_with("/tmp/kdab/"));
+        assert!(String::from(&qstring).ends_with(".txt"));
+        // Converting back gives the file name which Qt uses to access the file
+        assert_eq!(
+            PathBuf::from(&qstring).as_os_str().as_bytes(),
+            ffi::qstring_encode_file_name(&qstring).as_slice()
+        );
+    }
+
+    #[test]
+    fn from_utf16_keeps_unpaired_surrogates() {
+        let qstring = QString::from_utf16(&[0x61, 0xD800, 0x62]);
+        assert_eq!(qstring.as_slice(), &[0x61, 0xD800, 0x62]);
+    }
 }
@@ -0,0 +1,48 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Joshua Booth <joshua.n.booth@gmail.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#include "cxx-qt-lib/qthread.h"
+
+#include <QtCore/QDeadlineTimer>
+
+#include "cxx-qt-lib/qobject.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QThread>
+qthreadNew()
+{
+  return ::std::make_unique<QThread>();
+}
+
+bool
+qthreadWait(QThread& thread, int msecs)
+{
+  // QThread::wait(unsigned long) uses a platform dependent integer type,
+  // so go through QDeadlineTimer instead
+  if (msecs < 0) {
+    return thread.wait(QDeadlineTimer(QDeadlineTimer::Forever));
+  }
+  return thread.wait(QDeadlineTimer(msecs));
+}
+
+bool
+qthreadMoveObject(QThread& thread, QObject& object)
+{
+  if (!qobjectMoveToThread(object, &thread)) {
+    return false;
+  }
+
+  // The thread owns the object from now on, deferred deletions are still
+  // processed in the thread after finished has been emitted
+  QObject::connect(
+    &thread, &QThread::finished, &object, &QObject::deleteLater);
+  return true;
+}
+
+}
+}
@@ -0,0 +1,233 @@
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Joshua Booth <joshua.n.booth@gmail.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::QObject;
+use core::pin::Pin;
+use cxx::{memory::UniquePtrTarget, UniquePtr};
+use cxx_qt::{casting::Upcast, CxxQtThread, MoveToThread, Threading};
+
+#[cxx_qt::bridge]
+mod ffi {
+    /// This enum type indicates how the operating system should schedule newly created threads.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QThreadPriority {
+        /// Scheduled only when no other threads are running.
+        IdlePriority,
+        /// Scheduled less often than [`LowPriority`](Self::LowPriority).
+        LowestPriority,
+        /// Scheduled less often than [`NormalPriority`](Self::NormalPriority).
+        LowPriority,
+        /// The default priority of the operating system.
+        NormalPriority,
+        /// Scheduled more often than [`NormalPriority`](Self::NormalPriority).
+        HighPriority,
+        /// Scheduled more often than [`HighPriority`](Self::HighPriority).
+        HighestPriority,
+        /// Scheduled as often as possible.
+        TimeCriticalPriority,
+        /// Use the same priority as the creating thread. This is the default.
+        InheritPriority,
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qthread.h");
+        type QThreadPriority;
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qobject.h");
+        type QObject = crate::QObject;
+    }
+
+    unsafe extern "C++Qt" {
+        /// The `QThread` class provides a platform-independent way to manage threads.
+        ///
+        /// By default, [`start`](Self::start) runs an event loop in the new thread. Objects can be moved into the thread with [`move_object`](Self::move_object), after which their slots and queued closures run in that thread.
+        ///
+        /// **Note:** Dropping a `QThread` while it is still running will abort the program. Call [`quit`](Self::quit) and [`wait`](Self::wait) first.
+        ///
+        /// Qt Documentation: [QThread](https://doc.qt.io/qt/qthread.html#details)
+        #[qobject]
+        type QThread;
+
+        /// Tells the thread's event loop to exit with a return code.
+        ///
+        /// After calling this function, the thread leaves the event loop and returns from the call to `QEventLoop::exec()`. By convention, a `return_code` of 0 means success, any non-zero value indicates an error.
+        ///
+        /// No event loops will be started anymore in this thread until [`start`](Self::start) has been called again.
+        fn exit(self: Pin<&mut QThread>, return_code: i32);
+
+        /// Returns `true` if the thread is finished; otherwise returns `false`.
+        #[rust_name = "is_finished"]
+        fn isFinished(self: &QThread) -> bool;
+
+        /// Return `true` if the task running on this thread should be stopped. An interruption can be requested by [`request_interruption`](Self::request_interruption).
+        #[rust_name = "is_interruption_requested"]
+        fn isInterruptionRequested(self: &QThread) -> bool;
+
+        /// Returns `true` if the thread is running; otherwise returns `false`.
+        #[rust_name = "is_running"]
+        fn isRunning(self: &QThread) -> bool;
+
+        /// Returns the current event loop level for the thread.
+        ///
+        /// **Note:** This can only be called within the thread itself, i.e. when it is the current thread.
+        #[rust_name = "loop_level"]
+        fn loopLevel(self: &QThread) -> i32;
+
+        /// Returns the priority for a running thread. If the thread is not running, this function returns [`QThreadPriority::InheritPriority`].
+        fn priority(self: &QThread) -> QThreadPriority;
+
+        /// Tells the thread's event loop to exit with return code 0 (success). Equivalent to calling [`exit(0)`](Self::exit).
+        ///
+        /// This function does nothing if the thread does not have an event loop.
+        fn quit(self: Pin<&mut QThread>);
+
+        /// Request the interruption of the thread. That request is advisory and it is up to code running on the thread to decide if and how it should act upon such request. This function does not stop any event loop running on the thread and does not terminate it in any way.
+        #[rust_name = "request_interruption"]
+        fn requestInterruption(self: Pin<&mut QThread>);
+
+        /// This function sets the `priority` for a running thread. If the thread is not running, this function does nothing and returns immediately. Use [`start_with_priority`](Self::start_with_priority) to start a thread with a specific priority.
+        #[rust_name = "set_priority"]
+        fn setPriority(self: Pin<&mut QThread>, priority: QThreadPriority);
+
+        /// Begins execution of the thread. The operating system will schedule the thread according to the `priority` parameter. If the thread is already running, this function does nothing.
+        #[rust_name = "start_with_priority"]
+        fn start(self: Pin<&mut QThread>, priority: QThreadPriority);
+
+        /// This signal is emitted from the associated thread right before it finishes executing.
+        ///
+        /// When this signal is emitted, the event loop has already stopped running. No more events will be processed in the thread, except for deferred deletion events.
+        #[qsignal]
+        fn finished(self: Pin<&mut QThread>);
+
+        /// This signal is emitted from the associated thread when it starts executing, before any code is run in the thread.
+        #[qsignal]
+        fn started(self: Pin<&mut QThread>);
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qthread_new"]
+        fn qthreadNew() -> UniquePtr<QThread>;
+
+        #[doc(hidden)]
+        #[rust_name = "qthread_wait"]
+        fn qthreadWait(thread: Pin<&mut QThread>, msecs: i32) -> bool;
+
+        #[doc(hidden)]
+        #[rust_name = "qthread_move_object"]
+        fn qthreadMoveObject(thread: Pin<&mut QThread>, object: Pin<&mut QObject>) -> bool;
+    }
+
+    // These are all static, so we need to create bindings until CXX supports statics
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qthread_current_thread"]
+        fn qthreadCurrentThread() -> *mut QThread;
+        #[doc(hidden)]
+        #[rust_name = "qthread_ideal_thread_count"]
+        fn qthreadIdealThreadCount() -> i32;
+    }
+
+    impl UniquePtr<QThread> {}
+}
+
+pub use ffi::{QThread, QThreadPriority};
+
+impl QThread {
+    /// Constructs a new `QThread` to manage a new thread. The thread does not begin executing until [`start`](Self::start) is called.
+    pub fn new() -> cxx::UniquePtr<Self> {
+        ffi::qthread_new()
+    }
+
+    /// Moves `object` to this thread and hands its ownership over to the thread, which deletes the object
+    /// in this thread once it has [`finished`](Self::finished). This is the usual way of running a worker object in a `QThread`.
+    ///
+    /// Returns a [`CxxQtThread`] for queueing closures onto the object, which are run in this thread.
+    /// As the object is no longer reachable from the current thread, this is the only way to access it.
+    ///
+    /// If the object is null or cannot be moved, for example because it has a parent, it is returned unchanged.
+    ///
+    /// **Note:** The object is only deleted if the thread finishes after this call, so start the thread before quitting it.
+    pub fn move_object<T>(
+        self: Pin<&mut Self>,
+        mut object: UniquePtr<T>,
+    ) -> Result<CxxQtThread<T>, UniquePtr<T>>
+    where
+        T: Threading + MoveToThread + Upcast<QObject> + UniquePtrTarget,
+    {
+        // Create the handle while the object still lives in the current thread
+        let Some(qt_thread) = object.as_ref().map(Threading::qt_thread) else {
+            return Err(object);
+        };
+        if !ffi::qthread_move_object(self, object.pin_mut().upcast_pin()) {
+            return Err(object);
+        }
+        // The thread now owns the object, so it must not be deleted from the current thread
+        let _ = object.into_raw();
+        Ok(qt_thread)
+    }
+
+    /// Returns a pointer to a `QThread` which manages the currently executing thread.
+    pub fn current_thread() -> *mut Self {
+        ffi::qthread_current_thread()
+    }
+
+    /// Returns the ideal number of threads that this process can run in parallel. This is done by querying the number of logical processors available to this process (if supported by this OS) or the total number of logical processors in the system. This function returns 1 if neither value could be determined.
+    pub fn ideal_thread_count() -> i32 {
+        ffi::qthread_ideal_thread_count()
+    }
+
+    /// Begins execution of the thread with the same priority as the creating thread. If the thread is already running, this function does nothing.
+    pub fn start(self: Pin<&mut Self>) {
+        self.start_with_priority(QThreadPriority::InheritPriority);
+    }
+
+    /// Blocks the calling thread until either the thread has finished execution, or `msecs` milliseconds have passed. If `msecs` is -1, this function will not time out.
+    ///
+    /// Returns `true` if the thread has finished or has not been started yet; otherwise returns `false` (if the operation timed out).
+    pub fn wait(self: Pin<&mut Self>, msecs: i32) -> bool {
+        ffi::qthread_wait(self, msecs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{QFileSystemWatcher, QObjectExt};
+
+    #[test]
+    fn qthread_start_quit_wait() {
+        let mut thread = QThread::new();
+        assert!(!thread.is_running());
+        thread.pin_mut().start();
+        assert!(thread.is_running());
+        thread.pin_mut().quit();
+        assert!(thread.pin_mut().wait(-1));
+        assert!(thread.is_finished());
+    }
+
+    #[test]
+    fn qobject_move_to_thread() {
+        let mut thread = QThread::new();
+        let mut watcher = QFileSystemWatcher::new();
+        assert_eq!(watcher.thread(), QThread::current_thread());
+
+        // Safety: the watcher is not accessed from this thread anymore and is destroyed once the thread has finished
+        assert!(unsafe { watcher.pin_mut().move_to_thread(thread.pin_mut()) });
+        let thread_ptr: *const QThread = &*thread;
+        assert_eq!(watcher.thread().cast_const(), thread_ptr);
+
+        thread.pin_mut().start();
+        thread.pin_mut().quit();
+        assert!(thread.pin_mut().wait(-1));
+    }
+}
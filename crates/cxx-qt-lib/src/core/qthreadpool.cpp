@@ -0,0 +1,39 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Joshua Booth <joshua.n.booth@gmail.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#include "cxx-qt-lib/qthreadpool.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QThreadPool>
+qthreadpoolNew()
+{
+  return ::std::make_unique<QThreadPool>();
+}
+
+void
+qthreadpoolStart(QThreadPool& pool,
+                 ::std::unique_ptr<QRunnable> runnable,
+                 int priority)
+{
+  // The runnable has autoDelete enabled, so the pool takes ownership
+  pool.start(runnable.release(), priority);
+}
+
+bool
+qthreadpoolTryStart(QThreadPool& pool, ::std::unique_ptr<QRunnable> runnable)
+{
+  // The pool only takes ownership if the runnable was started
+  if (pool.tryStart(runnable.get())) {
+    runnable.release();
+    return true;
+  }
+  return false;
+}
+
+}
+}
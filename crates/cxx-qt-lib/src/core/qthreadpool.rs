@@ -0,0 +1,165 @@
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Joshua Booth <joshua.n.booth@gmail.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::QRunnable;
+use core::pin::Pin;
+use cxx::UniquePtr;
+
+#[cxx_qt::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qrunnable.h");
+        type QRunnable = crate::QRunnable;
+    }
+
+    unsafe extern "C++Qt" {
+        include!("cxx-qt-lib/qthreadpool.h");
+        /// The `QThreadPool` class manages a collection of `QThread`s.
+        ///
+        /// Each Qt application has one global `QThreadPool` object, which can be accessed by calling [`global_instance`](Self::global_instance).
+        ///
+        /// Qt Documentation: [QThreadPool](https://doc.qt.io/qt/qthreadpool.html#details)
+        #[qobject]
+        type QThreadPool;
+
+        /// Returns the number of active threads in the thread pool.
+        ///
+        /// **Note:** It is possible for this function to return a value that is greater than [`max_thread_count`](Self::max_thread_count).
+        #[rust_name = "active_thread_count"]
+        fn activeThreadCount(self: &QThreadPool) -> i32;
+
+        /// Removes the runnables that are not yet started from the queue. The runnables are deleted.
+        fn clear(self: Pin<&mut QThreadPool>);
+
+        /// Threads that are unused for `expiry_timeout` milliseconds are considered to have expired and will exit. Such threads will be restarted as needed. The default `expiry_timeout` is 30000 milliseconds (30 seconds). If `expiry_timeout` is negative, newly created threads will not expire.
+        #[rust_name = "expiry_timeout"]
+        fn expiryTimeout(self: &QThreadPool) -> i32;
+
+        /// This property represents the maximum number of threads used by the thread pool. This property will default to the value of [`QThread::ideal_thread_count`](crate::QThread::ideal_thread_count) at the moment the `QThreadPool` object is created.
+        #[rust_name = "max_thread_count"]
+        fn maxThreadCount(self: &QThreadPool) -> i32;
+
+        /// Releases a thread previously reserved by a call to [`reserve_thread`](Self::reserve_thread).
+        #[rust_name = "release_thread"]
+        fn releaseThread(self: Pin<&mut QThreadPool>);
+
+        /// Reserves one thread, disregarding [`active_thread_count`](Self::active_thread_count) and [`max_thread_count`](Self::max_thread_count).
+        ///
+        /// Once you are done with the thread, call [`release_thread`](Self::release_thread) to allow it to be reused.
+        #[rust_name = "reserve_thread"]
+        fn reserveThread(self: Pin<&mut QThreadPool>);
+
+        /// Sets the expiry timeout in milliseconds. See [`expiry_timeout`](Self::expiry_timeout).
+        #[rust_name = "set_expiry_timeout"]
+        fn setExpiryTimeout(self: Pin<&mut QThreadPool>, expiry_timeout: i32);
+
+        /// Sets the maximum number of threads used by the thread pool. See [`max_thread_count`](Self::max_thread_count).
+        #[rust_name = "set_max_thread_count"]
+        fn setMaxThreadCount(self: Pin<&mut QThreadPool>, max_thread_count: i32);
+
+        /// Waits up to `msecs` milliseconds for all threads to exit and removes all threads from the thread pool. Returns `true` if all threads were removed; otherwise it returns `false`. If `msecs` is -1, this function waits for the last thread to exit.
+        #[rust_name = "wait_for_done"]
+        fn waitForDone(self: Pin<&mut QThreadPool>, msecs: i32) -> bool;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qthreadpool_new"]
+        fn qthreadpoolNew() -> UniquePtr<QThreadPool>;
+
+        #[doc(hidden)]
+        #[rust_name = "qthreadpool_start"]
+        fn qthreadpoolStart(
+            pool: Pin<&mut QThreadPool>,
+            runnable: UniquePtr<QRunnable>,
+            priority: i32,
+        );
+
+        #[doc(hidden)]
+        #[rust_name = "qthreadpool_try_start"]
+        fn qthreadpoolTryStart(pool: Pin<&mut QThreadPool>, runnable: UniquePtr<QRunnable>)
+            -> bool;
+    }
+
+    // These are all static, so we need to create bindings until CXX supports statics
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qthreadpool_global_instance"]
+        fn qthreadpoolGlobalInstance() -> *mut QThreadPool;
+    }
+
+    impl UniquePtr<QThreadPool> {}
+}
+
+pub use ffi::QThreadPool;
+
+impl QThreadPool {
+    /// Constructs a thread pool.
+    pub fn new() -> UniquePtr<Self> {
+        ffi::qthreadpool_new()
+    }
+
+    /// Returns the global `QThreadPool` instance.
+    pub fn global_instance() -> *mut Self {
+        ffi::qthreadpool_global_instance()
+    }
+
+    /// Reserves a thread and uses it to run `runnable`, unless this thread will make the current thread count exceed [`max_thread_count`](Self::max_thread_count). In that case, `runnable` is added to a run queue instead.
+    ///
+    /// The thread pool takes ownership of the runnable and deletes it after it has been run.
+    pub fn start(self: Pin<&mut Self>, runnable: UniquePtr<QRunnable>) {
+        self.start_with_priority(runnable, 0);
+    }
+
+    /// Reserves a thread and uses it to run `runnable`. The `priority` argument can be used to control the run queue's order of execution.
+    ///
+    /// The thread pool takes ownership of the runnable and deletes it after it has been run.
+    pub fn start_with_priority(
+        self: Pin<&mut Self>,
+        runnable: UniquePtr<QRunnable>,
+        priority: i32,
+    ) {
+        ffi::qthreadpool_start(self, runnable, priority);
+    }
+
+    /// Reserves a thread and runs `closure` on it, or adds it to the run queue if no thread is available.
+    pub fn spawn<F>(self: Pin<&mut Self>, closure: F)
+    where
+        F: FnOnce() + Send + 'static,
+    {
+        self.start(QRunnable::new(closure));
+    }
+
+    /// Attempts to reserve a thread to run `runnable`.
+    ///
+    /// If no threads are available at the time of calling, then this function does nothing and returns `false`. Otherwise, `runnable` is run immediately using one available thread and this function returns `true`.
+    pub fn try_start(self: Pin<&mut Self>, runnable: UniquePtr<QRunnable>) -> bool {
+        ffi::qthreadpool_try_start(self, runnable)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    #[test]
+    fn qthreadpool_spawn() {
+        let counter = Arc::new(AtomicUsize::new(0));
+        let mut pool = QThreadPool::new();
+        pool.pin_mut().set_max_thread_count(2);
+        for _ in 0..8 {
+            let counter = counter.clone();
+            pool.pin_mut().spawn(move || {
+                counter.fetch_add(1, Ordering::SeqCst);
+            });
+        }
+        assert!(pool.pin_mut().wait_for_done(-1));
+        assert_eq!(counter.load(Ordering::SeqCst), 8);
+    }
+}
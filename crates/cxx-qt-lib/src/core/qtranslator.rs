@@ -0,0 +1,124 @@
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Joshua Booth <joshua.n.booth@gmail.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx_qt::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qlocale.h");
+        type QLocale = crate::QLocale;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+    }
+
+    unsafe extern "C++Qt" {
+        include!("cxx-qt-lib/qtranslator.h");
+        /// The `QTranslator` class provides internationalization support for text output.
+        ///
+        /// A translator is installed with [`QCoreApplication::install_translator`](crate::QCoreApplication::install_translator), and removes itself from the application when it is dropped.
+        ///
+        /// Qt Documentation: [QTranslator](https://doc.qt.io/qt/qtranslator.html#details)
+        #[qobject]
+        type QTranslator;
+
+        /// Returns the path of the loaded translation file.
+        ///
+        /// The file path is empty if no translation was loaded yet, the loading failed, or if the translation was not loaded from a file.
+        #[rust_name = "file_path"]
+        fn filePath(self: &QTranslator) -> QString;
+
+        /// Returns `true` if this translator is empty, otherwise returns `false`.
+        #[rust_name = "is_empty"]
+        fn isEmpty(self: &QTranslator) -> bool;
+
+        /// Returns the target language as stored in the translation file.
+        fn language(self: &QTranslator) -> QString;
+
+        /// Loads `filename + suffix` (".qm" if the `suffix` is empty), which may be an absolute file name or relative to `directory`. Returns `true` if the translation is successfully loaded; otherwise returns `false`.
+        ///
+        /// If `directory` is empty, the directory of the application is used. Any existing translation is discarded.
+        ///
+        /// If the file name does not exist, other file names are tried by removing the text after the last character of `search_delimiters` ("_." if empty) in turn, so that for example `"mymodule_fr_CA"` falls back to `"mymodule_fr"` and `"mymodule"`.
+        fn load(
+            self: Pin<&mut QTranslator>,
+            filename: &QString,
+            directory: &QString,
+            search_delimiters: &QString,
+            suffix: &QString,
+        ) -> bool;
+
+        /// Loads `filename + prefix + ui language name + suffix` (".qm" if the `suffix` is empty), which may be an absolute file name or relative to `directory`. Returns `true` if the translation is successfully loaded; otherwise returns `false`.
+        ///
+        /// The ui language names of `locale` are tried in turn, for example `"fr_CA"`, `"fr"` and then no language name.
+        #[rust_name = "load_locale"]
+        fn load(
+            self: Pin<&mut QTranslator>,
+            locale: &QLocale,
+            filename: &QString,
+            prefix: &QString,
+            directory: &QString,
+            suffix: &QString,
+        ) -> bool;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qtranslator_new"]
+        fn qtranslatorNew() -> UniquePtr<QTranslator>;
+    }
+
+    impl UniquePtr<QTranslator> {}
+}
+
+pub use ffi::QTranslator;
+
+impl QTranslator {
+    /// Constructs an empty message file object.
+    pub fn new() -> cxx::UniquePtr<Self> {
+        ffi::qtranslator_new()
+    }
+}
+
+/// Translates `source_text` in `context` with the translators installed in the application,
+/// see [`QCoreApplication::translate`](crate::QCoreApplication::translate).
+///
+/// The context, source text and disambiguation need to be string literals, so that the strings
+/// can be extracted from Rust sources by the `lupdate` tool of `qt-build-utils`.
+/// If a count is given, it is used to choose the plural form of the translation.
+///
+/// ```ignore
+/// use cxx_qt_lib::tr;
+///
+/// let title = tr!("MainWindow", "Open file");
+/// let action = tr!("MainWindow", "Open", "verb");
+/// let status = tr!("MainWindow", "%n file(s) selected", "", count);
+/// ```
+#[macro_export]
+macro_rules! tr {
+    ($context:literal, $source_text:literal $(,)?) => {
+        $crate::QCoreApplication::translate(
+            $context,
+            $source_text,
+            ::core::option::Option::None,
+            ::core::option::Option::None,
+        )
+    };
+    ($context:literal, $source_text:literal, $disambiguation:literal $(,)?) => {
+        $crate::QCoreApplication::translate(
+            $context,
+            $source_text,
+            ::core::option::Option::Some($disambiguation),
+            ::core::option::Option::None,
+        )
+    };
+    ($context:literal, $source_text:literal, $disambiguation:literal, $n:expr $(,)?) => {
+        $crate::QCoreApplication::translate(
+            $context,
+            $source_text,
+            ::core::option::Option::Some($disambiguation),
+            ::core::option::Option::Some($n),
+        )
+    };
+}
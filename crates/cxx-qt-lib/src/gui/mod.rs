@@ -9,6 +9,15 @@ pub use qcolor::{QColor, QColorNameFormat, QColorSpec};
 mod qguiapplication;
 pub use qguiapplication::QGuiApplication;
 
+mod qhoverevent;
+pub use qhoverevent::QHoverEvent;
+
+mod qkeyevent;
+pub use qkeyevent::QKeyEvent;
+
+mod qmouseevent;
+pub use qmouseevent::QMouseEvent;
+
 mod qvector2d;
 pub use qvector2d::QVector2D;
 
@@ -4,7 +4,9 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
-use crate::{KeyboardModifiers, MouseButtons, QByteArray, QFont, QString, QStringList, QVector};
+use crate::{
+    KeyboardModifiers, MouseButtons, QByteArray, QFont, QString, QStringList, QTranslator, QVector,
+};
 use core::pin::Pin;
 
 #[cxx_qt::bridge]
@@ -20,6 +22,8 @@ mod ffi {
         type QVector_QByteArray = crate::QVector<QByteArray>;
         include!("cxx-qt-lib/qfont.h");
         type QFont = crate::QFont;
+        include!("cxx-qt-lib/qtranslator.h");
+        type QTranslator = crate::QTranslator;
 
         include!("cxx-qt-lib/qcoreapplication.h");
         type QCoreApplication = crate::QCoreApplication;
@@ -71,6 +75,12 @@ mod ffi {
         #[rust_name = "qguiapplication_exec"]
         fn qapplicationExec(app: Pin<&mut QGuiApplication>) -> i32;
         #[doc(hidden)]
+        #[rust_name = "qguiapplication_install_translator"]
+        fn qapplicationInstallTranslator(
+            app: Pin<&mut QGuiApplication>,
+            translator: Pin<&mut QTranslator>,
+        ) -> bool;
+        #[doc(hidden)]
         #[rust_name = "qguiapplication_library_paths"]
         fn qapplicationLibraryPaths(app: &QGuiApplication) -> QStringList;
         #[doc(hidden)]
@@ -80,6 +90,12 @@ mod ffi {
         #[rust_name = "qguiapplication_organization_name"]
         fn qapplicationOrganizationName(app: &QGuiApplication) -> QString;
         #[doc(hidden)]
+        #[rust_name = "qguiapplication_remove_translator"]
+        fn qapplicationRemoveTranslator(
+            app: Pin<&mut QGuiApplication>,
+            translator: Pin<&mut QTranslator>,
+        ) -> bool;
+        #[doc(hidden)]
         #[rust_name = "qguiapplication_set_application_name"]
         fn qapplicationSetApplicationName(app: Pin<&mut QGuiApplication>, name: &QString);
         #[doc(hidden)]
@@ -158,6 +174,16 @@ impl QGuiApplication {
         ffi::qguiapplication_font()
     }
 
+    /// Adds `translator` to the list of translation files to be used for translations.
+    /// Returns `true` on success and `false` on failure.
+    ///
+    /// Multiple translation files can be installed. Translations are searched for in the reverse order in which they were installed, so the most recently installed translation file is searched first.
+    ///
+    /// The translator is removed from the application when it is dropped.
+    pub fn install_translator(self: Pin<&mut Self>, translator: Pin<&mut QTranslator>) -> bool {
+        ffi::qguiapplication_install_translator(self, translator)
+    }
+
     /// Returns a list of paths that the application will search when dynamically loading libraries.
     pub fn library_paths(&self) -> QStringList {
         ffi::qguiapplication_library_paths(self)
@@ -198,6 +224,12 @@ impl QGuiApplication {
         ffi::qguiapplication_organization_name(self)
     }
 
+    /// Removes `translator` from the list of translation files used by this application.
+    /// Returns `true` on success and `false` on failure.
+    pub fn remove_translator(self: Pin<&mut Self>, translator: Pin<&mut QTranslator>) -> bool {
+        ffi::qguiapplication_remove_translator(self, translator)
+    }
+
     /// Set the `name` of this application.
     pub fn set_application_name(self: Pin<&mut Self>, name: &QString) {
         ffi::qguiapplication_set_application_name(self, name);
@@ -0,0 +1,24 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Joshua Booth <joshua.n.booth@gmail.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#include "cxx-qt-lib/qhoverevent.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+QPointF
+qhovereventPosition(const QHoverEvent& event)
+{
+#if (QT_VERSION >= QT_VERSION_CHECK(6, 0, 0))
+  return event.position();
+#else
+  return event.posF();
+#endif
+}
+
+}
+}
@@ -0,0 +1,69 @@
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Joshua Booth <joshua.n.booth@gmail.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::QPointF;
+
+#[cxx::bridge]
+mod ffi {
+    #[namespace = "Qt"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qt.h");
+        type KeyboardModifiers = crate::KeyboardModifiers;
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qpointf.h");
+        type QPointF = crate::QPointF;
+
+        include!("cxx-qt-lib/qhoverevent.h");
+        /// The `QHoverEvent` class contains parameters that describe a mouse event.
+        ///
+        /// Hover events are received by overriding `hoverEnterEvent`, `hoverMoveEvent` and `hoverLeaveEvent` of a [`QQuickItem`](https://doc.qt.io/qt/qquickitem.html) subclass, which must also enable them with `setAcceptHoverEvents`.
+        ///
+        /// Qt Documentation: [QHoverEvent](https://doc.qt.io/qt/qhoverevent.html#details)
+        type QHoverEvent;
+
+        /// Sets the accept flag of the event object, indicating that the receiver wants the event. Unwanted events might be propagated to the parent item.
+        fn accept(self: Pin<&mut QHoverEvent>);
+
+        /// Clears the accept flag of the event object, indicating that the event receiver does not want the event. Unwanted events might be propagated to the parent item.
+        fn ignore(self: Pin<&mut QHoverEvent>);
+
+        /// Returns `true` if the event has been accepted.
+        #[rust_name = "is_accepted"]
+        fn isAccepted(self: &QHoverEvent) -> bool;
+
+        /// Returns the keyboard modifier flags that existed immediately before the event occurred.
+        fn modifiers(self: &QHoverEvent) -> KeyboardModifiers;
+
+        /// Returns the previous position of the mouse cursor, relative to the item that received the event.
+        ///
+        /// On hover enter events, this position is `(-1, -1)`.
+        #[rust_name = "old_position"]
+        fn oldPosF(self: &QHoverEvent) -> QPointF;
+
+        /// Sets the accept flag of the event object.
+        #[rust_name = "set_accepted"]
+        fn setAccepted(self: Pin<&mut QHoverEvent>, accepted: bool);
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qhoverevent_position"]
+        fn qhovereventPosition(event: &QHoverEvent) -> QPointF;
+    }
+}
+
+pub use ffi::QHoverEvent;
+
+impl QHoverEvent {
+    /// Returns the position of the mouse cursor, relative to the item that received the event.
+    ///
+    /// On hover leave events, this position is `(-1, -1)`.
+    pub fn position(&self) -> QPointF {
+        ffi::qhoverevent_position(self)
+    }
+}
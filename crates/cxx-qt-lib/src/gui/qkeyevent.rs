@@ -0,0 +1,66 @@
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Joshua Booth <joshua.n.booth@gmail.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx::bridge]
+mod ffi {
+    #[namespace = "Qt"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qt.h");
+        type KeyboardModifiers = crate::KeyboardModifiers;
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+
+        include!("cxx-qt-lib/qkeyevent.h");
+        /// The `QKeyEvent` class describes a key event.
+        ///
+        /// Key events are received by overriding `keyPressEvent` and `keyReleaseEvent` of a [`QQuickItem`](https://doc.qt.io/qt/qquickitem.html) subclass, which must have active focus.
+        ///
+        /// Qt Documentation: [QKeyEvent](https://doc.qt.io/qt/qkeyevent.html#details)
+        type QKeyEvent;
+
+        /// Sets the accept flag of the event object, indicating that the receiver wants the event. Unwanted events might be propagated to the parent item.
+        fn accept(self: Pin<&mut QKeyEvent>);
+
+        /// Returns the number of keys involved in this event. If [`text`](Self::text) is not empty, this is simply the length of the string.
+        fn count(self: &QKeyEvent) -> i32;
+
+        /// Clears the accept flag of the event object, indicating that the event receiver does not want the event. Unwanted events might be propagated to the parent item.
+        fn ignore(self: Pin<&mut QKeyEvent>);
+
+        /// Returns `true` if the event has been accepted.
+        #[rust_name = "is_accepted"]
+        fn isAccepted(self: &QKeyEvent) -> bool;
+
+        /// Returns `true` if this event comes from an auto-repeating key; returns `false` if it comes from an initial key press.
+        #[rust_name = "is_auto_repeat"]
+        fn isAutoRepeat(self: &QKeyEvent) -> bool;
+
+        /// Returns the code of the key that was pressed or released, as a value of [Qt::Key](https://doc.qt.io/qt/qt.html#Key-enum).
+        ///
+        /// If the key is not known to Qt, this is either 0 or a platform-specific value.
+        fn key(self: &QKeyEvent) -> i32;
+
+        /// Returns the keyboard modifier flags that existed immediately after the event occurred.
+        fn modifiers(self: &QKeyEvent) -> KeyboardModifiers;
+
+        /// Returns the native scan code of the key event. If the key event does not contain this data 0 is returned.
+        #[rust_name = "native_scan_code"]
+        fn nativeScanCode(self: &QKeyEvent) -> u32;
+
+        /// Sets the accept flag of the event object.
+        #[rust_name = "set_accepted"]
+        fn setAccepted(self: Pin<&mut QKeyEvent>, accepted: bool);
+
+        /// Returns the Unicode text that this key generated.
+        ///
+        /// The text is not limited to the printable range of Unicode code points, and may be empty for modifier keys and special keys.
+        fn text(self: &QKeyEvent) -> QString;
+    }
+}
+
+pub use ffi::QKeyEvent;
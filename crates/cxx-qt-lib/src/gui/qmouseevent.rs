@@ -0,0 +1,89 @@
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Joshua Booth <joshua.n.booth@gmail.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::QPointF;
+
+#[cxx::bridge]
+mod ffi {
+    #[namespace = "Qt"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qt.h");
+        type KeyboardModifiers = crate::KeyboardModifiers;
+        type MouseButton = crate::MouseButton;
+        type MouseButtons = crate::MouseButtons;
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qpointf.h");
+        type QPointF = crate::QPointF;
+
+        include!("cxx-qt-lib/qmouseevent.h");
+        /// The `QMouseEvent` class contains parameters that describe a mouse event.
+        ///
+        /// Mouse events are received by overriding `mousePressEvent`, `mouseReleaseEvent`, `mouseMoveEvent` and `mouseDoubleClickEvent` of a [`QQuickItem`](https://doc.qt.io/qt/qquickitem.html) subclass.
+        ///
+        /// Qt Documentation: [QMouseEvent](https://doc.qt.io/qt/qmouseevent.html#details)
+        type QMouseEvent;
+
+        /// Sets the accept flag of the event object, indicating that the receiver wants the event. Unwanted events might be propagated to the parent item.
+        fn accept(self: Pin<&mut QMouseEvent>);
+
+        /// Returns the button that caused the event.
+        ///
+        /// Note that the returned value is always [`MouseButton::NoButton`](crate::MouseButton::NoButton) for mouse move events.
+        fn button(self: &QMouseEvent) -> MouseButton;
+
+        /// Returns the button state when the event was generated. The button state is a combination of [`MouseButton::LeftButton`](crate::MouseButton::LeftButton), [`MouseButton::RightButton`](crate::MouseButton::RightButton), and [`MouseButton::MiddleButton`](crate::MouseButton::MiddleButton).
+        ///
+        /// For mouse move events, this is all buttons that are pressed down. For mouse press and double click events this includes the button that caused the event. For mouse release events this excludes the button that caused the event.
+        fn buttons(self: &QMouseEvent) -> MouseButtons;
+
+        /// Clears the accept flag of the event object, indicating that the event receiver does not want the event. Unwanted events might be propagated to the parent item.
+        fn ignore(self: Pin<&mut QMouseEvent>);
+
+        /// Returns `true` if the event has been accepted.
+        #[rust_name = "is_accepted"]
+        fn isAccepted(self: &QMouseEvent) -> bool;
+
+        /// Returns the keyboard modifier flags that existed immediately before the event occurred.
+        fn modifiers(self: &QMouseEvent) -> KeyboardModifiers;
+
+        /// Sets the accept flag of the event object.
+        #[rust_name = "set_accepted"]
+        fn setAccepted(self: Pin<&mut QMouseEvent>, accepted: bool);
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qmouseevent_position"]
+        fn qmouseeventPosition(event: &QMouseEvent) -> QPointF;
+        #[doc(hidden)]
+        #[rust_name = "qmouseevent_scene_position"]
+        fn qmouseeventScenePosition(event: &QMouseEvent) -> QPointF;
+        #[doc(hidden)]
+        #[rust_name = "qmouseevent_global_position"]
+        fn qmouseeventGlobalPosition(event: &QMouseEvent) -> QPointF;
+    }
+}
+
+pub use ffi::QMouseEvent;
+
+impl QMouseEvent {
+    /// Returns the position of the point in this event, relative to the item that received the event.
+    pub fn position(&self) -> QPointF {
+        ffi::qmouseevent_position(self)
+    }
+
+    /// Returns the position of the point in this event, relative to the window or scene.
+    pub fn scene_position(&self) -> QPointF {
+        ffi::qmouseevent_scene_position(self)
+    }
+
+    /// Returns the position of the point in this event on the screen or virtual desktop.
+    pub fn global_position(&self) -> QPointF {
+        ffi::qmouseevent_global_position(self)
+    }
+}
@@ -26,6 +26,11 @@ mod qml;
 #[cfg(feature = "qt_qml")]
 pub use crate::qml::*;
 
+#[cfg(feature = "qt_quick")]
+mod quick;
+#[cfg(feature = "qt_quick")]
+pub use crate::quick::*;
+
 #[cfg(feature = "qt_quickcontrols")]
 mod quickcontrols;
 #[cfg(feature = "qt_quickcontrols")]
@@ -3,8 +3,33 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
+mod qjsengine;
+pub use qjsengine::QJSEngine;
+
+#[cfg(any(cxxqt_qt_version_at_least_7, cxxqt_qt_version_at_least_6_1))]
+mod qjsmanagedvalue;
+#[cfg(any(cxxqt_qt_version_at_least_7, cxxqt_qt_version_at_least_6_1))]
+pub use qjsmanagedvalue::{QJSManagedValue, QJSManagedValueType};
+
+mod qjsvalue;
+#[cfg(feature = "serde")]
+pub use qjsvalue::QJSValueSerdeError;
+pub use qjsvalue::{QJSError, QJSValue, QJSValueErrorType, QJSValueSpecialValue};
+
 mod qqmlapplicationengine;
 pub use qqmlapplicationengine::QQmlApplicationEngine;
 
+mod qqmlcomponent;
+pub use qqmlcomponent::{QQmlComponent, QQmlComponentStatus};
+
+mod qqmlcontext;
+pub use qqmlcontext::QQmlContext;
+
 mod qqmlengine;
 pub use qqmlengine::QQmlEngine;
+
+mod qqmlerror;
+pub use qqmlerror::{QQmlError, QQmlErrorList};
+
+mod qqmlincubator;
+pub use qqmlincubator::{QQmlIncubator, QQmlIncubatorIncubationMode, QQmlIncubatorStatus};
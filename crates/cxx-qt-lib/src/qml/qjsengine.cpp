@@ -0,0 +1,35 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Joshua Booth <joshua.n.booth@gmail.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#include "cxx-qt-lib/qjsengine.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+QJSValue
+qjsengineEvaluate(QJSEngine& engine,
+                  const QString& program,
+                  const QString& fileName,
+                  ::std::int32_t lineNumber)
+{
+  return engine.evaluate(program, fileName, static_cast<int>(lineNumber));
+}
+
+::std::unique_ptr<QJSEngine>
+qjsengineNew()
+{
+  return ::std::make_unique<QJSEngine>();
+}
+
+QJSValue
+qjsengineToScriptValue(QJSEngine& engine, const QVariant& value)
+{
+  return engine.toScriptValue(value);
+}
+
+}
+}
@@ -0,0 +1,145 @@
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Joshua Booth <joshua.n.booth@gmail.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::{QJSError, QJSValue, QString, QVariant};
+use core::pin::Pin;
+
+#[cxx_qt::bridge]
+mod ffi {
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qjsvalue.h");
+        type QJSValueErrorType = crate::QJSValueErrorType;
+    }
+
+    unsafe extern "C++" {
+        type QJSValue = crate::QJSValue;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+        include!("cxx-qt-lib/qvariant.h");
+        type QVariant = crate::QVariant;
+    }
+
+    unsafe extern "C++Qt" {
+        include!("cxx-qt-lib/qjsengine.h");
+        /// The `QJSEngine` class provides an environment for evaluating JavaScript code.
+        ///
+        /// [`QQmlEngine`](crate::QQmlEngine) inherits from `QJSEngine`, so JavaScript can also be evaluated in the context of a QML engine by upcasting it.
+        ///
+        /// Qt Documentation: [QJSEngine](https://doc.qt.io/qt/qjsengine.html#details)
+        #[qobject]
+        type QJSEngine;
+
+        /// Runs the garbage collector.
+        ///
+        /// The garbage collector will attempt to reclaim memory by locating and disposing of objects that are no longer reachable in the script environment.
+        #[rust_name = "collect_garbage"]
+        fn collectGarbage(self: Pin<&mut QJSEngine>);
+
+        /// Returns this engine's Global Object.
+        #[rust_name = "global_object"]
+        fn globalObject(self: &QJSEngine) -> QJSValue;
+
+        /// Imports the module located at `file_name` and returns a module namespace object that contains all exported variables, constants and functions as properties.
+        ///
+        /// If this is the first time the module is imported in the engine, the file is loaded from the specified location in either the local file system or the Qt resource system and evaluated as an ECMAScript module.
+        #[rust_name = "import_module"]
+        fn importModule(self: Pin<&mut QJSEngine>, file_name: &QString) -> QJSValue;
+
+        /// Creates a JavaScript object of class Array with the given `length`.
+        #[rust_name = "new_array"]
+        fn newArray(self: Pin<&mut QJSEngine>, length: u32) -> QJSValue;
+
+        /// Creates a JavaScript object of class Error, with `message` as the error message.
+        ///
+        /// The prototype of the created object will be `error_type`.
+        #[rust_name = "new_error_object"]
+        fn newErrorObject(
+            self: Pin<&mut QJSEngine>,
+            error_type: QJSValueErrorType,
+            message: &QString,
+        ) -> QJSValue;
+
+        /// Creates a JavaScript object of class Object.
+        ///
+        /// The prototype of the created object will be the Object prototype object.
+        #[rust_name = "new_object"]
+        fn newObject(self: Pin<&mut QJSEngine>) -> QJSValue;
+
+        /// Creates a JavaScript object that wraps the given `QObject` `object`, using JavaScriptOwnership.
+        ///
+        /// Signals and slots, properties and children of `object` are available as properties of the created `QJSValue`.
+        ///
+        /// If `object` is a null pointer, this function returns a null value.
+        ///
+        /// # Safety
+        ///
+        /// `object` must be a valid pointer, or null. Unless an explicit ownership is set for `object`, the engine takes ownership of it and may delete it when it is garbage collected.
+        #[rust_name = "new_qobject"]
+        unsafe fn newQObject(self: Pin<&mut QJSEngine>, object: *mut QObject) -> QJSValue;
+
+        /// Throws a run-time error (exception) with the given `error_type` and `message`.
+        ///
+        /// This is intended to be called from an invokable which is called from JavaScript, to report an error to the JavaScript caller.
+        #[cxx_name = "throwError"]
+        fn throw_error(self: Pin<&mut QJSEngine>, error_type: QJSValueErrorType, message: &QString);
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qjsengine_evaluate"]
+        fn qjsengineEvaluate(
+            engine: Pin<&mut QJSEngine>,
+            program: &QString,
+            file_name: &QString,
+            line_number: i32,
+        ) -> QJSValue;
+
+        #[doc(hidden)]
+        #[rust_name = "qjsengine_new"]
+        fn qjsengineNew() -> UniquePtr<QJSEngine>;
+
+        #[doc(hidden)]
+        #[rust_name = "qjsengine_to_script_value"]
+        fn qjsengineToScriptValue(engine: Pin<&mut QJSEngine>, value: &QVariant) -> QJSValue;
+    }
+
+    // QJSEngine is not a trivial to CXX and is not relocatable in Qt
+    // as the following fails in C++. So we cannot mark it as a trivial type
+    // and need to use references or pointers.
+    // static_assert(QTypeInfo<QJSEngine>::isRelocatable);
+    impl UniquePtr<QJSEngine> {}
+}
+
+pub use ffi::QJSEngine;
+
+impl QJSEngine {
+    /// Create a new `QJSEngine`.
+    pub fn new() -> cxx::UniquePtr<Self> {
+        ffi::qjsengine_new()
+    }
+
+    /// Evaluates `program`, using `line_number` as the base line number, and returns the result of the evaluation.
+    ///
+    /// The script code will be evaluated in the context of the global object.
+    ///
+    /// `file_name` is used for error reporting, and is available as [`QJSError::file_name`] if evaluation throws an exception.
+    pub fn evaluate(
+        self: Pin<&mut Self>,
+        program: &QString,
+        file_name: &QString,
+        line_number: i32,
+    ) -> Result<QJSValue, QJSError> {
+        ffi::qjsengine_evaluate(self, program, file_name, line_number).into_result()
+    }
+
+    /// Creates a `QJSValue` with the given `value`.
+    ///
+    /// Lists and maps of variants are converted to JavaScript arrays and objects.
+    pub fn to_script_value(self: Pin<&mut Self>, value: &QVariant) -> QJSValue {
+        ffi::qjsengine_to_script_value(self, value)
+    }
+}
@@ -0,0 +1,44 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Joshua Booth <joshua.n.booth@gmail.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#include "cxx-qt-lib/qjsmanagedvalue.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+QJSValue
+qjsmanagedvalueCall(const QJSManagedValue& value,
+                    ::rust::Slice<const QJSValue> args)
+{
+  QJSValueList list;
+  list.reserve(static_cast<qsizetype>(args.size()));
+  for (const QJSValue& arg : args) {
+    list.append(arg);
+  }
+  return value.call(list);
+}
+
+::std::unique_ptr<QJSManagedValue>
+qjsmanagedvalueNew(const QJSValue& value, QJSEngine& engine)
+{
+  return ::std::make_unique<QJSManagedValue>(value, &engine);
+}
+
+QJSValue
+qjsengineCatchError(QJSEngine& engine)
+{
+  return engine.catchError();
+}
+
+bool
+qjsengineHasError(const QJSEngine& engine)
+{
+  return engine.hasError();
+}
+
+}
+}
@@ -0,0 +1,215 @@
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Joshua Booth <joshua.n.booth@gmail.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::{QJSEngine, QJSError, QJSValue};
+use core::pin::Pin;
+
+#[cxx::bridge]
+mod ffi {
+    /// This enum represents the JavaScript native types, as specified by ECMA-262.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QJSManagedValueType {
+        /// The undefined type.
+        Undefined,
+        /// The boolean type.
+        Boolean,
+        /// The number type.
+        Number,
+        /// The string type.
+        String,
+        /// The object type.
+        Object,
+        /// The symbol type.
+        Symbol,
+        /// The function type.
+        Function,
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qjsmanagedvalue.h");
+        type QJSManagedValueType;
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qjsengine.h");
+        type QJSEngine = crate::QJSEngine;
+        include!("cxx-qt-lib/qjsvalue.h");
+        type QJSValue = crate::QJSValue;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+        include!("cxx-qt-lib/qvariant.h");
+        type QVariant = crate::QVariant;
+
+        /// The `QJSManagedValue` class represents a value on the JavaScript heap belonging to a [`QJSEngine`].
+        ///
+        /// Unlike [`QJSValue`], a `QJSManagedValue` follows the semantics of JavaScript exactly, and any exception thrown by its methods is left on the engine, where it can be retrieved with [`QJSEngine::catch_error`].
+        ///
+        /// Qt Documentation: [QJSManagedValue](https://doc.qt.io/qt/qjsmanagedvalue.html#details)
+        type QJSManagedValue;
+
+        /// Deletes the property `name` from this value.
+        ///
+        /// Returns `true` if the property was deleted, otherwise returns `false`.
+        #[rust_name = "delete_property"]
+        fn deleteProperty(self: Pin<&mut QJSManagedValue>, name: &QString) -> bool;
+
+        /// Returns the [`QJSEngine`] this value belongs to.
+        fn engine(self: &QJSManagedValue) -> *mut QJSEngine;
+
+        /// Returns `true` if this value is equal to `other`, following the JavaScript `==` operator.
+        fn equals(self: &QJSManagedValue, other: &QJSManagedValue) -> bool;
+
+        /// Returns `true` if this value has an own (not prototype-inherited) property `name`, otherwise returns `false`.
+        #[rust_name = "has_own_property"]
+        fn hasOwnProperty(self: &QJSManagedValue, name: &QString) -> bool;
+
+        /// Returns `true` if this value is an object and has a property `name`, otherwise returns `false`.
+        #[rust_name = "has_property"]
+        fn hasProperty(self: &QJSManagedValue, name: &QString) -> bool;
+
+        /// Returns `true` if this value is an Array object, otherwise returns `false`.
+        #[rust_name = "is_array"]
+        fn isArray(self: &QJSManagedValue) -> bool;
+
+        /// Returns `true` if this value is of the boolean type, otherwise returns `false`.
+        #[rust_name = "is_boolean"]
+        fn isBoolean(self: &QJSManagedValue) -> bool;
+
+        /// Returns `true` if this value is an Error object, otherwise returns `false`.
+        #[rust_name = "is_error"]
+        fn isError(self: &QJSManagedValue) -> bool;
+
+        /// Returns `true` if this value is of the function type, otherwise returns `false`.
+        #[rust_name = "is_function"]
+        fn isFunction(self: &QJSManagedValue) -> bool;
+
+        /// Returns `true` if this value is the null value, otherwise returns `false`.
+        #[rust_name = "is_null"]
+        fn isNull(self: &QJSManagedValue) -> bool;
+
+        /// Returns `true` if this value is of the number type, otherwise returns `false`.
+        #[rust_name = "is_number"]
+        fn isNumber(self: &QJSManagedValue) -> bool;
+
+        /// Returns `true` if this value is of the object type, otherwise returns `false`.
+        ///
+        /// Note that null is not an object, whereas functions are.
+        #[rust_name = "is_object"]
+        fn isObject(self: &QJSManagedValue) -> bool;
+
+        /// Returns `true` if this value is of the string type, otherwise returns `false`.
+        #[rust_name = "is_string"]
+        fn isString(self: &QJSManagedValue) -> bool;
+
+        /// Returns `true` if this value is of the undefined type, otherwise returns `false`.
+        #[rust_name = "is_undefined"]
+        fn isUndefined(self: &QJSManagedValue) -> bool;
+
+        /// Looks up the property `name` of this value, following the prototype chain, and returns it.
+        ///
+        /// If the property does not exist, undefined is returned.
+        fn property(self: &QJSManagedValue, name: &QString) -> QJSValue;
+
+        /// Sets the property `name` to `value` on this value.
+        #[rust_name = "set_property"]
+        fn setProperty(self: Pin<&mut QJSManagedValue>, name: &QString, value: &QJSValue);
+
+        /// Returns `true` if this value is equal to `other` using strict comparison, following the JavaScript `===` operator.
+        #[rust_name = "strictly_equals"]
+        fn strictlyEquals(self: &QJSManagedValue, other: &QJSManagedValue) -> bool;
+
+        /// Converts this value to a boolean, following the JavaScript `ToBoolean` conversion.
+        #[rust_name = "to_boolean"]
+        fn toBoolean(self: &QJSManagedValue) -> bool;
+
+        /// Copies this value into a [`QJSValue`].
+        #[rust_name = "to_js_value"]
+        fn toJSValue(self: &QJSManagedValue) -> QJSValue;
+
+        /// Converts this value to a number, following the JavaScript `ToNumber` conversion.
+        #[rust_name = "to_number"]
+        fn toNumber(self: &QJSManagedValue) -> f64;
+
+        /// Converts this value to a string, following the JavaScript `ToString` conversion.
+        #[rust_name = "to_qstring"]
+        fn toString(self: &QJSManagedValue) -> QString;
+
+        /// Copies this value into a [`QVariant`].
+        #[rust_name = "to_variant"]
+        fn toVariant(self: &QJSManagedValue) -> QVariant;
+
+        /// Returns the JavaScript type of this value.
+        #[cxx_name = "type"]
+        fn value_type(self: &QJSManagedValue) -> QJSManagedValueType;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qjsmanagedvalue_call"]
+        fn qjsmanagedvalueCall(value: &QJSManagedValue, args: &[QJSValue]) -> QJSValue;
+
+        #[doc(hidden)]
+        #[rust_name = "qjsmanagedvalue_new"]
+        fn qjsmanagedvalueNew(
+            value: &QJSValue,
+            engine: Pin<&mut QJSEngine>,
+        ) -> UniquePtr<QJSManagedValue>;
+
+        #[doc(hidden)]
+        #[rust_name = "qjsengine_catch_error"]
+        fn qjsengineCatchError(engine: Pin<&mut QJSEngine>) -> QJSValue;
+
+        #[doc(hidden)]
+        #[rust_name = "qjsengine_has_error"]
+        fn qjsengineHasError(engine: &QJSEngine) -> bool;
+    }
+
+    impl UniquePtr<QJSManagedValue> {}
+}
+
+pub use ffi::{QJSManagedValue, QJSManagedValueType};
+
+impl QJSManagedValue {
+    /// Creates a `QJSManagedValue` from `value`, using the heap of `engine`.
+    pub fn new(value: &QJSValue, engine: Pin<&mut QJSEngine>) -> cxx::UniquePtr<Self> {
+        ffi::qjsmanagedvalue_new(value, engine)
+    }
+
+    /// Calls this value as a function, passing `args` as arguments, and returns the result.
+    ///
+    /// If the function throws an exception, it is caught from the engine and returned as an error.
+    pub fn call(&self, args: &[QJSValue]) -> Result<QJSValue, QJSError> {
+        let result = ffi::qjsmanagedvalue_call(self, args);
+        // SAFETY: the engine of a managed value outlives it, and is either valid or null.
+        let Some(engine) = (unsafe { self.engine().as_mut() }) else {
+            return Ok(result);
+        };
+        // SAFETY: QObjects are never moved.
+        let mut engine = unsafe { Pin::new_unchecked(engine) };
+        if engine.has_error() {
+            Err(QJSError::from(&engine.as_mut().catch_error()))
+        } else {
+            Ok(result)
+        }
+    }
+}
+
+impl QJSEngine {
+    /// If an exception is currently pending, catches it and returns it as a [`QJSValue`]. Otherwise returns undefined.
+    ///
+    /// Exceptions are left pending by the methods of [`QJSManagedValue`].
+    pub fn catch_error(self: Pin<&mut Self>) -> QJSValue {
+        ffi::qjsengine_catch_error(self)
+    }
+
+    /// Returns `true` if the last JavaScript execution resulted in an exception, or if [`throw_error`](Self::throw_error) was called. Otherwise returns `false`.
+    pub fn has_error(&self) -> bool {
+        ffi::qjsengine_has_error(self)
+    }
+}
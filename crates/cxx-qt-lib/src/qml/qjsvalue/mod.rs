@@ -0,0 +1,474 @@
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Joshua Booth <joshua.n.booth@gmail.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::{QString, QVariant};
+use cxx::{type_id, ExternType};
+use std::fmt;
+use std::marker::PhantomData;
+use std::mem::MaybeUninit;
+
+#[cfg(feature = "serde")]
+mod serde_impl;
+#[cfg(feature = "serde")]
+pub use serde_impl::QJSValueSerdeError;
+
+#[cxx::bridge]
+mod ffi {
+    /// This enum is used to specify a type of Error object.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QJSValueErrorType {
+        /// A plain value, not an Error object.
+        NoError,
+        /// A generic Error object, but not of a specific sub-type.
+        GenericError,
+        /// An Error object originating from the JavaScript `eval` function.
+        EvalError,
+        /// An Error object indicating a value out of range.
+        RangeError,
+        /// An Error object indicating a reference to a non-existent variable.
+        ReferenceError,
+        /// An Error object indicating invalid JavaScript syntax.
+        SyntaxError,
+        /// An Error object indicating a value of an unexpected type.
+        TypeError,
+        /// An Error object produced by one of the URI functions, such as `decodeURIComponent`.
+        URIError,
+    }
+
+    /// This enum is used to specify a single special value.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QJSValueSpecialValue {
+        /// A null value.
+        NullValue,
+        /// An undefined value.
+        UndefinedValue,
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qjsvalue.h");
+        type QJSValueErrorType;
+        type QJSValueSpecialValue;
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+        include!("cxx-qt-lib/qstringlist.h");
+        type QStringList = crate::QStringList;
+        include!("cxx-qt-lib/qvariant.h");
+        type QVariant = crate::QVariant;
+        include!("cxx-qt-lib/qobject.h");
+        type QObject = crate::QObject;
+
+        include!("cxx-qt-lib/qjsvalue.h");
+        type QJSValue = super::QJSValue;
+
+        /// Deletes the property with the given `name`.
+        ///
+        /// Returns `true` if the property was deleted, otherwise returns `false`.
+        #[rust_name = "delete_property"]
+        fn deleteProperty(self: &mut QJSValue, name: &QString) -> bool;
+
+        /// Returns `true` if this value is equal to `other`, otherwise returns `false`.
+        ///
+        /// The comparison follows the behavior of the JavaScript `==` operator.
+        fn equals(self: &QJSValue, other: &QJSValue) -> bool;
+
+        /// Returns the error type this value represents if it is an Error object, otherwise [`QJSValueErrorType::NoError`].
+        #[rust_name = "error_type"]
+        fn errorType(self: &QJSValue) -> QJSValueErrorType;
+
+        /// Returns `true` if this object has an own (not prototype-inherited) property of the given `name`, otherwise returns `false`.
+        #[rust_name = "has_own_property"]
+        fn hasOwnProperty(self: &QJSValue, name: &QString) -> bool;
+
+        /// Returns `true` if this object has a property of the given `name`, otherwise returns `false`.
+        #[rust_name = "has_property"]
+        fn hasProperty(self: &QJSValue, name: &QString) -> bool;
+
+        /// Returns `true` if this value is an object of the Array class, otherwise returns `false`.
+        #[rust_name = "is_array"]
+        fn isArray(self: &QJSValue) -> bool;
+
+        /// Returns `true` if this value is of the primitive type Boolean, otherwise returns `false`.
+        #[rust_name = "is_bool"]
+        fn isBool(self: &QJSValue) -> bool;
+
+        /// Returns `true` if this value can be called as a function, otherwise returns `false`.
+        #[rust_name = "is_callable"]
+        fn isCallable(self: &QJSValue) -> bool;
+
+        /// Returns `true` if this value is an object of the Date class, otherwise returns `false`.
+        #[rust_name = "is_date"]
+        fn isDate(self: &QJSValue) -> bool;
+
+        /// Returns `true` if this value is an object of the Error class, otherwise returns `false`.
+        #[rust_name = "is_error"]
+        fn isError(self: &QJSValue) -> bool;
+
+        /// Returns `true` if this value is of the primitive type Null, otherwise returns `false`.
+        #[rust_name = "is_null"]
+        fn isNull(self: &QJSValue) -> bool;
+
+        /// Returns `true` if this value is of the primitive type Number, otherwise returns `false`.
+        #[rust_name = "is_number"]
+        fn isNumber(self: &QJSValue) -> bool;
+
+        /// Returns `true` if this value is of the Object type, otherwise returns `false`.
+        ///
+        /// Note that function values, variant values, and `QObject` values are objects, so this function returns `true` for such values.
+        #[rust_name = "is_object"]
+        fn isObject(self: &QJSValue) -> bool;
+
+        /// Returns `true` if this value is a `QObject`, otherwise returns `false`.
+        #[rust_name = "is_qobject"]
+        fn isQObject(self: &QJSValue) -> bool;
+
+        /// Returns `true` if this value is of the primitive type String, otherwise returns `false`.
+        #[rust_name = "is_string"]
+        fn isString(self: &QJSValue) -> bool;
+
+        /// Returns `true` if this value is of the primitive type Undefined, otherwise returns `false`.
+        #[rust_name = "is_undefined"]
+        fn isUndefined(self: &QJSValue) -> bool;
+
+        /// Returns `true` if this value is a variant value, otherwise returns `false`.
+        #[rust_name = "is_variant"]
+        fn isVariant(self: &QJSValue) -> bool;
+
+        /// Returns the value of this object's property with the given `name`.
+        /// If no such property exists, an undefined value is returned.
+        fn property(self: &QJSValue, name: &QString) -> QJSValue;
+
+        /// Returns the property at the given `index` of an array.
+        ///
+        /// This is more efficient than converting the index to a string and calling [`property`](Self::property).
+        #[cxx_name = "property"]
+        fn property_at(self: &QJSValue, index: u32) -> QJSValue;
+
+        /// Sets the value of this object's property with the given `name` to the given `value`.
+        ///
+        /// If this object is not an object, this function does nothing.
+        #[rust_name = "set_property"]
+        fn setProperty(self: &mut QJSValue, name: &QString, value: &QJSValue);
+
+        /// Sets the property at the given `index` of an array to the given `value`.
+        #[cxx_name = "setProperty"]
+        fn set_property_at(self: &mut QJSValue, index: u32, value: &QJSValue);
+
+        /// Returns `true` if this value is equal to `other` using strict comparison (no conversion), otherwise returns `false`.
+        ///
+        /// The comparison follows the behavior of the JavaScript `===` operator.
+        #[rust_name = "strictly_equals"]
+        fn strictlyEquals(self: &QJSValue, other: &QJSValue) -> bool;
+
+        /// Returns the boolean value of this value, following the JavaScript `ToBoolean` conversion.
+        #[rust_name = "to_bool"]
+        fn toBool(self: &QJSValue) -> bool;
+
+        /// Returns the signed 32-bit integer value of this value, following the JavaScript `ToInt32` conversion.
+        #[rust_name = "to_i32"]
+        fn toInt(self: &QJSValue) -> i32;
+
+        /// Returns the number value of this value, following the JavaScript `ToNumber` conversion.
+        #[rust_name = "to_number"]
+        fn toNumber(self: &QJSValue) -> f64;
+
+        /// If this value is a `QObject`, returns the `QObject` pointer that this value represents, otherwise returns a null pointer.
+        #[rust_name = "to_qobject"]
+        fn toQObject(self: &QJSValue) -> *mut QObject;
+
+        /// Returns the string value of this value, following the JavaScript `ToString` conversion.
+        #[rust_name = "to_qstring"]
+        fn toString(self: &QJSValue) -> QString;
+
+        /// Returns the unsigned 32-bit integer value of this value, following the JavaScript `ToUint32` conversion.
+        #[rust_name = "to_u32"]
+        fn toUInt(self: &QJSValue) -> u32;
+
+        /// Returns the [`QVariant`] value of this value.
+        ///
+        /// Arrays are converted to a list of variants, and objects are converted to a map of variants.
+        #[rust_name = "to_variant"]
+        fn toVariant(self: &QJSValue) -> QVariant;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/common.h");
+
+        #[doc(hidden)]
+        #[rust_name = "qjsvalue_drop"]
+        fn drop(value: &mut QJSValue);
+
+        #[doc(hidden)]
+        #[rust_name = "qjsvalue_init_default"]
+        fn construct() -> QJSValue;
+        #[doc(hidden)]
+        #[rust_name = "qjsvalue_init_from_qjsvalue"]
+        fn construct(value: &QJSValue) -> QJSValue;
+        #[doc(hidden)]
+        #[rust_name = "qjsvalue_init_from_bool"]
+        fn construct(value: bool) -> QJSValue;
+        #[doc(hidden)]
+        #[rust_name = "qjsvalue_init_from_i32"]
+        fn construct(value: i32) -> QJSValue;
+        #[doc(hidden)]
+        #[rust_name = "qjsvalue_init_from_u32"]
+        fn construct(value: u32) -> QJSValue;
+        #[doc(hidden)]
+        #[rust_name = "qjsvalue_init_from_f64"]
+        fn construct(value: f64) -> QJSValue;
+        #[doc(hidden)]
+        #[rust_name = "qjsvalue_init_from_qstring"]
+        fn construct(value: &QString) -> QJSValue;
+        #[doc(hidden)]
+        #[rust_name = "qjsvalue_init_from_special_value"]
+        fn construct(value: QJSValueSpecialValue) -> QJSValue;
+
+        #[doc(hidden)]
+        #[rust_name = "qjsvalue_call"]
+        fn qjsvalueCall(value: &QJSValue, args: &[QJSValue]) -> QJSValue;
+        #[doc(hidden)]
+        #[rust_name = "qjsvalue_call_as_constructor"]
+        fn qjsvalueCallAsConstructor(value: &QJSValue, args: &[QJSValue]) -> QJSValue;
+        #[doc(hidden)]
+        #[rust_name = "qjsvalue_call_with_instance"]
+        fn qjsvalueCallWithInstance(
+            value: &QJSValue,
+            instance: &QJSValue,
+            args: &[QJSValue],
+        ) -> QJSValue;
+        #[doc(hidden)]
+        #[rust_name = "qjsvalue_property_names"]
+        fn qjsvaluePropertyNames(value: &QJSValue) -> QStringList;
+    }
+}
+
+pub use ffi::{QJSValueErrorType, QJSValueSpecialValue};
+
+/// The `QJSValue` class acts as a container for Qt/JavaScript data types.
+///
+/// A `QJSValue` can be passed to and from QML, for example as the parameter of an invokable, which allows a QML caller to pass a JavaScript function to Rust as a callback:
+///
+/// ```ignore
+/// #[qinvokable]
+/// fn fetch(self: Pin<&mut Self>, on_finished: QJSValue);
+/// ```
+///
+/// `QJSValue` is bound to the [`QJSEngine`](crate::QJSEngine) that created it, and must only be used from the thread of that engine.
+///
+/// Qt Documentation: [QJSValue](https://doc.qt.io/qt/qjsvalue.html#details)
+#[repr(C)]
+pub struct QJSValue {
+    /// Qt5 `QJSValue` has one member, a `quintptr`
+    /// Qt6 `QJSValue` has one member, a `quint64`
+    #[cfg(cxxqt_qt_version_major = "5")]
+    _space: MaybeUninit<usize>,
+    #[cfg(cxxqt_qt_version_major = "6")]
+    _space: MaybeUninit<u64>,
+    /// `QJSValue` is not thread-safe, as it may refer to the heap of its engine
+    _marker: PhantomData<*const ()>,
+}
+
+/// An exception that was thrown by JavaScript code, such as from [`QJSValue::call`] or [`QJSEngine::evaluate`](crate::QJSEngine::evaluate).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct QJSError {
+    /// The type of the Error object, or [`QJSValueErrorType::NoError`] if a value other than an Error object was thrown.
+    pub error_type: QJSValueErrorType,
+    /// The message of the error.
+    pub message: String,
+    /// The file in which the error occurred, if known.
+    pub file_name: String,
+    /// The line at which the error occurred, or 0 if unknown.
+    pub line_number: i32,
+    /// The JavaScript stack trace of the error, with one frame per line.
+    pub stack: String,
+}
+
+impl fmt::Display for QJSError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if !self.file_name.is_empty() {
+            write!(f, "{}:{}: ", self.file_name, self.line_number)?;
+        }
+        f.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for QJSError {}
+
+impl From<&QJSValue> for QJSError {
+    /// Reads the properties of a thrown Error object.
+    ///
+    /// If `value` is not an Error object, its string conversion is used as the message.
+    fn from(value: &QJSValue) -> Self {
+        let property = |name: &str| value.property(&QString::from(name));
+        if !value.is_error() {
+            return Self {
+                error_type: QJSValueErrorType::NoError,
+                message: value.to_qstring().to_string(),
+                file_name: String::new(),
+                line_number: 0,
+                stack: String::new(),
+            };
+        }
+        Self {
+            error_type: value.error_type(),
+            message: property("message").to_qstring().to_string(),
+            file_name: property("fileName").to_qstring().to_string(),
+            line_number: property("lineNumber").to_i32(),
+            stack: property("stack").to_qstring().to_string(),
+        }
+    }
+}
+
+impl QJSValue {
+    /// Constructs a new `QJSValue` with the special `value`.
+    pub fn special(value: QJSValueSpecialValue) -> Self {
+        ffi::qjsvalue_init_from_special_value(value)
+    }
+
+    /// Constructs a new `QJSValue` with a null value.
+    pub fn null() -> Self {
+        Self::special(QJSValueSpecialValue::NullValue)
+    }
+
+    /// Constructs a new `QJSValue` with an undefined value.
+    pub fn undefined() -> Self {
+        Self::special(QJSValueSpecialValue::UndefinedValue)
+    }
+
+    /// Calls this value as a function, passing `args` as arguments.
+    ///
+    /// Returns the value returned from the function, or an error if the function threw an exception.
+    /// Returns an error with [`QJSValueErrorType::TypeError`] if this value is not callable.
+    ///
+    /// Note that a function which returns an Error object rather than throwing it also results in an error.
+    pub fn call(&self, args: &[QJSValue]) -> Result<QJSValue, QJSError> {
+        Self::into_result(ffi::qjsvalue_call(self, args))
+    }
+
+    /// Creates a new object and calls this value as a constructor, using the created object as the `this` object and passing `args` as arguments.
+    ///
+    /// Returns the created object, or an error if the constructor threw an exception.
+    pub fn call_as_constructor(&self, args: &[QJSValue]) -> Result<QJSValue, QJSError> {
+        Self::into_result(ffi::qjsvalue_call_as_constructor(self, args))
+    }
+
+    /// Calls this value as a function, using `instance` as the `this` object in the function call, and passing `args` as arguments.
+    ///
+    /// Returns the value returned from the function, or an error if the function threw an exception.
+    pub fn call_with_instance(
+        &self,
+        instance: &QJSValue,
+        args: &[QJSValue],
+    ) -> Result<QJSValue, QJSError> {
+        Self::into_result(ffi::qjsvalue_call_with_instance(self, instance, args))
+    }
+
+    /// Returns the names of the enumerable own properties of this object, in the order in which they were added.
+    pub fn property_names(&self) -> crate::QStringList {
+        ffi::qjsvalue_property_names(self)
+    }
+
+    pub(crate) fn into_result(self) -> Result<QJSValue, QJSError> {
+        if self.is_error() {
+            Err(QJSError::from(&self))
+        } else {
+            Ok(self)
+        }
+    }
+}
+
+impl Clone for QJSValue {
+    /// Constructs a new `QJSValue` that is a copy of `self`.
+    ///
+    /// Note that if `self` is an object, the new value refers to the same object.
+    fn clone(&self) -> Self {
+        ffi::qjsvalue_init_from_qjsvalue(self)
+    }
+}
+
+impl Default for QJSValue {
+    /// Constructs a new `QJSValue` with an undefined value.
+    fn default() -> Self {
+        ffi::qjsvalue_init_default()
+    }
+}
+
+impl Drop for QJSValue {
+    /// Destroys this `QJSValue`.
+    fn drop(&mut self) {
+        ffi::qjsvalue_drop(self)
+    }
+}
+
+impl fmt::Debug for QJSValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "QJSValue({:?})", self.to_qstring())
+    }
+}
+
+impl From<bool> for QJSValue {
+    /// Constructs a new `QJSValue` with a boolean `value`.
+    fn from(value: bool) -> Self {
+        ffi::qjsvalue_init_from_bool(value)
+    }
+}
+
+impl From<i32> for QJSValue {
+    /// Constructs a new `QJSValue` with a number `value`.
+    fn from(value: i32) -> Self {
+        ffi::qjsvalue_init_from_i32(value)
+    }
+}
+
+impl From<u32> for QJSValue {
+    /// Constructs a new `QJSValue` with a number `value`.
+    fn from(value: u32) -> Self {
+        ffi::qjsvalue_init_from_u32(value)
+    }
+}
+
+impl From<f64> for QJSValue {
+    /// Constructs a new `QJSValue` with a number `value`.
+    fn from(value: f64) -> Self {
+        ffi::qjsvalue_init_from_f64(value)
+    }
+}
+
+impl From<&QString> for QJSValue {
+    /// Constructs a new `QJSValue` with a string `value`.
+    fn from(value: &QString) -> Self {
+        ffi::qjsvalue_init_from_qstring(value)
+    }
+}
+
+impl From<&str> for QJSValue {
+    /// Constructs a new `QJSValue` with a string `value`.
+    fn from(value: &str) -> Self {
+        Self::from(&QString::from(value))
+    }
+}
+
+impl From<&QJSValue> for QVariant {
+    /// Converts the `QJSValue` to a `QVariant`, as with [`QJSValue::to_variant`].
+    fn from(value: &QJSValue) -> Self {
+        value.to_variant()
+    }
+}
+
+// Safety:
+//
+// Static checks on the C++ side to ensure the size is the same.
+unsafe impl ExternType for QJSValue {
+    type Id = type_id!("QJSValue");
+    type Kind = cxx::kind::Trivial;
+}
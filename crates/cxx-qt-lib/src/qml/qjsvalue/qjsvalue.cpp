@@ -0,0 +1,87 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Joshua Booth <joshua.n.booth@gmail.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#include "cxx-qt-lib/qjsvalue.h"
+
+#include <QtQml/QJSValueIterator>
+
+#include <cxx-qt-lib/assertion_utils.h>
+
+// QJSValue has a single member, which is a quintptr in Qt 5 and a quint64 in
+// Qt 6
+//
+// https://code.qt.io/cgit/qt/qtdeclarative.git/tree/src/qml/jsapi/qjsvalue.h?h=v5.15.6-lts-lgpl
+// https://code.qt.io/cgit/qt/qtdeclarative.git/tree/src/qml/jsapi/qjsvalue.h?h=v6.2.4
+#if (QT_VERSION >= QT_VERSION_CHECK(6, 0, 0))
+assert_alignment_and_size(QJSValue, { ::std::uint64_t a0; });
+#else
+assert_alignment_and_size(QJSValue, { ::std::size_t a0; });
+#endif
+
+static_assert(!::std::is_trivially_copy_assignable<QJSValue>::value);
+static_assert(!::std::is_trivially_copy_constructible<QJSValue>::value);
+
+static_assert(!::std::is_trivially_destructible<QJSValue>::value);
+
+namespace {
+
+QJSValueList
+toQJSValueList(::rust::Slice<const QJSValue> args)
+{
+  QJSValueList list;
+  list.reserve(static_cast<qsizetype>(args.size()));
+  for (const QJSValue& arg : args) {
+    list.append(arg);
+  }
+  return list;
+}
+
+}
+
+namespace rust {
+namespace cxxqtlib1 {
+
+// call, callAsConstructor and callWithInstance are only const since Qt 6, so
+// call them on a copy, which refers to the same JavaScript value
+
+QJSValue
+qjsvalueCall(const QJSValue& value, ::rust::Slice<const QJSValue> args)
+{
+  QJSValue function = value;
+  return function.call(toQJSValueList(args));
+}
+
+QJSValue
+qjsvalueCallAsConstructor(const QJSValue& value,
+                          ::rust::Slice<const QJSValue> args)
+{
+  QJSValue function = value;
+  return function.callAsConstructor(toQJSValueList(args));
+}
+
+QJSValue
+qjsvalueCallWithInstance(const QJSValue& value,
+                         const QJSValue& instance,
+                         ::rust::Slice<const QJSValue> args)
+{
+  QJSValue function = value;
+  return function.callWithInstance(instance, toQJSValueList(args));
+}
+
+QStringList
+qjsvaluePropertyNames(const QJSValue& value)
+{
+  QStringList names;
+  QJSValueIterator it(value);
+  while (it.hasNext()) {
+    it.next();
+    names.append(it.name());
+  }
+  return names;
+}
+
+}
+}
@@ -0,0 +1,958 @@
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Joshua Booth <joshua.n.booth@gmail.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! Converts serde types to and from JavaScript values.
+//!
+//! Structs and maps become JavaScript objects, sequences and tuples become arrays, and `None` and
+//! unit values become `null`. Enums are externally tagged, as in JSON: unit variants become their
+//! name as a string, and other variants become an object with the variant name as its only key.
+//!
+//! Map keys become property names, so keys of other types such as numbers are converted to strings
+//! and parsed back when deserialized. Integers must be within ±2^53 to be represented exactly as a
+//! JavaScript number.
+
+use super::QJSValue;
+use crate::{QJSEngine, QString};
+use core::pin::Pin;
+use serde::de::value::MapAccessDeserializer;
+use serde::de::{self, DeserializeOwned, DeserializeSeed, IntoDeserializer, Visitor};
+use serde::ser::{self, Impossible, Serialize, Serializer};
+use std::fmt;
+
+/// An error that occurred while converting a value with [`QJSEngine::to_script_value_from`] or [`QJSValue::deserialize_into`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum QJSValueSerdeError {
+    /// A custom error reported by a `Serialize` or `Deserialize` implementation.
+    Custom(String),
+    /// A map key could not be converted to a property name.
+    KeyMustBeAString,
+    /// The value has a shape that cannot be converted, such as a JavaScript function.
+    Unsupported(&'static str),
+}
+
+impl fmt::Display for QJSValueSerdeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Custom(message) => f.write_str(message),
+            Self::KeyMustBeAString => {
+                f.write_str("map keys must be strings, numbers, booleans or characters")
+            }
+            Self::Unsupported(shape) => write!(f, "{shape} cannot be converted"),
+        }
+    }
+}
+
+impl std::error::Error for QJSValueSerdeError {}
+
+impl ser::Error for QJSValueSerdeError {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        Self::Custom(msg.to_string())
+    }
+}
+
+impl de::Error for QJSValueSerdeError {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        Self::Custom(msg.to_string())
+    }
+}
+
+type Result<T> = std::result::Result<T, QJSValueSerdeError>;
+
+impl QJSEngine {
+    /// Converts `value` to a JavaScript value, creating objects and arrays in this engine.
+    ///
+    /// ```ignore
+    /// #[derive(Serialize)]
+    /// struct Point {
+    ///     x: f64,
+    ///     y: f64,
+    /// }
+    ///
+    /// let point = engine.as_mut().to_script_value_from(&Point { x: 1.0, y: 2.0 })?;
+    /// callback.call(&[point])?;
+    /// ```
+    pub fn to_script_value_from<T: Serialize + ?Sized>(
+        self: Pin<&mut Self>,
+        value: &T,
+    ) -> Result<QJSValue> {
+        value.serialize(ValueSerializer { engine: self })
+    }
+}
+
+impl QJSValue {
+    /// Converts this JavaScript value to a value of type `T`.
+    ///
+    /// Numbers without a fractional part are deserialized as integers, and all other numbers as floats.
+    pub fn deserialize_into<T: DeserializeOwned>(&self) -> Result<T> {
+        T::deserialize(ValueDeserializer {
+            value: self.clone(),
+        })
+    }
+}
+
+/// The largest magnitude of an integer that a JavaScript number represents exactly.
+const INTEGER_LIMIT: u64 = 1 << 53;
+
+/// Returns `value` as an `i64` if it is a number without a fractional part.
+fn as_integer(value: f64) -> Option<i64> {
+    if value.fract() == 0.0 && value.abs() <= INTEGER_LIMIT as f64 {
+        Some(value as i64)
+    } else {
+        None
+    }
+}
+
+/// Returns `magnitude` as a JavaScript number if it can be represented exactly.
+fn exact_number(magnitude: u64, negative: bool) -> Result<QJSValue> {
+    if magnitude > INTEGER_LIMIT {
+        return Err(QJSValueSerdeError::Unsupported(
+            "an integer outside of ±2^53",
+        ));
+    }
+    let number = magnitude as f64;
+    Ok(QJSValue::from(if negative { -number } else { number }))
+}
+
+fn array_len(value: &QJSValue) -> u32 {
+    value.property(&QString::from("length")).to_u32()
+}
+
+impl Serialize for QJSValue {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        use ser::{Error, SerializeMap, SerializeSeq};
+
+        if self.is_undefined() || self.is_null() {
+            serializer.serialize_none()
+        } else if self.is_bool() {
+            serializer.serialize_bool(self.to_bool())
+        } else if self.is_number() {
+            let number = self.to_number();
+            match as_integer(number) {
+                Some(integer) => serializer.serialize_i64(integer),
+                None => serializer.serialize_f64(number),
+            }
+        } else if self.is_string() || self.is_date() {
+            serializer.serialize_str(&String::from(&self.to_qstring()))
+        } else if self.is_callable() {
+            Err(S::Error::custom(
+                "JavaScript functions cannot be serialized",
+            ))
+        } else if self.is_qobject() {
+            Err(S::Error::custom("QObject values cannot be serialized"))
+        } else if self.is_variant() {
+            Err(S::Error::custom("variant values cannot be serialized"))
+        } else if self.is_array() {
+            let len = array_len(self);
+            let mut seq = serializer.serialize_seq(Some(len as usize))?;
+            for index in 0..len {
+                seq.serialize_element(&self.property_at(index))?;
+            }
+            seq.end()
+        } else if self.is_object() {
+            let names = self.property_names();
+            let mut map = serializer.serialize_map(Some(names.len() as usize))?;
+            for name in names.iter() {
+                map.serialize_entry(&String::from(name), &self.property(name))?;
+            }
+            map.end()
+        } else {
+            Err(S::Error::custom("unsupported JavaScript value"))
+        }
+    }
+}
+
+struct ValueSerializer<'a> {
+    engine: Pin<&'a mut QJSEngine>,
+}
+
+impl<'a> Serializer for ValueSerializer<'a> {
+    type Ok = QJSValue;
+    type Error = QJSValueSerdeError;
+
+    type SerializeSeq = SeqSerializer<'a>;
+    type SerializeTuple = SeqSerializer<'a>;
+    type SerializeTupleStruct = SeqSerializer<'a>;
+    type SerializeTupleVariant = VariantSerializer<SeqSerializer<'a>>;
+    type SerializeMap = MapSerializer<'a>;
+    type SerializeStruct = MapSerializer<'a>;
+    type SerializeStructVariant = VariantSerializer<MapSerializer<'a>>;
+
+    fn serialize_bool(self, v: bool) -> Result<QJSValue> {
+        Ok(QJSValue::from(v))
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<QJSValue> {
+        Ok(QJSValue::from(i32::from(v)))
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<QJSValue> {
+        Ok(QJSValue::from(i32::from(v)))
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<QJSValue> {
+        Ok(QJSValue::from(v))
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<QJSValue> {
+        exact_number(v.unsigned_abs(), v < 0)
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<QJSValue> {
+        Ok(QJSValue::from(u32::from(v)))
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<QJSValue> {
+        Ok(QJSValue::from(u32::from(v)))
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<QJSValue> {
+        Ok(QJSValue::from(v))
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<QJSValue> {
+        exact_number(v, false)
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<QJSValue> {
+        Ok(QJSValue::from(f64::from(v)))
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<QJSValue> {
+        Ok(QJSValue::from(v))
+    }
+
+    fn serialize_char(self, v: char) -> Result<QJSValue> {
+        self.serialize_str(v.encode_utf8(&mut [0; 4]))
+    }
+
+    fn serialize_str(self, v: &str) -> Result<QJSValue> {
+        Ok(QJSValue::from(v))
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<QJSValue> {
+        use ser::SerializeSeq;
+
+        let mut seq = self.serialize_seq(Some(v.len()))?;
+        for byte in v {
+            seq.serialize_element(byte)?;
+        }
+        seq.end()
+    }
+
+    fn serialize_none(self) -> Result<QJSValue> {
+        Ok(QJSValue::null())
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<QJSValue> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<QJSValue> {
+        Ok(QJSValue::null())
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<QJSValue> {
+        self.serialize_unit()
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> Result<QJSValue> {
+        self.serialize_str(variant)
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<QJSValue> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        mut self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<QJSValue> {
+        let mut object = self.engine.as_mut().new_object();
+        let value = value.serialize(ValueSerializer {
+            engine: self.engine.as_mut(),
+        })?;
+        object.set_property(&QString::from(variant), &value);
+        Ok(object)
+    }
+
+    fn serialize_seq(mut self, len: Option<usize>) -> Result<SeqSerializer<'a>> {
+        let len = len.and_then(|len| u32::try_from(len).ok()).unwrap_or(0);
+        let array = self.engine.as_mut().new_array(len);
+        Ok(SeqSerializer {
+            engine: self.engine,
+            array,
+            index: 0,
+        })
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<SeqSerializer<'a>> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_struct(self, _name: &'static str, len: usize) -> Result<SeqSerializer<'a>> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_variant(
+        mut self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeTupleVariant> {
+        let object = self.engine.as_mut().new_object();
+        Ok(VariantSerializer {
+            object,
+            variant,
+            inner: self.serialize_seq(Some(len))?,
+        })
+    }
+
+    fn serialize_map(mut self, _len: Option<usize>) -> Result<MapSerializer<'a>> {
+        let object = self.engine.as_mut().new_object();
+        Ok(MapSerializer {
+            engine: self.engine,
+            object,
+            key: None,
+        })
+    }
+
+    fn serialize_struct(self, _name: &'static str, len: usize) -> Result<MapSerializer<'a>> {
+        self.serialize_map(Some(len))
+    }
+
+    fn serialize_struct_variant(
+        mut self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeStructVariant> {
+        let object = self.engine.as_mut().new_object();
+        Ok(VariantSerializer {
+            object,
+            variant,
+            inner: self.serialize_map(Some(len))?,
+        })
+    }
+}
+
+struct SeqSerializer<'a> {
+    engine: Pin<&'a mut QJSEngine>,
+    array: QJSValue,
+    index: u32,
+}
+
+impl SeqSerializer<'_> {
+    fn push<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+        let value = value.serialize(ValueSerializer {
+            engine: self.engine.as_mut(),
+        })?;
+        self.array.set_property_at(self.index, &value);
+        self.index += 1;
+        Ok(())
+    }
+}
+
+impl ser::SerializeSeq for SeqSerializer<'_> {
+    type Ok = QJSValue;
+    type Error = QJSValueSerdeError;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<QJSValue> {
+        Ok(self.array)
+    }
+}
+
+impl ser::SerializeTuple for SeqSerializer<'_> {
+    type Ok = QJSValue;
+    type Error = QJSValueSerdeError;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<QJSValue> {
+        Ok(self.array)
+    }
+}
+
+impl ser::SerializeTupleStruct for SeqSerializer<'_> {
+    type Ok = QJSValue;
+    type Error = QJSValueSerdeError;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<QJSValue> {
+        Ok(self.array)
+    }
+}
+
+struct MapSerializer<'a> {
+    engine: Pin<&'a mut QJSEngine>,
+    object: QJSValue,
+    key: Option<QString>,
+}
+
+impl MapSerializer<'_> {
+    fn insert<T: Serialize + ?Sized>(&mut self, key: &QString, value: &T) -> Result<()> {
+        let value = value.serialize(ValueSerializer {
+            engine: self.engine.as_mut(),
+        })?;
+        self.object.set_property(key, &value);
+        Ok(())
+    }
+}
+
+impl ser::SerializeMap for MapSerializer<'_> {
+    type Ok = QJSValue;
+    type Error = QJSValueSerdeError;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<()> {
+        self.key = Some(key.serialize(KeySerializer)?);
+        Ok(())
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+        let key = self
+            .key
+            .take()
+            .ok_or_else(|| ser::Error::custom("serialize_value called before serialize_key"))?;
+        self.insert(&key, value)
+    }
+
+    fn end(self) -> Result<QJSValue> {
+        Ok(self.object)
+    }
+}
+
+impl ser::SerializeStruct for MapSerializer<'_> {
+    type Ok = QJSValue;
+    type Error = QJSValueSerdeError;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<()> {
+        self.insert(&QString::from(key), value)
+    }
+
+    fn end(self) -> Result<QJSValue> {
+        Ok(self.object)
+    }
+}
+
+/// Wraps the value of a tuple or struct variant in an object with the variant name as its key.
+struct VariantSerializer<S> {
+    object: QJSValue,
+    variant: &'static str,
+    inner: S,
+}
+
+impl<S> VariantSerializer<S> {
+    fn wrap(mut self, value: QJSValue) -> QJSValue {
+        self.object
+            .set_property(&QString::from(self.variant), &value);
+        self.object
+    }
+}
+
+impl ser::SerializeTupleVariant for VariantSerializer<SeqSerializer<'_>> {
+    type Ok = QJSValue;
+    type Error = QJSValueSerdeError;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+        self.inner.push(value)
+    }
+
+    fn end(mut self) -> Result<QJSValue> {
+        let array = std::mem::take(&mut self.inner.array);
+        Ok(self.wrap(array))
+    }
+}
+
+impl ser::SerializeStructVariant for VariantSerializer<MapSerializer<'_>> {
+    type Ok = QJSValue;
+    type Error = QJSValueSerdeError;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<()> {
+        self.inner.insert(&QString::from(key), value)
+    }
+
+    fn end(mut self) -> Result<QJSValue> {
+        let object = std::mem::take(&mut self.inner.object);
+        Ok(self.wrap(object))
+    }
+}
+
+/// Converts map keys to property names.
+struct KeySerializer;
+
+macro_rules! serialize_key_display {
+    ($($method:ident($t:ty)),*) => {
+        $(
+            fn $method(self, v: $t) -> Result<QString> {
+                Ok(QString::from(&v.to_string()))
+            }
+        )*
+    };
+}
+
+impl Serializer for KeySerializer {
+    type Ok = QString;
+    type Error = QJSValueSerdeError;
+
+    type SerializeSeq = Impossible<QString, QJSValueSerdeError>;
+    type SerializeTuple = Impossible<QString, QJSValueSerdeError>;
+    type SerializeTupleStruct = Impossible<QString, QJSValueSerdeError>;
+    type SerializeTupleVariant = Impossible<QString, QJSValueSerdeError>;
+    type SerializeMap = Impossible<QString, QJSValueSerdeError>;
+    type SerializeStruct = Impossible<QString, QJSValueSerdeError>;
+    type SerializeStructVariant = Impossible<QString, QJSValueSerdeError>;
+
+    serialize_key_display!(
+        serialize_bool(bool),
+        serialize_i8(i8),
+        serialize_i16(i16),
+        serialize_i32(i32),
+        serialize_i64(i64),
+        serialize_u8(u8),
+        serialize_u16(u16),
+        serialize_u32(u32),
+        serialize_u64(u64),
+        serialize_f32(f32),
+        serialize_f64(f64),
+        serialize_char(char)
+    );
+
+    fn serialize_str(self, v: &str) -> Result<QString> {
+        Ok(QString::from(v))
+    }
+
+    fn serialize_bytes(self, _v: &[u8]) -> Result<QString> {
+        Err(QJSValueSerdeError::KeyMustBeAString)
+    }
+
+    fn serialize_none(self) -> Result<QString> {
+        Err(QJSValueSerdeError::KeyMustBeAString)
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<QString> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<QString> {
+        Err(QJSValueSerdeError::KeyMustBeAString)
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<QString> {
+        Err(QJSValueSerdeError::KeyMustBeAString)
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> Result<QString> {
+        self.serialize_str(variant)
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<QString> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _value: &T,
+    ) -> Result<QString> {
+        Err(QJSValueSerdeError::KeyMustBeAString)
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> Result<Self::SerializeSeq> {
+        Err(QJSValueSerdeError::KeyMustBeAString)
+    }
+
+    fn serialize_tuple(self, _len: usize) -> Result<Self::SerializeTuple> {
+        Err(QJSValueSerdeError::KeyMustBeAString)
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleStruct> {
+        Err(QJSValueSerdeError::KeyMustBeAString)
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleVariant> {
+        Err(QJSValueSerdeError::KeyMustBeAString)
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap> {
+        Err(QJSValueSerdeError::KeyMustBeAString)
+    }
+
+    fn serialize_struct(self, _name: &'static str, _len: usize) -> Result<Self::SerializeStruct> {
+        Err(QJSValueSerdeError::KeyMustBeAString)
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStructVariant> {
+        Err(QJSValueSerdeError::KeyMustBeAString)
+    }
+}
+
+struct ValueDeserializer {
+    value: QJSValue,
+}
+
+impl<'de> de::Deserializer<'de> for ValueDeserializer {
+    type Error = QJSValueSerdeError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        let value = self.value;
+        if value.is_undefined() || value.is_null() {
+            visitor.visit_unit()
+        } else if value.is_bool() {
+            visitor.visit_bool(value.to_bool())
+        } else if value.is_number() {
+            let number = value.to_number();
+            match as_integer(number) {
+                Some(integer) => visitor.visit_i64(integer),
+                None => visitor.visit_f64(number),
+            }
+        } else if value.is_string() || value.is_date() {
+            visitor.visit_string(String::from(&value.to_qstring()))
+        } else if value.is_callable() {
+            Err(QJSValueSerdeError::Unsupported("a JavaScript function"))
+        } else if value.is_qobject() {
+            Err(QJSValueSerdeError::Unsupported("a QObject"))
+        } else if value.is_variant() {
+            Err(QJSValueSerdeError::Unsupported("a variant"))
+        } else if value.is_array() {
+            let len = array_len(&value);
+            visitor.visit_seq(SeqDeserializer {
+                array: value,
+                index: 0,
+                len,
+            })
+        } else if value.is_object() {
+            visitor.visit_map(MapDeserializer::new(value))
+        } else {
+            Err(QJSValueSerdeError::Unsupported("this JavaScript value"))
+        }
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        if self.value.is_undefined() || self.value.is_null() {
+            visitor.visit_none()
+        } else {
+            visitor.visit_some(self)
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value> {
+        if self.value.is_string() {
+            visitor.visit_enum(String::from(&self.value.to_qstring()).into_deserializer())
+        } else if self.value.is_object()
+            && !self.value.is_array()
+            && !self.value.is_qobject()
+            && !self.value.is_variant()
+        {
+            visitor.visit_enum(MapAccessDeserializer::new(MapDeserializer::new(self.value)))
+        } else {
+            Err(de::Error::invalid_type(
+                de::Unexpected::Other("JavaScript value"),
+                &"a string or an object with a single property",
+            ))
+        }
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf unit unit_struct seq tuple tuple_struct map struct
+        identifier ignored_any
+    }
+}
+
+struct SeqDeserializer {
+    array: QJSValue,
+    index: u32,
+    len: u32,
+}
+
+impl<'de> de::SeqAccess<'de> for SeqDeserializer {
+    type Error = QJSValueSerdeError;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(&mut self, seed: T) -> Result<Option<T::Value>> {
+        if self.index >= self.len {
+            return Ok(None);
+        }
+        let value = self.array.property_at(self.index);
+        self.index += 1;
+        seed.deserialize(ValueDeserializer { value }).map(Some)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some((self.len - self.index) as usize)
+    }
+}
+
+struct MapDeserializer {
+    object: QJSValue,
+    names: std::vec::IntoIter<QString>,
+    value: Option<QJSValue>,
+}
+
+impl MapDeserializer {
+    fn new(object: QJSValue) -> Self {
+        let names: Vec<QString> = object.property_names().iter().cloned().collect();
+        Self {
+            object,
+            names: names.into_iter(),
+            value: None,
+        }
+    }
+}
+
+impl<'de> de::MapAccess<'de> for MapDeserializer {
+    type Error = QJSValueSerdeError;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(&mut self, seed: K) -> Result<Option<K::Value>> {
+        let Some(name) = self.names.next() else {
+            return Ok(None);
+        };
+        self.value = Some(self.object.property(&name));
+        seed.deserialize(KeyDeserializer {
+            key: String::from(&name),
+        })
+        .map(Some)
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value> {
+        let value = self
+            .value
+            .take()
+            .ok_or_else(|| de::Error::custom("next_value_seed called before next_key_seed"))?;
+        seed.deserialize(ValueDeserializer { value })
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.names.len())
+    }
+}
+
+/// Converts property names back to map keys, parsing them when a number, boolean or character is expected.
+struct KeyDeserializer {
+    key: String,
+}
+
+macro_rules! deserialize_key_parse {
+    ($($method:ident => $visit:ident),*) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+                match self.key.parse() {
+                    Ok(v) => visitor.$visit(v),
+                    Err(_) => visitor.visit_string(self.key),
+                }
+            }
+        )*
+    };
+}
+
+impl<'de> de::Deserializer<'de> for KeyDeserializer {
+    type Error = QJSValueSerdeError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_string(self.key)
+    }
+
+    deserialize_key_parse!(
+        deserialize_bool => visit_bool,
+        deserialize_i8 => visit_i8,
+        deserialize_i16 => visit_i16,
+        deserialize_i32 => visit_i32,
+        deserialize_i64 => visit_i64,
+        deserialize_i128 => visit_i128,
+        deserialize_u8 => visit_u8,
+        deserialize_u16 => visit_u16,
+        deserialize_u32 => visit_u32,
+        deserialize_u64 => visit_u64,
+        deserialize_u128 => visit_u128,
+        deserialize_f32 => visit_f32,
+        deserialize_f64 => visit_f64,
+        deserialize_char => visit_char
+    );
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_some(self)
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value> {
+        visitor.visit_enum(self.key.into_deserializer())
+    }
+
+    serde::forward_to_deserialize_any! {
+        str string bytes byte_buf unit unit_struct seq tuple tuple_struct map struct
+        identifier ignored_any
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+    use std::collections::BTreeMap;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    enum Shape {
+        Empty,
+        Circle(f64),
+        Rect { width: u32, height: u32 },
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Scene {
+        name: String,
+        id: i64,
+        visible: Option<bool>,
+        shapes: Vec<Shape>,
+        layers: BTreeMap<u8, String>,
+    }
+
+    #[test]
+    fn qjsvalue_serde_round_trip() {
+        let mut engine = QJSEngine::new();
+        let scene = Scene {
+            name: "scene".to_owned(),
+            id: -(1 << 53),
+            visible: None,
+            shapes: vec![
+                Shape::Empty,
+                Shape::Circle(0.5),
+                Shape::Rect {
+                    width: 2,
+                    height: 3,
+                },
+            ],
+            layers: BTreeMap::from([(1, "background".to_owned()), (2, "foreground".to_owned())]),
+        };
+
+        let value = engine.pin_mut().to_script_value_from(&scene).unwrap();
+        assert!(value.is_object());
+        assert!(value.property(&QString::from("visible")).is_null());
+        assert!(value
+            .property(&QString::from("layers"))
+            .has_own_property(&QString::from("1")));
+        assert_eq!(value.deserialize_into::<Scene>().unwrap(), scene);
+    }
+
+    #[test]
+    fn qjsvalue_serde_integer_limits() {
+        let mut engine = QJSEngine::new();
+        let limit = 1_i64 << 53;
+
+        let value = engine.pin_mut().to_script_value_from(&limit).unwrap();
+        assert_eq!(value.deserialize_into::<i64>().unwrap(), limit);
+        assert_eq!(
+            engine
+                .pin_mut()
+                .to_script_value_from(&(limit + 1))
+                .unwrap_err(),
+            QJSValueSerdeError::Unsupported("an integer outside of ±2^53")
+        );
+        assert!(engine.pin_mut().to_script_value_from(&i64::MIN).is_err());
+        assert!(engine.pin_mut().to_script_value_from(&u64::MAX).is_err());
+    }
+
+    #[test]
+    fn qjsvalue_serde_unsupported() {
+        let mut engine = QJSEngine::new();
+        let function = engine
+            .pin_mut()
+            .evaluate(&QString::from("(function () {})"), &QString::default(), 1)
+            .unwrap();
+        assert_eq!(
+            function.deserialize_into::<BTreeMap<String, i32>>(),
+            Err(QJSValueSerdeError::Unsupported("a JavaScript function"))
+        );
+
+        let variant = engine
+            .pin_mut()
+            .to_script_value(&crate::QVariant::from(&crate::QPoint::new(1, 2)));
+        assert!(variant.is_variant());
+        assert_eq!(
+            variant.deserialize_into::<BTreeMap<String, i32>>(),
+            Err(QJSValueSerdeError::Unsupported("a variant"))
+        );
+    }
+}
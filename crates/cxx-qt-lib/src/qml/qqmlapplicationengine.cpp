@@ -16,5 +16,36 @@ qqmlapplicationengineNew()
   return ::std::make_unique<QQmlApplicationEngine>();
 }
 
+::std::unique_ptr<QQmlErrorList>
+qqmlapplicationengineLoadChecked(QQmlApplicationEngine& engine,
+                                 const QUrl& url)
+{
+  // Collect the warnings emitted while loading, which include the errors of
+  // the component if it failed to load
+  auto warnings = ::std::make_unique<QQmlErrorList>();
+  const auto connection =
+    QObject::connect(&engine,
+                     &QQmlEngine::warnings,
+                     [&warnings](const QList<QQmlError>& errors) {
+                       warnings->append(errors);
+                     });
+  engine.load(url);
+  QObject::disconnect(connection);
+  return warnings;
+}
+
+QObject*
+qqmlapplicationengineRootObject(const QQmlApplicationEngine& engine,
+                                ::rust::isize index)
+{
+  return engine.rootObjects().at(static_cast<qsizetype>(index));
+}
+
+::rust::isize
+qqmlapplicationengineRootObjectsLen(const QQmlApplicationEngine& engine)
+{
+  return static_cast<::rust::isize>(engine.rootObjects().size());
+}
+
 }
 }
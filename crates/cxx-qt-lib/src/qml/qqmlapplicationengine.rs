@@ -115,11 +115,35 @@ mod ffi {
         type QQmlEngine = crate::QQmlEngine;
     }
 
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qqmlerror.h");
+        type QQmlErrorList = crate::QQmlErrorList;
+    }
+
     #[namespace = "rust::cxxqtlib1"]
     unsafe extern "C++" {
         #[doc(hidden)]
         #[rust_name = "qqmlapplicationengine_new"]
         fn qqmlapplicationengineNew() -> UniquePtr<QQmlApplicationEngine>;
+
+        #[doc(hidden)]
+        #[rust_name = "qqmlapplicationengine_load_checked"]
+        fn qqmlapplicationengineLoadChecked(
+            engine: Pin<&mut QQmlApplicationEngine>,
+            url: &QUrl,
+        ) -> UniquePtr<QQmlErrorList>;
+
+        #[doc(hidden)]
+        #[rust_name = "qqmlapplicationengine_root_object"]
+        fn qqmlapplicationengineRootObject(
+            engine: &QQmlApplicationEngine,
+            index: isize,
+        ) -> *mut QObject;
+
+        #[doc(hidden)]
+        #[rust_name = "qqmlapplicationengine_root_objects_len"]
+        fn qqmlapplicationengineRootObjectsLen(engine: &QQmlApplicationEngine) -> isize;
     }
 
     // QQmlApplicationEngine is not a trivial to CXX and is not relocatable in Qt
@@ -131,6 +155,13 @@ mod ffi {
 
 pub use ffi::QQmlApplicationEngine;
 
+use crate::{QJSEngine, QObject, QQmlEngine, QQmlError, QString, QUrl};
+use core::pin::Pin;
+use cxx_qt::casting::Upcast;
+use cxx_qt::impl_transitive_cast;
+
+impl_transitive_cast!(QQmlApplicationEngine, QQmlEngine, QJSEngine, QObject);
+
 impl QQmlApplicationEngine {
     /// Create a new `QQmlApplicationEngine`.
     ///
@@ -138,4 +169,48 @@ impl QQmlApplicationEngine {
     pub fn new() -> cxx::UniquePtr<Self> {
         ffi::qqmlapplicationengine_new()
     }
+
+    /// Loads the root QML file located at `url`, and returns the root objects that were created.
+    ///
+    /// If no root object was created, returns the warnings that were emitted by the engine while loading,
+    /// such as syntax errors with their file, line and column.
+    ///
+    /// Note that only local files and resources are loaded synchronously, so this should not be used with remote URLs.
+    pub fn load_checked(
+        mut self: Pin<&mut Self>,
+        url: &QUrl,
+    ) -> Result<Vec<*mut QObject>, Vec<QQmlError>> {
+        let before = ffi::qqmlapplicationengine_root_objects_len(&self);
+        let warnings = ffi::qqmlapplicationengine_load_checked(self.as_mut(), url)
+            .as_ref()
+            .map(|warnings| warnings.to_vec())
+            .unwrap_or_default();
+        let after = ffi::qqmlapplicationengine_root_objects_len(&self);
+
+        if after > before {
+            return Ok((before..after)
+                .map(|index| ffi::qqmlapplicationengine_root_object(&self, index))
+                .collect());
+        }
+
+        if warnings.is_empty() {
+            let mut error = QQmlError::default();
+            error.set_url(url);
+            error.set_description(&QString::from("No root object was created"));
+            Err(vec![error])
+        } else {
+            Err(warnings)
+        }
+    }
+
+    /// Returns a list of all the root objects instantiated by the `QQmlApplicationEngine`.
+    ///
+    /// This will only contain objects loaded via [`load`](Self::load) or a convenience constructor.
+    ///
+    /// The objects are owned by the engine, and are deleted when the engine is destroyed.
+    pub fn root_objects(&self) -> Vec<*mut QObject> {
+        (0..ffi::qqmlapplicationengine_root_objects_len(self))
+            .map(|index| ffi::qqmlapplicationengine_root_object(self, index))
+            .collect()
+    }
 }
@@ -0,0 +1,32 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Joshua Booth <joshua.n.booth@gmail.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#include "cxx-qt-lib/qqmlcomponent.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QQmlErrorList>
+qqmlcomponentErrors(const QQmlComponent& component)
+{
+  return ::std::make_unique<QQmlErrorList>(component.errors());
+}
+
+::std::unique_ptr<QQmlComponent>
+qqmlcomponentNew(QQmlEngine& engine)
+{
+  return ::std::make_unique<QQmlComponent>(&engine);
+}
+
+::std::unique_ptr<QQmlComponent>
+qqmlcomponentFromUrl(QQmlEngine& engine, const QUrl& url)
+{
+  return ::std::make_unique<QQmlComponent>(&engine, url);
+}
+
+}
+}
@@ -0,0 +1,234 @@
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Joshua Booth <joshua.n.booth@gmail.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::{QByteArray, QQmlEngine, QQmlError, QUrl};
+use core::pin::Pin;
+
+#[cxx_qt::bridge]
+mod ffi {
+    /// Specifies the loading status of the `QQmlComponent`.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QQmlComponentStatus {
+        /// This `QQmlComponent` has no data. Call [`QQmlComponent::load_url`] or [`QQmlComponent::set_data`] to add QML content.
+        Null,
+        /// This `QQmlComponent` is ready and [`QQmlComponent::create`] may be called.
+        Ready,
+        /// This `QQmlComponent` is loading network data.
+        Loading,
+        /// An error has occurred. Call [`QQmlComponent::errors`] to retrieve a list of errors.
+        Error,
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qqmlcomponent.h");
+        type QQmlComponentStatus;
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qbytearray.h");
+        type QByteArray = crate::QByteArray;
+        include!("cxx-qt-lib/qmap.h");
+        type QMap_QString_QVariant = crate::QMap<crate::QMapPair_QString_QVariant>;
+        include!("cxx-qt-lib/qqmlcontext.h");
+        type QQmlContext = crate::QQmlContext;
+        include!("cxx-qt-lib/qqmlengine.h");
+        type QQmlEngine = crate::QQmlEngine;
+        include!("cxx-qt-lib/qqmlincubator.h");
+        type QQmlIncubator = crate::QQmlIncubator;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+        include!("cxx-qt-lib/qurl.h");
+        type QUrl = crate::QUrl;
+    }
+
+    unsafe extern "C++Qt" {
+        /// The `QQmlComponent` class encapsulates a QML component definition.
+        ///
+        /// Components are reusable, encapsulated QML types with well-defined interfaces, which can be loaded from a URL or from QML data and instantiated from Rust.
+        ///
+        /// Qt Documentation: [QQmlComponent](https://doc.qt.io/qt/qqmlcomponent.html#details)
+        #[qobject]
+        type QQmlComponent;
+
+        /// Emitted whenever the component's loading progress changes.
+        /// `progress` will be the current progress between 0.0 (nothing loaded) and 1.0 (finished).
+        #[qsignal]
+        #[rust_name = "progress_changed"]
+        fn progressChanged(self: Pin<&mut QQmlComponent>, progress: f64);
+
+        /// Emitted whenever the component's status changes. `status` will be the new status.
+        #[qsignal]
+        #[rust_name = "status_changed"]
+        fn statusChanged(self: Pin<&mut QQmlComponent>, status: QQmlComponentStatus);
+    }
+
+    unsafe extern "C++" {
+        /// Create an object instance from this component, within the specified `context`.
+        /// Returns a null pointer if creation failed.
+        ///
+        /// If `context` is null, the root context of the engine is used.
+        ///
+        /// The caller takes ownership of the returned object.
+        ///
+        /// # Safety
+        ///
+        /// `context` must be a valid pointer, or null.
+        unsafe fn create(self: Pin<&mut QQmlComponent>, context: *mut QQmlContext) -> *mut QObject;
+
+        /// Create an object instance from this component using the provided `incubator`.
+        ///
+        /// `context` specifies the context within which to create the object instance. If `context` is null, the root context of the engine is used.
+        ///
+        /// `for_context` specifies a context that this object creation depends upon. If the `for_context` is being created asynchronously, and the incubator's mode is AsynchronousIfNested, this object will also be created asynchronously. If `for_context` is null, the `context` is used.
+        ///
+        /// # Safety
+        ///
+        /// `context` and `for_context` must be valid pointers, or null, and the incubator must outlive the incubation.
+        #[cxx_name = "create"]
+        unsafe fn create_with_incubator(
+            self: Pin<&mut QQmlComponent>,
+            incubator: Pin<&mut QQmlIncubator>,
+            context: *mut QQmlContext,
+            for_context: *mut QQmlContext,
+        );
+
+        /// Create an object instance of this component, within the specified `context`, and initialize its top-level properties with `properties`.
+        /// Returns a null pointer if creation failed.
+        ///
+        /// If any of the properties cannot be set, a warning is issued. Unlike setting the properties after creation, they are set before any bindings are evaluated.
+        ///
+        /// The caller takes ownership of the returned object.
+        ///
+        /// # Safety
+        ///
+        /// `context` must be a valid pointer, or null.
+        #[rust_name = "create_with_initial_properties"]
+        unsafe fn createWithInitialProperties(
+            self: Pin<&mut QQmlComponent>,
+            properties: &QMap_QString_QVariant,
+            context: *mut QQmlContext,
+        ) -> *mut QObject;
+
+        /// Returns the `QQmlContext` the component was created in. This is only valid for components created directly from QML.
+        #[rust_name = "creation_context"]
+        fn creationContext(self: &QQmlComponent) -> *mut QQmlContext;
+
+        /// Returns a human-readable description of any error.
+        ///
+        /// The string includes the file, location, and description of each error. If multiple errors are present, they are separated by a newline character.
+        ///
+        /// If no errors are present, an empty string is returned.
+        #[rust_name = "error_string"]
+        fn errorString(self: &QQmlComponent) -> QString;
+
+        /// Returns `true` if [`status`](Self::status) is Error.
+        #[rust_name = "is_error"]
+        fn isError(self: &QQmlComponent) -> bool;
+
+        /// Returns `true` if [`status`](Self::status) is Loading.
+        #[rust_name = "is_loading"]
+        fn isLoading(self: &QQmlComponent) -> bool;
+
+        /// Returns `true` if [`status`](Self::status) is Null.
+        #[rust_name = "is_null"]
+        fn isNull(self: &QQmlComponent) -> bool;
+
+        /// Returns `true` if [`status`](Self::status) is Ready.
+        #[rust_name = "is_ready"]
+        fn isReady(self: &QQmlComponent) -> bool;
+
+        /// Load the `QQmlComponent` from the provided `url`.
+        ///
+        /// Ensure that the URL provided is full and correct, in particular, use [`QUrl::from_local_file`](crate::QUrl::from_local_file) when loading a file from the local filesystem.
+        #[rust_name = "load_url"]
+        fn loadUrl(self: Pin<&mut QQmlComponent>, url: &QUrl);
+
+        /// The progress of loading the component, from 0.0 (nothing loaded) to 1.0 (finished).
+        fn progress(self: &QQmlComponent) -> f64;
+
+        /// Sets the `QQmlComponent` to use the given QML `data`. If `url` is provided, it is used to set the component name and to provide a base path for items resolved by this component.
+        #[rust_name = "set_data"]
+        fn setData(self: Pin<&mut QQmlComponent>, data: &QByteArray, url: &QUrl);
+
+        /// Returns the component's current status.
+        fn status(self: &QQmlComponent) -> QQmlComponentStatus;
+
+        /// The component URL. This is the URL passed to either the constructor, or the [`load_url`](Self::load_url) or [`set_data`](Self::set_data) methods.
+        fn url(self: &QQmlComponent) -> QUrl;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qqmlerror.h");
+        type QQmlErrorList = crate::QQmlErrorList;
+
+        #[doc(hidden)]
+        #[rust_name = "qqmlcomponent_errors"]
+        fn qqmlcomponentErrors(component: &QQmlComponent) -> UniquePtr<QQmlErrorList>;
+
+        #[doc(hidden)]
+        #[rust_name = "qqmlcomponent_new"]
+        fn qqmlcomponentNew(engine: Pin<&mut QQmlEngine>) -> UniquePtr<QQmlComponent>;
+
+        #[doc(hidden)]
+        #[rust_name = "qqmlcomponent_from_url"]
+        fn qqmlcomponentFromUrl(
+            engine: Pin<&mut QQmlEngine>,
+            url: &QUrl,
+        ) -> UniquePtr<QQmlComponent>;
+    }
+
+    // QQmlComponent is not a trivial to CXX and is not relocatable in Qt
+    // as the following fails in C++. So we cannot mark it as a trivial type
+    // and need to use references or pointers.
+    // static_assert(QTypeInfo<QQmlComponent>::isRelocatable);
+    impl UniquePtr<QQmlComponent> {}
+}
+
+pub use ffi::{QQmlComponent, QQmlComponentStatus};
+
+impl QQmlComponent {
+    /// Create a `QQmlComponent` with no data for the given `engine`.
+    ///
+    /// Set the data with [`set_data`](Self::set_data) or [`load_url`](Self::load_url).
+    pub fn new(engine: Pin<&mut QQmlEngine>) -> cxx::UniquePtr<Self> {
+        ffi::qqmlcomponent_new(engine)
+    }
+
+    /// Create a `QQmlComponent` for the given `engine`, loaded from the given `url`.
+    ///
+    /// Local files are loaded synchronously, whereas remote files are loaded asynchronously; check the [`status`](Self::status) before creating objects.
+    pub fn from_url(engine: Pin<&mut QQmlEngine>, url: &QUrl) -> cxx::UniquePtr<Self> {
+        ffi::qqmlcomponent_from_url(engine, url)
+    }
+
+    /// Create a `QQmlComponent` for the given `engine`, loaded from the given QML `data`.
+    ///
+    /// The `url` is used as the component name, and as the base path for items resolved by this component.
+    pub fn from_data(
+        engine: Pin<&mut QQmlEngine>,
+        data: &QByteArray,
+        url: &QUrl,
+    ) -> cxx::UniquePtr<Self> {
+        let mut component = Self::new(engine);
+        if let Some(component) = component.as_mut() {
+            component.set_data(data, url);
+        }
+        component
+    }
+
+    /// Returns the list of errors that occurred during the last compile or create operation.
+    ///
+    /// An empty list is returned if [`is_error`](Self::is_error) is not set.
+    pub fn errors(&self) -> Vec<QQmlError> {
+        ffi::qqmlcomponent_errors(self)
+            .as_ref()
+            .map(|errors| errors.to_vec())
+            .unwrap_or_default()
+    }
+}
@@ -0,0 +1,114 @@
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Joshua Booth <joshua.n.booth@gmail.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use core::pin::Pin;
+
+#[cxx_qt::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qqmlengine.h");
+        type QQmlEngine = crate::QQmlEngine;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+        include!("cxx-qt-lib/qurl.h");
+        type QUrl = crate::QUrl;
+        include!("cxx-qt-lib/qvariant.h");
+        type QVariant = crate::QVariant;
+    }
+
+    unsafe extern "C++Qt" {
+        include!("cxx-qt-lib/qqmlcontext.h");
+        /// The `QQmlContext` class defines a context within a QML engine.
+        ///
+        /// Contexts hold the objects identified by id in a QML document, and allow data to be exposed to the QML components instantiated by the QML engine.
+        ///
+        /// Qt Documentation: [QQmlContext](https://doc.qt.io/qt/qqmlcontext.html#details)
+        #[qobject]
+        type QQmlContext;
+    }
+
+    unsafe extern "C++" {
+        /// Returns the base URL of the component, or the containing component if none is set.
+        #[rust_name = "base_url"]
+        fn baseUrl(self: &QQmlContext) -> QUrl;
+
+        /// Returns the context object, or a null pointer if there is no context object.
+        #[rust_name = "context_object"]
+        fn contextObject(self: &QQmlContext) -> *mut QObject;
+
+        /// Returns the value of the `name` property for this context.
+        #[rust_name = "context_property"]
+        fn contextProperty(self: &QQmlContext, name: &QString) -> QVariant;
+
+        /// Return the context's [`QQmlEngine`], or a null pointer if the context has no engine or the engine was destroyed.
+        fn engine(self: &QQmlContext) -> *mut QQmlEngine;
+
+        /// Returns whether the context is valid.
+        ///
+        /// To be valid, a context must have a engine, and it's [`context_object`](Self::context_object), if any, must not have been deleted.
+        #[rust_name = "is_valid"]
+        fn isValid(self: &QQmlContext) -> bool;
+
+        /// Return the context's parent `QQmlContext`, or a null pointer if this context has no parent or if the parent has been destroyed.
+        #[rust_name = "parent_context"]
+        fn parentContext(self: &QQmlContext) -> *mut QQmlContext;
+
+        /// Explicitly sets the url returned by [`base_url`](Self::base_url) to `base_url`.
+        ///
+        /// Calling this function will override the url of the containing component used by default.
+        #[rust_name = "set_base_url"]
+        fn setBaseUrl(self: Pin<&mut QQmlContext>, base_url: &QUrl);
+
+        /// Set the context `object`, whose properties are available by name in the context.
+        ///
+        /// # Safety
+        ///
+        /// `object` must be a valid pointer, or null, and must outlive the context.
+        #[rust_name = "set_context_object"]
+        unsafe fn setContextObject(self: Pin<&mut QQmlContext>, object: *mut QObject);
+
+        /// Set the `value` of the `name` property on this context.
+        ///
+        /// Setting a context property with the same name as an existing property replaces it, which causes every binding depending on it to be re-evaluated.
+        #[rust_name = "set_context_property"]
+        fn setContextProperty(self: Pin<&mut QQmlContext>, name: &QString, value: &QVariant);
+
+        /// Set the `name` property on this context to the `QObject` `value`.
+        ///
+        /// # Safety
+        ///
+        /// `value` must be a valid pointer, or null, and must outlive the context, as the context does not take ownership of it.
+        #[cxx_name = "setContextProperty"]
+        unsafe fn set_context_property_object(
+            self: Pin<&mut QQmlContext>,
+            name: &QString,
+            value: *mut QObject,
+        );
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qqmlcontext_new"]
+        fn qqmlcontextNew(parent_context: Pin<&mut QQmlContext>) -> UniquePtr<QQmlContext>;
+    }
+
+    // QQmlContext is not a trivial to CXX and is not relocatable in Qt
+    // as the following fails in C++. So we cannot mark it as a trivial type
+    // and need to use references or pointers.
+    // static_assert(QTypeInfo<QQmlContext>::isRelocatable);
+    impl UniquePtr<QQmlContext> {}
+}
+
+pub use ffi::QQmlContext;
+
+impl QQmlContext {
+    /// Create a new `QQmlContext` with the given `parent_context`.
+    ///
+    /// Objects created in the new context can access the context properties and objects of its parent context.
+    pub fn new(parent_context: Pin<&mut Self>) -> cxx::UniquePtr<Self> {
+        ffi::qqmlcontext_new(parent_context)
+    }
+}
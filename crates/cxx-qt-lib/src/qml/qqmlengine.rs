@@ -3,14 +3,22 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
+use crate::QJSEngine;
+use cxx_qt::casting::Upcast;
+use cxx_qt::impl_transitive_cast;
+use cxx_qt::QObject;
+
 #[cxx_qt::bridge]
 mod ffi {
     unsafe extern "C++Qt" {
         include!("cxx-qt-lib/qqmlengine.h");
         /// The `QQmlEngine` class provides an environment for instantiating QML components.
         ///
+        /// `QQmlEngine` inherits from [`QJSEngine`](crate::QJSEngine), which can be reached with [`upcast`](cxx_qt::casting::Upcast) to evaluate JavaScript.
+        ///
         /// Qt Documentation: [QQmlEngine](https://doc.qt.io/qt/qqmlengine.html#details)
         #[qobject]
+        #[base = QJSEngine]
         type QQmlEngine;
 
         /// This signal is emitted when the QML loaded by the engine would like to exit from the event loop with the specified return code `ret_code`.
@@ -20,9 +28,23 @@ mod ffi {
         /// This signal is emitted when the QML loaded by the engine would like to quit.
         #[qsignal]
         fn quit(self: Pin<&mut QQmlEngine>);
+
+        /// This signal is emitted when `warnings` messages are generated by QML.
+        #[qsignal]
+        fn warnings(self: Pin<&mut QQmlEngine>, warnings: &QQmlErrorList);
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qqmlerror.h");
+        type QQmlErrorList = crate::QQmlErrorList;
     }
 
     unsafe extern "C++" {
+        include!("cxx-qt-lib/qjsengine.h");
+        type QJSEngine = crate::QJSEngine;
+        include!("cxx-qt-lib/qqmlcontext.h");
+        type QQmlContext = crate::QQmlContext;
         include!("cxx-qt-lib/qstring.h");
         type QString = crate::QString;
         include!("cxx-qt-lib/qstringlist.h");
@@ -75,6 +97,16 @@ mod ffi {
         #[rust_name = "plugin_path_list"]
         fn pluginPathList(self: &QQmlEngine) -> QStringList;
 
+        /// Returns the engine's root context.
+        ///
+        /// The root context is automatically created by the `QQmlEngine`. Data that should be available to all QML component instances instantiated by the engine should be put in the root context.
+        #[rust_name = "root_context"]
+        fn rootContext(self: &QQmlEngine) -> *mut QQmlContext;
+
+        /// Removes the image provider for `provider_id`.
+        #[rust_name = "remove_image_provider"]
+        fn removeImageProvider(self: Pin<&mut QQmlEngine>, provider_id: &QString);
+
         /// Set the base URL for this engine to `url`.
         #[rust_name = "set_base_url"]
         fn setBaseUrl(self: Pin<&mut QQmlEngine>, url: &QUrl);
@@ -116,6 +148,8 @@ mod ffi {
 
 pub use ffi::QQmlEngine;
 
+impl_transitive_cast!(QQmlEngine, QJSEngine, QObject);
+
 impl QQmlEngine {
     /// Create a new `QQmlEngine`.
     pub fn new() -> cxx::UniquePtr<Self> {
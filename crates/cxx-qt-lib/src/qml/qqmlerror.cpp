@@ -0,0 +1,40 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Joshua Booth <joshua.n.booth@gmail.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#include "cxx-qt-lib/qqmlerror.h"
+
+#include <cxx-qt-lib/assertion_utils.h>
+
+// QQmlError has a single pointer as it's member
+//
+// https://code.qt.io/cgit/qt/qtdeclarative.git/tree/src/qml/qml/qqmlerror.h?h=v5.15.6-lts-lgpl
+// https://code.qt.io/cgit/qt/qtdeclarative.git/tree/src/qml/qml/qqmlerror.h?h=v6.2.4
+assert_alignment_and_size(QQmlError, { ::std::size_t a0; });
+
+static_assert(!::std::is_trivially_copy_assignable<QQmlError>::value);
+static_assert(!::std::is_trivially_copy_constructible<QQmlError>::value);
+
+static_assert(!::std::is_trivially_destructible<QQmlError>::value);
+
+namespace rust {
+namespace cxxqtlib1 {
+
+QQmlError
+qqmlerrorlistGet(const QQmlErrorList& list, ::rust::isize index)
+{
+  // Qt 5 has an int Qt 6 has a qsizetype
+  return list.at(static_cast<qsizetype>(index));
+}
+
+::rust::isize
+qqmlerrorlistLen(const QQmlErrorList& list)
+{
+  // Qt 5 has an int Qt 6 has a qsizetype
+  return static_cast<::rust::isize>(list.size());
+}
+
+}
+}
@@ -0,0 +1,203 @@
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Joshua Booth <joshua.n.booth@gmail.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use cxx::{type_id, ExternType};
+use std::fmt;
+use std::mem::MaybeUninit;
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qobject.h");
+        type QObject = crate::QObject;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+        include!("cxx-qt-lib/qtlogging.h");
+        type QtMsgType = crate::QtMsgType;
+        include!("cxx-qt-lib/qurl.h");
+        type QUrl = crate::QUrl;
+
+        include!("cxx-qt-lib/qqmlerror.h");
+        type QQmlError = super::QQmlError;
+
+        /// Returns the error column number.
+        fn column(self: &QQmlError) -> i32;
+
+        /// Returns the error description.
+        fn description(self: &QQmlError) -> QString;
+
+        /// Returns `true` if this error is valid, otherwise `false`.
+        #[rust_name = "is_valid"]
+        fn isValid(self: &QQmlError) -> bool;
+
+        /// Returns the error line number.
+        fn line(self: &QQmlError) -> i32;
+
+        /// Returns the message type.
+        #[rust_name = "message_type"]
+        fn messageType(self: &QQmlError) -> QtMsgType;
+
+        /// Returns the nearest object where this error occurred.
+        /// Exceptions in bound property expressions set this to the object to which the property belongs. It will be a null pointer for all other exceptions.
+        fn object(self: &QQmlError) -> *mut QObject;
+
+        /// Sets the error `column` number.
+        #[rust_name = "set_column"]
+        fn setColumn(self: &mut QQmlError, column: i32);
+
+        /// Sets the error `description`.
+        #[rust_name = "set_description"]
+        fn setDescription(self: &mut QQmlError, description: &QString);
+
+        /// Sets the error `line` number.
+        #[rust_name = "set_line"]
+        fn setLine(self: &mut QQmlError, line: i32);
+
+        /// Sets the message type of this error to `message_type`.
+        #[rust_name = "set_message_type"]
+        fn setMessageType(self: &mut QQmlError, message_type: QtMsgType);
+
+        /// Sets the nearest `object` where this error occurred.
+        ///
+        /// # Safety
+        ///
+        /// `object` must be a valid pointer, or null.
+        #[rust_name = "set_object"]
+        unsafe fn setObject(self: &mut QQmlError, object: *mut QObject);
+
+        /// Sets the `url` for the file that caused this error.
+        #[rust_name = "set_url"]
+        fn setUrl(self: &mut QQmlError, url: &QUrl);
+
+        /// Returns the error as a human readable string, in the form `url:line:column: description`.
+        #[rust_name = "to_qstring"]
+        fn toString(self: &QQmlError) -> QString;
+
+        /// Returns the url for the file that caused this error.
+        fn url(self: &QQmlError) -> QUrl;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        /// A list of [`QQmlError`]s, as emitted by [`QQmlEngine::warnings`](crate::QQmlEngine::warnings).
+        ///
+        /// Use [`to_vec`](Self::to_vec) to collect the errors.
+        type QQmlErrorList;
+
+        #[doc(hidden)]
+        #[rust_name = "qqmlerrorlist_get"]
+        fn qqmlerrorlistGet(list: &QQmlErrorList, index: isize) -> QQmlError;
+        #[doc(hidden)]
+        #[rust_name = "qqmlerrorlist_len"]
+        fn qqmlerrorlistLen(list: &QQmlErrorList) -> isize;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/common.h");
+
+        #[doc(hidden)]
+        #[rust_name = "qqmlerror_drop"]
+        fn drop(error: &mut QQmlError);
+
+        #[doc(hidden)]
+        #[rust_name = "qqmlerror_init_default"]
+        fn construct() -> QQmlError;
+        #[doc(hidden)]
+        #[rust_name = "qqmlerror_init_from_qqmlerror"]
+        fn construct(error: &QQmlError) -> QQmlError;
+    }
+
+    impl UniquePtr<QQmlErrorList> {}
+}
+
+pub use ffi::QQmlErrorList;
+
+/// The `QQmlError` class encapsulates a QML error.
+///
+/// Errors are reported by [`QQmlEngine::warnings`](crate::QQmlEngine::warnings), [`QQmlComponent::errors`](crate::QQmlComponent::errors) and [`QQmlApplicationEngine::load_checked`](crate::QQmlApplicationEngine::load_checked).
+///
+/// Qt Documentation: [QQmlError](https://doc.qt.io/qt/qqmlerror.html#details)
+#[repr(C)]
+pub struct QQmlError {
+    _space: MaybeUninit<usize>,
+}
+
+impl Clone for QQmlError {
+    /// Creates a copy of `self`.
+    fn clone(&self) -> Self {
+        ffi::qqmlerror_init_from_qqmlerror(self)
+    }
+}
+
+impl Default for QQmlError {
+    /// Creates an empty error object.
+    fn default() -> Self {
+        ffi::qqmlerror_init_default()
+    }
+}
+
+impl Drop for QQmlError {
+    /// Destroys the error.
+    fn drop(&mut self) {
+        ffi::qqmlerror_drop(self)
+    }
+}
+
+impl fmt::Display for QQmlError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_qstring())
+    }
+}
+
+impl fmt::Debug for QQmlError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "QQmlError({:?})", self.to_qstring())
+    }
+}
+
+impl std::error::Error for QQmlError {}
+
+// Safety:
+//
+// Static checks on the C++ side to ensure the size is the same.
+unsafe impl ExternType for QQmlError {
+    type Id = type_id!("QQmlError");
+    type Kind = cxx::kind::Trivial;
+}
+
+impl QQmlErrorList {
+    /// Returns the error at `index`, or `None` if `index` is out of bounds.
+    pub fn get(&self, index: isize) -> Option<QQmlError> {
+        if (0..self.len()).contains(&index) {
+            Some(ffi::qqmlerrorlist_get(self, index))
+        } else {
+            None
+        }
+    }
+
+    /// Returns `true` if the list contains no errors.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the number of errors in the list.
+    pub fn len(&self) -> isize {
+        ffi::qqmlerrorlist_len(self)
+    }
+
+    /// Returns a copy of the errors in the list.
+    pub fn to_vec(&self) -> Vec<QQmlError> {
+        (0..self.len())
+            .map(|index| ffi::qqmlerrorlist_get(self, index))
+            .collect()
+    }
+}
+
+impl From<&QQmlErrorList> for Vec<QQmlError> {
+    fn from(list: &QQmlErrorList) -> Self {
+        list.to_vec()
+    }
+}
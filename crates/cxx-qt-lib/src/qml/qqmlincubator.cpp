@@ -0,0 +1,20 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Joshua Booth <joshua.n.booth@gmail.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#include "cxx-qt-lib/qqmlincubator.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QQmlIncubator>
+qqmlincubatorNew(QQmlIncubatorIncubationMode mode)
+{
+  return ::std::make_unique<QQmlIncubator>(mode);
+}
+
+}
+}
@@ -0,0 +1,117 @@
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Joshua Booth <joshua.n.booth@gmail.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx_qt::bridge]
+mod ffi {
+    /// Specifies the mode the incubator operates in.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QQmlIncubatorIncubationMode {
+        /// The object will be created asynchronously.
+        Asynchronous,
+        /// If the object is being created in a context that is already part of an asynchronous creation, this incubator will join that existing incubation and execute asynchronously.
+        /// Otherwise the object is created synchronously.
+        AsynchronousIfNested,
+        /// The object will be created synchronously.
+        Synchronous,
+    }
+
+    /// Specifies the status of the incubator.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QQmlIncubatorStatus {
+        /// Incubation is not in progress.
+        Null,
+        /// Incubation has completed successfully.
+        Ready,
+        /// Incubation is in progress.
+        Loading,
+        /// Incubation failed.
+        Error,
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qqmlincubator.h");
+        type QQmlIncubatorIncubationMode;
+        type QQmlIncubatorStatus;
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qmap.h");
+        type QMap_QString_QVariant = crate::QMap<crate::QMapPair_QString_QVariant>;
+
+        /// The `QQmlIncubator` class allows QML objects to be created asynchronously.
+        ///
+        /// An incubator is passed to [`QQmlComponent::create_with_incubator`](crate::QQmlComponent::create_with_incubator), which creates the object over time while the engine's incubation controller is running, such as in a `QQuickWindow`.
+        ///
+        /// Qt Documentation: [QQmlIncubator](https://doc.qt.io/qt/qqmlincubator.html#details)
+        type QQmlIncubator;
+
+        /// Clears the incubator.
+        ///
+        /// Any in-progress incubation is aborted.
+        /// If the incubator is in the Ready state, the created object is **not** deleted.
+        fn clear(self: Pin<&mut QQmlIncubator>);
+
+        /// Force any in-progress incubation to finish synchronously.
+        ///
+        /// Once this call returns, the incubator will not be in the Loading state.
+        #[rust_name = "force_completion"]
+        fn forceCompletion(self: Pin<&mut QQmlIncubator>);
+
+        /// Return the incubation mode passed to [`QQmlIncubator::new`].
+        #[rust_name = "incubation_mode"]
+        fn incubationMode(self: &QQmlIncubator) -> QQmlIncubatorIncubationMode;
+
+        /// Returns `true` if the incubator's [`status`](Self::status) is Error.
+        #[rust_name = "is_error"]
+        fn isError(self: &QQmlIncubator) -> bool;
+
+        /// Returns `true` if the incubator's [`status`](Self::status) is Loading.
+        #[rust_name = "is_loading"]
+        fn isLoading(self: &QQmlIncubator) -> bool;
+
+        /// Returns `true` if the incubator's [`status`](Self::status) is Null.
+        #[rust_name = "is_null"]
+        fn isNull(self: &QQmlIncubator) -> bool;
+
+        /// Returns `true` if the incubator's [`status`](Self::status) is Ready.
+        #[rust_name = "is_ready"]
+        fn isReady(self: &QQmlIncubator) -> bool;
+
+        /// Return the incubated object if the status is Ready, otherwise a null pointer.
+        ///
+        /// The caller takes ownership of the object.
+        fn object(self: &QQmlIncubator) -> *mut QObject;
+
+        /// Stores a mapping from property names to initial values, contained in `properties`, with which the incubated component will be initialized.
+        #[rust_name = "set_initial_properties"]
+        fn setInitialProperties(self: Pin<&mut QQmlIncubator>, properties: &QMap_QString_QVariant);
+
+        /// Return the current status of the incubator.
+        fn status(self: &QQmlIncubator) -> QQmlIncubatorStatus;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qqmlincubator_new"]
+        fn qqmlincubatorNew(mode: QQmlIncubatorIncubationMode) -> UniquePtr<QQmlIncubator>;
+    }
+
+    impl UniquePtr<QQmlIncubator> {}
+}
+
+pub use ffi::{QQmlIncubator, QQmlIncubatorIncubationMode, QQmlIncubatorStatus};
+
+impl QQmlIncubator {
+    /// Create a new incubator with the specified `mode`.
+    pub fn new(mode: QQmlIncubatorIncubationMode) -> cxx::UniquePtr<Self> {
+        ffi::qqmlincubator_new(mode)
+    }
+}
@@ -0,0 +1,13 @@
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Joshua Booth <joshua.n.booth@gmail.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+mod qquickimageprovider;
+pub use qquickimageprovider::ImageProvider;
+
+mod qquickitem;
+pub use qquickitem::{QQuickItem, QQuickItemFlag};
+
+mod qquickpainteditem;
+pub use qquickpainteditem::{QQuickPaintedItem, QQuickPaintedItemPerformanceHint};
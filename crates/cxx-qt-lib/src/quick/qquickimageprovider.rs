@@ -0,0 +1,111 @@
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Joshua Booth <joshua.n.booth@gmail.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::{QImage, QQmlEngine, QSize, QString};
+use core::pin::Pin;
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qquickimageprovider.h");
+        include!("cxx-qt-lib/qimage.h");
+        type QImage = crate::QImage;
+        include!("cxx-qt-lib/qqmlengine.h");
+        type QQmlEngine = crate::QQmlEngine;
+        include!("cxx-qt-lib/qsize.h");
+        type QSize = crate::QSize;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+    }
+
+    extern "Rust" {
+        type ImageProviderBox;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qqmlengine_add_image_provider"]
+        fn qqmlengineAddImageProvider(
+            engine: Pin<&mut QQmlEngine>,
+            id: &QString,
+            provider: Box<ImageProviderBox>,
+            request: fn(&ImageProviderBox, &QString, &QSize) -> QImage,
+        );
+
+        #[doc(hidden)]
+        #[rust_name = "qqmlengine_add_async_image_provider"]
+        fn qqmlengineAddAsyncImageProvider(
+            engine: Pin<&mut QQmlEngine>,
+            id: &QString,
+            provider: Box<ImageProviderBox>,
+            request: fn(&ImageProviderBox, &QString, &QSize) -> QImage,
+        );
+    }
+}
+
+/// Provides images to QML from Rust, for use with [`QQmlEngine::add_image_provider`] and
+/// [`QQmlEngine::add_async_image_provider`].
+///
+/// Images are requested in QML with the `image://` URL scheme, for example `image://icons/close`
+/// requests the image with id `close` from the provider which was added as `icons`.
+///
+/// Qt Documentation: [QQuickImageProvider](https://doc.qt.io/qt/qquickimageprovider.html#details)
+pub trait ImageProvider: Send + Sync + 'static {
+    /// Returns the image with the given `id`.
+    ///
+    /// `requested_size` corresponds to the `sourceSize` of the QML `Image` item.
+    /// If it is valid, the image should be scaled to it, otherwise the image should be returned at its natural size.
+    ///
+    /// This may be called from a thread other than the GUI thread, so it must not access any `QObject` of the GUI thread.
+    fn request_image(&self, id: &QString, requested_size: &QSize) -> QImage;
+}
+
+struct ImageProviderBox(Box<dyn ImageProvider>);
+
+impl ImageProviderBox {
+    fn request_image(&self, id: &QString, requested_size: &QSize) -> QImage {
+        self.0.request_image(id, requested_size)
+    }
+}
+
+impl QQmlEngine {
+    /// Sets the `provider` to use for images requested via the `image://` URL scheme, with host `id`.
+    ///
+    /// Images are requested in the GUI thread, unless the QML `Image` item has `asynchronous: true`,
+    /// in which case they are requested in a separate thread.
+    ///
+    /// Any existing provider with the same `id` is replaced.
+    pub fn add_image_provider<P: ImageProvider>(self: Pin<&mut Self>, id: &QString, provider: P) {
+        ffi::qqmlengine_add_image_provider(
+            self,
+            id,
+            Box::new(ImageProviderBox(Box::new(provider))),
+            ImageProviderBox::request_image,
+        );
+    }
+
+    /// Sets the `provider` to use for images requested via the `image://` URL scheme, with host `id`.
+    ///
+    /// Unlike [`add_image_provider`](Self::add_image_provider), images are always requested on a
+    /// thread pool owned by the provider and delivered to QML once they are ready,
+    /// so that slow requests such as loading from the network do not block QML.
+    ///
+    /// Any existing provider with the same `id` is replaced.
+    ///
+    /// Qt Documentation: [QQuickAsyncImageProvider](https://doc.qt.io/qt/qquickasyncimageprovider.html#details)
+    pub fn add_async_image_provider<P: ImageProvider>(
+        self: Pin<&mut Self>,
+        id: &QString,
+        provider: P,
+    ) {
+        ffi::qqmlengine_add_async_image_provider(
+            self,
+            id,
+            Box::new(ImageProviderBox(Box::new(provider))),
+            ImageProviderBox::request_image,
+        );
+    }
+}
@@ -0,0 +1,199 @@
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Joshua Booth <joshua.n.booth@gmail.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::QSizeF;
+
+#[cxx_qt::bridge]
+mod ffi {
+    /// This enum type is used to specify various item properties.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QQuickItemFlag {
+        /// Indicates this item should visually clip its children so that they are rendered only within the boundaries of this item.
+        ItemClipsChildrenToShape = 0x01,
+        /// Indicates the item supports text input methods.
+        ItemAcceptsInputMethod = 0x02,
+        /// Indicates the item is a focus scope.
+        ItemIsFocusScope = 0x04,
+        /// Indicates the item has visual content and should be rendered by the scene graph.
+        ItemHasContents = 0x08,
+        /// Indicates the item accepts drag and drop events.
+        ItemAcceptsDrops = 0x10,
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qquickitem.h");
+        type QQuickItemFlag;
+    }
+
+    #[namespace = "Qt"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qt.h");
+        type MouseButtons = crate::MouseButtons;
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qrectf.h");
+        type QRectF = crate::QRectF;
+    }
+
+    unsafe extern "C++Qt" {
+        /// The `QQuickItem` class provides the most basic of all visual items in Qt Quick.
+        ///
+        /// A Rust `QObject` can use `QQuickItem` as its base class with `#[base = QQuickItem]`, and handle input by overriding its virtual event handlers with `#[cxx_override]`, such as `mousePressEvent`, `keyPressEvent`, `hoverMoveEvent` and `geometryChange`.
+        ///
+        /// Qt Documentation: [QQuickItem](https://doc.qt.io/qt/qquickitem.html#details)
+        #[qobject]
+        type QQuickItem;
+
+        /// Returns whether hover events are accepted by this item.
+        ///
+        /// The default value is `false`.
+        #[rust_name = "accept_hover_events"]
+        fn acceptHoverEvents(self: &QQuickItem) -> bool;
+
+        /// Returns the mouse buttons accepted by this item.
+        ///
+        /// The default value is no buttons.
+        #[rust_name = "accepted_mouse_buttons"]
+        fn acceptedMouseButtons(self: &QQuickItem) -> MouseButtons;
+
+        /// Returns whether the item is antialiased.
+        fn antialiasing(self: &QQuickItem) -> bool;
+
+        /// Returns the extents of the item in its own coordinate system: a rectangle from `(0, 0)` to [`width`](Self::width) and [`height`](Self::height).
+        #[rust_name = "bounding_rect"]
+        fn boundingRect(self: &QQuickItem) -> QRectF;
+
+        /// Forces active focus on the item.
+        ///
+        /// This method sets focus on the item and ensures that all ancestor focus scopes in the object hierarchy are also given focus.
+        #[rust_name = "force_active_focus"]
+        fn forceActiveFocus(self: Pin<&mut QQuickItem>);
+
+        /// Returns whether the item has active focus, which is required to receive key events.
+        #[rust_name = "has_active_focus"]
+        fn hasActiveFocus(self: &QQuickItem) -> bool;
+
+        /// Returns the height of the item.
+        fn height(self: &QQuickItem) -> f64;
+
+        /// Returns whether the item receives mouse and keyboard events.
+        #[rust_name = "is_enabled"]
+        fn isEnabled(self: &QQuickItem) -> bool;
+
+        /// Returns whether the item is visible.
+        #[rust_name = "is_visible"]
+        fn isVisible(self: &QQuickItem) -> bool;
+
+        /// Returns the opacity of the item, between 0.0 (fully transparent) and 1.0 (fully opaque).
+        fn opacity(self: &QQuickItem) -> f64;
+
+        /// Returns the visual parent of the item, or a null pointer if it has none.
+        #[rust_name = "parent_item"]
+        fn parentItem(self: &QQuickItem) -> *mut QQuickItem;
+
+        /// Schedules a polish event for this item.
+        ///
+        /// When the scene graph processes the request, it will call `updatePolish` on this item.
+        fn polish(self: Pin<&mut QQuickItem>);
+
+        /// Sets whether hover events are accepted by this item.
+        #[rust_name = "set_accept_hover_events"]
+        fn setAcceptHoverEvents(self: Pin<&mut QQuickItem>, enabled: bool);
+
+        /// Sets the mouse buttons accepted by this item to `buttons`.
+        ///
+        /// **Note:** In Qt 5, calling this function implicitly sets the item's `acceptTouchEvents` to `true`.
+        #[rust_name = "set_accepted_mouse_buttons"]
+        fn setAcceptedMouseButtons(self: Pin<&mut QQuickItem>, buttons: MouseButtons);
+
+        /// Sets whether the item is antialiased.
+        #[rust_name = "set_antialiasing"]
+        fn setAntialiasing(self: Pin<&mut QQuickItem>, antialiasing: bool);
+
+        /// Sets whether the item receives mouse and keyboard events.
+        #[rust_name = "set_enabled"]
+        fn setEnabled(self: Pin<&mut QQuickItem>, enabled: bool);
+
+        /// Enables the specified `flag` for this item if `enabled` is `true`; if `enabled` is `false`, the flag is disabled.
+        #[rust_name = "set_flag"]
+        fn setFlag(self: Pin<&mut QQuickItem>, flag: QQuickItemFlag, enabled: bool);
+
+        /// Sets whether the item has focus within its enclosing focus scope.
+        #[rust_name = "set_focus"]
+        fn setFocus(self: Pin<&mut QQuickItem>, focus: bool);
+
+        /// Sets the height of the item.
+        #[rust_name = "set_height"]
+        fn setHeight(self: Pin<&mut QQuickItem>, height: f64);
+
+        /// Sets the opacity of the item, between 0.0 (fully transparent) and 1.0 (fully opaque).
+        #[rust_name = "set_opacity"]
+        fn setOpacity(self: Pin<&mut QQuickItem>, opacity: f64);
+
+        /// Sets the visual parent of the item to `parent`.
+        ///
+        /// # Safety
+        ///
+        /// `parent` must be a valid pointer to a `QQuickItem`, or a null pointer.
+        #[rust_name = "set_parent_item"]
+        unsafe fn setParentItem(self: Pin<&mut QQuickItem>, parent: *mut QQuickItem);
+
+        /// Sets whether the item is visible.
+        #[rust_name = "set_visible"]
+        fn setVisible(self: Pin<&mut QQuickItem>, visible: bool);
+
+        /// Sets the width of the item.
+        #[rust_name = "set_width"]
+        fn setWidth(self: Pin<&mut QQuickItem>, width: f64);
+
+        /// Sets the x position of the item, relative to its parent.
+        #[rust_name = "set_x"]
+        fn setX(self: Pin<&mut QQuickItem>, x: f64);
+
+        /// Sets the y position of the item, relative to its parent.
+        #[rust_name = "set_y"]
+        fn setY(self: Pin<&mut QQuickItem>, y: f64);
+
+        /// Schedules a call to `updatePaintNode` for this item, provided that the item has the [`QQuickItemFlag::ItemHasContents`] flag and is visible.
+        fn update(self: Pin<&mut QQuickItem>);
+
+        /// Returns the width of the item.
+        fn width(self: &QQuickItem) -> f64;
+
+        /// Returns the x position of the item, relative to its parent.
+        fn x(self: &QQuickItem) -> f64;
+
+        /// Returns the y position of the item, relative to its parent.
+        fn y(self: &QQuickItem) -> f64;
+
+        /// This signal is emitted when the height of the item changes.
+        #[qsignal]
+        #[rust_name = "height_changed"]
+        fn heightChanged(self: Pin<&mut QQuickItem>);
+
+        /// This signal is emitted when the visibility of the item changes.
+        #[qsignal]
+        #[rust_name = "visible_changed"]
+        fn visibleChanged(self: Pin<&mut QQuickItem>);
+
+        /// This signal is emitted when the width of the item changes.
+        #[qsignal]
+        #[rust_name = "width_changed"]
+        fn widthChanged(self: Pin<&mut QQuickItem>);
+    }
+}
+
+pub use ffi::{QQuickItem, QQuickItemFlag};
+
+impl QQuickItem {
+    /// Returns the size of the item.
+    pub fn size(&self) -> QSizeF {
+        QSizeF::new(self.width(), self.height())
+    }
+}
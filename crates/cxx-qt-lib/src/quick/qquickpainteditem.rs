@@ -0,0 +1,111 @@
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Joshua Booth <joshua.n.booth@gmail.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::{QQuickItem, QRect};
+use core::pin::Pin;
+use cxx_qt::casting::Upcast;
+use cxx_qt::impl_transitive_cast;
+use cxx_qt::QObject;
+
+#[cxx_qt::bridge]
+mod ffi {
+    /// This enum describes the ways in which the painting of a [`QQuickPaintedItem`] can be optimized.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QQuickPaintedItemPerformanceHint {
+        /// The item's framebuffer object is not resized to the size of the item, but is instead kept at the largest size the item has had, which makes resizing cheaper.
+        FastFBOResizing = 0x1,
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qquickpainteditem.h");
+        type QQuickPaintedItemPerformanceHint;
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qcolor.h");
+        type QColor = crate::QColor;
+        include!("cxx-qt-lib/qrect.h");
+        type QRect = crate::QRect;
+        include!("cxx-qt-lib/qsize.h");
+        type QSize = crate::QSize;
+
+        include!("cxx-qt-lib/qquickitem.h");
+        type QQuickItem = crate::QQuickItem;
+    }
+
+    unsafe extern "C++Qt" {
+        /// The `QQuickPaintedItem` class provides a way to use the [`QPainter`](crate::QPainter) API in the QML Scene Graph.
+        ///
+        /// A Rust `QObject` can use `QQuickPaintedItem` as its base class with `#[base = QQuickPaintedItem]`, and draw its contents by overriding `paint` with `#[cxx_override]`. As the item is rendered into an image, this also works with the software and offscreen platforms.
+        ///
+        /// Qt Documentation: [QQuickPaintedItem](https://doc.qt.io/qt/qquickpainteditem.html#details)
+        #[qobject]
+        #[base = QQuickItem]
+        type QQuickPaintedItem;
+
+        /// Returns the color used to fill the item's background before painting.
+        ///
+        /// The default fill color is transparent.
+        #[rust_name = "fill_color"]
+        fn fillColor(self: &QQuickPaintedItem) -> QColor;
+
+        /// Returns whether mipmapping is enabled for the item.
+        fn mipmap(self: &QQuickPaintedItem) -> bool;
+
+        /// Returns whether the item is opaque, meaning that [`paint`](https://doc.qt.io/qt/qquickpainteditem.html#paint) paints every pixel of the item.
+        #[rust_name = "opaque_painting"]
+        fn opaquePainting(self: &QQuickPaintedItem) -> bool;
+
+        /// Sets the color used to fill the item's background before painting.
+        #[rust_name = "set_fill_color"]
+        fn setFillColor(self: Pin<&mut QQuickPaintedItem>, color: &QColor);
+
+        /// Sets whether mipmapping is enabled for the item.
+        #[rust_name = "set_mipmap"]
+        fn setMipmap(self: Pin<&mut QQuickPaintedItem>, enable: bool);
+
+        /// Sets whether the item is opaque, which allows the scene graph to optimize its rendering.
+        #[rust_name = "set_opaque_painting"]
+        fn setOpaquePainting(self: Pin<&mut QQuickPaintedItem>, opaque: bool);
+
+        /// Sets the given performance `hint` on the item if `enabled` is `true`; otherwise clears the performance hint.
+        #[rust_name = "set_performance_hint"]
+        fn setPerformanceHint(
+            self: Pin<&mut QQuickPaintedItem>,
+            hint: QQuickPaintedItemPerformanceHint,
+            enabled: bool,
+        );
+
+        /// Sets the size of the texture which the item is painted into.
+        ///
+        /// By default, the texture size follows the size of the item.
+        #[rust_name = "set_texture_size"]
+        fn setTextureSize(self: Pin<&mut QQuickPaintedItem>, size: &QSize);
+
+        /// Returns the size of the texture which the item is painted into.
+        #[rust_name = "texture_size"]
+        fn textureSize(self: &QQuickPaintedItem) -> QSize;
+
+        /// Schedules a redraw of the area covered by `rect` in this item.
+        #[rust_name = "update_rect"]
+        fn update(self: Pin<&mut QQuickPaintedItem>, rect: &QRect);
+    }
+}
+
+pub use ffi::{QQuickPaintedItem, QQuickPaintedItemPerformanceHint};
+
+impl_transitive_cast!(QQuickPaintedItem, QQuickItem, QObject);
+
+impl QQuickPaintedItem {
+    /// Schedules a redraw of the whole item, which calls `paint` when the scene graph next renders.
+    ///
+    /// Note that this must be used instead of [`QQuickItem::update`], which does not mark the contents of the item as dirty.
+    pub fn update(self: Pin<&mut Self>) {
+        self.update_rect(&QRect::default());
+    }
+}
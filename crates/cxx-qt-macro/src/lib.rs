@@ -72,6 +72,64 @@ pub fn init_qml_module(args: TokenStream) -> TokenStream {
     .into()
 }
 
+#[proc_macro]
+pub fn export_qml_plugin(args: TokenStream) -> TokenStream {
+    let module_uri = syn::parse_macro_input!(args as syn::LitStr);
+    let module_name = syn::Ident::new(&module_uri.value().replace('.', "_"), module_uri.span());
+
+    // These are the functions generated by moc for the plugin, renamed by cxx-qt-build so that
+    // the exported functions of the shared library can forward to them
+    let instance_function = quote::format_ident!("cxx_qt_plugin_instance_{module_name}");
+    let metadata_function = quote::format_ident!("cxx_qt_plugin_query_metadata_{module_name}");
+    let metadata_v2_function =
+        quote::format_ident!("cxx_qt_plugin_query_metadata_v2_{module_name}");
+    quote::quote! {
+        const _: () = {
+            #[cfg(cxxqt_qt_version_at_least_6)]
+            #[repr(C)]
+            #[allow(dead_code)]
+            struct QPluginMetaData {
+                data: *const u8,
+                size: usize,
+            }
+
+            extern "C" {
+                fn #instance_function() -> *mut ::core::ffi::c_void;
+                #[cfg(cxxqt_qt_version_at_least_6_3)]
+                fn #metadata_v2_function() -> QPluginMetaData;
+                #[cfg(all(cxxqt_qt_version_at_least_6, not(cxxqt_qt_version_at_least_6_3)))]
+                fn #metadata_function() -> QPluginMetaData;
+                #[cfg(not(cxxqt_qt_version_at_least_6))]
+                fn #metadata_function() -> *const ::core::ffi::c_char;
+            }
+
+            #[no_mangle]
+            extern "C" fn qt_plugin_instance() -> *mut ::core::ffi::c_void {
+                unsafe { #instance_function() }
+            }
+
+            #[cfg(cxxqt_qt_version_at_least_6_3)]
+            #[no_mangle]
+            extern "C" fn qt_plugin_query_metadata_v2() -> QPluginMetaData {
+                unsafe { #metadata_v2_function() }
+            }
+
+            #[cfg(all(cxxqt_qt_version_at_least_6, not(cxxqt_qt_version_at_least_6_3)))]
+            #[no_mangle]
+            extern "C" fn qt_plugin_query_metadata() -> QPluginMetaData {
+                unsafe { #metadata_function() }
+            }
+
+            #[cfg(not(cxxqt_qt_version_at_least_6))]
+            #[no_mangle]
+            extern "C" fn qt_plugin_query_metadata() -> *const ::core::ffi::c_char {
+                unsafe { #metadata_function() }
+            }
+        };
+    }
+    .into()
+}
+
 // Take the module and C++ namespace and generate the rust code
 fn extract_and_generate(module: ItemMod) -> TokenStream {
     Parser::from(module)
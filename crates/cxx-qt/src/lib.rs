@@ -59,6 +59,18 @@ pub use cxx_qt_macro::init_crate;
 /// Force a QML module with the given URI to be initialized
 pub use cxx_qt_macro::init_qml_module;
 
+/// Export the plugin of the QML module with the given URI from a shared library
+///
+/// The QML module needs to be built as a dynamic plugin by setting `dynamic_plugin` in the
+/// `QmlModule` given to `cxx_qt_build::CxxQtBuilder::qml_module`, and the crate needs to have
+/// the `cdylib` crate type. The QML engine can then load the shared library as the plugin of the
+/// module.
+///
+/// ```rust,ignore
+/// cxx_qt::export_qml_plugin!("com.kdab.cxx_qt.demo");
+/// ```
+pub use cxx_qt_macro::export_qml_plugin;
+
 /// A macro which describes that a struct should be made into a QObject.
 ///
 /// It should not be used by itself and instead should be used inside a cxx_qt::bridge definition.
@@ -216,6 +228,65 @@ pub trait Threading: Sized {
     fn threading_drop(cxx_qt_thread: core::pin::Pin<&mut CxxQtThread<Self>>);
 }
 
+/// This trait indicates that a QObject may be moved to another thread, for example with
+/// `cxx_qt_lib::QThread::move_object` or `cxx_qt_lib::QObjectExt::move_to_thread`.
+///
+/// Once a QObject has been moved, its slots, invokables and queued closures run on the thread
+/// that it now lives in. Any Rust state owned by the QObject is therefore accessed from that thread.
+///
+/// This trait is implemented automatically for every QObject generated by CXX-Qt whose inner Rust
+/// struct is [`Send`](https://doc.rust-lang.org/std/marker/trait.Send.html).
+///
+/// For a `#[qobject]` declared in an `extern "C++Qt"` block, CXX-Qt cannot know whether the C++
+/// class may be used from another thread, so the trait has to be implemented explicitly:
+///
+/// ```ignore
+/// // Safety: QTimer has no state which is tied to the thread that it was created on
+/// unsafe impl cxx_qt::MoveToThread for ffi::QTimer {}
+/// ```
+///
+/// Note that a [CxxQtThread] created before the QObject was moved will queue closures onto the new thread afterwards.
+///
+/// # Safety
+///
+/// Implementing this trait asserts that all state owned by the QObject can safely be accessed from
+/// another thread than the one that it was created on.
+pub unsafe trait MoveToThread {}
+
+/// This trait is implemented automatically by CXX-Qt for the extension type `T` of a QML element
+/// with the `#[qml_extended = T]` attribute.
+///
+/// QML creates an instance of the extension for each instance of the extended type `E`, passing the
+/// extended object as its parent. The extension can then reach the object which it extends with
+/// [QmlExtension::extended_object], for example to implement its properties and invokables.
+pub trait QmlExtension<E> {
+    /// Returns a pointer to the object which is extended by this extension object.
+    ///
+    /// The pointer is null if this object was not created by QML as the extension of an `E`.
+    fn extended_object(&self) -> *mut E
+    where
+        Self: casting::Upcast<QObject> + Sized,
+        E: casting::Upcast<QObject>,
+    {
+        let parent = casting::Upcast::<QObject>::upcast(self).cxx_qt_ffi_parent();
+        if parent.is_null() {
+            return core::ptr::null_mut();
+        }
+        // Safety: the parent is a valid QObject, which is checked to be an E with a dynamic_cast
+        unsafe { <E as casting::Upcast<QObject>>::from_base_ptr(parent) }.cast_mut()
+    }
+}
+
+// Safety:
+//
+// The only Rust state of a CXX-Qt QObject is its inner Rust struct.
+unsafe impl<T> MoveToThread for T
+where
+    T: CxxQtType,
+    T::Rust: Send,
+{
+}
+
 /// This trait can be implemented on any [CxxQtType] to define a
 /// custom constructor in C++ for the QObject.
 ///
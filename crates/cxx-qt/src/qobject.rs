@@ -16,6 +16,10 @@ mod ffi {
         ///
         /// Qt Documentation: [QObject](https://doc.qt.io/qt/qobject.html#details)
         type QObject;
+
+        #[doc(hidden)]
+        #[cxx_name = "parent"]
+        pub fn cxx_qt_ffi_parent(self: &QObject) -> *mut QObject;
     }
 }
 
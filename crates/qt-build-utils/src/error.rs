@@ -6,6 +6,7 @@
 use thiserror::Error;
 
 #[derive(Error, Debug)]
+#[non_exhaustive]
 /// Errors that can occur while using [crate::QtBuild]
 pub enum QtBuildError {
     /// `QMAKE` environment variable was set but Qt was not detected
@@ -19,6 +20,12 @@ pub enum QtBuildError {
     /// Qt was not found
     #[error("Could not find Qt")]
     QtMissing,
+    /// `QT_INSTALLATION` environment variable was set to an unknown installation, or one whose feature is not enabled
+    #[error("QT_INSTALLATION environment variable specified as {installation} but only qmake, cmake or pkg-config with their feature enabled are supported")]
+    QtInstallationUnsupported {
+        /// The value of the `QT_INSTALLATION` environment variable
+        installation: String,
+    },
     /// Executing `qmake -query` failed
     #[error("Executing `qmake -query` failed: {0:?}")]
     QmakeFailed(#[from] std::io::Error),
@@ -30,6 +37,32 @@ pub enum QtBuildError {
         /// The [std::num::ParseIntError] when parsing the `QT_VERSION_MAJOR`
         source: std::num::ParseIntError,
     },
+    /// A Qt tool such as qmllint could not be found in the Qt installation
+    #[error("Could not find {tool}: {error:?}")]
+    ToolMissing {
+        /// The name of the tool
+        tool: String,
+        /// The inner error that occurred
+        error: Box<anyhow::Error>,
+    },
+    /// A Qt tool such as moc or rcc failed to run or reported an error
+    #[error("{tool} failed for {input}:\n{output}")]
+    ToolFailed {
+        /// The name of the tool
+        tool: String,
+        /// The file or module the tool was run for
+        input: String,
+        /// The error output of the tool, or the error which occurred when running it
+        output: String,
+    },
+    /// A QML file of a QML module cannot be declared in the qmldir as requested
+    #[error("QML file {file} cannot be declared in the qmldir: {reason}")]
+    QmlFileInvalid {
+        /// The path of the QML file in the Qt resource system
+        file: String,
+        /// Why the file cannot be declared
+        reason: String,
+    },
     /// `QT_VERSION_MAJOR` environment variable was specified but the Qt version specified by `qmake -query QT_VERSION` did not match
     #[error("qmake version ({qmake_version}) does not match version specified by QT_VERSION_MAJOR ({qt_version_major})")]
     QtVersionMajorDoesNotMatch {
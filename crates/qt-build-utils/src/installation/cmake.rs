@@ -0,0 +1,154 @@
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Joshua Booth <joshua.n.booth@gmail.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use semver::Version;
+use std::{
+    env,
+    path::{Path, PathBuf},
+};
+
+use super::qmake::QtInstallationQMake;
+use crate::{LinkArgs, QtBuildError, QtInstallation, QtTool};
+
+/// A implementation of [QtInstallation] using the CMake package config of Qt 6
+///
+/// The `Qt6Config.cmake` file is used to find the Qt installation, which is then queried
+/// with [qtpaths](https://doc.qt.io/qt-6/qtpaths.html), so this does not require qmake.
+/// Note that `qtpaths --query` requires Qt 6.2 or later.
+pub struct QtInstallationCMake {
+    cmake_dir: PathBuf,
+    qtpaths: QtInstallationQMake,
+}
+
+impl QtInstallationCMake {
+    /// The `Qt6_DIR` environment variable may be set to the directory containing
+    /// `Qt6Config.cmake`, as it would be for CMake. Otherwise the `lib*/cmake/Qt6` directories of
+    /// the prefixes in the `CMAKE_PREFIX_PATH` environment variable are searched, followed by the
+    /// parent directories of the `PATH` environment variable, like CMake does.
+    ///
+    /// qtpaths is then found in the `bin` directory of the installation, alternatively the
+    /// `QTPATHS` environment variable may be set to specify an explicit path to qtpaths.
+    pub fn new() -> anyhow::Result<Self> {
+        println!("cargo::rerun-if-env-changed=Qt6_DIR");
+        if let Ok(cmake_dir) = env::var("Qt6_DIR") {
+            return Self::try_from(PathBuf::from(cmake_dir));
+        }
+
+        println!("cargo::rerun-if-env-changed=CMAKE_PREFIX_PATH");
+        let prefix_paths = env::var_os("CMAKE_PREFIX_PATH")
+            .map(|paths| env::split_paths(&paths).collect::<Vec<_>>())
+            .unwrap_or_default();
+        let path_prefixes = env::var_os("PATH")
+            .map(|paths| {
+                env::split_paths(&paths)
+                    .filter_map(|path| path.parent().map(Path::to_path_buf))
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+
+        prefix_paths
+            .iter()
+            .chain(path_prefixes.iter())
+            .find_map(|prefix| find_cmake_dir(prefix))
+            .ok_or_else(|| QtBuildError::QtMissing.into())
+            .and_then(Self::try_from)
+    }
+
+    /// The directory containing `Qt6Config.cmake` of this installation
+    pub fn cmake_dir(&self) -> &Path {
+        &self.cmake_dir
+    }
+}
+
+impl TryFrom<PathBuf> for QtInstallationCMake {
+    type Error = anyhow::Error;
+
+    /// Create an installation from the directory containing `Qt6Config.cmake`
+    fn try_from(cmake_dir: PathBuf) -> anyhow::Result<Self> {
+        if !cmake_dir.join("Qt6Config.cmake").exists() {
+            return Err(anyhow::anyhow!(
+                "Could not find Qt6Config.cmake in {}",
+                cmake_dir.display()
+            ));
+        }
+
+        println!("cargo::rerun-if-env-changed=QTPATHS");
+        let qtpaths_path = match env::var("QTPATHS") {
+            Ok(qtpaths) => PathBuf::from(qtpaths),
+            Err(_) => find_qtpaths(&cmake_dir).ok_or_else(|| {
+                anyhow::anyhow!(
+                    "Could not find qtpaths for the Qt installation of {}",
+                    cmake_dir.display()
+                )
+            })?,
+        };
+        let qtpaths = QtInstallationQMake::try_from_query_tool(qtpaths_path, "--query")?;
+
+        Ok(Self { cmake_dir, qtpaths })
+    }
+}
+
+impl QtInstallation for QtInstallationCMake {
+    fn include_paths(&self, qt_modules: &[String]) -> Vec<PathBuf> {
+        self.qtpaths.include_paths(qt_modules)
+    }
+
+    fn link_modules(&self, qt_modules: &[String]) -> LinkArgs {
+        self.qtpaths.link_modules(qt_modules)
+    }
+
+    fn try_find_tool(&self, tool: QtTool) -> anyhow::Result<PathBuf> {
+        self.qtpaths.try_find_tool(tool)
+    }
+
+    fn version(&self) -> Version {
+        self.qtpaths.version()
+    }
+}
+
+/// Find the directory containing `Qt6Config.cmake` within a CMake prefix
+fn find_cmake_dir(prefix: &Path) -> Option<PathBuf> {
+    let is_cmake_dir = |dir: &Path| dir.join("Qt6Config.cmake").exists();
+    if is_cmake_dir(prefix) {
+        return Some(prefix.to_owned());
+    }
+
+    // Qt is installed in lib/cmake/Qt6, but distributions can also use lib64 or a directory
+    // for the architecture such as lib/x86_64-linux-gnu
+    let lib_dirs = std::fs::read_dir(prefix)
+        .ok()?
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .filter(|path| {
+            path.file_name()
+                .is_some_and(|name| name.to_string_lossy().starts_with("lib"))
+        })
+        .collect::<Vec<_>>();
+    lib_dirs
+        .iter()
+        .map(|lib_dir| lib_dir.join("cmake").join("Qt6"))
+        .chain(lib_dirs.iter().flat_map(|lib_dir| {
+            std::fs::read_dir(lib_dir)
+                .into_iter()
+                .flatten()
+                .filter_map(Result::ok)
+                .map(|entry| entry.path().join("cmake").join("Qt6"))
+        }))
+        .find(|dir| is_cmake_dir(dir))
+}
+
+/// Find qtpaths in the `bin` directory of the installation containing the CMake directory
+fn find_qtpaths(cmake_dir: &Path) -> Option<PathBuf> {
+    cmake_dir.ancestors().find_map(|ancestor| {
+        ["qtpaths6", "qtpaths"]
+            .iter()
+            .map(|name| {
+                ancestor
+                    .join("bin")
+                    .join(format!("{name}{}", env::consts::EXE_SUFFIX))
+            })
+            .find(|path| path.is_file())
+    })
+}
@@ -3,11 +3,21 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
-#[cfg(feature = "qmake")]
+#[cfg(feature = "cmake")]
+pub(crate) mod cmake;
+
+#[cfg(feature = "pkg_config")]
+pub(crate) mod pkg_config;
+
+#[cfg(any(feature = "qmake", feature = "cmake"))]
 pub(crate) mod qmake;
 
 use semver::Version;
-use std::path::PathBuf;
+use std::{
+    collections::HashSet,
+    path::PathBuf,
+    sync::{Mutex, OnceLock},
+};
 
 use crate::QtTool;
 
@@ -20,23 +30,185 @@ pub trait QtInstallation {
     ///
     /// This is intended to be passed to whichever tool you are using to invoke the C++ compiler.
     fn include_paths(&self, qt_modules: &[String]) -> Vec<PathBuf>;
-    /// Configure the given cc::Build and cargo to link to the given Qt modules
+    /// Return the arguments needed to link to the given Qt modules
     ///
-    // TODO: should we hand in a cc::Build or should we instead return a struct
-    // with details of the rustc-link-lib / search paths ? and then have the
-    // calling function apply those and any flags to the cc::Build?
-    // eg return the following?
-    //
-    // pub struct LinkArgs {
-    //     builder_flag_if_supported: Vec<String>,
-    //     builder_object: Vec<String>,
-    //     rustc_link_arg: Vec<String>,
-    //     rustc_link_lib: Vec<String>,
-    //     rustc_link_search: Vec<String>,
-    // }
-    fn link_modules(&self, builder: &mut cc::Build, qt_modules: &[String]);
+    /// The arguments are applied to a cc::Build and cargo with [LinkArgs::apply].
+    fn link_modules(&self, qt_modules: &[String]) -> LinkArgs;
     /// Find the path to a given Qt tool for the Qt installation
     fn try_find_tool(&self, tool: QtTool) -> anyhow::Result<PathBuf>;
     /// Version of the detected Qt installation
     fn version(&self) -> Version;
 }
+
+/// A single argument needed to link to Qt modules, see [LinkArgs]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum LinkArg {
+    /// A flag for the C++ compiler, passed to [cc::Build::flag_if_supported]
+    BuilderFlagIfSupported(String),
+    /// An object file to link, passed to [cc::Build::object]
+    BuilderObject(PathBuf),
+    /// An argument for the linker, printed as `cargo::rustc-link-arg`
+    RustcLinkArg(String),
+    /// A library to link, printed as `cargo::rustc-link-lib`
+    RustcLinkLib(String),
+    /// A library search path, printed as `cargo::rustc-link-search`
+    RustcLinkSearch(String),
+}
+
+/// The arguments needed to link to Qt modules, as returned by [QtInstallation::link_modules]
+///
+/// The arguments are kept in the order in which they were found, as the linker can depend on
+/// the order of search paths, libraries and linker arguments.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct LinkArgs {
+    /// The arguments, in the order in which they are applied
+    pub args: Vec<LinkArg>,
+}
+
+impl LinkArgs {
+    /// Append an argument
+    pub fn push(&mut self, arg: LinkArg) {
+        self.args.push(arg);
+    }
+
+    /// Append the arguments of `other` to these arguments
+    pub fn extend(&mut self, other: LinkArgs) {
+        self.args.extend(other.args);
+    }
+
+    /// Append the arguments of `other` which are not already part of these arguments
+    ///
+    /// This allows for combining the arguments of multiple sets of Qt modules without linking
+    /// any library or object file twice.
+    pub fn merge(&mut self, other: LinkArgs) {
+        for arg in other.args {
+            if !self.args.contains(&arg) {
+                self.args.push(arg);
+            }
+        }
+    }
+
+    /// The compiler flags of these arguments, for configuring a cc::Build without linking
+    pub fn builder_flags(&self) -> impl Iterator<Item = &str> {
+        self.args.iter().filter_map(|arg| match arg {
+            LinkArg::BuilderFlagIfSupported(flag) => Some(flag.as_str()),
+            _ => None,
+        })
+    }
+
+    /// Configure the given cc::Build and cargo with these arguments
+    ///
+    /// Object files which have already been applied by another [LinkArgs] are skipped, as
+    /// linking the same object file twice fails with duplicate symbol errors.
+    pub fn apply(&self, builder: &mut cc::Build) {
+        for arg in &self.args {
+            match arg {
+                LinkArg::BuilderFlagIfSupported(flag) => {
+                    builder.flag_if_supported(flag);
+                }
+                LinkArg::BuilderObject(object) => {
+                    let linked_object_files =
+                        LINKED_OBJECT_FILES.get_or_init(|| Mutex::new(HashSet::new()));
+                    if linked_object_files
+                        .lock()
+                        .expect("Lock poisoned")
+                        .insert(object.clone())
+                    {
+                        // Cargo doesn't have a means to directly specify an object to link,
+                        // so use the cc crate to specify it instead.
+                        // TODO: pass file path directly when link-arg library type is stabilized
+                        // https://github.com/rust-lang/rust/issues/99427#issuecomment-1562092085
+                        builder.object(object);
+                    }
+                }
+                LinkArg::RustcLinkArg(arg) => println!("cargo::rustc-link-arg={arg}"),
+                LinkArg::RustcLinkLib(lib) => println!("cargo::rustc-link-lib={lib}"),
+                LinkArg::RustcLinkSearch(search) => println!("cargo::rustc-link-search={search}"),
+            }
+        }
+    }
+}
+
+/// The object files which have been linked by [LinkArgs::apply]
+static LINKED_OBJECT_FILES: OnceLock<Mutex<HashSet<PathBuf>>> = OnceLock::new();
+
+/// Find the [QtInstallation] used by [crate::QtBuild::new]
+///
+/// The `QT_INSTALLATION` environment variable can be set to `qmake`, `cmake` or `pkg-config`
+/// to select an installation, otherwise each installation with its feature enabled is tried
+/// in that order.
+#[cfg(any(feature = "qmake", feature = "cmake", feature = "pkg_config"))]
+pub(crate) fn find_installation() -> anyhow::Result<Box<dyn QtInstallation>> {
+    type Find = fn() -> anyhow::Result<Box<dyn QtInstallation>>;
+    let installations: &[(&str, Find)] = &[
+        #[cfg(feature = "qmake")]
+        ("qmake", || Ok(Box::new(qmake::QtInstallationQMake::new()?))),
+        #[cfg(feature = "cmake")]
+        ("cmake", || Ok(Box::new(cmake::QtInstallationCMake::new()?))),
+        #[cfg(feature = "pkg_config")]
+        ("pkg-config", || {
+            Ok(Box::new(pkg_config::QtInstallationPkgConfig::new()?))
+        }),
+    ];
+
+    println!("cargo::rerun-if-env-changed=QT_INSTALLATION");
+    if let Ok(installation) = std::env::var("QT_INSTALLATION") {
+        return match installations
+            .iter()
+            .find(|(name, _)| *name == installation.trim())
+        {
+            Some((_, find)) => find(),
+            None => Err(crate::QtBuildError::QtInstallationUnsupported { installation }.into()),
+        };
+    }
+
+    // Use the first installation that is found, otherwise report the error of the first one
+    let mut first_error = None;
+    for (_, find) in installations {
+        match find() {
+            Ok(installation) => return Ok(installation),
+            Err(err) => {
+                first_error.get_or_insert(err);
+            }
+        }
+    }
+    Err(first_error.unwrap_or_else(|| crate::QtBuildError::QtMissing.into()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn link_args_merge() {
+        let mut link_args = LinkArgs {
+            args: vec![
+                LinkArg::RustcLinkSearch("/qt/lib".to_owned()),
+                LinkArg::RustcLinkLib("Qt6Core".to_owned()),
+                LinkArg::RustcLinkArg("-Wl,-rpath,/qt/lib".to_owned()),
+            ],
+        };
+        link_args.merge(LinkArgs {
+            args: vec![
+                LinkArg::RustcLinkSearch("/qt/lib".to_owned()),
+                LinkArg::RustcLinkLib("Qt6Core".to_owned()),
+                LinkArg::RustcLinkLib("Qt6Quick".to_owned()),
+                LinkArg::BuilderFlagIfSupported("-F/qt/lib".to_owned()),
+            ],
+        });
+        assert_eq!(
+            link_args.args,
+            vec![
+                LinkArg::RustcLinkSearch("/qt/lib".to_owned()),
+                LinkArg::RustcLinkLib("Qt6Core".to_owned()),
+                LinkArg::RustcLinkArg("-Wl,-rpath,/qt/lib".to_owned()),
+                LinkArg::RustcLinkLib("Qt6Quick".to_owned()),
+                LinkArg::BuilderFlagIfSupported("-F/qt/lib".to_owned()),
+            ]
+        );
+        assert_eq!(
+            link_args.builder_flags().collect::<Vec<_>>(),
+            vec!["-F/qt/lib"]
+        );
+    }
+}
@@ -0,0 +1,163 @@
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Joshua Booth <joshua.n.booth@gmail.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use semver::Version;
+use std::{
+    cell::RefCell, collections::HashMap, env, ffi::OsString, path::PathBuf, process::Command,
+};
+
+use crate::{parse_cflags, LinkArgs, QtBuildError, QtInstallation, QtTool};
+
+/// A implementation of [QtInstallation] using the [pkg-config](https://www.freedesktop.org/wiki/Software/pkg-config/)
+/// `.pc` files of Qt, such as `Qt6Core.pc`, so this does not require qmake.
+pub struct QtInstallationPkgConfig {
+    pkg_config_path: PathBuf,
+    version: Version,
+    // Internal cache of paths for tools, see QtInstallationQMake
+    tool_cache: RefCell<HashMap<QtTool, PathBuf>>,
+}
+
+impl QtInstallationPkgConfig {
+    /// The `pkg-config` executable is searched for in the directories specified by the `PATH`
+    /// environment variable. Alternatively, the `PKG_CONFIG` environment variable may be set
+    /// to specify an explicit path to pkg-config. The `.pc` files are found by pkg-config itself,
+    /// so for example `PKG_CONFIG_PATH` can be used to add the `lib/pkgconfig` directory of Qt.
+    ///
+    /// If the `.pc` files of multiple major versions of Qt are available, Qt 6 is preferred,
+    /// set the `QT_VERSION_MAJOR` environment variable to force which one to use.
+    pub fn new() -> anyhow::Result<Self> {
+        println!("cargo::rerun-if-env-changed=PKG_CONFIG");
+        println!("cargo::rerun-if-env-changed=PKG_CONFIG_PATH");
+        let pkg_config_path = env::var("PKG_CONFIG")
+            .map(PathBuf::from)
+            .unwrap_or_else(|_| PathBuf::from("pkg-config"));
+
+        println!("cargo::rerun-if-env-changed=QT_VERSION_MAJOR");
+        let version_majors = match env::var("QT_VERSION_MAJOR") {
+            Ok(qt_version_major) => vec![qt_version_major.trim().parse::<u64>().map_err(|e| {
+                QtBuildError::QtVersionMajorInvalid {
+                    qt_version_major_env_var: qt_version_major,
+                    source: e,
+                }
+            })?],
+            Err(_) => vec![6, 5],
+        };
+
+        version_majors
+            .into_iter()
+            .find_map(|version_major| {
+                let output = Command::new(&pkg_config_path)
+                    .args(["--modversion", &format!("Qt{version_major}Core")])
+                    .output()
+                    .ok()
+                    .filter(|output| output.status.success())?;
+                let version =
+                    Version::parse(String::from_utf8_lossy(&output.stdout).trim()).ok()?;
+                Some(Self {
+                    pkg_config_path: pkg_config_path.clone(),
+                    version,
+                    tool_cache: HashMap::default().into(),
+                })
+            })
+            .ok_or_else(|| QtBuildError::QtMissing.into())
+    }
+
+    fn package_names(&self, qt_modules: &[String]) -> Vec<String> {
+        qt_modules
+            .iter()
+            .map(|qt_module| format!("Qt{}{qt_module}", self.version.major))
+            .collect()
+    }
+
+    fn pkg_config(&self, args: impl IntoIterator<Item = impl Into<OsString>>) -> Vec<u8> {
+        let args = args.into_iter().map(Into::into).collect::<Vec<_>>();
+        let output = Command::new(&self.pkg_config_path)
+            .args(&args)
+            .output()
+            .unwrap_or_else(|_| panic!("pkg-config failed for {args:?}"));
+
+        if !output.status.success() {
+            panic!(
+                "pkg-config failed for {args:?}:\n{}",
+                String::from_utf8_lossy(&output.stderr)
+            );
+        }
+
+        output.stdout
+    }
+
+    fn pkg_config_variable(&self, variable: &str) -> String {
+        let package = format!("Qt{}Core", self.version.major);
+        String::from_utf8_lossy(&self.pkg_config([&format!("--variable={variable}"), &package]))
+            .trim()
+            .to_owned()
+    }
+
+    fn try_pkg_config_find_tool(&self, tool_name: &str) -> anyhow::Result<PathBuf> {
+        // Qt 6 has libexecdir for the Qt-internal tools and bindir for the developer-facing
+        // tools, whereas Qt 5 has host_bins for all of them
+        let mut failed_paths = vec![];
+        ["libexecdir", "host_bins", "bindir"]
+            .iter()
+            .map(|variable| self.pkg_config_variable(variable))
+            .filter(|dir| !dir.is_empty())
+            .find_map(|dir| {
+                let executable_path = PathBuf::from(dir).join(tool_name);
+                match Command::new(&executable_path).args(["-help"]).output() {
+                    Err(_err) => {
+                        failed_paths.push(executable_path);
+                        None
+                    }
+                    Ok(_) => Some(executable_path),
+                }
+            })
+            .ok_or_else(|| anyhow::anyhow!("Failed to find {tool_name}, tried: {failed_paths:?}"))
+    }
+}
+
+impl QtInstallation for QtInstallationPkgConfig {
+    fn include_paths(&self, qt_modules: &[String]) -> Vec<PathBuf> {
+        let cflags = self.pkg_config(
+            ["--cflags-only-I".to_owned()]
+                .into_iter()
+                .chain(self.package_names(qt_modules)),
+        );
+        String::from_utf8_lossy(&cflags)
+            .split_whitespace()
+            .filter_map(|flag| flag.strip_prefix("-I"))
+            .map(PathBuf::from)
+            // Only add paths if they exist
+            .filter(|path| path.exists())
+            .collect()
+    }
+
+    fn link_modules(&self, qt_modules: &[String]) -> LinkArgs {
+        let mut link_args = LinkArgs::default();
+        for package in self.package_names(qt_modules) {
+            let libs = self.pkg_config(["--libs", &package]);
+            parse_cflags::parse_libs_cflags(&package, &libs, &mut link_args);
+        }
+        link_args
+    }
+
+    fn try_find_tool(&self, tool: QtTool) -> anyhow::Result<PathBuf> {
+        let find_tool = || self.try_pkg_config_find_tool(tool.binary_name());
+        // Attempt to use the cache
+        let Ok(mut tool_cache) = self.tool_cache.try_borrow_mut() else {
+            return find_tool();
+        };
+        // Read the tool from the cache or insert
+        if let Some(path) = tool_cache.get(&tool) {
+            return Ok(path.clone());
+        }
+        let path = find_tool()?;
+        tool_cache.insert(tool, path.clone());
+        Ok(path)
+    }
+
+    fn version(&self) -> Version {
+        self.version.clone()
+    }
+}
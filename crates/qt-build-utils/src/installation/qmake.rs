@@ -13,11 +13,13 @@ use std::{
     process::Command,
 };
 
-use crate::{parse_cflags, utils, QtBuildError, QtInstallation, QtTool};
+use crate::{parse_cflags, utils, LinkArg, LinkArgs, QtBuildError, QtInstallation, QtTool};
 
 /// A implementation of [QtInstallation] using qmake
 pub struct QtInstallationQMake {
     qmake_path: PathBuf,
+    // The argument used to query qmake, qtpaths uses the same queries with --query
+    query_arg: &'static str,
     qmake_version: Version,
     // Internal cache of paths for tools
     //
@@ -63,6 +65,7 @@ impl QtInstallationQMake {
     ///     WORKING_DIRECTORY ${CMAKE_CURRENT_SOURCE_DIR}
     /// )
     /// ```
+    #[cfg(feature = "qmake")]
     pub fn new() -> anyhow::Result<Self> {
         // Try the QMAKE variable first
         println!("cargo::rerun-if-env-changed=QMAKE");
@@ -101,9 +104,20 @@ impl TryFrom<PathBuf> for QtInstallationQMake {
     type Error = anyhow::Error;
 
     fn try_from(qmake_path: PathBuf) -> anyhow::Result<Self> {
+        Self::try_from_query_tool(qmake_path, "-query")
+    }
+}
+
+impl QtInstallationQMake {
+    /// Create an installation from qmake, or from another tool which supports the same queries
+    /// such as qtpaths with `--query`
+    pub(crate) fn try_from_query_tool(
+        qmake_path: PathBuf,
+        query_arg: &'static str,
+    ) -> anyhow::Result<Self> {
         // Attempt to read the QT_VERSION from qmake
         let qmake_version = match Command::new(&qmake_path)
-            .args(["-query", "QT_VERSION"])
+            .args([query_arg, "QT_VERSION"])
             .output()
         {
             Err(e) if e.kind() == ErrorKind::NotFound => Err(QtBuildError::QtMissing),
@@ -137,6 +151,7 @@ impl TryFrom<PathBuf> for QtInstallationQMake {
 
         Ok(Self {
             qmake_path,
+            query_arg,
             qmake_version,
             tool_cache: HashMap::default().into(),
         })
@@ -170,10 +185,11 @@ impl QtInstallation for QtInstallationQMake {
             .collect()
     }
 
-    fn link_modules(&self, builder: &mut cc::Build, qt_modules: &[String]) {
+    fn link_modules(&self, qt_modules: &[String]) -> LinkArgs {
+        let mut link_args = LinkArgs::default();
         let prefix_path = self.qmake_query("QT_INSTALL_PREFIX");
         let lib_path = self.qmake_query("QT_INSTALL_LIBS");
-        println!("cargo::rustc-link-search={lib_path}");
+        link_args.push(LinkArg::RustcLinkSearch(lib_path.clone()));
 
         let target = env::var("TARGET");
 
@@ -189,16 +205,19 @@ impl QtInstallation for QtInstallationQMake {
         // Note that this adds the framework path which allows for
         // includes such as <QtCore/QObject> to be resolved correctly
         if utils::is_apple_target() {
-            println!("cargo::rustc-link-search=framework={lib_path}");
+            link_args.push(LinkArg::RustcLinkSearch(format!("framework={lib_path}")));
 
             // Ensure that any framework paths are set to -F
             for framework_path in self.qmake_framework_paths() {
-                builder.flag_if_supported(format!("-F{}", framework_path.display()));
+                link_args.push(LinkArg::BuilderFlagIfSupported(format!(
+                    "-F{}",
+                    framework_path.display()
+                )));
                 // Also set the -rpath otherwise frameworks can not be found at runtime
-                println!(
-                    "cargo::rustc-link-arg=-Wl,-rpath,{}",
+                link_args.push(LinkArg::RustcLinkArg(format!(
+                    "-Wl,-rpath,{}",
                     framework_path.display()
-                );
+                )));
             }
         }
 
@@ -238,22 +257,24 @@ impl QtInstallation for QtInstallationQMake {
                 &lib_path,
                 &link_lib,
                 &prl_path,
-                builder,
+                &mut link_args,
             );
         }
 
         if utils::is_emscripten_target() {
             let platforms_path = format!("{}/platforms", self.qmake_query("QT_INSTALL_PLUGINS"));
-            println!("cargo::rustc-link-search={platforms_path}");
+            link_args.push(LinkArg::RustcLinkSearch(platforms_path.clone()));
             self.link_qt_library(
                 "qwasm",
                 &prefix_path,
                 &lib_path,
                 "qwasm",
                 &format!("{platforms_path}/libqwasm.prl"),
-                builder,
+                &mut link_args,
             );
         }
+
+        link_args
     }
 
     fn try_find_tool(&self, tool: QtTool) -> anyhow::Result<PathBuf> {
@@ -310,9 +331,9 @@ impl QtInstallationQMake {
         lib_path: &str,
         link_lib: &str,
         prl_path: &str,
-        builder: &mut cc::Build,
+        link_args: &mut LinkArgs,
     ) {
-        println!("cargo::rustc-link-lib={link_lib}");
+        link_args.push(LinkArg::RustcLinkLib(link_lib.to_owned()));
 
         match std::fs::read_to_string(prl_path) {
             Ok(prl) => {
@@ -323,7 +344,7 @@ impl QtInstallationQMake {
                             line.replace(r"$$[QT_INSTALL_LIBS]", lib_path)
                                 .replace(r"$$[QT_INSTALL_PREFIX]", prefix_path)
                                 .as_bytes(),
-                            builder,
+                            link_args,
                         );
                     }
                 }
@@ -360,7 +381,7 @@ impl QtInstallationQMake {
     fn qmake_query(&self, var_name: &str) -> String {
         String::from_utf8_lossy(
             &Command::new(&self.qmake_path)
-                .args(["-query", var_name])
+                .args([self.query_arg, var_name])
                 .output()
                 .unwrap()
                 .stdout,
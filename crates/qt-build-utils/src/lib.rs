@@ -22,23 +22,34 @@ mod initializer;
 pub use initializer::Initializer;
 
 mod installation;
-pub use installation::QtInstallation;
+pub use installation::{LinkArg, LinkArgs, QtInstallation};
+
+#[cfg(feature = "cmake")]
+pub use installation::cmake::QtInstallationCMake;
+
+#[cfg(feature = "pkg_config")]
+pub use installation::pkg_config::QtInstallationPkgConfig;
 
 #[cfg(feature = "qmake")]
 pub use installation::qmake::QtInstallationQMake;
 
-#[cfg(feature = "qmake")]
+#[cfg(any(feature = "qmake", feature = "cmake", feature = "pkg_config"))]
 mod parse_cflags;
 
 mod platform;
 pub use platform::QtPlatformLinker;
 
+mod qml;
+pub use qml::{QmlDirEntries, QmlFile, QmlPluginType};
+
 mod tool;
 pub use tool::{
-    MocArguments, MocProducts, QmlCacheArguments, QmlCacheProducts, QtTool, QtToolMoc,
-    QtToolQmlCacheGen, QtToolQmlTypeRegistrar, QtToolRcc,
+    MocArguments, MocProducts, QmlCacheArguments, QmlCacheProducts, QmlLintOutput, QtTool,
+    QtToolLrelease, QtToolLupdate, QtToolMoc, QtToolQmlCacheGen, QtToolQmlFormat, QtToolQmlLint,
+    QtToolQmlTypeRegistrar, QtToolRcc, QtToolUic,
 };
 
+#[cfg(any(feature = "qmake", feature = "cmake"))]
 mod utils;
 
 use std::{
@@ -66,6 +77,10 @@ pub struct QmlModuleRegistrationFiles {
     pub plugin_init: Initializer,
     /// An optional include path that should be included
     pub include_path: Option<PathBuf>,
+    /// The generated qmldir file of the QML module
+    pub qmldir: PathBuf,
+    /// The qmltypes file of the QML module generated by qmltyperegistrar
+    pub qmltypes: PathBuf,
 }
 
 /// Helper for build.rs scripts using Qt
@@ -82,15 +97,20 @@ pub struct QtBuild {
 }
 
 impl QtBuild {
-    /// Create a [QtBuild] using the default [QtInstallation] (currently uses [QtInstallationQMake])
-    /// and specify which Qt modules you are linking, ommitting the `Qt` prefix (`"Core"`
-    /// rather than `"QtCore"`).
+    /// Create a [QtBuild] using the default [QtInstallation] and specify which Qt modules you
+    /// are linking, ommitting the `Qt` prefix (`"Core"` rather than `"QtCore"`).
     ///
-    /// Currently this function is only available when the `qmake` feature is enabled.
+    /// The `QT_INSTALLATION` environment variable selects the [QtInstallation] to use, which can
+    /// be `qmake` ([QtInstallationQMake]), `cmake` (`QtInstallationCMake`) or `pkg-config`
+    /// (`QtInstallationPkgConfig`). If it is not set, each installation with its feature
+    /// enabled is tried in that order.
+    ///
+    /// Currently this function is only available when the `qmake`, `cmake` or `pkg_config`
+    /// feature is enabled.
     /// Use [Self::with_installation] to create a [QtBuild] with a custom [QtInstallation].
-    #[cfg(feature = "qmake")]
+    #[cfg(any(feature = "qmake", feature = "cmake", feature = "pkg_config"))]
     pub fn new(qt_modules: Vec<String>) -> anyhow::Result<Self> {
-        let qt_installation = Box::new(QtInstallationQMake::new()?);
+        let qt_installation = installation::find_installation()?;
         Ok(Self::with_installation(qt_installation, qt_modules))
     }
 
@@ -112,7 +132,12 @@ impl QtBuild {
 
     /// Tell Cargo to link each Qt module.
     pub fn cargo_link_libraries(&self, builder: &mut cc::Build) {
-        self.qt_installation.link_modules(builder, &self.qt_modules);
+        self.link_args().apply(builder);
+    }
+
+    /// Get the arguments needed to link each Qt module, without applying them.
+    pub fn link_args(&self) -> LinkArgs {
+        self.qt_installation.link_modules(&self.qt_modules)
     }
 
     /// Get the include paths for Qt, including Qt module subdirectories. This is intended
@@ -136,10 +161,18 @@ impl QtBuild {
     /// Generate C++ files to automatically register a QML module at build time using the JSON output from [moc](Self::moc).
     ///
     /// This generates a [qmldir file](https://doc.qt.io/qt-6/qtqml-modules-qmldir.html) for the QML module.
+    /// The `qmldir_entries` are added to the qmldir, along with type entries for any `qml_files` which
+    /// are singletons, internal or have an alias.
     /// The `qml_files` and `qrc_files` are registered with the [Qt Resource System](https://doc.qt.io/qt-6/resources.html) in
     /// the [default QML import path](https://doc.qt.io/qt-6/qtqml-syntax-imports.html#qml-import-path) `qrc:/qt/qml/uri/of/module/`.
     ///
     /// When using Qt 6, this will [run qmlcachegen](https://doc.qt.io/qt-6/qtqml-qtquick-compiler-tech.html) to compile the specified .qml files ahead-of-time.
+    ///
+    /// The `plugin_type` determines whether the plugin is imported statically by the returned
+    /// [QmlModuleRegistrationFiles::plugin_init] or loaded by the QML engine from a shared library.
+    /// A static plugin needs to be compiled with `QT_STATICPLUGIN` defined, while a dynamic plugin
+    /// must be compiled without it, so that moc generates the plugin metadata which the QML engine
+    /// reads from the `.qtmetadata` section of the shared library.
     pub fn register_qml_module(
         &mut self,
         metatypes_json: &[impl AsRef<Path>],
@@ -147,9 +180,41 @@ impl QtBuild {
         version_major: usize,
         version_minor: usize,
         plugin_name: &str,
-        qml_files: &[impl AsRef<Path>],
+        qml_files: &[QmlFile],
         qrc_files: &[impl AsRef<Path>],
+        qmldir_entries: &QmlDirEntries,
+        plugin_type: &QmlPluginType,
     ) -> QmlModuleRegistrationFiles {
+        self.try_register_qml_module(
+            metatypes_json,
+            uri,
+            version_major,
+            version_minor,
+            plugin_name,
+            qml_files,
+            qrc_files,
+            qmldir_entries,
+            plugin_type,
+        )
+        .unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Register a QML module like [QtBuild::register_qml_module], returning a
+    /// [QtBuildError::ToolFailed] if moc, rcc, qmlcachegen or qmltyperegistrar fails or a
+    /// [QtBuildError::QmlFileInvalid] if a QML file cannot be declared in the qmldir instead of
+    /// panicking.
+    pub fn try_register_qml_module(
+        &mut self,
+        metatypes_json: &[impl AsRef<Path>],
+        uri: &str,
+        version_major: usize,
+        version_minor: usize,
+        plugin_name: &str,
+        qml_files: &[QmlFile],
+        qrc_files: &[impl AsRef<Path>],
+        qmldir_entries: &QmlDirEntries,
+        plugin_type: &QmlPluginType,
+    ) -> Result<QmlModuleRegistrationFiles, QtBuildError> {
         let qml_uri_dirs = uri.replace('.', "/");
 
         let out_dir = env::var("OUT_DIR").unwrap();
@@ -164,30 +229,45 @@ impl QtBuild {
         let plugin_class_name = format!("{qml_uri_underscores}_plugin");
 
         // Generate qmldir file
+        //
+        // A static plugin is optional, as it is already linked into the application
+        let plugin_line = match plugin_type {
+            QmlPluginType::Static => format!("optional plugin {plugin_name}"),
+            QmlPluginType::Dynamic { path: None } => format!("plugin {plugin_name}"),
+            QmlPluginType::Dynamic { path: Some(path) } => format!("plugin {plugin_name} {path}"),
+        };
         let qmldir_file_path = qml_module_dir.join("qmldir");
         {
             let mut qmldir = File::create(&qmldir_file_path).expect("Could not create qmldir file");
             write!(
                 qmldir,
                 "module {uri}
-optional plugin {plugin_name}
+{plugin_line}
 classname {plugin_class_name}
 typeinfo plugin.qmltypes
 prefer :/qt/qml/{qml_uri_dirs}/
 "
             )
             .expect("Could not write qmldir file");
+
+            let mut entries = qmldir_entries.lines();
+            for file in qml_files {
+                entries.extend(file.qmldir_entry(version_major, version_minor)?);
+            }
+            for entry in entries {
+                writeln!(qmldir, "{entry}").expect("Could not write qmldir file");
+            }
         }
 
         // Generate .qrc file and run rcc on it
         let qrc_path =
             qml_module_dir.join(format!("qml_module_resources_{qml_uri_underscores}.qrc"));
         {
-            fn qrc_file_line(file_path: &impl AsRef<Path>) -> String {
+            fn qrc_file_line(file_path: &impl AsRef<Path>, alias: &str) -> String {
                 let path_display = file_path.as_ref().display();
                 format!(
                     "    <file alias=\"{}\">{}</file>\n",
-                    path_display,
+                    alias,
                     std::fs::canonicalize(file_path)
                         .unwrap_or_else(|_| panic!("Could not canonicalize path {path_display}"))
                         .display()
@@ -195,11 +275,12 @@ prefer :/qt/qml/{qml_uri_dirs}/
             }
 
             let mut qml_files_qrc = String::new();
-            for file_path in qml_files {
-                qml_files_qrc.push_str(&qrc_file_line(file_path));
+            for file in qml_files {
+                qml_files_qrc.push_str(&qrc_file_line(&file.path(), &file.resource_path()));
             }
             for file_path in qrc_files {
-                qml_files_qrc.push_str(&qrc_file_line(file_path));
+                let alias = file_path.as_ref().display().to_string();
+                qml_files_qrc.push_str(&qrc_file_line(file_path, &alias));
             }
 
             let mut qrc = File::create(&qrc_path).expect("Could not create qrc file");
@@ -229,13 +310,17 @@ prefer :/qt/qml/{qml_uri_dirs}/
         if self.qt_installation.version().major >= 6 {
             let qml_cache_args = QmlCacheArguments {
                 uri: uri.to_owned(),
-                qmldir_path: qmldir_file_path,
+                qmldir_path: qmldir_file_path.clone(),
                 qmldir_qrc_path: qrc_path.clone(),
             };
             let mut qml_resource_paths = Vec::new();
             for file in qml_files {
                 let result = QtToolQmlCacheGen::new(self.qt_installation.as_ref())
-                    .compile(qml_cache_args.clone(), file);
+                    .try_compile_aliased(
+                        qml_cache_args.clone(),
+                        file.path(),
+                        &file.resource_path(),
+                    )?;
                 qmlcachegen_file_paths.push(result.qml_cache_path);
                 qml_resource_paths.push(result.qml_resource_path);
             }
@@ -244,7 +329,7 @@ prefer :/qt/qml/{qml_uri_dirs}/
             if !qml_files.is_empty() {
                 qmlcachegen_file_paths.push(
                     QtToolQmlCacheGen::new(self.qt_installation.as_ref())
-                        .compile_loader(qml_cache_args.clone(), &qml_resource_paths),
+                        .try_compile_loader(qml_cache_args.clone(), &qml_resource_paths)?,
                 );
             }
         }
@@ -253,12 +338,12 @@ prefer :/qt/qml/{qml_uri_dirs}/
         std::fs::create_dir_all(&qml_plugin_dir).expect("Could not create qml_plugin dir");
 
         // Run qmltyperegistrar over the meta types
-        let qmltyperegistrar_path = self.qmltyperegistrar().compile(
+        let qmltyperegistrar_path = self.qmltyperegistrar().try_compile(
             metatypes_json,
-            qmltypes_path,
+            qmltypes_path.clone(),
             uri,
             Version::new(version_major as u64, version_minor as u64, 0),
-        );
+        )?;
 
         // Generate QQmlEngineExtensionPlugin
         let qml_plugin_cpp_path = qml_plugin_dir.join(format!("{plugin_class_name}.cpp"));
@@ -288,6 +373,19 @@ prefer :/qt/qml/{qml_uri_dirs}/
             let declarations = declarations.join("\n");
             let usages = usages.join("\n");
 
+            // Without QT_STATICPLUGIN, moc exports the instance and metadata of the plugin from
+            // the plugin file. The shared library built by Cargo only exports the functions of
+            // cxx_qt::export_qml_plugin!, so these are renamed for it to forward to them.
+            let plugin_renames = match plugin_type {
+                QmlPluginType::Static => String::new(),
+                QmlPluginType::Dynamic { .. } => format!(
+                    r#"#define qt_plugin_instance cxx_qt_plugin_instance_{qml_uri_underscores}
+#define qt_plugin_query_metadata cxx_qt_plugin_query_metadata_{qml_uri_underscores}
+#define qt_plugin_query_metadata_v2 cxx_qt_plugin_query_metadata_v2_{qml_uri_underscores}
+"#
+                ),
+            };
+
             std::fs::write(
                 &qml_plugin_cpp_path,
                 format!(
@@ -311,33 +409,42 @@ public:
 }};
 
 // The moc-generated cpp file doesn't compile on its own; it needs to be #included here.
-#include "moc_{plugin_class_name}.cpp.cpp"
+{plugin_renames}#include "moc_{plugin_class_name}.cpp.cpp"
 "#,
                 ),
             )
             .expect("Failed to write plugin definition");
 
-            let moc_product = self.moc().compile(
+            let moc_product = self.moc().try_compile(
                 &qml_plugin_cpp_path,
                 MocArguments::default().uri(uri.to_owned()),
-            );
+            )?;
             // Pass the include directory of the moc file to the caller
             include_path = moc_product.cpp.parent().map(Path::to_path_buf);
 
             // Generate Initializer for static QQmlExtensionPlugin
-            let plugin_init = Initializer {
-                file: None,
-                init_call: None,
-                init_declaration: Some(format!(
-                    r#"
+            //
+            // A shared library plugin is loaded by the QML engine instead
+            let plugin_init = match plugin_type {
+                QmlPluginType::Static => Initializer {
+                    file: None,
+                    init_call: None,
+                    init_declaration: Some(format!(
+                        r#"
 #include <QtPlugin>
 Q_IMPORT_PLUGIN({plugin_class_name});
 "#
-                )),
+                    )),
+                },
+                QmlPluginType::Dynamic { .. } => Initializer {
+                    file: None,
+                    init_call: None,
+                    init_declaration: None,
+                },
             };
 
-            let rcc = self.rcc().compile(&qrc_path);
-            QmlModuleRegistrationFiles {
+            let rcc = self.rcc().try_compile(&qrc_path)?;
+            Ok(QmlModuleRegistrationFiles {
                 // The rcc file is automatically initialized when importing the plugin.
                 // so we don't need to treat it like an initializer here.
                 rcc: rcc.file.unwrap(),
@@ -346,7 +453,9 @@ Q_IMPORT_PLUGIN({plugin_class_name});
                 plugin: qml_plugin_cpp_path,
                 plugin_init,
                 include_path,
-            }
+                qmldir: qmldir_file_path,
+                qmltypes: qmltypes_path,
+            })
         }
     }
 
@@ -357,8 +466,56 @@ Q_IMPORT_PLUGIN({plugin_class_name});
         QtToolRcc::new(self.qt_installation.as_ref())
     }
 
+    /// Create a [QtToolUic] for this [QtBuild]
+    ///
+    /// This allows for using [uic](https://doc.qt.io/qt-6/uic.html)
+    pub fn uic(&self) -> QtToolUic {
+        QtToolUic::new(self.qt_installation.as_ref())
+    }
+
     /// Create a [QtToolQmlTypeRegistrar] for this [QtBuild]
     pub fn qmltyperegistrar(&self) -> QtToolQmlTypeRegistrar {
         QtToolQmlTypeRegistrar::new(self.qt_installation.as_ref())
     }
+
+    /// Create a [QtToolQmlLint] for this [QtBuild]
+    ///
+    /// This allows for using [qmllint](https://doc.qt.io/qt-6/qtqml-tooling-qmllint.html)
+    pub fn qmllint(&self) -> QtToolQmlLint {
+        QtToolQmlLint::new(self.qt_installation.as_ref())
+    }
+
+    /// Create a [QtToolQmlLint] like [QtBuild::qmllint], returning a [QtBuildError::ToolMissing]
+    /// if qmllint is not part of the Qt installation instead of panicking.
+    pub fn try_qmllint(&self) -> Result<QtToolQmlLint, QtBuildError> {
+        QtToolQmlLint::try_new(self.qt_installation.as_ref())
+    }
+
+    /// Create a [QtToolQmlFormat] for this [QtBuild]
+    ///
+    /// This allows for using [qmlformat](https://doc.qt.io/qt-6/qtqml-tooling-qmlformat.html)
+    pub fn qmlformat(&self) -> QtToolQmlFormat {
+        QtToolQmlFormat::new(self.qt_installation.as_ref())
+    }
+
+    /// Create a [QtToolQmlFormat] like [QtBuild::qmlformat], returning a
+    /// [QtBuildError::ToolMissing] if qmlformat is not part of the Qt installation instead of
+    /// panicking.
+    pub fn try_qmlformat(&self) -> Result<QtToolQmlFormat, QtBuildError> {
+        QtToolQmlFormat::try_new(self.qt_installation.as_ref())
+    }
+
+    /// Create a [QtToolLupdate] for this [QtBuild]
+    ///
+    /// This allows for using [lupdate](https://doc.qt.io/qt-6/linguist-lupdate.html)
+    pub fn lupdate(&self) -> QtToolLupdate {
+        QtToolLupdate::new(self.qt_installation.as_ref())
+    }
+
+    /// Create a [QtToolLrelease] for this [QtBuild]
+    ///
+    /// This allows for using [lrelease](https://doc.qt.io/qt-6/linguist-lrelease.html)
+    pub fn lrelease(&self) -> QtToolLrelease {
+        QtToolLrelease::new(self.qt_installation.as_ref())
+    }
 }
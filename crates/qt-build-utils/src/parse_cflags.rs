@@ -7,18 +7,11 @@
 //! This module contains code from the [pkg-config crate](https://github.com/rust-lang/pkg-config-rs).
 //! It has been decoupled from the pkg-config crate because qt-build-utils reads Qt's .prl files instead, which
 //! does not require a pkg-config executable to be available.
+//! The same parsing is used for the output of the pkg-config executable when the `pkg_config` feature is enabled.
 
-use std::env;
-
-#[cfg(feature = "link_qt_object_files")]
-use std::{
-    collections::HashSet,
-    path::PathBuf,
-    sync::{OnceLock, RwLock},
-};
+use crate::{LinkArg, LinkArgs};
 
-#[cfg(feature = "link_qt_object_files")]
-static LINKED_OBJECT_FILES: OnceLock<RwLock<HashSet<PathBuf>>> = OnceLock::new();
+use std::env;
 
 /// Extract the &str to pass to cargo::rustc-link-lib from a filename (just the file name, not including directories)
 /// using target-specific logic.
@@ -124,7 +117,7 @@ fn split_flags(link_args: &[u8]) -> Vec<String> {
     words
 }
 
-pub(crate) fn parse_libs_cflags(name: &str, link_args: &[u8], _builder: &mut cc::Build) {
+pub(crate) fn parse_libs_cflags(name: &str, link_args: &[u8], output: &mut LinkArgs) {
     let mut is_msvc = false;
     let target = env::var("TARGET");
     if let Ok(target) = &target {
@@ -143,10 +136,10 @@ pub(crate) fn parse_libs_cflags(name: &str, link_args: &[u8], _builder: &mut cc:
     for (flag, val) in parts {
         match flag {
             "-L" => {
-                println!("cargo::rustc-link-search=native={val}");
+                output.push(LinkArg::RustcLinkSearch(format!("native={val}")));
             }
             "-F" => {
-                println!("cargo::rustc-link-search=framework={val}");
+                output.push(LinkArg::RustcLinkSearch(format!("framework={val}")));
             }
             "-I" => (),
             "-l" => {
@@ -155,7 +148,7 @@ pub(crate) fn parse_libs_cflags(name: &str, link_args: &[u8], _builder: &mut cc:
                     continue;
                 }
 
-                println!("cargo::rustc-link-lib={val}");
+                output.push(LinkArg::RustcLinkLib(val.to_owned()));
             }
             "-D" => (),
             _ => {}
@@ -174,7 +167,7 @@ pub(crate) fn parse_libs_cflags(name: &str, link_args: &[u8], _builder: &mut cc:
         match part {
             "-framework" => {
                 if let Some(lib) = iter.next() {
-                    println!("cargo::rustc-link-lib=framework={lib}");
+                    output.push(LinkArg::RustcLinkLib(format!("framework={lib}")));
                 }
             }
             "-isystem" | "-iquote" | "-idirafter" => {}
@@ -187,38 +180,20 @@ pub(crate) fn parse_libs_cflags(name: &str, link_args: &[u8], _builder: &mut cc:
                         (path.parent(), path.file_name(), &target)
                     {
                         if is_object_file(path) {
+                            // Object files are linked by LinkArgs::apply, which skips object files
+                            // that are already linked, as many of Qt's .prl files repeat listing
+                            // .o files that other .prl files also list.
                             #[cfg(feature = "link_qt_object_files")]
-                            {
-                                // Linking will fail with duplicate symbol errors if the same .o file is linked twice.
-                                // Many of Qt's .prl files repeat listing .o files that other .prl files also list.
-                                let already_linked_object_files =
-                                    LINKED_OBJECT_FILES.get_or_init(|| RwLock::new(HashSet::new()));
-                                if !already_linked_object_files
-                                    .read()
-                                    .expect("Lock poisoned")
-                                    .contains(path)
-                                {
-                                    // Cargo doesn't have a means to directly specify an object to link,
-                                    // so use the cc crate to specify it instead.
-                                    // TODO: pass file path directly when link-arg library type is stabilized
-                                    // https://github.com/rust-lang/rust/issues/99427#issuecomment-1562092085
-                                    // TODO: remove builder argument when it's not used anymore to link object files.
-                                    // also remove the dependency on cc when this is done
-                                    _builder.object(path);
-                                    already_linked_object_files
-                                        .write()
-                                        .expect("Lock poisoned!")
-                                        .insert(path.to_path_buf());
-                                }
-                            }
+                            output.push(LinkArg::BuilderObject(path.to_path_buf()));
                         } else {
                             match extract_lib_from_filename(target, &file_name.to_string_lossy()) {
                                 Some(lib_basename) => {
-                                    println!("cargo::rustc-link-search={}", dir.display());
-                                    println!("cargo::rustc-link-lib={lib_basename}");
+                                    output
+                                        .push(LinkArg::RustcLinkSearch(dir.display().to_string()));
+                                    output.push(LinkArg::RustcLinkLib(lib_basename.to_owned()));
                                 }
                                 None => {
-                                    println!("cargo::warning=File path {} found in .prl file for {name}, but could not extract library base name to pass to linker command line", path.display());
+                                    println!("cargo::warning=File path {} found in link flags for {name}, but could not extract library base name to pass to linker command line", path.display());
                                 }
                             }
                         }
@@ -246,6 +221,9 @@ pub(crate) fn parse_libs_cflags(name: &str, link_args: &[u8], _builder: &mut cc:
             ld_option.push(subopt);
         }
 
-        println!("cargo::rustc-link-arg=-Wl,{}", ld_option.join(","));
+        output.push(LinkArg::RustcLinkArg(format!(
+            "-Wl,{}",
+            ld_option.join(",")
+        )));
     }
 }
@@ -0,0 +1,232 @@
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Joshua Booth <joshua.n.booth@gmail.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::QtBuildError;
+use std::path::{Path, PathBuf};
+
+/// A `.qml` (or `.js`) file of a QML module registered with [crate::QtBuild::register_qml_module]
+///
+/// QmlFile can be created using the `From<impl AsRef<Path>>` trait.
+/// ```
+/// # use qt_build_utils::QmlFile;
+/// QmlFile::from("qml/Theme.qml")
+///     .alias("Style/Theme.qml")
+///     .singleton(true);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct QmlFile {
+    path: PathBuf,
+    alias: Option<String>,
+    singleton: bool,
+    internal: bool,
+}
+
+impl<T> From<T> for QmlFile
+where
+    T: AsRef<Path>,
+{
+    fn from(path: T) -> Self {
+        Self {
+            path: path.as_ref().to_owned(),
+            alias: None,
+            singleton: false,
+            internal: false,
+        }
+    }
+}
+
+impl QmlFile {
+    /// The path of the file in the Qt resource system, relative to the directory of the QML module
+    ///
+    /// By default the path given to [QmlFile::from] is used.
+    pub fn alias(mut self, alias: impl Into<String>) -> Self {
+        self.alias = Some(alias.into());
+        self
+    }
+
+    /// Declare the type of this file as a singleton in the qmldir
+    ///
+    /// Note that the file itself also needs to contain `pragma Singleton`.
+    pub fn singleton(mut self, singleton: bool) -> Self {
+        self.singleton = singleton;
+        self
+    }
+
+    /// Declare the type of this file as internal in the qmldir, so that it can only be used
+    /// from within the QML module
+    pub fn internal(mut self, internal: bool) -> Self {
+        self.internal = internal;
+        self
+    }
+
+    /// The path of the file on disk
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// The path of the file in the Qt resource system, relative to the directory of the QML module
+    pub fn resource_path(&self) -> String {
+        match &self.alias {
+            Some(alias) => alias.clone(),
+            None => self.path.display().to_string(),
+        }
+    }
+
+    /// The qmldir entry declaring the type of this file, if any
+    ///
+    /// Entries are only written for files which are a singleton, internal or have an explicit
+    /// alias, other files are found by the QML engine through the module directory.
+    ///
+    /// Returns a [QtBuildError::QmlFileInvalid] if the file is a singleton or internal but its
+    /// name is not a QML type name starting with an uppercase letter.
+    pub(crate) fn qmldir_entry(
+        &self,
+        version_major: usize,
+        version_minor: usize,
+    ) -> Result<Option<String>, QtBuildError> {
+        if !self.singleton && !self.internal && self.alias.is_none() {
+            return Ok(None);
+        }
+
+        let resource_path = self.resource_path();
+        let type_name = Path::new(&resource_path)
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .filter(|type_name| type_name.starts_with(|c: char| c.is_ascii_uppercase()));
+        let Some(type_name) = type_name else {
+            // An alias of a file which is not a type, such as a JavaScript file, only moves it in
+            // the resource system
+            if !self.singleton && !self.internal {
+                return Ok(None);
+            }
+            return Err(QtBuildError::QmlFileInvalid {
+                file: resource_path,
+                reason: "singleton and internal types must have a file name starting with an uppercase letter".to_owned(),
+            });
+        };
+
+        let entry = if self.internal {
+            format!("internal {type_name} {resource_path}")
+        } else {
+            format!("{type_name} {version_major}.{version_minor} {resource_path}")
+        };
+        if self.singleton {
+            Ok(Some(format!("singleton {entry}")))
+        } else {
+            Ok(Some(entry))
+        }
+    }
+}
+
+/// Additional entries for the [qmldir](https://doc.qt.io/qt-6/qtqml-modules-qmldir.html) of a
+/// QML module registered with [crate::QtBuild::register_qml_module]
+///
+/// Module entries are written as given, so they can contain a version, for example `"QtQuick"`,
+/// `"QtQuick 2.15"` or `"QtQuick auto"`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct QmlDirEntries {
+    /// Modules which the QML engine needs to load before this module, written as `depends` entries
+    pub depends: Vec<String>,
+    /// Modules which are imported along with this module, written as `import` entries
+    pub imports: Vec<String>,
+    /// Modules which tooling may import along with this module, written as `optional import` entries
+    pub optional_imports: Vec<String>,
+    /// Whether the module is supported by Qt Design Studio, written as a `designersupported` entry
+    pub designer_supported: bool,
+}
+
+impl QmlDirEntries {
+    /// The qmldir lines for these entries
+    pub(crate) fn lines(&self) -> Vec<String> {
+        let depends = self
+            .depends
+            .iter()
+            .map(|module| format!("depends {module}"));
+        let imports = self.imports.iter().map(|module| format!("import {module}"));
+        let optional_imports = self
+            .optional_imports
+            .iter()
+            .map(|module| format!("optional import {module}"));
+        depends
+            .chain(imports)
+            .chain(optional_imports)
+            .chain(
+                self.designer_supported
+                    .then(|| "designersupported".to_owned()),
+            )
+            .collect()
+    }
+}
+
+/// How the plugin of a QML module registered with [crate::QtBuild::register_qml_module] is loaded
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum QmlPluginType {
+    /// The plugin is linked statically into the application and imported by the
+    /// [crate::QmlModuleRegistrationFiles::plugin_init] initializer
+    #[default]
+    Static,
+    /// The plugin is a shared library which the QML engine loads through the qmldir
+    ///
+    /// The plugin needs to be compiled without `QT_STATICPLUGIN`, so that moc generates the
+    /// plugin metadata. The shared library needs to export the `qt_plugin_instance` and
+    /// `qt_plugin_query_metadata` (or `qt_plugin_query_metadata_v2` with Qt 6.3 and later)
+    /// functions, which forward to the functions generated by moc which are renamed to
+    /// `cxx_qt_plugin_instance_<module>` and `cxx_qt_plugin_query_metadata_<module>` (or
+    /// `cxx_qt_plugin_query_metadata_v2_<module>`).
+    Dynamic {
+        /// The directory containing the shared library, relative to the directory of the qmldir.
+        /// If this is `None` the shared library needs to be next to the qmldir.
+        path: Option<String>,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn qmldir_entry() {
+        assert_eq!(
+            QmlFile::from("qml/main.qml").qmldir_entry(1, 0).unwrap(),
+            None
+        );
+        assert_eq!(
+            QmlFile::from("qml/style/Theme.qml")
+                .alias("Theme.qml")
+                .singleton(true)
+                .qmldir_entry(1, 2)
+                .unwrap(),
+            Some("singleton Theme 1.2 Theme.qml".to_owned())
+        );
+        assert_eq!(
+            QmlFile::from("qml/Helper.qml")
+                .internal(true)
+                .qmldir_entry(1, 0)
+                .unwrap(),
+            Some("internal Helper qml/Helper.qml".to_owned())
+        );
+        assert_eq!(
+            QmlFile::from("qml/utils.js")
+                .alias("utils.js")
+                .qmldir_entry(1, 0)
+                .unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn qmldir_entry_lowercase_singleton() {
+        assert!(matches!(
+            QmlFile::from("qml/theme.qml")
+                .singleton(true)
+                .qmldir_entry(1, 0),
+            Err(QtBuildError::QmlFileInvalid { file, .. }) if file == "qml/theme.qml"
+        ));
+        assert!(QmlFile::from("qml/helper.qml")
+            .internal(true)
+            .qmldir_entry(1, 0)
+            .is_err());
+    }
+}
@@ -0,0 +1,68 @@
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Joshua Booth <joshua.n.booth@gmail.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::{QtBuildError, QtInstallation, QtTool};
+
+use std::{
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+/// A wrapper around the [lrelease](https://doc.qt.io/qt-6/linguist-lrelease.html) tool
+pub struct QtToolLrelease {
+    executable: PathBuf,
+}
+
+impl QtToolLrelease {
+    /// Construct a [QtToolLrelease] from a given [QtInstallation]
+    pub fn new(qt_installation: &dyn QtInstallation) -> Self {
+        let executable = qt_installation
+            .try_find_tool(QtTool::Lrelease)
+            .expect("Could not find lrelease");
+
+        Self { executable }
+    }
+
+    /// Run [lrelease](https://doc.qt.io/qt-6/linguist-lrelease.html) on a .ts file and save the
+    /// compiled .qm file into [cargo's OUT_DIR](https://doc.rust-lang.org/cargo/reference/environment-variables.html).
+    /// The path to the .qm file is returned, which has the same file name as the .ts file.
+    pub fn compile(&self, input_file: impl AsRef<Path>) -> PathBuf {
+        self.try_compile(input_file)
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Run lrelease like [QtToolLrelease::compile], returning a [QtBuildError::ToolFailed] with the output
+    /// of lrelease if it fails instead of panicking.
+    pub fn try_compile(&self, input_file: impl AsRef<Path>) -> Result<PathBuf, QtBuildError> {
+        let input_path = input_file.as_ref();
+        let output_folder = QtTool::Lrelease.writable_path();
+        std::fs::create_dir_all(&output_folder).expect("Could not create lrelease dir");
+        let mut output_path = output_folder.join(input_path.file_name().unwrap());
+        output_path.set_extension("qm");
+
+        let tool_failed = |output: String| QtBuildError::ToolFailed {
+            tool: "lrelease".to_owned(),
+            input: input_path.display().to_string(),
+            output,
+        };
+        let cmd = Command::new(&self.executable)
+            .args([
+                "-silent",
+                input_path.to_str().unwrap(),
+                "-qm",
+                output_path.to_str().unwrap(),
+            ])
+            .output()
+            .map_err(|err| tool_failed(err.to_string()))?;
+
+        if !cmd.status.success() {
+            return Err(tool_failed(
+                String::from_utf8_lossy(&cmd.stderr).into_owned(),
+            ));
+        }
+
+        Ok(output_path)
+    }
+}
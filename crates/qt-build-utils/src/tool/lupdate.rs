@@ -0,0 +1,521 @@
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Joshua Booth <joshua.n.booth@gmail.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::{QtInstallation, QtTool};
+
+use std::{
+    fmt::Write,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+/// A wrapper around the [lupdate](https://doc.qt.io/qt-6/linguist-lupdate.html) tool
+///
+/// Unlike the other tools, lupdate is not run as part of a build, instead it is used to extract
+/// the translatable strings of an application into .ts files for translators.
+pub struct QtToolLupdate {
+    executable: PathBuf,
+}
+
+impl QtToolLupdate {
+    /// Construct a [QtToolLupdate] from a given [QtInstallation]
+    pub fn new(qt_installation: &dyn QtInstallation) -> Self {
+        let executable = qt_installation
+            .try_find_tool(QtTool::Lupdate)
+            .expect("Could not find lupdate");
+
+        Self { executable }
+    }
+
+    /// Run [lupdate](https://doc.qt.io/qt-6/linguist-lupdate.html) on the given source files
+    /// to create or update the given .ts files.
+    ///
+    /// lupdate cannot parse Rust, so for each .rs file a C++ file containing the `tr!` invocations
+    /// of the Rust file is generated in [cargo's OUT_DIR](https://doc.rust-lang.org/cargo/reference/environment-variables.html)
+    /// and passed to lupdate instead. The invocations are kept on the same lines, so the locations
+    /// in the .ts files match the Rust file, although they refer to the generated file.
+    /// Other sources, such as .qml and .ui files, are passed to lupdate as they are.
+    pub fn update(&self, sources: &[impl AsRef<Path>], ts_files: &[impl AsRef<Path>]) {
+        let output_folder = QtTool::Lupdate.writable_path();
+        std::fs::create_dir_all(&output_folder).expect("Could not create lupdate dir");
+
+        let sources = sources
+            .iter()
+            .map(|source| {
+                let source = source.as_ref();
+                if source
+                    .extension()
+                    .is_some_and(|extension| extension == "rs")
+                {
+                    Self::generate_rust_stub(source, &output_folder)
+                } else {
+                    source.to_owned()
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let cmd = Command::new(&self.executable)
+            .arg("-silent")
+            .args(&sources)
+            .arg("-ts")
+            .args(ts_files.iter().map(AsRef::as_ref))
+            .output()
+            .unwrap_or_else(|_| panic!("lupdate failed for {}", sources_display(&sources)));
+
+        if !cmd.status.success() {
+            panic!(
+                "lupdate failed for {}:\n{}",
+                sources_display(&sources),
+                String::from_utf8_lossy(&cmd.stderr)
+            );
+        }
+    }
+
+    /// Write a C++ file with the `tr!` invocations of a Rust file, returning the path to it
+    fn generate_rust_stub(input_path: &Path, output_folder: &Path) -> PathBuf {
+        let source = std::fs::read_to_string(input_path)
+            .unwrap_or_else(|_| panic!("Could not read {}", input_path.display()));
+
+        let mut lines = vec![String::new(); source.lines().count().max(1)];
+        for message in TrScanner::new(&source).messages() {
+            lines[message.line].push_str(&message.to_cpp());
+        }
+
+        let file_name = input_path
+            .to_string_lossy()
+            .replace(['/', '\\', ':'], "_")
+            .trim_start_matches('_')
+            .to_owned();
+        let output_path = output_folder.join(format!("{file_name}.cpp"));
+        std::fs::write(&output_path, lines.join("\n") + "\n")
+            .unwrap_or_else(|_| panic!("Could not write {}", output_path.display()));
+        output_path
+    }
+}
+
+fn sources_display(sources: &[PathBuf]) -> String {
+    sources
+        .iter()
+        .map(|source| source.display().to_string())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// A `tr!` invocation in a Rust file
+struct TrMessage {
+    /// The zero-based line of the invocation
+    line: usize,
+    /// The string literals of the invocation: context, source text and disambiguation
+    strings: Vec<String>,
+    /// Whether the invocation has a count for plural forms
+    has_count: bool,
+}
+
+impl TrMessage {
+    /// The equivalent C++ call which lupdate can extract
+    fn to_cpp(&self) -> String {
+        let mut strings = self.strings.clone();
+        if self.has_count && strings.len() == 2 {
+            strings.push(String::new());
+        }
+        let mut args = strings
+            .iter()
+            .map(|string| cpp_string_literal(string))
+            .collect::<Vec<_>>();
+        if self.has_count {
+            args.push("n".to_owned());
+        }
+        format!("QCoreApplication::translate({}); ", args.join(", "))
+    }
+}
+
+fn cpp_string_literal(string: &str) -> String {
+    let mut literal = String::with_capacity(string.len() + 2);
+    literal.push('"');
+    for c in string.chars() {
+        match c {
+            '"' => literal.push_str("\\\""),
+            '\\' => literal.push_str("\\\\"),
+            '\n' => literal.push_str("\\n"),
+            '\r' => literal.push_str("\\r"),
+            '\t' => literal.push_str("\\t"),
+            c if c.is_ascii_control() => {
+                // Octal escapes have at most three digits, unlike hex escapes
+                write!(literal, "\\{:03o}", c as u32).unwrap();
+            }
+            c => literal.push(c),
+        }
+    }
+    literal.push('"');
+    literal
+}
+
+/// A minimal scanner which finds the `tr!` invocations in Rust source code
+///
+/// Comments, strings and character literals are skipped, so that they are not mistaken for
+/// invocations.
+struct TrScanner {
+    chars: Vec<char>,
+    pos: usize,
+    line: usize,
+}
+
+impl TrScanner {
+    fn new(source: &str) -> Self {
+        Self {
+            chars: source.chars().collect(),
+            pos: 0,
+            line: 0,
+        }
+    }
+
+    fn peek(&self, offset: usize) -> Option<char> {
+        self.chars.get(self.pos + offset).copied()
+    }
+
+    fn starts_with(&self, pattern: &str) -> bool {
+        pattern
+            .chars()
+            .enumerate()
+            .all(|(offset, c)| self.peek(offset) == Some(c))
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek(0)?;
+        self.pos += 1;
+        if c == '\n' {
+            self.line += 1;
+        }
+        Some(c)
+    }
+
+    fn is_ident_char(c: char) -> bool {
+        c.is_alphanumeric() || c == '_'
+    }
+
+    fn follows_ident(&self) -> bool {
+        self.pos > 0 && Self::is_ident_char(self.chars[self.pos - 1])
+    }
+
+    fn messages(mut self) -> Vec<TrMessage> {
+        let mut messages = vec![];
+        while let Some(c) = self.peek(0) {
+            if self.skip_comment() {
+                continue;
+            }
+            if self.string_literal().is_some() {
+                continue;
+            }
+            match c {
+                '\'' => self.skip_char_literal(),
+                't' if !self.follows_ident() && self.starts_with("tr") => {
+                    let line = self.line;
+                    self.pos += 2;
+                    if let Some(message) = self.invocation(line) {
+                        messages.push(message);
+                    }
+                }
+                _ => {
+                    self.bump();
+                }
+            }
+        }
+        messages
+    }
+
+    /// Skip a line or (nested) block comment, returning whether there was one
+    fn skip_comment(&mut self) -> bool {
+        if self.starts_with("//") {
+            while self.peek(0).is_some_and(|c| c != '\n') {
+                self.bump();
+            }
+            return true;
+        }
+        if !self.starts_with("/*") {
+            return false;
+        }
+        let mut depth = 0;
+        while self.peek(0).is_some() {
+            if self.starts_with("/*") {
+                depth += 1;
+                self.pos += 2;
+            } else if self.starts_with("*/") {
+                depth -= 1;
+                self.pos += 2;
+                if depth == 0 {
+                    break;
+                }
+            } else {
+                self.bump();
+            }
+        }
+        true
+    }
+
+    /// Skip a character literal or a lifetime
+    fn skip_char_literal(&mut self) {
+        self.bump();
+        if self.peek(0) == Some('\\') {
+            self.bump();
+            while self.peek(0).is_some_and(|c| c != '\'') {
+                self.bump();
+            }
+            self.bump();
+        } else if self.peek(1) == Some('\'') {
+            self.bump();
+            self.bump();
+        }
+    }
+
+    /// Parse a (raw, byte or C) string literal, returning its value
+    fn string_literal(&mut self) -> Option<String> {
+        if self.follows_ident() {
+            return None;
+        }
+        let mut offset = 0;
+        if matches!(self.peek(offset), Some('b' | 'c')) {
+            offset += 1;
+        }
+        let raw = self.peek(offset) == Some('r');
+        if raw {
+            offset += 1;
+        }
+        let mut hashes = 0;
+        while raw && self.peek(offset + hashes) == Some('#') {
+            hashes += 1;
+        }
+        if self.peek(offset + hashes) != Some('"') {
+            return None;
+        }
+        for _ in 0..=offset + hashes {
+            self.bump();
+        }
+
+        let mut value = String::new();
+        while let Some(c) = self.bump() {
+            match c {
+                '"' if (0..hashes).all(|offset| self.peek(offset) == Some('#')) => {
+                    self.pos += hashes;
+                    break;
+                }
+                '\\' if !raw => self.unescape(&mut value),
+                c => value.push(c),
+            }
+        }
+        Some(value)
+    }
+
+    /// Unescape the escape sequence following a backslash
+    fn unescape(&mut self, value: &mut String) {
+        match self.bump() {
+            Some('n') => value.push('\n'),
+            Some('r') => value.push('\r'),
+            Some('t') => value.push('\t'),
+            Some('0') => value.push('\0'),
+            Some('x') => {
+                let digits = [self.bump(), self.bump()]
+                    .into_iter()
+                    .flatten()
+                    .collect::<String>();
+                if let Ok(byte) = u8::from_str_radix(&digits, 16) {
+                    value.push(char::from(byte));
+                }
+            }
+            Some('u') => {
+                let mut digits = String::new();
+                while let Some(c) = self.bump() {
+                    match c {
+                        '{' => {}
+                        '}' => break,
+                        c => digits.push(c),
+                    }
+                }
+                if let Some(c) = u32::from_str_radix(&digits.replace('_', ""), 16)
+                    .ok()
+                    .and_then(char::from_u32)
+                {
+                    value.push(c);
+                }
+            }
+            Some('\n') => {
+                // A line continuation also skips the leading whitespace of the next line
+                while self.peek(0).is_some_and(char::is_whitespace) {
+                    self.bump();
+                }
+            }
+            Some(c) => value.push(c),
+            None => {}
+        }
+    }
+
+    /// Skip whitespace and comments
+    fn skip_trivia(&mut self) {
+        loop {
+            if self.skip_comment() {
+                continue;
+            }
+            if self.peek(0).is_some_and(char::is_whitespace) {
+                self.bump();
+            } else {
+                break;
+            }
+        }
+    }
+
+    /// Parse the arguments of a `tr!` invocation, after the `tr` identifier
+    fn invocation(&mut self, line: usize) -> Option<TrMessage> {
+        if self.peek(0).is_some_and(Self::is_ident_char) {
+            return None;
+        }
+        self.skip_trivia();
+        if self.peek(0) != Some('!') {
+            return None;
+        }
+        self.bump();
+        self.skip_trivia();
+        if !matches!(self.peek(0), Some('(' | '[' | '{')) {
+            return None;
+        }
+        self.bump();
+
+        let mut message = TrMessage {
+            line,
+            strings: vec![],
+            has_count: false,
+        };
+        loop {
+            self.skip_trivia();
+            if message.strings.len() == 3 {
+                message.has_count = !matches!(self.peek(0), Some(')' | ']' | '}'));
+                break;
+            }
+            let Some(string) = self.string_literal() else {
+                message.has_count = !matches!(self.peek(0), Some(')' | ']' | '}'));
+                break;
+            };
+            message.strings.push(string);
+            self.skip_trivia();
+            if self.peek(0) == Some(',') {
+                self.bump();
+            } else {
+                break;
+            }
+        }
+
+        if message.strings.len() < 2 {
+            return None;
+        }
+        Some(message)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scan(source: &str) -> Vec<(usize, Vec<String>, bool)> {
+        TrScanner::new(source)
+            .messages()
+            .into_iter()
+            .map(|message| (message.line, message.strings, message.has_count))
+            .collect()
+    }
+
+    fn strings(strings: &[&str]) -> Vec<String> {
+        strings.iter().map(|string| (*string).to_owned()).collect()
+    }
+
+    #[test]
+    fn scan_strings() {
+        let source = r#"
+let title = tr!("MainWindow", "Open file");
+let action = tr!["MainWindow", "Open", "verb",];
+let status = tr! { "MainWindow", "%n file(s)", "", count };
+let plural = tr!("MainWindow", "%n item(s)", "", items.len() as i32);
+"#;
+        assert_eq!(
+            scan(source),
+            vec![
+                (1, strings(&["MainWindow", "Open file"]), false),
+                (2, strings(&["MainWindow", "Open", "verb"]), false),
+                (3, strings(&["MainWindow", "%n file(s)", ""]), true),
+                (4, strings(&["MainWindow", "%n item(s)", ""]), true),
+            ]
+        );
+    }
+
+    #[test]
+    fn scan_escapes() {
+        let source = r#"tr!("Ctx", "Say \"hi\"\n\t\\ \x41\u{e9}\u{1F600} \
+            continued");"#;
+        assert_eq!(
+            scan(source),
+            vec![(
+                0,
+                strings(&["Ctx", "Say \"hi\"\n\t\\ A\u{e9}\u{1F600} continued"]),
+                false
+            )]
+        );
+        assert_eq!(
+            TrMessage {
+                line: 0,
+                strings: strings(&["Ctx", "a\"b\\c\n\u{1}"]),
+                has_count: true,
+            }
+            .to_cpp(),
+            r#"QCoreApplication::translate("Ctx", "a\"b\\c\n\001", "", n); "#
+        );
+    }
+
+    #[test]
+    fn scan_raw_strings() {
+        let source = r###"
+tr!(r"Ctx", r#"Say "hi" \n"#);
+let skipped = r##"tr!("Ctx", "Not a message")"##;
+tr!("Ctx", br"bytes");
+"###;
+        assert_eq!(
+            scan(source),
+            vec![
+                (1, strings(&["Ctx", r#"Say "hi" \n"#]), false),
+                (3, strings(&["Ctx", "bytes"]), false),
+            ]
+        );
+    }
+
+    #[test]
+    fn scan_comments() {
+        let source = r#"
+// tr!("Ctx", "Line comment");
+/* tr!("Ctx", "Block comment") /* nested */ tr!("Ctx", "Still a comment") */
+let c = '"'; let quote = '\''; fn f<'a>(s: &'a str) {}
+tr!(/* context */ "Ctx", // source text
+    "After comments");
+"#;
+        assert_eq!(
+            scan(source),
+            vec![(4, strings(&["Ctx", "After comments"]), false)]
+        );
+    }
+
+    #[test]
+    fn scan_nested_macros() {
+        let source = r#"
+println!("{}", tr!("Ctx", "Nested"));
+format!("tr!(\"Ctx\", \"In a string\")");
+vec![tr!("Ctx", "First"), tr!("Ctx", "Second")];
+attr!("Ctx", "Not tr"); my_tr!("Ctx", "Not tr either"); tr("Ctx", "Not a macro");
+tr!(CONTEXT, "Not a literal");
+"#;
+        assert_eq!(
+            scan(source),
+            vec![
+                (1, strings(&["Ctx", "Nested"]), false),
+                (3, strings(&["Ctx", "First"]), false),
+                (3, strings(&["Ctx", "Second"]), false),
+            ]
+        );
+    }
+}
@@ -3,7 +3,7 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
-use crate::{QtInstallation, QtTool};
+use crate::{QtBuildError, QtInstallation, QtTool};
 
 use std::{
     path::{Path, PathBuf},
@@ -20,7 +20,7 @@ pub struct MocProducts {
 
 /// Arguments for a Qt moc invocation.
 /// See: [QtToolMoc::compile]
-#[derive(Default, Clone)]
+#[derive(Default, Clone, Hash)]
 pub struct MocArguments {
     uri: Option<String>,
     include_paths: Vec<PathBuf>,
@@ -70,6 +70,17 @@ impl QtToolMoc {
     /// The return value contains the path to the generated C++ file, which can then be passed to [cc::Build::files](https://docs.rs/cc/latest/cc/struct.Build.html#method.file),
     /// as well as the path to the generated metatypes.json file, which can be used for QML modules.
     pub fn compile(&self, input_file: impl AsRef<Path>, arguments: MocArguments) -> MocProducts {
+        self.try_compile(input_file, arguments)
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Run moc like [QtToolMoc::compile], returning a [QtBuildError::ToolFailed] with the output
+    /// of moc if it fails instead of panicking.
+    pub fn try_compile(
+        &self,
+        input_file: impl AsRef<Path>,
+        arguments: MocArguments,
+    ) -> Result<MocProducts, QtBuildError> {
         let input_path = input_file.as_ref();
         // Put all the moc files into one place, this can then be added to the include path
         let moc_dir = QtTool::Moc.writable_path();
@@ -102,21 +113,22 @@ impl QtToolMoc {
             .arg("-o")
             .arg(output_path.to_str().unwrap())
             .arg("--output-json");
-        let cmd = cmd
-            .output()
-            .unwrap_or_else(|_| panic!("moc failed for {}", input_path.display()));
+        let tool_failed = |output: String| QtBuildError::ToolFailed {
+            tool: "moc".to_owned(),
+            input: input_path.display().to_string(),
+            output,
+        };
+        let cmd = cmd.output().map_err(|err| tool_failed(err.to_string()))?;
 
         if !cmd.status.success() {
-            panic!(
-                "moc failed for {}:\n{}",
-                input_path.display(),
-                String::from_utf8_lossy(&cmd.stderr)
-            );
+            return Err(tool_failed(
+                String::from_utf8_lossy(&cmd.stderr).into_owned(),
+            ));
         }
 
-        MocProducts {
+        Ok(MocProducts {
             cpp: output_path,
             metatypes_json: metatypes_json_path,
-        }
+        })
     }
 }
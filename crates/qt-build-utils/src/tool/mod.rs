@@ -5,18 +5,33 @@
 
 use std::{env, path::PathBuf};
 
+mod lrelease;
+pub use lrelease::QtToolLrelease;
+
+mod lupdate;
+pub use lupdate::QtToolLupdate;
+
 mod moc;
 pub use moc::{MocArguments, MocProducts, QtToolMoc};
 
 mod qmlcachegen;
 pub use qmlcachegen::{QmlCacheArguments, QmlCacheProducts, QtToolQmlCacheGen};
 
+mod qmlformat;
+pub use qmlformat::QtToolQmlFormat;
+
+mod qmllint;
+pub use qmllint::{QmlLintOutput, QtToolQmlLint};
+
 mod qmltyperegistrar;
 pub use qmltyperegistrar::QtToolQmlTypeRegistrar;
 
 mod rcc;
 pub use rcc::QtToolRcc;
 
+mod uic;
+pub use uic::QtToolUic;
+
 /// An enum representing known Qt tools
 #[non_exhaustive]
 #[derive(Eq, Hash, PartialEq)]
@@ -29,6 +44,16 @@ pub enum QtTool {
     QmlCacheGen,
     /// Qml Type Registrar
     QmlTypeRegistrar,
+    /// Lupdate (extracts translatable strings)
+    Lupdate,
+    /// Lrelease (compiles translations)
+    Lrelease,
+    /// Qml lint
+    QmlLint,
+    /// Qml format
+    QmlFormat,
+    /// Uic (Qt Designer forms)
+    Uic,
     // TODO: could add a Custom(&str) thing here
 }
 
@@ -39,6 +64,11 @@ impl QtTool {
             Self::Rcc => "rcc",
             Self::QmlCacheGen => "qmlcachegen",
             Self::QmlTypeRegistrar => "qmltyperegistrar",
+            Self::Lupdate => "lupdate",
+            Self::Lrelease => "lrelease",
+            Self::QmlLint => "qmllint",
+            Self::QmlFormat => "qmlformat",
+            Self::Uic => "uic",
         }
     }
 
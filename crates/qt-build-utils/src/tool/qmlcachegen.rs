@@ -3,7 +3,7 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
-use crate::{QtInstallation, QtTool};
+use crate::{QtBuildError, QtInstallation, QtTool};
 use std::{
     path::{Path, PathBuf},
     process::Command,
@@ -49,6 +49,41 @@ impl QtToolQmlCacheGen {
         common_args: QmlCacheArguments,
         file: impl AsRef<Path>,
     ) -> QmlCacheProducts {
+        self.try_compile(common_args, file)
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Run qmlcachegen like [QtToolQmlCacheGen::compile], returning a [QtBuildError::ToolFailed]
+    /// with the output of qmlcachegen if it fails instead of panicking.
+    pub fn try_compile(
+        &self,
+        common_args: QmlCacheArguments,
+        file: impl AsRef<Path>,
+    ) -> Result<QmlCacheProducts, QtBuildError> {
+        let resource_path = file.as_ref().display().to_string();
+        self.try_compile_aliased(common_args, file, &resource_path)
+    }
+
+    /// Run qmlcachegen for a given qml file which has the given path in the Qt resource system,
+    /// relative to the directory of the QML module
+    pub fn compile_aliased(
+        &self,
+        common_args: QmlCacheArguments,
+        file: impl AsRef<Path>,
+        resource_path: &str,
+    ) -> QmlCacheProducts {
+        self.try_compile_aliased(common_args, file, resource_path)
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Run qmlcachegen like [QtToolQmlCacheGen::compile_aliased], returning a
+    /// [QtBuildError::ToolFailed] with the output of qmlcachegen if it fails instead of panicking.
+    pub fn try_compile_aliased(
+        &self,
+        common_args: QmlCacheArguments,
+        file: impl AsRef<Path>,
+        resource_path: &str,
+    ) -> Result<QmlCacheProducts, QtBuildError> {
         let uri = common_args.uri;
         let qml_uri_dirs = uri.replace('.', "/");
 
@@ -63,45 +98,45 @@ impl QtToolQmlCacheGen {
             common_args.qmldir_qrc_path.to_string_lossy().into_owned(),
         ];
 
-        let qml_cache_path = qmlcachegen_dir.join(format!(
-            "{}.cpp",
-            file.as_ref().file_name().unwrap().to_string_lossy()
-        ));
+        // Files from multiple directories can share a file name, so place the cache file in the
+        // directories of the resource path
+        let qml_cache_path =
+            qmlcachegen_dir.join(format!("{}.cpp", resource_path.replace('\\', "/")));
+        if let Some(parent) = qml_cache_path.parent() {
+            std::fs::create_dir_all(parent)
+                .expect("Could not create qmlcachegen directory for QML file");
+        }
 
-        let qml_resource_path = format!("/qt/qml/{qml_uri_dirs}/{}", file.as_ref().display());
+        let qml_resource_path = format!("/qt/qml/{qml_uri_dirs}/{resource_path}");
 
+        let tool_failed = |output: String| QtBuildError::ToolFailed {
+            tool: "qmlcachegen".to_owned(),
+            input: file.as_ref().display().to_string(),
+            output,
+        };
+        let file_path = std::fs::canonicalize(&file).map_err(|err| tool_failed(err.to_string()))?;
         let specific_args = vec![
             "--resource-path".to_owned(),
             qml_resource_path.clone(),
             "-o".to_owned(),
             qml_cache_path.to_string_lossy().into_owned(),
-            std::fs::canonicalize(&file)
-                .unwrap()
-                .to_string_lossy()
-                .into_owned(),
+            file_path.to_string_lossy().into_owned(),
         ];
 
         let cmd = Command::new(&self.executable)
             .args(common_args.iter().chain(&specific_args))
             .output()
-            .unwrap_or_else(|_| {
-                panic!(
-                    "qmlcachegen failed for {} in QML module {uri}",
-                    file.as_ref().display()
-                )
-            });
+            .map_err(|err| tool_failed(err.to_string()))?;
         if !cmd.status.success() {
-            panic!(
-                "qmlcachegen failed for {} in QML module {uri}:\n{}",
-                file.as_ref().display(),
-                String::from_utf8_lossy(&cmd.stderr)
-            );
+            return Err(tool_failed(
+                String::from_utf8_lossy(&cmd.stderr).into_owned(),
+            ));
         }
 
-        QmlCacheProducts {
+        Ok(QmlCacheProducts {
             qml_cache_path,
             qml_resource_path,
-        }
+        })
     }
 
     /// Compile a loader for given qml resource paths
@@ -110,6 +145,17 @@ impl QtToolQmlCacheGen {
         common_args: QmlCacheArguments,
         qml_resource_paths: &[String],
     ) -> PathBuf {
+        self.try_compile_loader(common_args, qml_resource_paths)
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Compile a loader like [QtToolQmlCacheGen::compile_loader], returning a
+    /// [QtBuildError::ToolFailed] with the output of qmlcachegen if it fails instead of panicking.
+    pub fn try_compile_loader(
+        &self,
+        common_args: QmlCacheArguments,
+        qml_resource_paths: &[String],
+    ) -> Result<PathBuf, QtBuildError> {
         let uri = common_args.uri;
         let qml_uri_dirs = uri.replace('.', "/");
         let qml_uri_underscores = uri.replace('.', "_");
@@ -133,6 +179,11 @@ impl QtToolQmlCacheGen {
             qmlcachegen_loader.to_string_lossy().into_owned(),
         ];
 
+        let tool_failed = |output: String| QtBuildError::ToolFailed {
+            tool: "qmlcachegen".to_owned(),
+            input: format!("QML module {uri}"),
+            output,
+        };
         let cmd = Command::new(&self.executable)
             .args(
                 common_args
@@ -141,14 +192,13 @@ impl QtToolQmlCacheGen {
                     .chain(qml_resource_paths),
             )
             .output()
-            .unwrap_or_else(|_| panic!("qmlcachegen failed for QML module {uri}"));
+            .map_err(|err| tool_failed(err.to_string()))?;
         if !cmd.status.success() {
-            panic!(
-                "qmlcachegen failed for QML module {uri}:\n{}",
-                String::from_utf8_lossy(&cmd.stderr)
-            );
+            return Err(tool_failed(
+                String::from_utf8_lossy(&cmd.stderr).into_owned(),
+            ));
         }
 
-        qmlcachegen_loader
+        Ok(qmlcachegen_loader)
     }
 }
@@ -0,0 +1,88 @@
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Joshua Booth <joshua.n.booth@gmail.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::{QtBuildError, QtInstallation, QtTool};
+
+use std::{
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+/// A wrapper around the [qmlformat](https://doc.qt.io/qt-6/qtqml-tooling-qmlformat.html) tool
+pub struct QtToolQmlFormat {
+    executable: PathBuf,
+}
+
+impl QtToolQmlFormat {
+    /// Construct a [QtToolQmlFormat] from a given [QtInstallation]
+    pub fn new(qt_installation: &dyn QtInstallation) -> Self {
+        Self::try_new(qt_installation).unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Construct a [QtToolQmlFormat] like [QtToolQmlFormat::new], returning a
+    /// [QtBuildError::ToolMissing] if qmlformat is not part of the Qt installation instead of
+    /// panicking.
+    pub fn try_new(qt_installation: &dyn QtInstallation) -> Result<Self, QtBuildError> {
+        let executable = qt_installation
+            .try_find_tool(QtTool::QmlFormat)
+            .map_err(|err| QtBuildError::ToolMissing {
+                tool: "qmlformat".to_owned(),
+                error: Box::new(err),
+            })?;
+
+        Ok(Self { executable })
+    }
+
+    /// Run [qmlformat](https://doc.qt.io/qt-6/qtqml-tooling-qmlformat.html) on a .qml file and
+    /// return the formatted contents, without modifying the file.
+    pub fn format(&self, qml_file: impl AsRef<Path>) -> String {
+        self.try_format(qml_file)
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Run qmlformat like [QtToolQmlFormat::format], returning a [QtBuildError::ToolFailed] with
+    /// the output of qmlformat if it fails instead of panicking.
+    pub fn try_format(&self, qml_file: impl AsRef<Path>) -> Result<String, QtBuildError> {
+        let qml_path = qml_file.as_ref();
+        let tool_failed = |output: String| QtBuildError::ToolFailed {
+            tool: "qmlformat".to_owned(),
+            input: qml_path.display().to_string(),
+            output,
+        };
+        let cmd = Command::new(&self.executable)
+            .arg(qml_path)
+            .output()
+            .map_err(|err| tool_failed(err.to_string()))?;
+
+        if !cmd.status.success() {
+            return Err(tool_failed(
+                String::from_utf8_lossy(&cmd.stderr).into_owned(),
+            ));
+        }
+
+        Ok(String::from_utf8_lossy(&cmd.stdout).into_owned())
+    }
+
+    /// Whether a .qml file is already formatted as [qmlformat](https://doc.qt.io/qt-6/qtqml-tooling-qmlformat.html)
+    /// would format it
+    pub fn is_formatted(&self, qml_file: impl AsRef<Path>) -> bool {
+        self.try_is_formatted(qml_file)
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Check the formatting like [QtToolQmlFormat::is_formatted], returning a
+    /// [QtBuildError::ToolFailed] if the file cannot be read or qmlformat fails instead of
+    /// panicking.
+    pub fn try_is_formatted(&self, qml_file: impl AsRef<Path>) -> Result<bool, QtBuildError> {
+        let qml_path = qml_file.as_ref();
+        let contents =
+            std::fs::read_to_string(qml_path).map_err(|err| QtBuildError::ToolFailed {
+                tool: "qmlformat".to_owned(),
+                input: qml_path.display().to_string(),
+                output: err.to_string(),
+            })?;
+        Ok(self.try_format(qml_path)? == contents)
+    }
+}
@@ -0,0 +1,158 @@
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Joshua Booth <joshua.n.booth@gmail.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::{QtBuildError, QtInstallation, QtTool};
+
+use std::{
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+/// The messages reported by [QtToolQmlLint::lint]
+pub struct QmlLintOutput {
+    /// Whether qmllint exited successfully, which is not the case if any warning or error
+    /// was reported
+    pub success: bool,
+    /// The warnings and errors reported by qmllint, each starting with the severity
+    /// followed by the file, line and column, for example `Warning: main.qml:3:5: ...`
+    pub messages: Vec<String>,
+}
+
+/// A wrapper around the [qmllint](https://doc.qt.io/qt-6/qtqml-tooling-qmllint.html) tool
+pub struct QtToolQmlLint {
+    executable: PathBuf,
+}
+
+impl QtToolQmlLint {
+    /// Construct a [QtToolQmlLint] from a given [QtInstallation]
+    pub fn new(qt_installation: &dyn QtInstallation) -> Self {
+        Self::try_new(qt_installation).unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Construct a [QtToolQmlLint] like [QtToolQmlLint::new], returning a
+    /// [QtBuildError::ToolMissing] if qmllint is not part of the Qt installation instead of
+    /// panicking.
+    pub fn try_new(qt_installation: &dyn QtInstallation) -> Result<Self, QtBuildError> {
+        let executable = qt_installation
+            .try_find_tool(QtTool::QmlLint)
+            .map_err(|err| QtBuildError::ToolMissing {
+                tool: "qmllint".to_owned(),
+                error: Box::new(err),
+            })?;
+
+        Ok(Self { executable })
+    }
+
+    /// Run [qmllint](https://doc.qt.io/qt-6/qtqml-tooling-qmllint.html) on the given .qml files.
+    ///
+    /// The `import_paths` are searched for QML modules in addition to the QML import path of Qt,
+    /// so that the types of a module registered with [crate::QtBuild::register_qml_module] can
+    /// be checked by passing the directory containing its generated qmldir and qmltypes files.
+    pub fn lint(
+        &self,
+        qml_files: &[impl AsRef<Path>],
+        import_paths: &[impl AsRef<Path>],
+    ) -> QmlLintOutput {
+        self.try_lint(qml_files, import_paths)
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Run qmllint like [QtToolQmlLint::lint], returning a [QtBuildError::ToolFailed] if
+    /// qmllint cannot be run instead of panicking.
+    ///
+    /// Note that warnings reported by qmllint are not an error, they are part of the output.
+    pub fn try_lint(
+        &self,
+        qml_files: &[impl AsRef<Path>],
+        import_paths: &[impl AsRef<Path>],
+    ) -> Result<QmlLintOutput, QtBuildError> {
+        let mut cmd = Command::new(&self.executable);
+        for import_path in import_paths {
+            cmd.arg("-I").arg(import_path.as_ref());
+        }
+        let qml_files = qml_files.iter().map(AsRef::as_ref).collect::<Vec<_>>();
+        let output = cmd
+            .args(&qml_files)
+            .output()
+            .map_err(|err| QtBuildError::ToolFailed {
+                tool: "qmllint".to_owned(),
+                input: qml_files
+                    .iter()
+                    .map(|qml_file| qml_file.display().to_string())
+                    .collect::<Vec<_>>()
+                    .join(", "),
+                output: err.to_string(),
+            })?;
+
+        Ok(QmlLintOutput {
+            success: output.status.success(),
+            messages: parse_messages(
+                &String::from_utf8_lossy(&output.stderr),
+                &String::from_utf8_lossy(&output.stdout),
+            ),
+        })
+    }
+}
+
+/// Collect the warnings and errors from the output of qmllint
+///
+/// qmllint writes the messages to stderr, followed by the code they refer to and any
+/// informational notes, which are skipped.
+fn parse_messages(stderr: &str, stdout: &str) -> Vec<String> {
+    stderr
+        .lines()
+        .chain(stdout.lines())
+        .filter(|line| {
+            ["Warning: ", "Critical: ", "Error: "]
+                .iter()
+                .any(|severity| line.starts_with(severity))
+        })
+        .map(str::to_owned)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_qmllint_messages() {
+        let stderr = r#"Warning: /qml/main.qml:3:5: Binding assigned to "widht", but no property "widht" exists in the current element. [missing-property]
+    widht: 100
+    ^^^^^
+Warning: /qml/main.qml:9:21: Unqualified access [unqualified]
+        onClicked: console.log(counter)
+                               ^^^^^^^
+Info: counter is a member of a parent element.
+      You can qualify the access with its id to avoid this warning:
+        onClicked: console.log(<id>.counter)
+Error: /qml/broken.qml:4:1: Expected token `}' [syntax]
+"#;
+        assert_eq!(
+            parse_messages(stderr, ""),
+            vec![
+                "Warning: /qml/main.qml:3:5: Binding assigned to \"widht\", but no property \"widht\" exists in the current element. [missing-property]",
+                "Warning: /qml/main.qml:9:21: Unqualified access [unqualified]",
+                "Error: /qml/broken.qml:4:1: Expected token `}' [syntax]",
+            ]
+        );
+
+        // Older versions of qmllint report errors as critical
+        assert_eq!(
+            parse_messages("Critical: /qml/broken.qml:4:1: Expected token `}'\n", ""),
+            vec!["Critical: /qml/broken.qml:4:1: Expected token `}'"]
+        );
+
+        // Messages may also be written to stdout, and nothing is reported for a clean file
+        assert_eq!(
+            parse_messages(
+                "",
+                "Warning: main.qml:1:1: Unused import [unused-imports]\n"
+            ),
+            vec!["Warning: main.qml:1:1: Unused import [unused-imports]"]
+        );
+        assert!(parse_messages("", "").is_empty());
+    }
+}
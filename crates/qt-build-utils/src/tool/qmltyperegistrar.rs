@@ -3,7 +3,7 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
-use crate::{QtInstallation, QtTool};
+use crate::{QtBuildError, QtInstallation, QtTool};
 use semver::Version;
 use std::{
     path::{Path, PathBuf},
@@ -33,6 +33,20 @@ impl QtToolQmlTypeRegistrar {
         uri: &str,
         version: Version,
     ) -> Option<PathBuf> {
+        self.try_compile(metatypes_json, qmltypes, uri, version)
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Run qmltyperegistrar like [QtToolQmlTypeRegistrar::compile], returning a
+    /// [QtBuildError::ToolFailed] with the output of qmltyperegistrar if it fails instead of
+    /// panicking.
+    pub fn try_compile(
+        &self,
+        metatypes_json: &[impl AsRef<Path>],
+        qmltypes: impl AsRef<Path>,
+        uri: &str,
+        version: Version,
+    ) -> Result<Option<PathBuf>, QtBuildError> {
         // Filter out empty jsons
         let metatypes_json: Vec<_> = metatypes_json
             .iter()
@@ -47,7 +61,7 @@ impl QtToolQmlTypeRegistrar {
 
         // Only run qmltyperegistrar if we have valid json files left out
         if metatypes_json.is_empty() {
-            return None;
+            return Ok(None);
         }
 
         let qml_uri_underscores = uri.replace('.', "_");
@@ -70,17 +84,21 @@ impl QtToolQmlTypeRegistrar {
             qmltyperegistrar_output_path.to_string_lossy().into_owned(),
         ];
         args.extend(metatypes_json);
+        let tool_failed = |output: String| QtBuildError::ToolFailed {
+            tool: "qmltyperegistrar".to_owned(),
+            input: uri.to_owned(),
+            output,
+        };
         let cmd = Command::new(&self.executable)
             .args(args)
             .output()
-            .unwrap_or_else(|_| panic!("qmltyperegistrar failed for {uri}"));
+            .map_err(|err| tool_failed(err.to_string()))?;
         if !cmd.status.success() {
-            panic!(
-                "qmltyperegistrar failed for {uri}:\n{}",
-                String::from_utf8_lossy(&cmd.stderr)
-            );
+            return Err(tool_failed(
+                String::from_utf8_lossy(&cmd.stderr).into_owned(),
+            ));
         }
 
-        Some(qmltyperegistrar_output_path)
+        Ok(Some(qmltyperegistrar_output_path))
     }
 }
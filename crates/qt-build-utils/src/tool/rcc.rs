@@ -3,7 +3,7 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
-use crate::{Initializer, QtInstallation, QtTool};
+use crate::{Initializer, QtBuildError, QtInstallation, QtTool};
 
 use semver::Version;
 use std::{
@@ -39,6 +39,13 @@ impl QtToolRcc {
     /// the `+whole-archive` flag is used, or the initializer function is called by the
     /// application.
     pub fn compile(&self, input_file: impl AsRef<Path>) -> Initializer {
+        self.try_compile(input_file)
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Run rcc like [QtToolRcc::compile], returning a [QtBuildError::ToolFailed] with the output
+    /// of rcc if it fails instead of panicking.
+    pub fn try_compile(&self, input_file: impl AsRef<Path>) -> Result<Initializer, QtBuildError> {
         let input_path = input_file.as_ref();
         let output_folder = QtTool::Rcc.writable_path();
         std::fs::create_dir_all(&output_folder).expect("Could not create qrc dir");
@@ -50,6 +57,11 @@ impl QtToolRcc {
             .to_string_lossy()
             .replace('.', "_");
 
+        let tool_failed = |output: String| QtBuildError::ToolFailed {
+            tool: "rcc".to_owned(),
+            input: input_path.display().to_string(),
+            output,
+        };
         let cmd = Command::new(&self.executable)
             .args([
                 input_path.to_str().unwrap(),
@@ -59,14 +71,12 @@ impl QtToolRcc {
                 &name,
             ])
             .output()
-            .unwrap_or_else(|_| panic!("rcc failed for {}", input_path.display()));
+            .map_err(|err| tool_failed(err.to_string()))?;
 
         if !cmd.status.success() {
-            panic!(
-                "rcc failed for {}:\n{}",
-                input_path.display(),
-                String::from_utf8_lossy(&cmd.stderr)
-            );
+            return Err(tool_failed(
+                String::from_utf8_lossy(&cmd.stderr).into_owned(),
+            ));
         }
 
         let qt_6_5 = Version::new(6, 5, 0);
@@ -76,11 +86,11 @@ impl QtToolRcc {
         } else {
             "QtCore/QDir"
         };
-        Initializer {
+        Ok(Initializer {
             file: Some(output_path),
             init_call: Some(format!("Q_INIT_RESOURCE({name});")),
             init_declaration: Some(format!("#include <{init_header}>")),
-        }
+        })
     }
 
     /// Run [rcc](https://doc.qt.io/qt-6/resources.html) on a .qrc file and return the paths of the sources
@@ -101,7 +111,8 @@ impl QtToolRcc {
         }
 
         String::from_utf8_lossy(&cmd_list.stdout)
-            .split('\n')
+            .lines()
+            .filter(|line| !line.is_empty())
             .map(PathBuf::from)
             .collect()
     }
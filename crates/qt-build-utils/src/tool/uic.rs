@@ -0,0 +1,75 @@
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Joshua Booth <joshua.n.booth@gmail.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::{QtBuildError, QtInstallation, QtTool};
+
+use std::{
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+/// A wrapper around the [uic](https://doc.qt.io/qt-6/uic.html) tool
+pub struct QtToolUic {
+    executable: PathBuf,
+}
+
+impl QtToolUic {
+    /// Construct a [QtToolUic] from a given [QtInstallation]
+    pub fn new(qt_installation: &dyn QtInstallation) -> Self {
+        let executable = qt_installation
+            .try_find_tool(QtTool::Uic)
+            .expect("Could not find uic");
+
+        Self { executable }
+    }
+
+    /// The directory where [QtToolUic::compile] writes the generated headers, which should be
+    /// added to the include paths
+    pub fn include_path(&self) -> PathBuf {
+        QtTool::Uic.writable_path()
+    }
+
+    /// Run [uic](https://doc.qt.io/qt-6/uic.html) on a .ui file and save the output into [cargo's OUT_DIR](https://doc.rust-lang.org/cargo/reference/environment-variables.html).
+    /// The path to the generated header is returned, which is named `ui_<name>.h` like with CMake
+    /// and qmake, so that `form.ui` can be included as `#include "ui_form.h"`.
+    pub fn compile(&self, input_file: impl AsRef<Path>) -> PathBuf {
+        self.try_compile(input_file)
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Run uic like [QtToolUic::compile], returning a [QtBuildError::ToolFailed] with the output
+    /// of uic if it fails instead of panicking.
+    pub fn try_compile(&self, input_file: impl AsRef<Path>) -> Result<PathBuf, QtBuildError> {
+        let input_path = input_file.as_ref();
+        let output_folder = self.include_path();
+        std::fs::create_dir_all(&output_folder).expect("Could not create uic dir");
+        let output_path = output_folder.join(format!(
+            "ui_{}.h",
+            input_path.file_stem().unwrap().to_string_lossy()
+        ));
+
+        let tool_failed = |output: String| QtBuildError::ToolFailed {
+            tool: "uic".to_owned(),
+            input: input_path.display().to_string(),
+            output,
+        };
+        let cmd = Command::new(&self.executable)
+            .args([
+                input_path.to_str().unwrap(),
+                "-o",
+                output_path.to_str().unwrap(),
+            ])
+            .output()
+            .map_err(|err| tool_failed(err.to_string()))?;
+
+        if !cmd.status.success() {
+            return Err(tool_failed(
+                String::from_utf8_lossy(&cmd.stderr).into_owned(),
+            ));
+        }
+
+        Ok(output_path)
+    }
+}
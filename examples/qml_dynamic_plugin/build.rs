@@ -0,0 +1,18 @@
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use cxx_qt_build::{CxxQtBuilder, QmlModule};
+
+fn main() {
+    CxxQtBuilder::new()
+        .qml_module(QmlModule::<_, &str> {
+            uri: "com.kdab.cxx_qt.dynamic_plugin",
+            rust_files: &["src/greeter.rs"],
+            // The qmldir is written to qml/com/kdab/cxx_qt/dynamic_plugin in the target directory,
+            // which can then be used with QML_IMPORT_PATH
+            dynamic_plugin: true,
+            ..Default::default()
+        })
+        .build();
+}
@@ -0,0 +1,23 @@
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+import QtQml 2.12
+
+import com.kdab.cxx_qt.dynamic_plugin 1.0
+
+// Run with the qml runtime, which exits with 0 if the plugin was loaded and works
+QtObject {
+    property Greeter greeter: Greeter {
+        greeting: "Hello"
+    }
+
+    property Timer timer: Timer {
+        interval: 0
+        running: true
+        onTriggered: {
+            const greeting = greeter.greet("QML");
+            console.log(greeting);
+            Qt.exit(greeting === "Hello QML" ? 0 : 1);
+        }
+    }
+}
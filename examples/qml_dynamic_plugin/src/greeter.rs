@@ -0,0 +1,46 @@
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+/// A QObject which is registered with QML by the dynamic plugin
+#[cxx_qt::bridge]
+pub mod qobject {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        /// An alias to the QString type
+        type QString = cxx_qt_lib::QString;
+    }
+
+    extern "RustQt" {
+        #[qobject]
+        #[qml_element]
+        #[qproperty(QString, greeting)]
+        type Greeter = super::GreeterRust;
+
+        /// Greet the given name with the greeting
+        #[qinvokable]
+        fn greet(&self, name: &QString) -> QString;
+    }
+}
+
+use cxx_qt_lib::QString;
+
+/// The Rust struct for the Greeter QObject
+pub struct GreeterRust {
+    greeting: QString,
+}
+
+impl Default for GreeterRust {
+    fn default() -> Self {
+        Self {
+            greeting: QString::from("Hello"),
+        }
+    }
+}
+
+impl qobject::Greeter {
+    /// Greet the given name with the greeting
+    pub fn greet(&self, name: &QString) -> QString {
+        QString::from(&format!("{} {name}", self.greeting()))
+    }
+}
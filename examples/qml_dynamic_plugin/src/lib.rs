@@ -0,0 +1,15 @@
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! A QML module whose plugin is a shared library, which is loaded by the QML engine when the
+//! module is imported, for example by the `qml` runtime:
+//!
+//! ```bash
+//! cargo build -p qml-dynamic-plugin
+//! QML_IMPORT_PATH=target/debug/qml qml examples/qml_dynamic_plugin/qml/main.qml
+//! ```
+
+pub mod greeter;
+
+cxx_qt::export_qml_plugin!("com.kdab.cxx_qt.dynamic_plugin");
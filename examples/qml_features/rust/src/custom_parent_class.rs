@@ -24,13 +24,10 @@ pub mod qobject {
         /// QPainter from cxx_qt_lib
         type QPainter = cxx_qt_lib::QPainter;
         include!("cxx-qt-lib/qpainter.h");
-    }
 
-    // Define the API from QtQuick that we need
-    unsafe extern "C++" {
-        include!(<QtQuick/QQuickPaintedItem>);
-        /// Base for Qt type
-        type QQuickPaintedItem;
+        /// QQuickPaintedItem from cxx_qt_lib
+        type QQuickPaintedItem = cxx_qt_lib::QQuickPaintedItem;
+        include!("cxx-qt-lib/qquickpainteditem.h");
     }
 
     unsafe extern "RustQt" {
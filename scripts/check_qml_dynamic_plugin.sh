@@ -0,0 +1,23 @@
+#!/usr/bin/env bash
+
+# SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+#
+# SPDX-License-Identifier: MIT OR Apache-2.0
+
+set -ex
+
+SOURCE_FOLDER=$1
+BUILD_FOLDER=$2
+QML_EXECUTABLE=$3
+
+# Ensure we are in the right directory
+cd "$SOURCE_FOLDER"
+
+# Build the shared library of the plugin, which also writes the qmldir to the qml import path
+cargo build --locked --release --target-dir="$BUILD_FOLDER" -p qml-dynamic-plugin
+
+# Import the QML module with the qml runtime, which loads the plugin from the shared library
+QML_IMPORT_PATH="$BUILD_FOLDER/release/qml" QT_QPA_PLATFORM=offscreen \
+    "$QML_EXECUTABLE" "$SOURCE_FOLDER/examples/qml_dynamic_plugin/qml/main.qml"
+
+exit 0
@@ -34,7 +34,9 @@
 #include "qpolygon.h"
 #include "qpolygonf.h"
 #include "qqmlapplicationengine.h"
+#include "qqmlcomponent.h"
 #include "qqmlengine.h"
+#include "qquickimageprovider.h"
 #include "qrect.h"
 #include "qrectf.h"
 #include "qregion.h"
@@ -87,7 +89,9 @@ main(int argc, char* argv[])
   runTest(QScopedPointer<QObject>(new QPointTest));
   runTest(QScopedPointer<QObject>(new QPointFTest));
   runTest(QScopedPointer<QObject>(new QQmlApplicationEngineTest));
+  runTest(QScopedPointer<QObject>(new QQmlComponentTest));
   runTest(QScopedPointer<QObject>(new QQmlEngineTest));
+  runTest(QScopedPointer<QObject>(new QQuickImageProviderTest));
   runTest(QScopedPointer<QObject>(new QRectTest));
   runTest(QScopedPointer<QObject>(new QRectFTest));
   runTest(QScopedPointer<QObject>(new QSetTest));
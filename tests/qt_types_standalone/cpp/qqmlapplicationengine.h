@@ -7,7 +7,10 @@
 #pragma once
 
 #include <QtCore/QCoreApplication>
+#include <QtCore/QFile>
+#include <QtCore/QTemporaryDir>
 #include <QtQml/QQmlApplicationEngine>
+#include <QtQml/QQmlError>
 #include <QtTest/QTest>
 
 #include "qt_types_standalone/src/qqmlapplicationengine.cxx.h"
@@ -44,4 +47,34 @@ private Q_SLOTS:
     engine.setBaseUrl(QUrl(QStringLiteral("qrc:/kdab.qml")));
     QVERIFY(read_qqmlapplicationengine(engine));
   }
+
+  void loadCheckedBrokenFile()
+  {
+    // QQmlEngine requires a QApplication
+    std::vector<char*> args;
+    std::string path = "/path";
+    args.push_back(path.data());
+    auto argc = static_cast<int>(args.size());
+    QCoreApplication app(argc, args.data());
+
+    QTemporaryDir dir;
+    QVERIFY(dir.isValid());
+    QFile file(dir.filePath(QStringLiteral("broken.qml")));
+    QVERIFY(file.open(QIODevice::WriteOnly));
+    file.write("import QtQml 2.0\n"
+               "\n"
+               "QtObject {\n"
+               "    notAProperty: 1\n"
+               "}\n");
+    file.close();
+
+    const auto url = QUrl::fromLocalFile(file.fileName());
+    QQmlApplicationEngine engine;
+    const auto error = load_checked_qqmlapplicationengine(engine, url);
+    QVERIFY(error.isValid());
+    QCOMPARE(error.url(), url);
+    QCOMPARE(error.line(), 4);
+    QVERIFY(error.description().contains(QStringLiteral("notAProperty")));
+    QVERIFY(engine.rootObjects().isEmpty());
+  }
 };
@@ -0,0 +1,83 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtGui/QAccessible>
+#include <QtGui/QGuiApplication>
+#include <QtGui/QWindow>
+#include <QtQml/QQmlEngine>
+#include <QtTest/QTest>
+
+#include <memory>
+
+#include "qt_types_standalone/src/qqmlcomponent.cxx.h"
+
+class QQmlComponentTest : public QObject
+{
+  Q_OBJECT
+
+private Q_SLOTS:
+  void createObjectTree()
+  {
+    // QtQuick requires a QGuiApplication, the tests run with the offscreen platform
+    std::vector<char*> args;
+    std::string path = "/path";
+    args.push_back(path.data());
+    auto argc = static_cast<int>(args.size());
+    QGuiApplication app(argc, args.data());
+
+    QQmlEngine engine;
+    std::unique_ptr<QObject> window(create_qqmlcomponent_window(engine));
+    QVERIFY(window != nullptr);
+    QCOMPARE(window->objectName(), QStringLiteral("window"));
+    QCOMPARE(window->property("title").toString(), QStringLiteral("Hello"));
+
+    const auto label = window->findChild<QObject*>(QStringLiteral("label"));
+    QVERIFY(label != nullptr);
+    QCOMPARE(label->property("text").toString(), QStringLiteral("Hello"));
+    QVERIFY(window->findChild<QObject*>(QStringLiteral("button")) != nullptr);
+  }
+
+  void inspectAccessibilityTree()
+  {
+    std::vector<char*> args;
+    std::string path = "/path";
+    args.push_back(path.data());
+    auto argc = static_cast<int>(args.size());
+    QGuiApplication app(argc, args.data());
+    QAccessible::setActive(true);
+
+    QQmlEngine engine;
+    std::unique_ptr<QObject> object(create_qqmlcomponent_window(engine));
+    const auto window = qobject_cast<QWindow*>(object.get());
+    QVERIFY(window != nullptr);
+    QVERIFY(QTest::qWaitForWindowExposed(window));
+
+    const auto windowInterface = QAccessible::queryAccessibleInterface(window);
+    QVERIFY(windowInterface != nullptr);
+    QCOMPARE(windowInterface->childCount(), 2);
+
+    QAccessibleInterface* label = nullptr;
+    QAccessibleInterface* button = nullptr;
+    for (int i = 0; i < windowInterface->childCount(); ++i) {
+      const auto child = windowInterface->child(i);
+      QVERIFY(child != nullptr);
+      QCOMPARE(child->parent(), windowInterface);
+      if (child->role() == QAccessible::StaticText) {
+        label = child;
+      } else if (child->role() == QAccessible::Button) {
+        button = child;
+      }
+    }
+
+    QVERIFY(label != nullptr);
+    QCOMPARE(label->text(QAccessible::Name), QStringLiteral("Hello"));
+    QVERIFY(button != nullptr);
+    QCOMPARE(button->text(QAccessible::Name), QStringLiteral("Press me"));
+
+    QAccessible::setActive(false);
+  }
+};
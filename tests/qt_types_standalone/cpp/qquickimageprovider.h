@@ -0,0 +1,75 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtGui/QGuiApplication>
+#include <QtQml/QQmlComponent>
+#include <QtQml/QQmlEngine>
+#include <QtTest/QTest>
+
+#include <memory>
+
+#include "qt_types_standalone/src/qquickimageprovider.cxx.h"
+
+class QQuickImageProviderTest : public QObject
+{
+  Q_OBJECT
+
+private Q_SLOTS:
+  void loadImages()
+  {
+    // QtQuick requires a QGuiApplication, the tests run with the offscreen platform
+    std::vector<char*> args;
+    std::string path = "/path";
+    args.push_back(path.data());
+    auto argc = static_cast<int>(args.size());
+    QGuiApplication app(argc, args.data());
+
+    QQmlEngine engine;
+    add_qquickimageproviders(engine);
+
+    QQmlComponent component(&engine);
+    component.setData("import QtQuick 2.12\n"
+                      "\n"
+                      "Item {\n"
+                      "    Image {\n"
+                      "        objectName: \"sync\"\n"
+                      "        source: \"image://colors/red\"\n"
+                      "    }\n"
+                      "    Image {\n"
+                      "        objectName: \"async\"\n"
+                      "        source: \"image://asynccolors/blue\"\n"
+                      "        sourceSize: Qt.size(8, 8)\n"
+                      "    }\n"
+                      "    Image {\n"
+                      "        objectName: \"missing\"\n"
+                      "        source: \"image://colors/green\"\n"
+                      "    }\n"
+                      "}\n",
+                      QUrl(QStringLiteral("qrc:/images.qml")));
+    std::unique_ptr<QObject> root(component.create());
+    QVERIFY2(root != nullptr, qPrintable(component.errorString()));
+
+    // The status of an Image is 1 once it is ready and 3 if there is an error
+    const auto sync = root->findChild<QObject*>(QStringLiteral("sync"));
+    QVERIFY(sync != nullptr);
+    QTRY_COMPARE(sync->property("status").toInt(), 1);
+    QCOMPARE(sync->property("implicitWidth").toReal(), 4.0);
+    QCOMPARE(sync->property("implicitHeight").toReal(), 2.0);
+
+    // The requested size is passed to the provider
+    const auto async = root->findChild<QObject*>(QStringLiteral("async"));
+    QVERIFY(async != nullptr);
+    QTRY_COMPARE(async->property("status").toInt(), 1);
+    QCOMPARE(async->property("implicitWidth").toReal(), 8.0);
+    QCOMPARE(async->property("implicitHeight").toReal(), 8.0);
+
+    // A null image is reported as an error
+    const auto missing = root->findChild<QObject*>(QStringLiteral("missing"));
+    QVERIFY(missing != nullptr);
+    QTRY_COMPARE(missing->property("status").toInt(), 3);
+  }
+};
@@ -33,7 +33,9 @@ fn main() {
         .file("src/qpolygon.rs")
         .file("src/qpolygonf.rs")
         .file("src/qqmlapplicationengine.rs")
+        .file("src/qqmlcomponent.rs")
         .file("src/qqmlengine.rs")
+        .file("src/qquickimageprovider.rs")
         .file("src/qrect.rs")
         .file("src/qrectf.rs")
         .file("src/qregion.rs")
@@ -30,7 +30,9 @@ mod qpointf;
 mod qpolygon;
 mod qpolygonf;
 mod qqmlapplicationengine;
+mod qqmlcomponent;
 mod qqmlengine;
+mod qquickimageprovider;
 mod qrect;
 mod qrectf;
 mod qregion;
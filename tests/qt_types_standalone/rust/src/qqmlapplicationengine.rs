@@ -3,18 +3,27 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
-use cxx_qt_lib::{QQmlApplicationEngine, QUrl};
+use core::pin::Pin;
+use cxx_qt_lib::{QQmlApplicationEngine, QQmlError, QUrl};
 
 #[cxx::bridge]
 mod qqmlapplicationengine_cxx {
     unsafe extern "C++" {
         include!("cxx-qt-lib/qqmlapplicationengine.h");
         type QQmlApplicationEngine = cxx_qt_lib::QQmlApplicationEngine;
+        include!("cxx-qt-lib/qqmlerror.h");
+        type QQmlError = cxx_qt_lib::QQmlError;
+        include!("cxx-qt-lib/qurl.h");
+        type QUrl = cxx_qt_lib::QUrl;
     }
 
     extern "Rust" {
         fn construct_qqmlapplicationengine() -> UniquePtr<QQmlApplicationEngine>;
         fn read_qqmlapplicationengine(c: &QQmlApplicationEngine) -> bool;
+        fn load_checked_qqmlapplicationengine(
+            engine: Pin<&mut QQmlApplicationEngine>,
+            url: &QUrl,
+        ) -> QQmlError;
     }
 }
 
@@ -29,3 +38,13 @@ fn construct_qqmlapplicationengine() -> cxx::UniquePtr<QQmlApplicationEngine> {
 fn read_qqmlapplicationengine(engine: &QQmlApplicationEngine) -> bool {
     engine.base_url().to_string() == "qrc:/kdab.qml"
 }
+
+fn load_checked_qqmlapplicationengine(
+    engine: Pin<&mut QQmlApplicationEngine>,
+    url: &QUrl,
+) -> QQmlError {
+    match engine.load_checked(url) {
+        Ok(_) => QQmlError::default(),
+        Err(errors) => errors.into_iter().next().unwrap_or_default(),
+    }
+}
@@ -0,0 +1,70 @@
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use core::pin::Pin;
+use cxx_qt::QObject;
+use cxx_qt_lib::{QByteArray, QQmlComponent, QQmlEngine, QString, QUrl, QVariant};
+
+#[cxx::bridge]
+mod qqmlcomponent_cxx {
+    unsafe extern "C++" {
+        include!(<QtCore/QObject>);
+        type QObject = cxx_qt::QObject;
+
+        include!("cxx-qt-lib/qqmlengine.h");
+        type QQmlEngine = cxx_qt_lib::QQmlEngine;
+    }
+
+    extern "Rust" {
+        fn create_qqmlcomponent_window(engine: Pin<&mut QQmlEngine>) -> *mut QObject;
+    }
+}
+
+const WINDOW_QML: &str = r#"
+import QtQuick 2.12
+import QtQuick.Window 2.12
+
+Window {
+    objectName: "window"
+    title: greeting
+    visible: true
+
+    Text {
+        objectName: "label"
+        text: greeting
+        Accessible.role: Accessible.StaticText
+        Accessible.name: text
+    }
+
+    Item {
+        objectName: "button"
+        Accessible.role: Accessible.Button
+        Accessible.name: "Press me"
+    }
+}
+"#;
+
+fn create_qqmlcomponent_window(mut engine: Pin<&mut QQmlEngine>) -> *mut QObject {
+    // Safety: the root context is owned by the engine, which outlives this function
+    if let Some(context) = unsafe { engine.as_ref().root_context().as_mut() } {
+        // Safety: the context is not moved out of the pointer
+        unsafe { Pin::new_unchecked(context) }.set_context_property(
+            &QString::from("greeting"),
+            &QVariant::from(&QString::from("Hello")),
+        );
+    }
+
+    let mut component = QQmlComponent::from_data(
+        engine,
+        &QByteArray::from(WINDOW_QML),
+        &QUrl::from("qrc:/window.qml"),
+    );
+    match component.as_mut() {
+        // Safety: a null context uses the root context of the engine
+        Some(component) if component.is_ready() => unsafe {
+            component.create(core::ptr::null_mut())
+        },
+        _ => core::ptr::null_mut(),
+    }
+}
@@ -0,0 +1,46 @@
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use core::pin::Pin;
+use cxx_qt_lib::{ImageProvider, QColor, QImage, QImageFormat, QQmlEngine, QSize, QString};
+
+#[cxx::bridge]
+mod qquickimageprovider_cxx {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qqmlengine.h");
+        type QQmlEngine = cxx_qt_lib::QQmlEngine;
+    }
+
+    extern "Rust" {
+        fn add_qquickimageproviders(engine: Pin<&mut QQmlEngine>);
+    }
+}
+
+struct ColorProvider;
+
+impl ImageProvider for ColorProvider {
+    fn request_image(&self, id: &QString, requested_size: &QSize) -> QImage {
+        let color = match String::from(id).as_str() {
+            "red" => QColor::from_rgb(255, 0, 0),
+            "blue" => QColor::from_rgb(0, 0, 255),
+            _ => return QImage::default(),
+        };
+        let (width, height) = if requested_size.is_valid() {
+            (requested_size.width(), requested_size.height())
+        } else {
+            (4, 2)
+        };
+        let mut image =
+            QImage::from_width_height_and_format(width, height, QImageFormat::Format_ARGB32);
+        image.fill(&color);
+        image
+    }
+}
+
+fn add_qquickimageproviders(mut engine: Pin<&mut QQmlEngine>) {
+    engine
+        .as_mut()
+        .add_image_provider(&QString::from("colors"), ColorProvider);
+    engine.add_async_image_provider(&QString::from("asynccolors"), ColorProvider);
+}